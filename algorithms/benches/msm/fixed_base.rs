@@ -0,0 +1,48 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_algorithms::msm::*;
+use snarkvm_curves::bls12_377::{Fr, G1Affine};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+#[macro_use]
+extern crate criterion;
+
+fn fixed_base_bls12_377(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+    let bases = (0..100_000).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+    let scalars = (0..100_000).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+
+    for size in [1_000, 10_000, 100_000] {
+        let table = FixedBaseMSMTable::new(&bases[..size]);
+        c.bench_function(&format!("FixedBase MSM on BLS12-377 ({size})"), |b| {
+            b.iter(|| FixedBase::msm_fixed_base(&table, &scalars[..size]))
+        });
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 ({size})"), |b| {
+            b.iter(|| VariableBase::msm(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
+criterion_group! {
+    name = fixed_base_group;
+    config = Criterion::default().sample_size(10);
+    targets = fixed_base_bls12_377
+}
+
+criterion_main!(fixed_base_group);
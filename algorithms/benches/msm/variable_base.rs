@@ -45,6 +45,34 @@ fn variable_base_bls12_377(c: &mut Criterion) {
     }
 }
 
+fn variable_base_bls12_377_glv(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1_000_000);
+
+    for size in [10_000, 100_000, 1_000_000] {
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 without GLV ({size})"), |b| {
+            b.iter(|| batched::msm(&bases[..size], &scalars[..size]))
+        });
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 with GLV ({size})"), |b| {
+            b.iter(|| glv::msm(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
+fn variable_base_bls12_377_g2(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G2Affine};
+    let (bases, scalars) = create_scalar_bases::<G2Affine, Fr>(100_000);
+
+    for size in [10_000, 100_000] {
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 G2 with projective buckets ({size})"), |b| {
+            b.iter(|| standard::msm(&bases[..size], &scalars[..size]))
+        });
+        c.bench_function(&format!("VariableBase MSM on BLS12-377 G2 with batched affine buckets ({size})"), |b| {
+            b.iter(|| batched::msm(&bases[..size], &scalars[..size]))
+        });
+    }
+}
+
 fn variable_base_edwards_bls12(c: &mut Criterion) {
     use snarkvm_curves::edwards_bls12::{EdwardsAffine, Fr};
     let (bases, scalars) = create_scalar_bases::<EdwardsAffine, Fr>(1_000_000);
@@ -59,7 +87,7 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_bls12_377_glv, variable_base_bls12_377_g2, variable_base_edwards_bls12
 }
 
 criterion_main!(variable_base_group);
@@ -0,0 +1,179 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! End-to-end benchmarks of committing, opening, proving and verifying, across representative circuit sizes.
+//!
+//! To guard against regressions, save a baseline with `cargo bench --bench end_to_end -- --save-baseline main`,
+//! and compare against it with `SNARKVM_BENCH_BASELINE=main cargo bench --bench end_to_end -- --baseline main`.
+//! The comparison fails if the mean time of any benchmark regresses by more than `SNARKVM_BENCH_THRESHOLD`
+//! percent (10 by default).
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::{
+    AlgebraicSponge,
+    SNARK,
+    crypto_hash::PoseidonSponge,
+    fft::DensePolynomial,
+    polycommit::sonic_pc::{CommitterUnionKey, LabeledPolynomial, SonicKZG10},
+    snark::varuna::{TestCircuit, VarunaHidingMode, VarunaSNARK, ahp::AHPForR1CS},
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::{BenchmarkId, Criterion};
+use std::{path::PathBuf, time::Duration};
+
+type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+type PC = SonicKZG10<Bls12_377, FS>;
+type FS = PoseidonSponge<Fq, 2, 1>;
+
+/// The name of the benchmark group.
+const GROUP: &str = "end_to_end";
+/// The number of constraints and variables of the benchmarked circuits, and the degrees of the committed polynomials.
+const SIZES: [usize; 3] = [1 << 8, 1 << 10, 1 << 12];
+/// The default regression threshold, in percent.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn end_to_end(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let max_size = SIZES[SIZES.len() - 1];
+    let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(max_size, max_size, max_size).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let mut group = c.benchmark_group(GROUP);
+    group.measurement_time(Duration::from_secs(10));
+    for size in SIZES {
+        // Commit to and open a polynomial of degree `size`.
+        let (ck, _) = PC::trim(&universal_srs, size, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+        let polynomial = LabeledPolynomial::new("p".to_string(), DensePolynomial::rand(size, rng), None, Some(1));
+        group.bench_with_input(BenchmarkId::new("commit", size), &polynomial, |b, polynomial| {
+            b.iter(|| PC::commit(universal_prover, &ck, [polynomial.into()], Some(&mut TestRng::default())).unwrap())
+        });
+
+        let (commitments, randomness) =
+            PC::commit(universal_prover, &ck, [(&polynomial).into()], Some(&mut TestRng::default())).unwrap();
+        let point = Fr::rand(rng);
+        group.bench_with_input(BenchmarkId::new("open", size), &polynomial, |b, polynomial| {
+            b.iter(|| {
+                PC::open_at_point(
+                    universal_prover,
+                    &ck,
+                    [polynomial].into_iter(),
+                    point,
+                    randomness.iter(),
+                    &mut FS::new(),
+                )
+                .unwrap()
+            })
+        });
+        let proof = PC::open_at_point(
+            universal_prover,
+            &ck,
+            [&polynomial].into_iter(),
+            point,
+            randomness.iter(),
+            &mut FS::new(),
+        )
+        .unwrap();
+        let value = polynomial.evaluate(point);
+        assert!(
+            PC::check_at_point(
+                universal_verifier,
+                commitments.iter(),
+                point,
+                [value].into_iter(),
+                &proof,
+                &mut FS::new()
+            )
+            .unwrap()
+        );
+
+        // Prove and verify a circuit with `size` constraints and variables.
+        let (circuit, public_inputs) = TestCircuit::gen_rand(1, size, size, rng);
+        let (pk, vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        group.bench_with_input(BenchmarkId::new("prove", size), &circuit, |b, circuit| {
+            b.iter(|| {
+                VarunaInst::prove(universal_prover, &fs_parameters, &pk, circuit, &mut TestRng::default()).unwrap()
+            })
+        });
+
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &pk, &circuit, rng).unwrap();
+        group.bench_with_input(BenchmarkId::new("verify", size), &proof, |b, proof| {
+            b.iter(|| {
+                assert!(
+                    VarunaInst::verify(universal_verifier, &fs_parameters, &vk, public_inputs.as_slice(), proof)
+                        .unwrap()
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Returns the mean time in nanoseconds of the benchmark `id` in the given criterion directory, if it was measured.
+fn mean_time(directory: &str, id: &str) -> Option<f64> {
+    let criterion_home = match std::env::var("CRITERION_HOME") {
+        Ok(criterion_home) => PathBuf::from(criterion_home),
+        Err(_) => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/criterion"),
+    };
+    let estimates = std::fs::read_to_string(criterion_home.join(GROUP).join(id).join(directory).join("estimates.json"));
+    let estimates: serde_json::Value = serde_json::from_str(&estimates.ok()?).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+/// Ensures that no benchmark regressed beyond the threshold, relative to the baseline in `SNARKVM_BENCH_BASELINE`.
+fn check_regressions() {
+    let Ok(baseline) = std::env::var("SNARKVM_BENCH_BASELINE") else {
+        return;
+    };
+    let threshold = match std::env::var("SNARKVM_BENCH_THRESHOLD") {
+        Ok(threshold) => threshold.parse::<f64>().expect("SNARKVM_BENCH_THRESHOLD must be a number"),
+        Err(_) => DEFAULT_THRESHOLD,
+    };
+
+    let mut regressions = Vec::new();
+    for stage in ["commit", "open", "prove", "verify"] {
+        for size in SIZES {
+            let id = format!("{stage}/{size}");
+            let (Some(old), Some(new)) = (mean_time(&baseline, &id), mean_time("new", &id)) else {
+                continue;
+            };
+            let change = (new - old) / old * 100.0;
+            if change > threshold {
+                regressions.push(format!("{GROUP}/{id} regressed by {change:.2}% (threshold {threshold}%)"));
+            }
+        }
+    }
+    assert!(regressions.is_empty(), "Performance regressions against '{baseline}':\n{}", regressions.join("\n"));
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = end_to_end,
+}
+
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+    check_regressions();
+}
@@ -0,0 +1,68 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::SNARKError;
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+/// A token that is shared between the caller and a long-running computation, such as proving,
+/// which the caller can use to request that the computation stops early.
+///
+/// The computation checks the token at well-defined points, such as between the rounds of a prover,
+/// and fails with `SNARKError::Cancelled` once cancellation has been requested.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Initializes a new token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of every computation that holds a clone of this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns an error if cancellation has been requested.
+    pub fn check(&self) -> Result<(), SNARKError> {
+        match self.is_cancelled() {
+            true => Err(SNARKError::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Runs `f` on the blocking thread pool of the current Tokio runtime, unless `cancellation`
+/// is cancelled before it starts. The output is discarded if cancellation is requested while it runs.
+#[cfg(feature = "async")]
+pub(crate) async fn spawn_blocking_cancellable<T: Send + 'static>(
+    cancellation: CancellationToken,
+    f: impl FnOnce(&CancellationToken) -> anyhow::Result<T> + Send + 'static,
+) -> anyhow::Result<T> {
+    cancellation.check()?;
+    let token = cancellation.clone();
+    let output = tokio::task::spawn_blocking(move || f(&token)).await??;
+    cancellation.check()?;
+    Ok(output)
+}
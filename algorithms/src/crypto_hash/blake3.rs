@@ -0,0 +1,318 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A portable implementation of the BLAKE3 hash function.
+//!
+//! This implementation follows the [BLAKE3 specification](https://github.com/BLAKE3-team/BLAKE3-specs),
+//! and supports the regular, keyed, and key derivation modes, with an incremental hashing API
+//! and extendable output.
+
+/// The number of bytes in a BLAKE3 digest.
+pub const BLAKE3_OUT_LEN: usize = 32;
+/// The number of bytes in a BLAKE3 key.
+pub const BLAKE3_KEY_LEN: usize = 32;
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+const KEYED_HASH: u32 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+const IV: [u32; 8] = [0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19];
+
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+/// Returns the BLAKE3 hash of the given data.
+pub fn blake3(data: &[u8]) -> [u8; BLAKE3_OUT_LEN] {
+    Blake3::new().update(data).finalize()
+}
+
+/// An incremental BLAKE3 hasher.
+///
+/// Input may be supplied across any number of calls to `update`,
+/// and the digest is independent of how the input is split.
+#[derive(Clone, Debug)]
+pub struct Blake3 {
+    /// The state of the chunk currently being absorbed.
+    chunk_state: ChunkState,
+    /// The key words, which are the IV outside of the keyed modes.
+    key_words: [u32; 8],
+    /// The chaining values of the completed subtrees, from the largest to the smallest.
+    cv_stack: Vec<[u32; 8]>,
+    /// The mode flags applied to every compression.
+    flags: u32,
+}
+
+impl Default for Blake3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Blake3 {
+    /// Initializes a new hasher for the regular hash function.
+    pub fn new() -> Self {
+        Self::new_internal(IV, 0)
+    }
+
+    /// Initializes a new hasher for the keyed hash function.
+    pub fn new_keyed(key: &[u8; BLAKE3_KEY_LEN]) -> Self {
+        Self::new_internal(words_from_le_bytes(key), KEYED_HASH)
+    }
+
+    /// Initializes a new hasher for the key derivation function.
+    ///
+    /// The context string should be hardcoded, globally unique, and application-specific.
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_key = [0u8; BLAKE3_KEY_LEN];
+        Self::new_internal(IV, DERIVE_KEY_CONTEXT).update(context.as_bytes()).finalize_xof(&mut context_key);
+        Self::new_internal(words_from_le_bytes(&context_key), DERIVE_KEY_MATERIAL)
+    }
+
+    fn new_internal(key_words: [u32; 8], flags: u32) -> Self {
+        Self { chunk_state: ChunkState::new(key_words, 0, flags), key_words, cv_stack: Vec::new(), flags }
+    }
+
+    /// Absorbs the given input into the hasher.
+    pub fn update(&mut self, mut input: &[u8]) -> &mut Self {
+        while !input.is_empty() {
+            // If the current chunk is complete, finalize it and start a new one.
+            // The last chunk is only finalized in `finalize`, as it may be the root.
+            if self.chunk_state.len() == CHUNK_LEN {
+                let chunk_cv = self.chunk_state.output().chaining_value();
+                let total_chunks = self.chunk_state.chunk_counter + 1;
+                self.push_chunk_chaining_value(chunk_cv, total_chunks);
+                self.chunk_state = ChunkState::new(self.key_words, total_chunks, self.flags);
+            }
+
+            let num_bytes = std::cmp::min(CHUNK_LEN - self.chunk_state.len(), input.len());
+            self.chunk_state.update(&input[..num_bytes]);
+            input = &input[num_bytes..];
+        }
+        self
+    }
+
+    /// Returns the 32-byte digest of the input absorbed so far.
+    pub fn finalize(&self) -> [u8; BLAKE3_OUT_LEN] {
+        let mut output = [0u8; BLAKE3_OUT_LEN];
+        self.finalize_xof(&mut output);
+        output
+    }
+
+    /// Fills the given buffer with the extendable output of the input absorbed so far.
+    /// The first 32 bytes of the output are equal to the digest from `finalize`.
+    pub fn finalize_xof(&self, output: &mut [u8]) {
+        // Merge the pending subtrees, from the smallest to the largest, into the root.
+        let mut node = self.chunk_state.output();
+        for cv in self.cv_stack.iter().rev() {
+            node = Output::parent(cv, &node.chaining_value(), self.key_words, self.flags);
+        }
+        node.root_output_bytes(output);
+    }
+
+    /// Merges the chaining value of a completed chunk into the stack of completed subtrees.
+    fn push_chunk_chaining_value(&mut self, mut cv: [u32; 8], mut total_chunks: u64) {
+        // Each trailing zero bit of the chunk count marks a completed subtree to merge with.
+        while total_chunks & 1 == 0 {
+            let left = self.cv_stack.pop().expect("The chaining value stack is missing a subtree");
+            cv = Output::parent(&left, &cv, self.key_words, self.flags).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack.push(cv);
+    }
+}
+
+/// The state of a chunk of up to `CHUNK_LEN` bytes.
+#[derive(Clone, Debug)]
+struct ChunkState {
+    chaining_value: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32,
+}
+
+impl ChunkState {
+    fn new(key_words: [u32; 8], chunk_counter: u64, flags: u32) -> Self {
+        Self {
+            chaining_value: key_words,
+            chunk_counter,
+            block: [0u8; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+            flags,
+        }
+    }
+
+    /// Returns the number of bytes absorbed into this chunk.
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    /// Returns the flag for the first block of the chunk.
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 { CHUNK_START } else { 0 }
+    }
+
+    fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // If the block buffer is full, compress it. The last block is only
+            // compressed in `output`, as it must be flagged as the end of the chunk.
+            if self.block_len == BLOCK_LEN {
+                let block_words = words_from_le_bytes(&self.block);
+                let flags = self.flags | self.start_flag();
+                let output = compress(&self.chaining_value, &block_words, self.chunk_counter, BLOCK_LEN as u32, flags);
+                self.chaining_value = first_8_words(output);
+                self.blocks_compressed += 1;
+                self.block = [0u8; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let num_bytes = std::cmp::min(BLOCK_LEN - self.block_len, input.len());
+            self.block[self.block_len..self.block_len + num_bytes].copy_from_slice(&input[..num_bytes]);
+            self.block_len += num_bytes;
+            input = &input[num_bytes..];
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            input_chaining_value: self.chaining_value,
+            block_words: words_from_le_bytes(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.flags | self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+/// The inputs to the final compression of a node,
+/// which yield either its chaining value or the root output.
+struct Output {
+    input_chaining_value: [u32; 8],
+    block_words: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl Output {
+    /// Returns the output of the parent node of the given children.
+    fn parent(left: &[u32; 8], right: &[u32; 8], key_words: [u32; 8], flags: u32) -> Self {
+        let mut block_words = [0u32; 16];
+        block_words[..8].copy_from_slice(left);
+        block_words[8..].copy_from_slice(right);
+        Self {
+            input_chaining_value: key_words,
+            block_words,
+            counter: 0,
+            block_len: BLOCK_LEN as u32,
+            flags: PARENT | flags,
+        }
+    }
+
+    fn chaining_value(&self) -> [u32; 8] {
+        first_8_words(compress(&self.input_chaining_value, &self.block_words, self.counter, self.block_len, self.flags))
+    }
+
+    fn root_output_bytes(&self, output: &mut [u8]) {
+        for (output_block_counter, output_block) in output.chunks_mut(BLOCK_LEN).enumerate() {
+            let words = compress(
+                &self.input_chaining_value,
+                &self.block_words,
+                output_block_counter as u64,
+                self.block_len,
+                self.flags | ROOT,
+            );
+            for (word, bytes) in words.iter().zip(output_block.chunks_mut(4)) {
+                bytes.copy_from_slice(&word.to_le_bytes()[..bytes.len()]);
+            }
+        }
+    }
+}
+
+/// The quarter-round function, which mixes two message words into four state words.
+#[inline(always)]
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, mx: u32, my: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(mx);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(my);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+#[inline(always)]
+fn round(state: &mut [u32; 16], m: &[u32; 16]) {
+    // Mix the columns.
+    g(state, 0, 4, 8, 12, m[0], m[1]);
+    g(state, 1, 5, 9, 13, m[2], m[3]);
+    g(state, 2, 6, 10, 14, m[4], m[5]);
+    g(state, 3, 7, 11, 15, m[6], m[7]);
+    // Mix the diagonals.
+    g(state, 0, 5, 10, 15, m[8], m[9]);
+    g(state, 1, 6, 11, 12, m[10], m[11]);
+    g(state, 2, 7, 8, 13, m[12], m[13]);
+    g(state, 3, 4, 9, 14, m[14], m[15]);
+}
+
+/// The compression function, which returns the full 16-word output state.
+fn compress(chaining_value: &[u32; 8], block_words: &[u32; 16], counter: u64, block_len: u32, flags: u32) -> [u32; 16] {
+    #[rustfmt::skip]
+    let mut state = [
+        chaining_value[0], chaining_value[1], chaining_value[2], chaining_value[3],
+        chaining_value[4], chaining_value[5], chaining_value[6], chaining_value[7],
+        IV[0], IV[1], IV[2], IV[3],
+        counter as u32, (counter >> 32) as u32, block_len, flags,
+    ];
+
+    let mut block = *block_words;
+    for i in 0..7 {
+        round(&mut state, &block);
+        // Permute the message words between rounds.
+        if i < 6 {
+            block = MSG_PERMUTATION.map(|j| block[j]);
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= chaining_value[i];
+    }
+    state
+}
+
+fn first_8_words(words: [u32; 16]) -> [u32; 8] {
+    let mut output = [0u32; 8];
+    output.copy_from_slice(&words[..8]);
+    output
+}
+
+/// Returns the little-endian words of the given bytes.
+fn words_from_le_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
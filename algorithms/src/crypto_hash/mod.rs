@@ -13,11 +13,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod blake3;
+pub use blake3::*;
+
+pub mod keccak256;
+pub use keccak256::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
 pub mod sha256;
 pub use sha256::*;
 
+pub mod transcript;
+pub use transcript::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -15,6 +15,7 @@
 
 use sha2::{Digest, Sha256};
 
+/// Returns the SHA-256 hash of the given data.
 pub fn sha256(data: &[u8]) -> [u8; 32] {
     let digest = Sha256::digest(data);
     let mut ret = [0u8; 32];
@@ -22,6 +23,7 @@ pub fn sha256(data: &[u8]) -> [u8; 32] {
     ret
 }
 
+/// Returns the SHA-256 hash of the SHA-256 hash of the given data.
 pub fn double_sha256(data: &[u8]) -> [u8; 32] {
     let digest = Sha256::digest(Sha256::digest(data));
     let mut ret = [0u8; 32];
@@ -29,6 +31,7 @@ pub fn double_sha256(data: &[u8]) -> [u8; 32] {
     ret
 }
 
+/// Returns the first 8 bytes of the double SHA-256 hash of the given data as a little-endian `u64`.
 pub fn sha256d_to_u64(data: &[u8]) -> u64 {
     let hash_slice = double_sha256(data);
     let mut hash = [0u8; 8];
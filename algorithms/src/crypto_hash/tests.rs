@@ -13,9 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AlgebraicSponge, DuplexSpongeMode, crypto_hash::PoseidonSponge};
+use crate::{
+    AlgebraicSponge,
+    DuplexSpongeMode,
+    crypto_hash::{Blake3, PoseidonSponge, Transcript, blake3, double_sha256, keccak256, sha256},
+};
 use snarkvm_curves::bls12_377::Fr;
-use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR, PoseidonParameters};
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -87,3 +91,163 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_poseidon_secure_round_numbers() {
+    fn single_rate_test<const RATE: usize>() {
+        // The default parameters use the secure round numbers at 128 bits of security.
+        let params = Fr::default_poseidon_parameters::<RATE>().unwrap();
+        let (full_rounds, partial_rounds) = PoseidonParameters::<Fr, RATE, 1>::secure_round_numbers(17, 128).unwrap();
+        assert_eq!((params.full_rounds, params.partial_rounds), (full_rounds, partial_rounds));
+    }
+    single_rate_test::<2>();
+    single_rate_test::<4>();
+    single_rate_test::<8>();
+
+    // Ensure the S-box exponent and security level are checked.
+    assert!(PoseidonParameters::<Fr, 2, 1>::secure_round_numbers(2, 128).is_err());
+    assert!(PoseidonParameters::<Fr, 2, 1>::secure_round_numbers(17, 0).is_err());
+}
+
+#[test]
+fn test_poseidon_parameters_security() {
+    fn single_rate_test<const RATE: usize>() {
+        // Ensure the default parameters pass the security checks.
+        let params = Fr::default_poseidon_parameters::<RATE>().unwrap();
+        params.check_security(128).unwrap();
+
+        // Ensure the secure parameters match the default parameters.
+        assert_eq!(PoseidonParameters::<Fr, RATE, 1>::new_secure(17, 128).unwrap(), params);
+    }
+    single_rate_test::<2>();
+    single_rate_test::<3>();
+    single_rate_test::<4>();
+    single_rate_test::<8>();
+
+    // Ensure parameters for a wider capacity pass the security checks.
+    let params = PoseidonParameters::<Fr, 4, 2>::new_secure(17, 128).unwrap();
+    params.check_security(128).unwrap();
+
+    // Ensure too few rounds fail the security checks.
+    let params = PoseidonParameters::<Fr, 2, 1>::new(17, 8, 10).unwrap();
+    assert!(params.check_security(128).is_err());
+    // Ensure malformed parameters fail the security checks.
+    let mut params = Fr::default_poseidon_parameters::<2>().unwrap();
+    params.mds.pop();
+    assert!(params.check_security(128).is_err());
+}
+
+#[test]
+fn test_absorb_domain() {
+    let sponge = PoseidonSponge::<Fr, 2, 1>::new();
+
+    let squeeze_after = |domains: &[&str]| {
+        let mut sponge = sponge.clone();
+        domains.iter().for_each(|domain| sponge.absorb_domain(domain));
+        sponge.squeeze_native_field_elements(1)[0]
+    };
+    // Ensure distinct sequences of tags are separated.
+    assert_eq!(squeeze_after(&["ab", "c"]), squeeze_after(&["ab", "c"]));
+    assert_ne!(squeeze_after(&["ab", "c"]), squeeze_after(&["a", "bc"]));
+    assert_ne!(squeeze_after(&["a"]), squeeze_after(&["\0a"]));
+    assert_ne!(squeeze_after(&[]), squeeze_after(&[""]));
+}
+
+#[test]
+fn test_transcript() {
+    type TestTranscript = Transcript<Fr, 2, PoseidonSponge<Fr, 2, 1>>;
+    let parameters = PoseidonSponge::<Fr, 2, 1>::sample_parameters();
+
+    let challenge = |protocol: &str, messages: &[(&str, &[u8])], label: &str| {
+        let mut transcript = TestTranscript::new(protocol, &parameters);
+        messages.iter().for_each(|(label, message)| transcript.append_bytes(label, message));
+        transcript.challenge_field_elements(label, 1)[0]
+    };
+    let expected = challenge("protocol", &[("a", b"xy"), ("b", b"z")], "c");
+    // Ensure the transcript is deterministic.
+    assert_eq!(expected, challenge("protocol", &[("a", b"xy"), ("b", b"z")], "c"));
+    // Ensure the protocol, labels, and message boundaries are bound to the challenges.
+    assert_ne!(expected, challenge("other", &[("a", b"xy"), ("b", b"z")], "c"));
+    assert_ne!(expected, challenge("protocol", &[("b", b"xy"), ("a", b"z")], "c"));
+    assert_ne!(expected, challenge("protocol", &[("a", b"x"), ("b", b"yz")], "c"));
+    assert_ne!(expected, challenge("protocol", &[("a", b"xy"), ("b", b"z")], "d"));
+
+    // Ensure successive challenges are distinct.
+    let mut transcript = TestTranscript::new("protocol", &parameters);
+    transcript.append_field_elements("field", &[Fr::from(1u64), Fr::from(2u64)]);
+    let first = transcript.challenge_field_elements("challenge", 2);
+    let second = transcript.challenge_field_elements("challenge", 2);
+    assert_ne!(first, second);
+    assert_ne!(first[0], first[1]);
+}
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn test_keccak256() {
+    // Reference values for the Ethereum `keccak256` function.
+    assert_eq!(keccak256(b"").to_vec(), from_hex("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"));
+    assert_eq!(
+        keccak256(b"hello world").to_vec(),
+        from_hex("47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad")
+    );
+}
+
+#[test]
+fn test_sha256() {
+    // Reference values from FIPS 180-4.
+    assert_eq!(sha256(b"").to_vec(), from_hex("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"));
+    assert_eq!(sha256(b"abc").to_vec(), from_hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"));
+    assert_eq!(double_sha256(b"abc"), sha256(&sha256(b"abc")));
+}
+
+#[test]
+fn test_blake3() {
+    // Reference values from the BLAKE3 test vectors.
+    assert_eq!(blake3(b"").to_vec(), from_hex("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"));
+    assert_eq!(blake3(b"abc").to_vec(), from_hex("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"));
+    assert_eq!(
+        Blake3::new_keyed(b"whats the Elvish word for friend").finalize().to_vec(),
+        from_hex("92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26")
+    );
+    assert_eq!(
+        Blake3::new_derive_key("BLAKE3 2019-12-27 16:29:52 test vectors context").finalize().to_vec(),
+        from_hex("2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d")
+    );
+
+    // Inputs spanning multiple chunks use the byte pattern `i % 251`.
+    let input = |len: usize| (0..len).map(|i| (i % 251) as u8).collect_vec();
+    assert_eq!(
+        blake3(&input(1025)).to_vec(),
+        from_hex("d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444")
+    );
+    assert_eq!(
+        blake3(&input(2048)).to_vec(),
+        from_hex("e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a")
+    );
+}
+
+#[test]
+fn test_blake3_incremental() {
+    // Cover inputs within a block, within a chunk, and across several levels of the tree.
+    for len in [0, 1, 63, 64, 65, 1023, 1024, 1025, 2048, 2049, 3072, 3073, 4096, 4097, 5120, 8193, 31745] {
+        let input = (0..len).map(|i| (i % 251) as u8).collect_vec();
+        let expected = blake3(&input);
+
+        // Ensure the digest is independent of how the input is split.
+        for step in [1, 7, 64, 1000, 1024, 1025] {
+            let mut hasher = Blake3::new();
+            input.chunks(step).for_each(|chunk| {
+                hasher.update(chunk);
+            });
+            assert_eq!(hasher.finalize(), expected, "Mismatch for length {len} with step {step}");
+        }
+
+        // Ensure the extendable output begins with the digest.
+        let mut output = [0u8; 200];
+        Blake3::new().update(&input).finalize_xof(&mut output);
+        assert_eq!(output[..32], expected);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use smallvec::SmallVec;
+use std::marker::PhantomData;
+
+/// A Fiat-Shamir transcript over an algebraic sponge.
+///
+/// The transcript is bound to a protocol name on initialization, and every message and challenge
+/// is bound to a label. Labels and message lengths are absorbed before the message itself,
+/// so transcripts with different labels or message boundaries yield independent challenges.
+#[derive(Clone, Debug)]
+pub struct Transcript<F: PrimeField, const RATE: usize, S: AlgebraicSponge<F, RATE>> {
+    /// The underlying sponge.
+    sponge: S,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, const RATE: usize, S: AlgebraicSponge<F, RATE>> Transcript<F, RATE, S> {
+    /// Initializes a new transcript for the given protocol, using a sponge with the given parameters.
+    pub fn new(protocol: &str, parameters: &S::Parameters) -> Self {
+        Self::from_sponge(protocol, S::new_with_parameters(parameters))
+    }
+
+    /// Initializes a new transcript for the given protocol, continuing from the given sponge.
+    pub fn from_sponge(protocol: &str, mut sponge: S) -> Self {
+        sponge.absorb_domain(protocol);
+        Self { sponge, _field: PhantomData }
+    }
+
+    /// Appends a labeled message of native field elements.
+    pub fn append_field_elements<T: ToConstraintField<F>>(&mut self, label: &str, elements: &[T]) {
+        let elements = elements.iter().flat_map(|e| e.to_field_elements().unwrap()).collect::<Vec<_>>();
+        self.append_message_header(label, elements.len());
+        self.sponge.absorb_native_field_elements(&elements);
+    }
+
+    /// Appends a labeled message of non-native field elements.
+    pub fn append_nonnative_field_elements<Target: PrimeField>(&mut self, label: &str, elements: &[Target]) {
+        self.append_message_header(label, elements.len());
+        self.sponge.absorb_nonnative_field_elements(elements.iter().copied());
+    }
+
+    /// Appends a labeled message of bytes.
+    pub fn append_bytes(&mut self, label: &str, bytes: &[u8]) {
+        self.append_message_header(label, bytes.len());
+        self.sponge.absorb_bytes(bytes);
+    }
+
+    /// Returns the given number of labeled native field element challenges.
+    pub fn challenge_field_elements(&mut self, label: &str, num: usize) -> SmallVec<[F; 10]> {
+        self.sponge.absorb_domain(label);
+        self.sponge.squeeze_native_field_elements(num)
+    }
+
+    /// Returns the given number of labeled non-native field element challenges.
+    pub fn challenge_nonnative_field_elements<Target: PrimeField>(
+        &mut self,
+        label: &str,
+        num: usize,
+    ) -> SmallVec<[Target; 10]> {
+        self.sponge.absorb_domain(label);
+        self.sponge.squeeze_nonnative_field_elements(num)
+    }
+
+    /// Returns the given number of labeled non-native field element challenges of 168 bits.
+    pub fn challenge_short_nonnative_field_elements<Target: PrimeField>(
+        &mut self,
+        label: &str,
+        num: usize,
+    ) -> SmallVec<[Target; 10]> {
+        self.sponge.absorb_domain(label);
+        self.sponge.squeeze_short_nonnative_field_elements(num)
+    }
+
+    /// Returns the underlying sponge.
+    pub fn sponge(&self) -> &S {
+        &self.sponge
+    }
+
+    /// Returns the underlying sponge, consuming the transcript.
+    pub fn into_sponge(self) -> S {
+        self.sponge
+    }
+
+    /// Absorbs the label and length of a message.
+    fn append_message_header(&mut self, label: &str, len: usize) {
+        self.sponge.absorb_domain(label);
+        self.sponge.absorb_native_field_elements(&[F::from(len as u64)]);
+    }
+}
@@ -47,6 +47,9 @@ pub enum SNARKError {
 
     #[error("Circuit not found")]
     CircuitNotFound,
+
+    #[error("The computation was cancelled")]
+    Cancelled,
 }
 
 impl From<AHPError> for SNARKError {
@@ -0,0 +1,79 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The backend used to compute FFTs over an `EvaluationDomain`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FFTBackend {
+    /// Computes FFTs on the CPU.
+    Cpu,
+    /// Computes FFTs on the GPU when the domain has at least `threshold` elements.
+    /// If the GPU is unavailable or fails, the FFT falls back to the CPU.
+    ///
+    /// Note that this requires the `cuda` feature; without it, this is equivalent to `FFTBackend::Cpu`.
+    Gpu { threshold: usize },
+}
+
+/// The GPU threshold of the process-wide backend, or `usize::MAX` if FFTs are computed on the CPU.
+static GPU_THRESHOLD: AtomicUsize = AtomicUsize::new(match FFTBackend::is_gpu_available() {
+    true => FFTBackend::DEFAULT_GPU_THRESHOLD,
+    false => usize::MAX,
+});
+
+impl FFTBackend {
+    /// The default minimum domain size for an FFT to be offloaded to the GPU.
+    pub const DEFAULT_GPU_THRESHOLD: usize = 1 << 5;
+
+    /// Returns `true` if the GPU backend is compiled in.
+    pub const fn is_gpu_available() -> bool {
+        cfg!(all(feature = "cuda", target_arch = "x86_64"))
+    }
+
+    /// Returns the backend that is currently used by all FFTs in this process.
+    pub fn global() -> Self {
+        match GPU_THRESHOLD.load(Ordering::Relaxed) {
+            usize::MAX => Self::Cpu,
+            threshold => Self::Gpu { threshold },
+        }
+    }
+
+    /// Sets the backend that is used by all FFTs in this process.
+    pub fn set_global(self) {
+        let threshold = match self {
+            Self::Cpu => usize::MAX,
+            Self::Gpu { threshold } => threshold.min(usize::MAX - 1),
+        };
+        GPU_THRESHOLD.store(threshold, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an FFT over a domain of the given size is offloaded to the GPU.
+    pub fn uses_gpu(&self, domain_size: usize) -> bool {
+        match self {
+            Self::Cpu => false,
+            Self::Gpu { threshold } => Self::is_gpu_available() && domain_size >= *threshold,
+        }
+    }
+}
+
+impl Default for FFTBackend {
+    /// Returns the GPU backend if it is compiled in, and the CPU backend otherwise.
+    fn default() -> Self {
+        match Self::is_gpu_available() {
+            true => Self::Gpu { threshold: Self::DEFAULT_GPU_THRESHOLD },
+            false => Self::Cpu,
+        }
+    }
+}
@@ -27,12 +27,11 @@
 
 use crate::{
     cfg_chunks_mut,
-    cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{DomainCoeff, SixStepConfig, SparsePolynomial},
 };
-use snarkvm_fields::{FftField, FftParameters, Field, batch_inversion};
+use snarkvm_fields::{FftField, FftParameters, Field, batch_inverse};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
@@ -189,6 +188,63 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
+    /// Compute an FFT in place, reusing the roots of unity in `pre_comp` and the `scratch` buffer.
+    ///
+    /// Unlike `fft_in_place`, this neither resizes `coeffs`, nor recomputes the roots of unity, nor builds a
+    /// thread pool. If `pre_comp` is for this domain, it does not allocate once `scratch` has grown to at most
+    /// a quarter of the size of the domain, so the same buffers can be reused across calls.
+    pub fn fft_in_place_with_buffers<T: DomainCoeff<F>>(
+        &self,
+        coeffs: &mut [T],
+        pre_comp: &FFTPrecomputation<F>,
+        scratch: &mut Vec<F>,
+    ) -> Result<()> {
+        ensure!(coeffs.len() == self.size(), "Expected {} coefficients, found {}", self.size(), coeffs.len());
+        ensure!(pre_comp.domain.size() >= self.size(), "The FFT precomputation is for a smaller domain");
+        self.fft_helper_in_place_with_pc_and_scratch(coeffs, FFTOrder::II, pre_comp, scratch);
+        Ok(())
+    }
+
+    /// Compute an IFFT in place, reusing the inverse roots of unity in `pre_comp` and the `scratch` buffer.
+    ///
+    /// Unlike `ifft_in_place`, this neither resizes `evals`, nor recomputes the roots of unity, nor builds a
+    /// thread pool. If `pre_comp` is for this domain, it does not allocate once `scratch` has grown to at most
+    /// a quarter of the size of the domain, so the same buffers can be reused across calls.
+    pub fn ifft_in_place_with_buffers<T: DomainCoeff<F>>(
+        &self,
+        evals: &mut [T],
+        pre_comp: &IFFTPrecomputation<F>,
+        scratch: &mut Vec<F>,
+    ) -> Result<()> {
+        ensure!(evals.len() == self.size(), "Expected {} evaluations, found {}", self.size(), evals.len());
+        ensure!(pre_comp.domain.size() >= self.size(), "The IFFT precomputation is for a smaller domain");
+        self.ifft_helper_in_place_with_pc_and_scratch(evals, FFTOrder::II, pre_comp, scratch);
+        cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+        Ok(())
+    }
+
+    /// Compute the FFTs of a batch of vectors, modifying them in place.
+    ///
+    /// If the domain is offloaded to the GPU, the FFTs are computed one after another so that their
+    /// transfers to the device do not contend with each other. Otherwise, they are computed in parallel.
+    pub fn fft_in_place_batch<T: DomainCoeff<F>>(&self, batch: &mut [Vec<T>]) {
+        match crate::fft::FFTBackend::global().uses_gpu(self.size()) {
+            true => batch.iter_mut().for_each(|coeffs| self.fft_in_place(coeffs)),
+            false => cfg_iter_mut!(batch).for_each(|coeffs| self.fft_in_place(coeffs)),
+        }
+    }
+
+    /// Compute the IFFTs of a batch of vectors, modifying them in place.
+    ///
+    /// If the domain is offloaded to the GPU, the IFFTs are computed one after another so that their
+    /// transfers to the device do not contend with each other. Otherwise, they are computed in parallel.
+    pub fn ifft_in_place_batch<T: DomainCoeff<F>>(&self, batch: &mut [Vec<T>]) {
+        match crate::fft::FFTBackend::global().uses_gpu(self.size()) {
+            true => batch.iter_mut().for_each(|evals| self.ifft_in_place(evals)),
+            false => cfg_iter_mut!(batch).for_each(|evals| self.ifft_in_place(evals)),
+        }
+    }
+
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -221,7 +277,7 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
-    fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
+    pub(crate) fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
 
@@ -283,7 +339,7 @@ impl<F: FftField> EvaluationDomain<F> {
                 r *= &self.group_gen;
             }
 
-            batch_inversion(u.as_mut_slice());
+            batch_inverse(u.as_mut_slice());
             cfg_iter_mut!(u).zip_eq(ls).for_each(|(tau_minus_r, l)| {
                 *tau_minus_r = l * *tau_minus_r;
             });
@@ -373,8 +429,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold and check that the type is Fr
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -387,6 +442,10 @@ impl<F: FftField> EvaluationDomain<F> {
             }
         }
 
+        if self.uses_six_step() && self.six_step_fft_in_place(x_s, SixStepConfig::global().block_size).is_ok() {
+            return;
+        }
+
         let pc = self.precompute_fft();
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc)
     }
@@ -402,8 +461,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -416,6 +474,10 @@ impl<F: FftField> EvaluationDomain<F> {
             }
         }
 
+        if self.uses_six_step() && self.six_step_ifft_in_place(x_s, SixStepConfig::global().block_size).is_ok() {
+            return;
+        }
+
         let pc = self.precompute_ifft();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
@@ -423,8 +485,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -437,6 +498,11 @@ impl<F: FftField> EvaluationDomain<F> {
             }
         }
 
+        if self.uses_six_step() && self.six_step_ifft_in_place(x_s, SixStepConfig::global().block_size).is_ok() {
+            Self::distribute_powers(x_s, self.generator_inv);
+            return;
+        }
+
         let pc = self.precompute_ifft();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
         let coset_shift = self.generator_inv;
@@ -450,8 +516,7 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &FFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -481,8 +546,7 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -515,8 +579,7 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if crate::fft::FFTBackend::global().uses_gpu(self.size()) && std::mem::size_of::<T>() == 32 {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -539,6 +602,16 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         ord: FFTOrder,
         pre_comp: &FFTPrecomputation<F>,
+    ) {
+        self.fft_helper_in_place_with_pc_and_scratch(x_s, ord, pre_comp, &mut Vec::new())
+    }
+
+    fn fft_helper_in_place_with_pc_and_scratch<T: DomainCoeff<F>>(
+        &self,
+        x_s: &mut [T],
+        ord: FFTOrder,
+        pre_comp: &FFTPrecomputation<F>,
+        scratch: &mut Vec<F>,
     ) {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
@@ -546,9 +619,9 @@ impl<F: FftField> EvaluationDomain<F> {
         let log_len = log2(x_s.len());
 
         if ord == OI {
-            self.oi_helper_with_roots(x_s, &pc.roots);
+            self.oi_helper_with_roots(x_s, &pc.roots, scratch);
         } else {
-            self.io_helper_with_roots(x_s, &pc.roots);
+            self.io_helper_with_roots(x_s, &pc.roots, scratch);
         }
 
         if ord == II {
@@ -564,6 +637,16 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         ord: FFTOrder,
         pre_comp: &IFFTPrecomputation<F>,
+    ) {
+        self.ifft_helper_in_place_with_pc_and_scratch(x_s, ord, pre_comp, &mut Vec::new())
+    }
+
+    fn ifft_helper_in_place_with_pc_and_scratch<T: DomainCoeff<F>>(
+        &self,
+        x_s: &mut [T],
+        ord: FFTOrder,
+        pre_comp: &IFFTPrecomputation<F>,
+        scratch: &mut Vec<F>,
     ) {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
@@ -575,9 +658,9 @@ impl<F: FftField> EvaluationDomain<F> {
         }
 
         if ord == IO {
-            self.io_helper_with_roots(x_s, &pc.inverse_roots);
+            self.io_helper_with_roots(x_s, &pc.inverse_roots, scratch);
         } else {
-            self.oi_helper_with_roots(x_s, &pc.inverse_roots);
+            self.oi_helper_with_roots(x_s, &pc.inverse_roots, scratch);
         }
     }
 
@@ -687,9 +770,10 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
-    #[allow(clippy::unnecessary_to_owned)]
-    fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F]) {
-        let mut roots = std::borrow::Cow::Borrowed(roots);
+    /// Applies the butterflies from the largest to the smallest gap, compacting the roots into `scratch`.
+    fn io_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots: &[F], scratch: &mut Vec<F>) {
+        // Whether the roots have been compacted into `scratch`.
+        let mut compacted = false;
 
         let mut step = 1;
         let mut first = true;
@@ -710,10 +794,21 @@ impl<F: FftField> EvaluationDomain<F> {
             // Which also implies a large lookup stride.
             if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
                 if !first {
-                    roots = Cow::Owned(cfg_into_iter!(roots.into_owned()).step_by(step * 2).collect());
+                    if compacted {
+                        // Compact the roots in place, as each root moves to a lower index.
+                        let len = scratch.len().div_ceil(step * 2);
+                        for i in 1..len {
+                            scratch[i] = scratch[i * step * 2];
+                        }
+                        scratch.truncate(len);
+                    } else {
+                        scratch.clear();
+                        scratch.resize(roots.len().div_ceil(step * 2), F::zero());
+                        cfg_iter_mut!(scratch).zip(cfg_iter!(roots).step_by(step * 2)).for_each(|(a, b)| *a = *b);
+                        compacted = true;
+                    }
                 }
                 step = 1;
-                roots.to_mut().shrink_to_fit();
             } else {
                 step = num_chunks;
             }
@@ -722,7 +817,7 @@ impl<F: FftField> EvaluationDomain<F> {
             Self::apply_butterfly(
                 Self::butterfly_fn_io,
                 xi,
-                &roots[..],
+                if compacted { &scratch[..] } else { roots },
                 step,
                 chunk_size,
                 num_chunks,
@@ -734,14 +829,17 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
-    fn oi_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots_cache: &[F]) {
+    /// Applies the butterflies from the smallest to the largest gap, compacting the roots into `scratch`.
+    fn oi_helper_with_roots<T: DomainCoeff<F>>(&self, xi: &mut [T], roots_cache: &[F], scratch: &mut Vec<F>) {
         // The `cmp::min` is only necessary for the case where
         // `MIN_NUM_CHUNKS_FOR_COMPACTION = 1`. Else, notice that we compact
         // the roots cache by a stride of at least `MIN_NUM_CHUNKS_FOR_COMPACTION`.
 
         let compaction_max_size =
             core::cmp::min(roots_cache.len() / 2, roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION);
-        let mut compacted_roots = vec![F::default(); compaction_max_size];
+        scratch.clear();
+        scratch.resize(compaction_max_size, F::default());
+        let compacted_roots = scratch;
 
         #[cfg(not(feature = "serial"))]
         let max_threads = snarkvm_utilities::parallel::max_available_threads();
@@ -886,9 +984,16 @@ pub struct FFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> FFTPrecomputation<F> {
+    /// Returns `true` if the precomputation matches the given domain.
+    pub(crate) fn is_consistent_with(&self, domain: &EvaluationDomain<F>) -> bool {
+        self.domain == *domain
+            && self.roots.len() == domain.size() / 2
+            && self.roots.get(1).map_or(true, |root| *root == domain.group_gen)
+    }
+
     pub fn to_ifft_precomputation(&self) -> IFFTPrecomputation<F> {
         let mut inverse_roots = self.roots.clone();
-        snarkvm_fields::batch_inversion(&mut inverse_roots);
+        snarkvm_fields::batch_inverse(&mut inverse_roots);
         IFFTPrecomputation { inverse_roots, domain: self.domain }
     }
 
@@ -916,6 +1021,13 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns `true` if the precomputation matches the given domain.
+    pub(crate) fn is_consistent_with(&self, domain: &EvaluationDomain<F>) -> bool {
+        self.domain == *domain
+            && self.inverse_roots.len() == domain.size() / 2
+            && self.inverse_roots.get(1).map_or(true, |root| *root == domain.group_gen_inv)
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
@@ -936,7 +1048,7 @@ impl<F: FftField> IFFTPrecomputation<F> {
 mod tests {
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     use crate::fft::domain::FFTOrder;
-    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use crate::fft::{DensePolynomial, EvaluationDomain, FFTBackend};
     use rand::Rng;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{FftField, Field, One, Zero};
@@ -1096,6 +1208,82 @@ mod tests {
         }
     }
 
+    /// Sets the global FFT backend, and restores the previous backend when dropped, even if the test panics.
+    struct GlobalBackendGuard(FFTBackend);
+
+    impl GlobalBackendGuard {
+        fn set(backend: FFTBackend) -> Self {
+            let previous = FFTBackend::global();
+            backend.set_global();
+            Self(previous)
+        }
+    }
+
+    impl Drop for GlobalBackendGuard {
+        fn drop(&mut self) {
+            self.0.set_global();
+        }
+    }
+
+    /// Tests that batched FFTs match individual FFTs, on either backend.
+    #[test]
+    fn test_fft_batch() {
+        let mut rng = TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(1 << 6).unwrap();
+        let batch = (0..4).map(|_| DensePolynomial::<Fr>::rand(domain.size() - 1, &mut rng).coeffs).collect::<Vec<_>>();
+        let expected = batch.iter().map(|coeffs| domain.fft(coeffs)).collect::<Vec<_>>();
+
+        let default_backend = FFTBackend::global();
+        assert_eq!(default_backend, FFTBackend::default());
+        for backend in [FFTBackend::Cpu, FFTBackend::Gpu { threshold: 1 }] {
+            let _guard = GlobalBackendGuard::set(backend);
+            assert_eq!(FFTBackend::global(), backend);
+            assert_eq!(backend.uses_gpu(domain.size()), FFTBackend::is_gpu_available() && backend != FFTBackend::Cpu);
+
+            let mut evaluations = batch.clone();
+            domain.fft_in_place_batch(&mut evaluations);
+            assert_eq!(evaluations, expected);
+            domain.ifft_in_place_batch(&mut evaluations);
+            assert_eq!(evaluations, batch);
+        }
+        assert_eq!(FFTBackend::global(), default_backend);
+    }
+
+    /// Tests that the FFTs with caller-provided buffers match the allocating FFTs.
+    #[test]
+    fn test_fft_with_buffers() {
+        let mut rng = TestRng::default();
+        let mut scratch = Vec::new();
+        for log_domain_size in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let fft_pc = domain.precompute_fft();
+            let ifft_pc = domain.precompute_ifft();
+            let coeffs = DensePolynomial::<Fr>::rand(domain.size() - 1, &mut rng).coeffs;
+
+            let mut evals = coeffs.clone();
+            domain.fft_in_place_with_buffers(&mut evals, &fft_pc, &mut scratch).unwrap();
+            assert_eq!(evals, domain.fft(&coeffs));
+            assert!(scratch.capacity() <= (domain.size() / 4).max(1) || log_domain_size == 0);
+
+            // Reusing the buffers does not allocate.
+            let capacity = scratch.capacity();
+            domain.ifft_in_place_with_buffers(&mut evals, &ifft_pc, &mut scratch).unwrap();
+            assert_eq!(evals, coeffs);
+            assert_eq!(scratch.capacity(), capacity);
+        }
+
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let small_domain = EvaluationDomain::<Fr>::new(4).unwrap();
+        assert!(
+            domain.fft_in_place_with_buffers(&mut [Fr::zero(); 4], &domain.precompute_fft(), &mut scratch).is_err()
+        );
+        assert!(
+            domain
+                .fft_in_place_with_buffers(&mut [Fr::zero(); 8], &small_domain.precompute_fft(), &mut scratch)
+                .is_err()
+        );
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
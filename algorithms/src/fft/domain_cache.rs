@@ -0,0 +1,284 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Evaluation domains with precomputed twiddle and coset tables, which can be serialized to disk and are
+//! shared through a process-wide cache.
+
+use crate::{
+    cfg_iter_mut,
+    fft::{
+        DomainCoeff,
+        EvaluationDomain,
+        domain::{FFTPrecomputation, IFFTPrecomputation},
+    },
+};
+use snarkvm_fields::FftField;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{Result, ensure};
+use parking_lot::RwLock;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The cached domains, keyed by the type of their field and their size.
+type DomainCache = RwLock<HashMap<(TypeId, u64), Arc<dyn Any + Send + Sync>>>;
+
+/// Returns the process-wide domain cache.
+fn domain_cache() -> &'static DomainCache {
+    static CACHE: OnceLock<DomainCache> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// An evaluation domain, together with its twiddle tables and the tables of powers of its coset shift.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedDomain<F: FftField> {
+    /// The evaluation domain.
+    domain: EvaluationDomain<F>,
+    /// The roots of unity used by the FFT.
+    fft_precomputation: FFTPrecomputation<F>,
+    /// The inverse roots of unity used by the IFFT.
+    ifft_precomputation: IFFTPrecomputation<F>,
+    /// The powers `g^i` of the multiplicative generator, for `i` in `0..size`.
+    coset_powers: Vec<F>,
+    /// The powers `g^{-i}` of the inverse of the multiplicative generator, for `i` in `0..size`.
+    coset_inverse_powers: Vec<F>,
+}
+
+impl<F: FftField> PrecomputedDomain<F> {
+    /// Precomputes the tables of the given domain.
+    pub fn new(domain: EvaluationDomain<F>) -> Self {
+        let fft_precomputation = domain.precompute_fft();
+        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
+        let coset_powers = Self::powers(domain.size(), F::multiplicative_generator());
+        let coset_inverse_powers = Self::powers(domain.size(), domain.generator_inv);
+        Self { domain, fft_precomputation, ifft_precomputation, coset_powers, coset_inverse_powers }
+    }
+
+    /// Returns the precomputed domain of the given size from the process-wide cache,
+    /// precomputing and caching it on first use.
+    pub fn cached(num_coeffs: usize) -> Option<Arc<Self>> {
+        let domain = EvaluationDomain::new(num_coeffs)?;
+        let key = (TypeId::of::<F>(), domain.size);
+        if let Some(cached) = domain_cache().read().get(&key) {
+            return cached.clone().downcast().ok();
+        }
+        // Precompute the tables without holding the lock, and keep the first domain that is cached.
+        let precomputed: Arc<dyn Any + Send + Sync> = Arc::new(Self::new(domain));
+        domain_cache().write().entry(key).or_insert(precomputed).clone().downcast().ok()
+    }
+
+    /// Adds the precomputed domain to the process-wide cache, e.g. after loading it from disk.
+    pub fn insert_into_cache(self: Arc<Self>) {
+        domain_cache().write().insert((TypeId::of::<F>(), self.domain.size), self);
+    }
+
+    /// Removes the precomputed domain of the given size from the process-wide cache.
+    pub fn remove_from_cache(num_coeffs: usize) {
+        if let Some(domain) = EvaluationDomain::<F>::new(num_coeffs) {
+            domain_cache().write().remove(&(TypeId::of::<F>(), domain.size));
+        }
+    }
+
+    /// Removes all precomputed domains over all fields from the process-wide cache.
+    pub fn clear_cache() {
+        domain_cache().write().clear();
+    }
+
+    /// Returns the evaluation domain.
+    pub const fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
+    /// Returns the roots of unity used by the FFT.
+    pub const fn fft_precomputation(&self) -> &FFTPrecomputation<F> {
+        &self.fft_precomputation
+    }
+
+    /// Returns the inverse roots of unity used by the IFFT.
+    pub const fn ifft_precomputation(&self) -> &IFFTPrecomputation<F> {
+        &self.ifft_precomputation
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) -> Result<()> {
+        ensure!(coeffs.len() <= self.domain.size(), "Expected at most {} coefficients", self.domain.size());
+        coeffs.resize(self.domain.size(), T::zero());
+        self.domain.in_order_fft_in_place_with_pc(coeffs, &self.fft_precomputation);
+        Ok(())
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) -> Result<()> {
+        ensure!(evals.len() <= self.domain.size(), "Expected at most {} evaluations", self.domain.size());
+        evals.resize(self.domain.size(), T::zero());
+        self.domain.in_order_ifft_in_place_with_pc(evals, &self.ifft_precomputation);
+        Ok(())
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the vector in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) -> Result<()> {
+        ensure!(coeffs.len() <= self.domain.size(), "Expected at most {} coefficients", self.domain.size());
+        cfg_iter_mut!(coeffs).zip(&self.coset_powers).for_each(|(coeff, power)| *coeff *= *power);
+        self.fft_in_place(coeffs)
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) -> Result<()> {
+        ensure!(evals.len() <= self.domain.size(), "Expected at most {} evaluations", self.domain.size());
+        self.ifft_in_place(evals)?;
+        cfg_iter_mut!(evals).zip(&self.coset_inverse_powers).for_each(|(eval, power)| *eval *= *power);
+        Ok(())
+    }
+
+    /// Returns `[1, g, g^2, ..., g^{size - 1}]`.
+    fn powers(size: usize, g: F) -> Vec<F> {
+        let mut powers = vec![F::one(); size];
+        EvaluationDomain::distribute_powers(&mut powers, g);
+        powers
+    }
+}
+
+impl<F: FftField> CanonicalSerialize for PrecomputedDomain<F> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.domain.serialize_with_mode(&mut writer, compress)?;
+        self.fft_precomputation.serialize_with_mode(&mut writer, compress)?;
+        self.ifft_precomputation.serialize_with_mode(&mut writer, compress)?;
+        self.coset_powers.serialize_with_mode(&mut writer, compress)?;
+        self.coset_inverse_powers.serialize_with_mode(&mut writer, compress)?;
+        Ok(())
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        self.domain
+            .serialized_size(mode)
+            .saturating_add(self.fft_precomputation.serialized_size(mode))
+            .saturating_add(self.ifft_precomputation.serialized_size(mode))
+            .saturating_add(self.coset_powers.serialized_size(mode))
+            .saturating_add(self.coset_inverse_powers.serialized_size(mode))
+    }
+}
+
+impl<F: FftField> Valid for PrecomputedDomain<F> {
+    /// Checks that the tables match the domain. The tables themselves are only spot-checked,
+    /// as verifying them in full costs as much as recomputing them.
+    fn check(&self) -> Result<(), SerializationError> {
+        let size = self.domain.size();
+        let is_valid = EvaluationDomain::new(size).as_ref() == Some(&self.domain)
+            && self.fft_precomputation.is_consistent_with(&self.domain)
+            && self.ifft_precomputation.is_consistent_with(&self.domain)
+            && self.coset_powers.len() == size
+            && self.coset_inverse_powers.len() == size
+            && self.coset_powers.get(1).map_or(true, |g| *g == F::multiplicative_generator())
+            && self.coset_inverse_powers.get(1).map_or(true, |g_inv| *g_inv == self.domain.generator_inv);
+        match is_valid {
+            true => Ok(()),
+            false => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for PrecomputedDomain<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let precomputed = Self {
+            domain: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            fft_precomputation: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            ifft_precomputation: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            coset_powers: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            coset_inverse_powers: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            precomputed.check()?;
+        }
+        Ok(precomputed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::{DensePolynomial, EvaluationDomain, PrecomputedDomain};
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, TestRng};
+
+    use std::sync::Arc;
+
+    #[test]
+    fn test_precomputed_domain_matches_domain() {
+        let mut rng = TestRng::default();
+        for log_domain_size in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let precomputed = PrecomputedDomain::new(domain);
+            let coeffs = DensePolynomial::<Fr>::rand(domain.size() - 1, &mut rng).coeffs;
+
+            let mut evals = coeffs.clone();
+            precomputed.fft_in_place(&mut evals).unwrap();
+            assert_eq!(evals, domain.fft(&coeffs));
+            precomputed.ifft_in_place(&mut evals).unwrap();
+            assert_eq!(evals, coeffs);
+
+            let mut coset_evals = coeffs.clone();
+            precomputed.coset_fft_in_place(&mut coset_evals).unwrap();
+            assert_eq!(coset_evals, domain.coset_fft(&coeffs));
+            precomputed.coset_ifft_in_place(&mut coset_evals).unwrap();
+            assert_eq!(coset_evals, coeffs);
+
+            assert!(precomputed.fft_in_place(&mut vec![Fr::from(1u64); domain.size() + 1]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_precomputed_domain_serialization() {
+        let domain = EvaluationDomain::<Fr>::new(1 << 6).unwrap();
+        let precomputed = PrecomputedDomain::new(domain);
+
+        let mut bytes = Vec::new();
+        precomputed.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), precomputed.compressed_size());
+        assert_eq!(PrecomputedDomain::deserialize_compressed(&*bytes).unwrap(), precomputed);
+
+        // Tables of another domain are rejected.
+        let mut other = PrecomputedDomain::new(EvaluationDomain::<Fr>::new(1 << 5).unwrap());
+        other.domain = domain;
+        let mut bytes = Vec::new();
+        other.serialize_compressed(&mut bytes).unwrap();
+        assert!(PrecomputedDomain::<Fr>::deserialize_compressed(&*bytes).is_err());
+    }
+
+    #[test]
+    fn test_domain_cache() {
+        let size = 1 << 9;
+        let cached = PrecomputedDomain::<Fr>::cached(size - 3).unwrap();
+        assert_eq!(cached.domain().size(), size);
+        assert!(Arc::ptr_eq(&cached, &PrecomputedDomain::<Fr>::cached(size).unwrap()));
+        // Domains over different fields do not collide.
+        assert_eq!(PrecomputedDomain::<Fq>::cached(size).unwrap().domain().size(), size);
+
+        let loaded = Arc::new(PrecomputedDomain::<Fr>::new(*cached.domain()));
+        loaded.clone().insert_into_cache();
+        assert!(Arc::ptr_eq(&loaded, &PrecomputedDomain::<Fr>::cached(size).unwrap()));
+
+        PrecomputedDomain::<Fr>::remove_from_cache(size);
+        assert!(!Arc::ptr_eq(&loaded, &PrecomputedDomain::<Fr>::cached(size).unwrap()));
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains a `MixedRadixEvaluationDomain` for performing (I)FFTs
+//! over multiplicative subgroups of size `2^a * 3^b`.
+//!
+//! Radix-2 domains must be padded to the next power of two, which doubles the
+//! cost of FFTs over sizes just above a power of two. Mixed-radix domains may
+//! additionally be a multiple of `3`, as far as the multiplicative group of the
+//! field allows, so that such sizes can be padded to a smaller domain.
+
+use crate::{
+    cfg_into_iter,
+    cfg_iter_mut,
+    fft::{DomainCoeff, SparsePolynomial, domain::compute_powers_serial},
+};
+use snarkvm_fields::{FftField, FftParameters};
+
+use std::fmt;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Defines a domain of size `2^a * 3^b` over which finite field (I)FFTs can be performed.
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct MixedRadixEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// The exponent `a` of the size `2^a * 3^b`.
+    pub two_adicity: u32,
+    /// The exponent `b` of the size `2^a * 3^b`.
+    pub three_adicity: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Inverse of the multiplicative generator of the finite field.
+    pub generator_inv: F,
+}
+
+impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mixed-radix multiplicative subgroup of size {}", self.size)
+    }
+}
+
+/// Divides the little-endian integer `limbs` by `divisor` in place, and returns the remainder.
+fn div_rem_in_place(limbs: &mut [u64], divisor: u64) -> u64 {
+    let mut remainder = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let value = (remainder << 64) | *limb as u128;
+        *limb = (value / divisor as u128) as u64;
+        remainder = value % divisor as u128;
+    }
+    remainder as u64
+}
+
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Returns the largest `k` such that `3^k` divides the order of the multiplicative group of the field.
+    pub fn max_three_adicity() -> u32 {
+        let mut order = F::characteristic().to_vec();
+        // The characteristic is an odd prime, so subtracting one does not borrow.
+        order[0] -= 1;
+        let mut three_adicity = 0;
+        let mut quotient = order.clone();
+        while div_rem_in_place(&mut quotient, 3) == 0 {
+            order.copy_from_slice(&quotient);
+            three_adicity += 1;
+        }
+        three_adicity
+    }
+
+    /// Returns the exponents `(a, b)` of the smallest size `2^a * 3^b` that is at least `num_coeffs`.
+    fn compute_adicities(num_coeffs: usize) -> Option<(u32, u32)> {
+        let num_coeffs = num_coeffs.max(1) as u128;
+        let mut best: Option<(u128, u32, u32)> = None;
+        let mut three_part = 1u128;
+        for three_adicity in 0..=Self::max_three_adicity() {
+            let two_part = num_coeffs.div_ceil(three_part).next_power_of_two();
+            let two_adicity = two_part.trailing_zeros();
+            let size = two_part * three_part;
+            if two_adicity <= F::FftParameters::TWO_ADICITY
+                && size <= usize::MAX as u128
+                && best.map_or(true, |(best_size, ..)| size < best_size)
+            {
+                best = Some((size, two_adicity, three_adicity));
+            }
+            if three_part >= num_coeffs {
+                break;
+            }
+            three_part *= 3;
+        }
+        best.map(|(_, two_adicity, three_adicity)| (two_adicity, three_adicity))
+    }
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let (two_adicity, three_adicity) = Self::compute_adicities(num_coeffs)?;
+        let size = (1u64 << two_adicity).checked_mul(3u64.checked_pow(three_adicity)?)?;
+
+        // Compute a generator of the subgroup of size `2^a`.
+        let two_gen = F::get_root_of_unity(1 << two_adicity)?;
+        // Compute a generator of the subgroup of size `3^b`, as `g^((p - 1) / 3^b)`.
+        let mut exponent = F::characteristic().to_vec();
+        exponent[0] -= 1;
+        for _ in 0..three_adicity {
+            div_rem_in_place(&mut exponent, 3);
+        }
+        let three_gen = F::multiplicative_generator().pow(&exponent);
+        // As the orders are coprime, the product generates the subgroup of size `2^a * 3^b`.
+        let group_gen = two_gen * three_gen;
+        debug_assert_eq!(group_gen.pow([size]), F::one());
+
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Self {
+            size,
+            two_adicity,
+            three_adicity,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let (two_adicity, three_adicity) = Self::compute_adicities(num_coeffs)?;
+        (1usize << two_adicity).checked_mul(3usize.checked_pow(three_adicity)?)
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        coeffs.resize(self.size(), T::zero());
+        *coeffs = self.mixed_radix_fft(coeffs, self.group_gen);
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        *evals = self.mixed_radix_fft(evals, self.group_gen_inv);
+        cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+    }
+
+    /// Compute an FFT over a coset of the domain.
+    pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        Self::distribute_powers_and_mul_by_const(coeffs, F::multiplicative_generator(), F::one());
+        self.fft_in_place(coeffs);
+    }
+
+    /// Compute an IFFT over a coset of the domain.
+    pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
+    pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        *evals = self.mixed_radix_fft(evals, self.group_gen_inv);
+        Self::distribute_powers_and_mul_by_const(evals, self.generator_inv, self.size_inv);
+    }
+
+    /// Multiply the `i`-th element of `coeffs` with `c*g^i`.
+    fn distribute_powers_and_mul_by_const<T: DomainCoeff<F>>(coeffs: &mut [T], g: F, c: F) {
+        let mut pow = c;
+        coeffs.iter_mut().for_each(|coeff| {
+            *coeff *= pow;
+            pow *= &g
+        })
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let coeffs = [(0, -F::one()), (self.size(), F::one())];
+        SparsePolynomial::from_coefficients(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        tau.pow([self.size]) - F::one()
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> impl Iterator<Item = F> {
+        let group_gen = self.group_gen;
+        core::iter::successors(Some(F::one()), move |element| Some(*element * group_gen)).take(self.size())
+    }
+
+    /// Returns the radices of the FFT, from the outermost to the innermost step.
+    fn radices(&self) -> Vec<usize> {
+        let mut radices = vec![3; self.three_adicity as usize];
+        radices.extend(vec![2; self.two_adicity as usize]);
+        radices
+    }
+
+    /// Computes the evaluations of `coeffs` at the powers of `root`, which must generate the domain.
+    fn mixed_radix_fft<T: DomainCoeff<F>>(&self, coeffs: &[T], root: F) -> Vec<T> {
+        let twiddles = compute_powers_serial(self.size(), root);
+        Self::fft_recursive(coeffs, &self.radices(), &twiddles, 1)
+    }
+
+    /// Computes the FFT of `coeffs`, whose size is the product of `radices`, with the `stride`-th powers of the
+    /// full-size `twiddles`, by splitting it into `radices[0]` interleaved FFTs of the remaining radices.
+    fn fft_recursive<T: DomainCoeff<F>>(coeffs: &[T], radices: &[usize], twiddles: &[F], stride: usize) -> Vec<T> {
+        let Some((&radix, radices)) = radices.split_first() else {
+            return coeffs.to_vec();
+        };
+        let size = coeffs.len();
+        let sub_size = size / radix;
+
+        // Compute the FFTs of the interleaved subsequences, over the subgroup of size `size / radix`.
+        let sub_ffts: Vec<Vec<T>> = cfg_into_iter!(0..radix)
+            .map(|j| {
+                let sub_coeffs = coeffs.iter().skip(j).step_by(radix).copied().collect::<Vec<_>>();
+                Self::fft_recursive(&sub_coeffs, radices, twiddles, stride * radix)
+            })
+            .collect();
+
+        // Combine them as `X[k] = sum_j w^(j * k) * Y_j[k mod (size / radix)]`.
+        let mut result = vec![T::zero(); size];
+        cfg_iter_mut!(result).enumerate().for_each(|(k, result)| {
+            *result = sub_ffts[0][k % sub_size];
+            for (j, sub_fft) in sub_ffts.iter().enumerate().skip(1) {
+                let mut term = sub_fft[k % sub_size];
+                term *= twiddles[(j * k % size) * stride];
+                *result += term;
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::{DensePolynomial, EvaluationDomain, MixedRadixEvaluationDomain};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{FftField, Field, One, Zero};
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_mixed_radix_domain_sizes() {
+        assert_eq!(MixedRadixEvaluationDomain::<Fr>::max_three_adicity(), 1);
+        for (num_coeffs, expected) in [(0, 1), (1, 1), (3, 3), (5, 6), (7, 8), (1025, 1536), (1536, 1536), (1537, 2048)]
+        {
+            assert_eq!(MixedRadixEvaluationDomain::<Fr>::compute_size_of_domain(num_coeffs), Some(expected));
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            assert_eq!(domain.size(), expected);
+            assert!(domain.size() <= EvaluationDomain::<Fr>::compute_size_of_domain(num_coeffs).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix_domain_elements() {
+        for num_coeffs in [3, 6, 12, 24] {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let elements = domain.elements().collect::<Vec<_>>();
+            assert_eq!(elements.len(), num_coeffs);
+            assert!(elements.iter().all(|element| domain.evaluate_vanishing_polynomial(*element).is_zero()));
+            // The generator has order exactly `size`.
+            assert!(elements.iter().skip(1).all(|element| !element.is_one()));
+            assert_eq!(domain.group_gen.pow([domain.size]), Fr::one());
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let rng = &mut TestRng::default();
+        for num_coeffs in [1, 2, 3, 6, 12, 48, 96, 768] {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            assert_eq!(domain.size(), num_coeffs);
+            let polynomial = DensePolynomial::<Fr>::rand(num_coeffs - 1, rng);
+
+            let evaluations = domain.fft(&polynomial.coeffs);
+            let coset_evaluations = domain.coset_fft(&polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                assert_eq!(evaluations[i], polynomial.evaluate(x));
+                assert_eq!(coset_evaluations[i], polynomial.evaluate(Fr::multiplicative_generator() * x));
+            }
+
+            let mut coeffs = domain.ifft(&evaluations);
+            coeffs.truncate(polynomial.coeffs.len());
+            assert_eq!(coeffs, polynomial.coeffs);
+            let mut coeffs = domain.coset_ifft(&coset_evaluations);
+            coeffs.truncate(polynomial.coeffs.len());
+            assert_eq!(coeffs, polynomial.coeffs);
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix_fft_matches_radix_2() {
+        let rng = &mut TestRng::default();
+        for log_size in 0..10 {
+            let polynomial = DensePolynomial::<Fr>::rand((1 << log_size) - 1, rng);
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let mixed_radix_domain = MixedRadixEvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            assert_eq!(mixed_radix_domain.group_gen, domain.group_gen);
+            assert_eq!(mixed_radix_domain.fft(&polynomial.coeffs), domain.fft(&polynomial.coeffs));
+        }
+    }
+}
@@ -16,9 +16,21 @@
 //! This crate implements functions for manipulating polynomials over finite fields,
 //! including FFTs.
 
+pub mod backend;
+pub use backend::FFTBackend;
+
 pub mod domain;
 pub use domain::EvaluationDomain;
 
+pub mod domain_cache;
+pub use domain_cache::PrecomputedDomain;
+
+pub mod mixed_radix_domain;
+pub use mixed_radix_domain::MixedRadixEvaluationDomain;
+
+pub mod six_step;
+pub use six_step::SixStepConfig;
+
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
@@ -0,0 +1,212 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A six-step FFT for domains that do not fit in the cache.
+//!
+//! A domain of size `n = n1 * n2` is viewed as a matrix with `n1` rows and `n2` columns. The FFT is computed
+//! by transposing the matrix, computing the FFTs of its rows of size `n1`, multiplying by twiddle factors,
+//! transposing again, computing the FFTs of its rows of size `n2`, and transposing back. Each row FFT fits
+//! in the cache, and the transposes are performed in blocks so that they access memory contiguously.
+
+use crate::{
+    cfg_chunks_mut,
+    fft::{DomainCoeff, EvaluationDomain},
+};
+use snarkvm_fields::FftField;
+
+use anyhow::{Result, anyhow, ensure};
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The configuration of the six-step FFT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SixStepConfig {
+    /// The minimum `log_2` of the domain size, from which FFTs use the six-step algorithm.
+    pub min_log_size: u32,
+    /// The side length of the square blocks in which the matrices are transposed.
+    pub block_size: usize,
+}
+
+/// The minimum `log_2` of the domain size of the process-wide configuration.
+static MIN_LOG_SIZE: AtomicU32 = AtomicU32::new(SixStepConfig::DEFAULT_MIN_LOG_SIZE);
+/// The block size of the process-wide configuration.
+static BLOCK_SIZE: AtomicUsize = AtomicUsize::new(SixStepConfig::DEFAULT_BLOCK_SIZE);
+
+impl SixStepConfig {
+    /// The default side length of the square blocks in which the matrices are transposed.
+    pub const DEFAULT_BLOCK_SIZE: usize = 64;
+    /// The default minimum `log_2` of the domain size, from which FFTs use the six-step algorithm.
+    pub const DEFAULT_MIN_LOG_SIZE: u32 = 22;
+
+    /// Returns the configuration that is currently used by all FFTs in this process.
+    pub fn global() -> Self {
+        Self { min_log_size: MIN_LOG_SIZE.load(Ordering::Relaxed), block_size: BLOCK_SIZE.load(Ordering::Relaxed) }
+    }
+
+    /// Sets the configuration that is used by all FFTs in this process.
+    pub fn set_global(self) {
+        MIN_LOG_SIZE.store(self.min_log_size, Ordering::Relaxed);
+        BLOCK_SIZE.store(self.block_size.max(1), Ordering::Relaxed);
+    }
+}
+
+impl Default for SixStepConfig {
+    fn default() -> Self {
+        Self { min_log_size: Self::DEFAULT_MIN_LOG_SIZE, block_size: Self::DEFAULT_BLOCK_SIZE }
+    }
+}
+
+/// Transposes the `rows x cols` matrix `src` into the `cols x rows` matrix `dst`, in square blocks.
+fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize, block_size: usize) {
+    // Each chunk of `dst` holds `block_size` of its rows, which are columns of `src`.
+    cfg_chunks_mut!(dst, rows * block_size).enumerate().for_each(|(block, dst)| {
+        let col_start = block * block_size;
+        let num_cols = dst.len() / rows;
+        for row_start in (0..rows).step_by(block_size) {
+            for row in row_start..(row_start + block_size).min(rows) {
+                for col in 0..num_cols {
+                    dst[col * rows + row] = src[row * cols + col_start + col];
+                }
+            }
+        }
+    });
+}
+
+impl<F: FftField> EvaluationDomain<F> {
+    /// Returns `true` if FFTs over this domain use the six-step algorithm.
+    pub(crate) fn uses_six_step(&self) -> bool {
+        self.log_size_of_group >= SixStepConfig::global().min_log_size.max(2)
+    }
+
+    /// Compute an FFT in place, using the six-step algorithm with the given block size.
+    pub fn six_step_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut [T], block_size: usize) -> Result<()> {
+        self.six_step_helper(coeffs, block_size, false)
+    }
+
+    /// Compute an IFFT in place, using the six-step algorithm with the given block size.
+    pub fn six_step_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut [T], block_size: usize) -> Result<()> {
+        self.six_step_helper(evals, block_size, true)
+    }
+
+    fn six_step_helper<T: DomainCoeff<F>>(&self, x_s: &mut [T], block_size: usize, inverse: bool) -> Result<()> {
+        ensure!(x_s.len() == self.size(), "Expected {} elements, found {}", self.size(), x_s.len());
+        ensure!(block_size > 0, "The block size must be positive");
+        if self.size() < 4 {
+            match inverse {
+                true => self.in_order_ifft_in_place(x_s),
+                false => self.in_order_fft_in_place(x_s),
+            }
+            return Ok(());
+        }
+
+        // Split the domain into `n1` rows and `n2` columns.
+        let log_n2 = self.log_size_of_group / 2;
+        let (n1, n2) = (1usize << (self.log_size_of_group - log_n2), 1usize << log_n2);
+        let domain_1 = EvaluationDomain::<F>::new(n1).ok_or_else(|| anyhow!("Cannot create a domain of size {n1}"))?;
+        let domain_2 = EvaluationDomain::<F>::new(n2).ok_or_else(|| anyhow!("Cannot create a domain of size {n2}"))?;
+        let root = if inverse { self.group_gen_inv } else { self.group_gen };
+
+        // Computes the (I)FFTs of the rows of `matrix`, over the given domain.
+        let row_ffts = |matrix: &mut [T], domain: &EvaluationDomain<F>| -> Result<()> {
+            match inverse {
+                true => {
+                    let pc = domain.precompute_ifft();
+                    cfg_chunks_mut!(matrix, domain.size())
+                        .try_for_each(|row| domain.ifft_in_place_with_buffers(row, &pc, &mut Vec::new()))
+                }
+                false => {
+                    let pc = domain.precompute_fft();
+                    cfg_chunks_mut!(matrix, domain.size())
+                        .try_for_each(|row| domain.fft_in_place_with_buffers(row, &pc, &mut Vec::new()))
+                }
+            }
+        };
+
+        // 1. Transpose the `n1 x n2` matrix, so that its columns are contiguous.
+        let mut buffer = x_s.to_vec();
+        transpose(x_s, &mut buffer, n1, n2, block_size);
+        // 2. Compute the FFTs of size `n1`.
+        row_ffts(&mut buffer, &domain_1)?;
+        // 3. Multiply the entry in row `j2` and column `k1` by `root^(j2 * k1)`.
+        cfg_chunks_mut!(buffer, n1).enumerate().for_each(|(j2, row)| {
+            let step = root.pow([j2 as u64]);
+            let mut twiddle = F::one();
+            for element in row.iter_mut() {
+                *element *= twiddle;
+                twiddle *= step;
+            }
+        });
+        // 4. Transpose the `n2 x n1` matrix.
+        transpose(&buffer, x_s, n2, n1, block_size);
+        // 5. Compute the FFTs of size `n2`.
+        row_ffts(x_s, &domain_2)?;
+        // 6. Transpose the `n1 x n2` matrix, so that the output is in order.
+        transpose(x_s, &mut buffer, n1, n2, block_size);
+        x_s.copy_from_slice(&buffer);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::{DensePolynomial, EvaluationDomain, SixStepConfig};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_six_step_fft_correctness() {
+        let mut rng = TestRng::default();
+        for log_domain_size in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let coeffs = DensePolynomial::<Fr>::rand(domain.size() - 1, &mut rng).coeffs;
+            let expected = domain.fft(&coeffs);
+            for block_size in [1, 3, 64] {
+                let mut evals = coeffs.clone();
+                domain.six_step_fft_in_place(&mut evals, block_size).unwrap();
+                assert_eq!(evals, expected, "log_domain_size = {log_domain_size}, block_size = {block_size}");
+                domain.six_step_ifft_in_place(&mut evals, block_size).unwrap();
+                assert_eq!(evals, coeffs, "log_domain_size = {log_domain_size}, block_size = {block_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_six_step_dispatch() {
+        let mut rng = TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(1 << 7).unwrap();
+        let poly = DensePolynomial::<Fr>::rand(domain.size() - 1, &mut rng);
+        let expected = domain.elements().map(|point| poly.evaluate(point)).collect::<Vec<_>>();
+
+        SixStepConfig { min_log_size: 6, block_size: 4 }.set_global();
+        assert!(domain.uses_six_step());
+        let evals = domain.fft(&poly.coeffs);
+        let coeffs = domain.ifft(&evals);
+        let coset_coeffs = domain.coset_ifft(&domain.coset_fft(&poly.coeffs));
+        SixStepConfig::default().set_global();
+
+        assert_eq!(evals, expected);
+        assert_eq!(coeffs, poly.coeffs);
+        assert_eq!(coset_coeffs, poly.coeffs);
+    }
+
+    #[test]
+    fn test_six_step_errors() {
+        let domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        assert!(domain.six_step_fft_in_place(&mut [Fr::from(1u64); 8], 64).is_err());
+        assert!(domain.six_step_fft_in_place(&mut vec![Fr::from(1u64); 1 << 10], 0).is_err());
+    }
+}
@@ -43,6 +43,19 @@ pub mod r1cs;
 #[cfg(feature = "snark")]
 pub mod snark;
 
+pub mod cancellation;
+pub use cancellation::*;
+
+#[cfg(feature = "prover")]
+pub mod progress;
+#[cfg(feature = "prover")]
+pub use progress::*;
+
+#[cfg(feature = "prover")]
+pub mod scheduler;
+#[cfg(feature = "prover")]
+pub use scheduler::*;
+
 pub mod srs;
 
 pub mod errors;
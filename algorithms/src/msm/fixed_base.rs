@@ -13,13 +13,73 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_curves::traits::ProjectiveCurve;
-use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::{ToBits, cfg_into_iter, cfg_iter, cfg_iter_mut};
+use super::variable_base::batched::{BucketPosition, batch_add};
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::{ToBits, cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The precomputed tables of an MSM over a fixed set of bases, such as the powers of an SRS
+/// or the generators of a Pedersen commitment.
+///
+/// For every base `G` and window `j`, the table stores `2^{j * window} * G`. An MSM over the bases then
+/// reduces to a single window of Pippenger's algorithm over `num_windows` times as many points, which skips
+/// the doublings and the per-window bucket reductions of a variable-base MSM, at the cost of `num_windows`
+/// times the memory of the bases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseMSMTable<G: AffineCurve> {
+    /// The number of scalar bits in each window.
+    window: usize,
+    /// The number of windows of a scalar.
+    num_windows: usize,
+    /// The shifted bases, where `2^{j * window} * G_i` is at index `i * num_windows + j`.
+    table: Vec<G>,
+}
+
+impl<G: AffineCurve> FixedBaseMSMTable<G> {
+    /// The largest number of scalar bits in a window.
+    pub const MAX_WINDOW: usize = 20;
+
+    /// Precomputes the tables of the given bases, with a window size chosen from the number of bases.
+    pub fn new(bases: &[G]) -> Self {
+        // Each thread accumulates its share of the bases into `2^window` buckets.
+        let bases_per_thread = bases.len().div_ceil(super::num_threads());
+        let window = crate::fft::domain::log2(bases_per_thread.max(1)) as usize;
+        Self::with_window(bases, window)
+    }
+
+    /// Precomputes the tables of the given bases, with windows of the given number of scalar bits.
+    /// The window is clamped to `1..=Self::MAX_WINDOW`.
+    pub fn with_window(bases: &[G], window: usize) -> Self {
+        let window = window.clamp(1, Self::MAX_WINDOW);
+        let num_windows = G::ScalarField::size_in_bits().div_ceil(window);
+
+        let mut table = vec![G::Projective::zero(); bases.len() * num_windows];
+        cfg_chunks_mut!(table, num_windows).zip(bases).for_each(|(shifted_bases, base)| {
+            let mut shifted_base = base.to_projective();
+            for entry in shifted_bases {
+                *entry = shifted_base;
+                for _ in 0..window {
+                    shifted_base.double_in_place();
+                }
+            }
+        });
+        Self { window, num_windows, table: G::Projective::batch_normalization_into_affine(table) }
+    }
+
+    /// Returns the number of bases.
+    pub fn num_bases(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    /// Returns the number of scalar bits in each window.
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+}
+
 pub struct FixedBase;
 
 impl FixedBase {
@@ -95,4 +155,64 @@ impl FixedBase {
 
         cfg_iter!(v).map(|e| Self::windowed_mul::<T>(outerc, window, table, e)).collect::<Vec<_>>()
     }
+
+    /// Returns the MSM of the given scalars with the bases of the table.
+    /// If there are fewer scalars than bases, the remaining bases are ignored, and vice versa.
+    pub fn msm_fixed_base<G: AffineCurve>(
+        table: &FixedBaseMSMTable<G>,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
+        let num_terms = scalars.len().min(table.num_bases());
+        if num_terms == 0 {
+            return G::Projective::zero();
+        }
+        let (window, num_windows) = (table.window, table.num_windows);
+        let num_buckets = (1 << window) - 1;
+
+        // Returns the `window` bits of the scalar that start at the given bit.
+        let digit = |scalar: &<G::ScalarField as PrimeField>::BigInteger, start: usize| {
+            let limbs = scalar.as_ref();
+            let (limb, shift) = (start / 64, start % 64);
+            let mut digit = limbs[limb] >> shift;
+            if shift + window > 64 && limb + 1 < limbs.len() {
+                digit |= limbs[limb + 1] << (64 - shift);
+            }
+            (digit & num_buckets as u64) as u32
+        };
+
+        // Each thread accumulates its share of the terms into its own buckets.
+        let terms_per_thread = num_terms.div_ceil(super::num_threads());
+        cfg_chunks!(scalars[..num_terms], terms_per_thread)
+            .enumerate()
+            .map(|(chunk_index, scalars)| {
+                let first_term = chunk_index * terms_per_thread;
+                let shifted_bases = &table.table[first_term * num_windows..(first_term + scalars.len()) * num_windows];
+
+                // Place every shifted base in the bucket of its digit. (Recall that there is no zero bucket.)
+                let mut bucket_positions = Vec::with_capacity(shifted_bases.len());
+                for (i, scalar) in scalars.iter().enumerate() {
+                    for j in 0..num_windows {
+                        let digit = digit(scalar, j * window);
+                        if digit != 0 {
+                            let scalar_index = (i * num_windows + j) as u32;
+                            bucket_positions.push(BucketPosition { bucket_index: digit - 1, scalar_index });
+                        }
+                    }
+                }
+                if bucket_positions.is_empty() {
+                    return G::Projective::zero();
+                }
+                let buckets = batch_add(num_buckets, shifted_bases, &mut bucket_positions);
+
+                // Sum the buckets, weighted by their digits.
+                let mut res = G::Projective::zero();
+                let mut running_sum = G::Projective::zero();
+                for bucket in buckets.into_iter().rev() {
+                    running_sum.add_assign_mixed(&bucket);
+                    res += &running_sum;
+                }
+                res
+            })
+            .sum()
+    }
 }
@@ -30,3 +30,11 @@ fn ln_without_floats(a: usize) -> usize {
     // log2(a) * ln(2)
     (crate::fft::domain::log2(a) * 69 / 100) as usize
 }
+
+/// Returns the number of threads that an MSM is split across.
+fn num_threads() -> usize {
+    #[cfg(not(feature = "serial"))]
+    return snarkvm_utilities::max_available_threads();
+    #[cfg(feature = "serial")]
+    return 1;
+}
@@ -65,3 +65,52 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
+
+#[test]
+fn fixed_base_msm_test_with_bls12() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = TestRng::default();
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+
+    let table = FixedBaseMSMTable::new(&g);
+    assert_eq!(table.num_bases(), SAMPLES);
+    assert_eq!(naive.to_affine(), FixedBase::msm_fixed_base(&table, &v).to_affine());
+
+    for window in [1, 5, 13, 64] {
+        let table = FixedBaseMSMTable::with_window(&g, window);
+        assert_eq!(naive.to_affine(), FixedBase::msm_fixed_base(&table, &v).to_affine(), "window = {window}");
+
+        // Fewer scalars than bases.
+        let naive = naive_variable_base_msm(&g[..100], &v[..100]);
+        assert_eq!(naive.to_affine(), FixedBase::msm_fixed_base(&table, &v[..100]).to_affine(), "window = {window}");
+    }
+
+    // Zero scalars.
+    let table = FixedBaseMSMTable::new(&g);
+    assert!(FixedBase::msm_fixed_base(&table, &vec![Fr::zero().to_bigint(); SAMPLES]).is_zero());
+    assert!(FixedBase::msm_fixed_base(&table, &[]).is_zero());
+}
+
+#[test]
+fn variable_base_test_with_bls12_381() {
+    use snarkvm_curves::bls12_381::{Fr, G1Projective, G2Projective};
+
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = TestRng::default();
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBase::msm(g.as_slice(), v.as_slice());
+    assert_eq!(naive.to_affine(), fast.to_affine());
+
+    let g = (0..SAMPLES).map(|_| G2Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBase::msm(g.as_slice(), v.as_slice());
+    assert_eq!(naive.to_affine(), fast.to_affine());
+}
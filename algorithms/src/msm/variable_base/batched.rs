@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::SignedDigits;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 use snarkvm_utilities::{BigInteger, BitIteratorBE, cfg_into_iter};
@@ -23,9 +24,13 @@ use rayon::prelude::*;
 #[cfg(target_arch = "x86_64")]
 use crate::{prefetch_slice, prefetch_slice_write};
 
+/// The flag of a `BucketPosition::scalar_index` that marks its base to be added negated.
+pub const NEGATED: u32 = 1 << 31;
+
 #[derive(Copy, Clone, Debug)]
 pub struct BucketPosition {
     pub bucket_index: u32,
+    /// The index of the base, which may be flagged as `NEGATED`.
     pub scalar_index: u32,
 }
 
@@ -142,16 +147,22 @@ fn batch_add_write<G: AffineCurve>(
     #[cfg(target_arch = "x86_64")]
     prefetch_iter.next();
 
+    // Returns the base at the given index, negated if the index is flagged.
+    let base = |index: u32| match index & NEGATED {
+        0 => bases[index as usize],
+        _ => -bases[(index & !NEGATED) as usize],
+    };
+
     // We run two loops over the data separated by an inversion
     for (idx, idy) in index.iter() {
         #[cfg(target_arch = "x86_64")]
         prefetch_slice_write!(G, bases, bases, prefetch_iter);
 
         if *idy == !0u32 {
-            addition_result.push(bases[*idx as usize]);
+            addition_result.push(base(*idx));
             scratch_space.push(None);
         } else {
-            let (mut a, mut b) = (bases[*idx as usize], bases[*idy as usize]);
+            let (mut a, mut b) = (base(*idx), base(*idy));
             G::batch_add_loop_1(&mut a, &mut b, &half, &mut inversion_tmp);
             addition_result.push(a);
             scratch_space.push(Some(b));
@@ -172,13 +183,14 @@ fn batch_add_write<G: AffineCurve>(
 }
 
 #[inline]
-pub(super) fn batch_add<G: AffineCurve>(
+pub(crate) fn batch_add<G: AffineCurve>(
     num_buckets: usize,
     bases: &[G],
     bucket_positions: &mut [BucketPosition],
 ) -> Vec<G> {
     assert!(bases.len() >= bucket_positions.len());
     assert!(!bases.is_empty());
+    assert!(bases.len() < NEGATED as usize);
 
     // Fetch the ideal batch size for the number of bases.
     let batch_size = batch_size(bases.len());
@@ -325,29 +337,18 @@ pub(super) fn batch_add<G: AffineCurve>(
 }
 
 #[inline]
-fn batched_window<G: AffineCurve>(
-    bases: &[G],
-    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
-    w_start: usize,
-    c: usize,
-) -> (G::Projective, usize) {
-    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-    let window_size = if (w_start % c) != 0 { w_start % c } else { c };
-    let num_buckets = (1 << window_size) - 1;
-
-    let mut bucket_positions: Vec<_> = scalars
-        .iter()
-        .enumerate()
-        .map(|(scalar_index, &scalar)| {
-            let mut scalar = scalar;
-
-            // We right-shift by w_start, thus getting rid of the lower bits.
-            scalar.divn(w_start as u32);
-
-            // We mod the remaining bits by the window size.
-            let scalar = (scalar.as_ref()[0] % (1 << c)) as i32;
-
-            BucketPosition { bucket_index: (scalar - 1) as u32, scalar_index: scalar_index as u32 }
+fn batched_window<G: AffineCurve>(bases: &[G], digits: &SignedDigits, window: usize) -> G::Projective {
+    // The buckets hold the multiples `1..=2^{c - 1}` of the bases; negative digits add the negated bases.
+    let num_buckets = 1 << (digits.window_size() - 1);
+
+    let mut bucket_positions: Vec<_> = (0..bases.len())
+        .filter_map(|scalar_index| {
+            let digit = digits.digit(scalar_index, window);
+            let scalar_index = match digit < 0 {
+                true => scalar_index as u32 | NEGATED,
+                false => scalar_index as u32,
+            };
+            (digit != 0).then(|| BucketPosition { bucket_index: digit.unsigned_abs() - 1, scalar_index })
         })
         .collect();
 
@@ -359,13 +360,21 @@ fn batched_window<G: AffineCurve>(
         running_sum.add_assign_mixed(&b);
         res += &running_sum;
     }
-
-    (res, window_size)
+    res
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-    if bases.len() < 15 {
-        let num_bits = G::ScalarField::size_in_bits();
+    msm_with_num_bits(bases, scalars, G::ScalarField::size_in_bits())
+}
+
+/// Computes the MSM of scalars that are all less than `2^num_bits`.
+pub(super) fn msm_with_num_bits<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    num_bits: usize,
+) -> G::Projective {
+    let num_terms = bases.len().min(scalars.len());
+    if num_terms < 15 {
         let bigint_size = <G::ScalarField as PrimeField>::BigInteger::NUM_LIMBS * 64;
         let mut bits =
             scalars.iter().map(|s| BitIteratorBE::new(s.as_ref()).skip(bigint_size - num_bits)).collect::<Vec<_>>();
@@ -386,30 +395,21 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         debug_assert!(bits.iter_mut().all(|b| b.next().is_none()));
         sum
     } else {
-        // Determine the bucket size `c` (chosen empirically).
-        let c = match scalars.len() < 32 {
-            true => 1,
-            false => crate::msm::ln_without_floats(scalars.len()) + 2,
-        };
+        // Recode the scalars into signed digits, with the window size that minimizes the cost.
+        let window_size = super::window_size(num_terms, num_bits, crate::msm::num_threads());
+        let digits = SignedDigits::new(&scalars[..num_terms], num_bits, window_size);
 
-        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
-
-        // Each window is of size `c`.
-        // We divide up the bits 0..num_bits into windows of size `c`, and
-        // in parallel process each such window.
-        let window_sums: Vec<_> =
-            cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| batched_window(bases, scalars, w_start, c)).collect();
-
-        // We store the sum for the lowest window.
-        let (lowest, window_sums) = window_sums.split_first().unwrap();
+        // Process the windows in parallel.
+        let window_sums: Vec<_> = cfg_into_iter!(0..digits.num_windows())
+            .map(|window| batched_window(&bases[..num_terms], &digits, window))
+            .collect();
 
         // We're traversing windows from high to low.
-        window_sums.iter().rev().fold(G::Projective::zero(), |mut total, (sum_i, window_size)| {
-            total += sum_i;
-            for _ in 0..*window_size {
+        window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+            for _ in 0..window_size {
                 total.double_in_place();
             }
-            total
-        }) + lowest.0
+            total + sum_i
+        })
     }
 }
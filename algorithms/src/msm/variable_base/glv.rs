@@ -0,0 +1,41 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::batched;
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, cfg_iter};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Computes the MSM by splitting every term `scalar * base` into two terms with half-length scalars,
+/// using the GLV endomorphism of the curve. This halves the number of windows of the batched MSM.
+///
+/// Returns `None` if the curve has no GLV endomorphism, or if a scalar is not a canonical field element.
+pub fn msm<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> Option<G::Projective> {
+    let decompositions = cfg_iter!(bases)
+        .zip(scalars)
+        .map(|(base, scalar)| base.glv_decompose(G::ScalarField::from_bigint(*scalar)?))
+        .collect::<Option<Vec<_>>>()?;
+    let (bases, scalars): (Vec<_>, Vec<_>) =
+        decompositions.into_iter().flatten().map(|(base, scalar)| (base, scalar.to_bigint())).unzip();
+
+    let num_bits = scalars.iter().map(|scalar| scalar.num_bits()).max().unwrap_or(0);
+    Some(batched::msm_with_num_bits(&bases, &scalars, num_bits as usize))
+}
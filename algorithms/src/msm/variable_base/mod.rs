@@ -14,6 +14,7 @@
 // limitations under the License.
 
 pub mod batched;
+pub mod glv;
 pub mod standard;
 
 #[cfg(target_arch = "x86_64")]
@@ -21,25 +22,168 @@ pub mod prefetch;
 
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, cfg_chunks_mut};
 
 use core::any::TypeId;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The backend used to compute a variable-base multi-scalar multiplication.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MSMBackend {
+    /// Computes the MSM on the CPU.
+    Cpu,
+    /// Computes the MSM on the GPU when it has more than `threshold` terms.
+    /// If the GPU is unavailable or fails, the MSM falls back to the CPU.
+    ///
+    /// Note that this requires the `cuda` feature; without it, this is equivalent to `MSMBackend::Cpu`.
+    Gpu { threshold: usize },
+}
+
+impl MSMBackend {
+    /// The default minimum number of terms for an MSM to be offloaded to the GPU.
+    pub const DEFAULT_GPU_THRESHOLD: usize = 1 << 10;
+
+    /// Returns `true` if the GPU backend is compiled in.
+    pub const fn is_gpu_available() -> bool {
+        cfg!(all(feature = "cuda", target_arch = "x86_64"))
+    }
+}
+
+impl Default for MSMBackend {
+    /// Returns the GPU backend if it is compiled in, and the CPU backend otherwise.
+    fn default() -> Self {
+        match Self::is_gpu_available() {
+            true => Self::Gpu { threshold: Self::DEFAULT_GPU_THRESHOLD },
+            false => Self::Cpu,
+        }
+    }
+}
+
+/// The smallest number of scalar bits in a window of the variable-base MSM.
+const MIN_WINDOW_SIZE: usize = 2;
+/// The largest number of scalar bits in a window of the variable-base MSM.
+const MAX_WINDOW_SIZE: usize = 20;
+
+/// Returns the window size that minimizes the estimated cost of Pippenger's algorithm with signed digits,
+/// for `num_terms` scalars of `num_bits` bits whose windows are processed on `num_threads` threads.
+pub(crate) fn window_size(num_terms: usize, num_bits: usize, num_threads: usize) -> usize {
+    (MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE)
+        .min_by_key(|&window_size| {
+            let num_windows = SignedDigits::num_windows_for(num_bits, window_size);
+            // Each window costs an addition per term, and two additions per bucket to sum its buckets.
+            let window_cost = num_terms + (1 << window_size);
+            // The windows are processed in parallel, in rounds of `num_threads` windows.
+            // Among equally fast window sizes, prefer the one with the least total work.
+            (num_windows.div_ceil(num_threads.max(1)) * window_cost, num_windows * window_cost)
+        })
+        .unwrap_or(MIN_WINDOW_SIZE)
+}
+
+/// The scalars of an MSM, recoded into signed digits of `window_size` bits.
+///
+/// Every digit lies in `-2^{c - 1}..2^{c - 1}`, so that each window of Pippenger's algorithm
+/// only needs `2^{c - 1}` buckets, and adds the negated base for a negative digit.
+pub(crate) struct SignedDigits {
+    /// The number of scalar bits in each window.
+    window_size: usize,
+    /// The number of windows of each scalar.
+    num_windows: usize,
+    /// The digit of the `i`-th scalar in window `j`, at index `i * num_windows + j`.
+    digits: Vec<i32>,
+}
+
+impl SignedDigits {
+    /// Recodes the lowest `num_bits` bits of the scalars into signed digits of `window_size` bits.
+    pub(crate) fn new<B: BigInteger>(scalars: &[B], num_bits: usize, window_size: usize) -> Self {
+        assert!((MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE).contains(&window_size));
+        let num_windows = Self::num_windows_for(num_bits, window_size);
+        let half = 1u64 << (window_size - 1);
+
+        // Returns the bits `start..start + window_size` of the scalar, truncated to `num_bits`.
+        let bits = |limbs: &[u64], start: usize| {
+            let end = (start + window_size).min(num_bits);
+            if start >= end {
+                return 0;
+            }
+            let (limb, shift) = (start / 64, start % 64);
+            let mut bits = limbs[limb] >> shift;
+            if shift + (end - start) > 64 {
+                bits |= limbs[limb + 1] << (64 - shift);
+            }
+            bits & ((1u64 << (end - start)) - 1)
+        };
+
+        let mut digits = vec![0i32; scalars.len() * num_windows];
+        cfg_chunks_mut!(digits, num_windows).zip(scalars).for_each(|(digits, scalar)| {
+            let mut carry = 0;
+            for (window, digit) in digits.iter_mut().enumerate() {
+                let value = bits(scalar.as_ref(), window * window_size) + carry;
+                // Digits of at least `2^{c - 1}` become negative, and carry `2^c` into the next window.
+                carry = u64::from(value >= half);
+                *digit = value as i32 - ((carry as i32) << window_size);
+            }
+        });
+        Self { window_size, num_windows, digits }
+    }
+
+    /// Returns the number of windows of `num_bits`-bit scalars. The last window has two spare bits,
+    /// so that it stays below `2^{c - 1}` after absorbing the final carry.
+    const fn num_windows_for(num_bits: usize, window_size: usize) -> usize {
+        (num_bits + 2).div_ceil(window_size)
+    }
+
+    /// Returns the number of scalar bits in each window.
+    pub(crate) const fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of windows of each scalar.
+    pub(crate) const fn num_windows(&self) -> usize {
+        self.num_windows
+    }
+
+    /// Returns the digit of the given scalar in the given window.
+    #[inline]
+    pub(crate) fn digit(&self, scalar_index: usize, window: usize) -> i32 {
+        self.digits[scalar_index * self.num_windows + window]
+    }
+}
+
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        Self::msm_with_backend(MSMBackend::default(), bases, scalars)
+    }
+
+    pub fn msm_with_backend<G: AffineCurve>(
+        backend: MSMBackend,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
+            if let MSMBackend::Gpu { threshold } = backend {
+                if scalars.len() > threshold {
+                    let result =
+                        snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
+                            bases, scalars,
+                        );
+                    if let Ok(result) = result {
+                        return result;
+                    }
                 }
             }
+            #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+            let _ = backend;
+            glv::msm(bases, scalars).unwrap_or_else(|| batched::msm(bases, scalars))
+        }
+        // For other curves with cheap batched affine additions, we accumulate the buckets of Pippenger's algorithm
+        // with batched affine additions.
+        else if G::PREFERS_BATCH_AFFINE_ADDITION {
             batched::msm(bases, scalars)
         }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
@@ -103,9 +247,107 @@ mod tests {
 
             let candidate = batched::msm(bases.as_slice(), scalars.as_slice()).to_affine();
             assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
+
+            let candidate = glv::msm(bases.as_slice(), scalars.as_slice()).unwrap().to_affine();
+            assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
+
+            let candidate = VariableBase::msm_with_backend(MSMBackend::Cpu, &bases, &scalars).to_affine();
+            assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
+
+            let backend = MSMBackend::Gpu { threshold: 0 };
+            let candidate = VariableBase::msm_with_backend(backend, &bases, &scalars).to_affine();
+            assert_eq!(naive_a, candidate, "MSM size: {msm_size}");
         }
     }
 
+    #[test]
+    fn test_signed_digits() {
+        use snarkvm_fields::{Field, One, Zero};
+        use snarkvm_utilities::Uniform;
+
+        let mut rng = TestRng::default();
+        let mut scalars = (0..20).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        scalars.extend([Fr::zero(), Fr::one(), -Fr::one()]);
+        let bigints = scalars.iter().map(|scalar| scalar.to_bigint()).collect::<Vec<_>>();
+
+        for window_size in MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE {
+            let digits = SignedDigits::new(&bigints, Fr::size_in_bits(), window_size);
+            let bound = 1i32 << (window_size - 1);
+            for (i, scalar) in scalars.iter().enumerate() {
+                let mut recomposed = Fr::zero();
+                for window in (0..digits.num_windows()).rev() {
+                    let digit = digits.digit(i, window);
+                    assert!((-bound..bound).contains(&digit), "window_size = {window_size}");
+                    recomposed *= Fr::from(2u64).pow([window_size as u64]);
+                    recomposed += if digit < 0 { -Fr::from(digit.unsigned_abs()) } else { Fr::from(digit as u32) };
+                }
+                assert_eq!(recomposed, *scalar, "window_size = {window_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_window_size() {
+        let num_bits = Fr::size_in_bits();
+        // Small MSMs use small windows, and large MSMs use large windows.
+        assert_eq!(window_size(1, num_bits, 1), MIN_WINDOW_SIZE);
+        assert!(window_size(1 << 10, num_bits, 1) < window_size(1 << 20, num_bits, 1));
+        assert!(window_size(1 << 30, num_bits, 1) <= MAX_WINDOW_SIZE);
+        // More threads favor more windows.
+        assert!(window_size(1 << 16, num_bits, 64) <= window_size(1 << 16, num_bits, 1));
+    }
+
+    #[test]
+    fn test_msm_g2() {
+        use snarkvm_curves::{ProjectiveCurve, bls12_377::G2Affine};
+        let mut rng = TestRng::default();
+        for msm_size in [1, 10, 100, 1000] {
+            let (bases, scalars) = create_scalar_bases::<G2Affine, Fr>(&mut rng, msm_size);
+            let naive = VariableBase::msm_naive(&bases, &scalars).to_affine();
+            assert_eq!(naive, VariableBase::msm(&bases, &scalars).to_affine(), "MSM size: {msm_size}");
+            assert_eq!(naive, standard::msm(&bases, &scalars).to_affine(), "MSM size: {msm_size}");
+        }
+    }
+
+    #[test]
+    fn test_msm_edwards() {
+        use snarkvm_curves::{
+            ProjectiveCurve,
+            edwards_bls12::{EdwardsAffine, Fr as EdwardsFr},
+        };
+        let mut rng = TestRng::default();
+        for msm_size in [1, 10, 100, 1000] {
+            let (bases, scalars) = create_scalar_bases::<EdwardsAffine, EdwardsFr>(&mut rng, msm_size);
+            let naive = VariableBase::msm_naive(&bases, &scalars).to_affine();
+            assert_eq!(naive, VariableBase::msm(&bases, &scalars).to_affine(), "MSM size: {msm_size}");
+            assert_eq!(naive, batched::msm(&bases, &scalars).to_affine(), "MSM size: {msm_size}");
+        }
+    }
+
+    #[test]
+    fn test_msm_glv_edge_cases() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::{One, Zero};
+
+        let mut rng = TestRng::default();
+        let (mut bases, mut scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 64);
+        scalars[0] = Fr::zero().to_bigint();
+        scalars[1] = Fr::one().to_bigint();
+        scalars[2] = (-Fr::one()).to_bigint();
+        bases[3] = G1Affine::zero();
+        let naive = VariableBase::msm_naive(&bases, &scalars).to_affine();
+        assert_eq!(naive, glv::msm(&bases, &scalars).unwrap().to_affine());
+
+        // All scalars are zero.
+        let zeros = vec![Fr::zero().to_bigint(); bases.len()];
+        assert!(glv::msm(&bases, &zeros).unwrap().is_zero());
+
+        // Non-canonical scalars are rejected.
+        scalars[0] = Fr::modulus();
+        assert!(glv::msm(&bases, &scalars).is_none());
+        assert_eq!(VariableBase::msm(&bases, &scalars).to_affine(), batched::msm(&bases, &scalars).to_affine());
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
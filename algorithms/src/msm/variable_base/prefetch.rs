@@ -33,9 +33,12 @@ macro_rules! prefetch_slice {
 macro_rules! prefetch_slice_write {
     ($curve: ident, $slice_1: ident, $slice_2: ident, $prefetch_iter: ident) => {
         if let Some((idp_1, idp_2)) = $prefetch_iter.next() {
-            $crate::msm::variable_base::prefetch::prefetch::<$curve>(&$slice_1[*idp_1 as usize]);
+            // Strip the flag of negated bases from the indices.
+            let idp_1 = *idp_1 & !$crate::msm::variable_base::batched::NEGATED;
+            $crate::msm::variable_base::prefetch::prefetch::<$curve>(&$slice_1[idp_1 as usize]);
             if *idp_2 != !0u32 {
-                $crate::msm::variable_base::prefetch::prefetch::<$curve>(&$slice_2[*idp_2 as usize]);
+                let idp_2 = *idp_2 & !$crate::msm::variable_base::batched::NEGATED;
+                $crate::msm::variable_base::prefetch::prefetch::<$curve>(&$slice_2[idp_2 as usize]);
             }
         }
     };
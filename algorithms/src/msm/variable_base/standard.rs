@@ -13,93 +13,53 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::SignedDigits;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{BigInteger, cfg_into_iter};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::cfg_into_iter;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
-fn update_buckets<G: AffineCurve>(
-    base: &G,
-    mut scalar: <G::ScalarField as PrimeField>::BigInteger,
-    w_start: usize,
-    c: usize,
-    buckets: &mut [G::Projective],
-) {
-    // We right-shift by w_start, thus getting rid of the lower bits.
-    scalar.divn(w_start as u32);
-
-    // We mod the remaining bits by the window size.
-    let scalar = scalar.as_ref()[0] % (1 << c);
-
-    // If the scalar is non-zero, we update the corresponding bucket.
-    // (Recall that `buckets` doesn't have a zero bucket.)
-    if scalar != 0 {
-        buckets[(scalar - 1) as usize].add_assign_mixed(base);
+fn standard_window<G: AffineCurve>(bases: &[G], digits: &SignedDigits, window: usize) -> G::Projective {
+    // The buckets hold the multiples `1..=2^{c - 1}` of the bases; negative digits add the negated bases.
+    let mut buckets = vec![G::Projective::zero(); 1 << (digits.window_size() - 1)];
+    for (scalar_index, base) in bases.iter().enumerate() {
+        let digit = digits.digit(scalar_index, window);
+        match digit.cmp(&0) {
+            core::cmp::Ordering::Greater => buckets[(digit - 1) as usize].add_assign_mixed(base),
+            core::cmp::Ordering::Less => buckets[(-digit - 1) as usize].add_assign_mixed(&-*base),
+            core::cmp::Ordering::Equal => (),
+        }
     }
-}
 
-fn standard_window<G: AffineCurve>(
-    bases: &[G],
-    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
-    w_start: usize,
-    c: usize,
-) -> (G::Projective, usize) {
     let mut res = G::Projective::zero();
-    let fr_one = G::ScalarField::one().to_bigint();
-
-    // We only process unit scalars once in the first window.
-    if w_start == 0 {
-        scalars.iter().zip(bases).filter(|(&s, _)| s == fr_one).for_each(|(_, base)| {
-            res.add_assign_mixed(base);
-        });
-    }
-
-    // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
-    let window_size = if (w_start % c) != 0 { w_start % c } else { c };
-    let mut buckets = vec![G::Projective::zero(); (1 << window_size) - 1];
-    scalars
-        .iter()
-        .zip(bases)
-        .filter(|(&s, _)| s > fr_one)
-        .for_each(|(&scalar, base)| update_buckets(base, scalar, w_start, c, &mut buckets));
-    // G::Projective::batch_normalization(&mut buckets);
-
     for running_sum in buckets.into_iter().rev().scan(G::Projective::zero(), |sum, b| {
         *sum += b;
         Some(*sum)
     }) {
         res += running_sum;
     }
-
-    (res, window_size)
+    res
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-    // Determine the bucket size `c` (chosen empirically).
-    let c = match scalars.len() < 32 {
-        true => 1,
-        false => crate::msm::ln_without_floats(scalars.len()) + 2,
-    };
-
+    // Recode the scalars into signed digits, with the window size that minimizes the cost.
+    let num_terms = bases.len().min(scalars.len());
     let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+    let window_size = super::window_size(num_terms, num_bits, crate::msm::num_threads());
+    let digits = SignedDigits::new(&scalars[..num_terms], num_bits, window_size);
 
-    // Each window is of size `c`.
-    // We divide up the bits 0..num_bits into windows of size `c`, and
-    // in parallel process each such window.
-    let window_sums: Vec<_> =
-        cfg_into_iter!(0..num_bits).step_by(c).map(|w_start| standard_window(bases, scalars, w_start, c)).collect();
-
-    // We store the sum for the lowest window.
-    let (lowest, window_sums) = window_sums.split_first().unwrap();
+    // Process the windows in parallel.
+    let window_sums: Vec<_> = cfg_into_iter!(0..digits.num_windows())
+        .map(|window| standard_window(&bases[..num_terms], &digits, window))
+        .collect();
 
     // We're traversing windows from high to low.
-    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, (sum_i, window_size)| {
-        total += sum_i;
-        for _ in 0..*window_size {
+    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum_i| {
+        for _ in 0..window_size {
             total.double_in_place();
         }
-        total
-    }) + lowest.0
+        total + sum_i
+    })
 }
@@ -61,14 +61,45 @@ pub enum PCError {
         num_powers: usize,
     },
 
-    #[error("The lagrange basis is not a power of two.")]
-    LagrangeBasisSizeIsNotPowerOfTwo,
+    #[error("the degree ({degree}) of the polynomial {label} is greater than the maximum degree ({max_degree})")]
+    DegreeTooLarge {
+        /// Degree of the polynomial.
+        degree: usize,
+        /// Maximum degree.
+        max_degree: usize,
+        /// Label of the offending polynomial.
+        label: String,
+    },
+
+    #[error("the Lagrange basis size ({size}) is not a power of two")]
+    LagrangeBasisSizeIsNotPowerOfTwo {
+        /// The requested size of the Lagrange basis.
+        size: usize,
+    },
 
-    #[error("The lagrange basis is larger than the supported degree.")]
-    LagrangeBasisSizeIsTooLarge,
+    #[error("the Lagrange basis size ({size}) is larger than the supported size ({max_size})")]
+    LagrangeBasisSizeIsTooLarge {
+        /// The requested size of the Lagrange basis.
+        size: usize,
+        /// The maximum supported size of a Lagrange basis.
+        max_size: usize,
+    },
 
-    #[error("The degree provided to `trim` was too large.")]
-    TrimmingDegreeTooLarge,
+    #[error("the degree provided to `trim` ({degree}) is greater than the maximum degree ({max_degree})")]
+    TrimmingDegreeTooLarge {
+        /// The degree provided to `trim`.
+        degree: usize,
+        /// The maximum degree supported by the parameters.
+        max_degree: usize,
+    },
+
+    #[error("the enforced degree bound ({degree_bound}) is greater than the supported degree ({supported_degree})")]
+    DegreeBoundTooLarge {
+        /// The enforced degree bound.
+        degree_bound: usize,
+        /// The supported degree provided to `trim`.
+        supported_degree: usize,
+    },
 
     #[error("the equation \"{0}\" contained degree-bounded polynomials")]
     EquationHasDegreeBounds(String),
@@ -76,8 +107,41 @@ pub enum PCError {
     #[error("the degree bound ({0}) is not supported by the parameters")]
     UnsupportedDegreeBound(usize),
 
-    #[error("the Lagrange basis size ({0}) is not supported by the parameters")]
-    UnsupportedLagrangeBasisSize(usize),
+    #[error(
+        "the Lagrange basis size ({size}) is not supported by the parameters (supported sizes: {supported_sizes:?})"
+    )]
+    UnsupportedLagrangeBasisSize {
+        /// The requested size of the Lagrange basis.
+        size: usize,
+        /// The sizes of the Lagrange bases held by the parameters.
+        supported_sizes: Vec<usize>,
+    },
+
+    #[error("{num_polynomials} polynomials were provided, but {num_randomness} commitment randomness values")]
+    MismatchedRandomness {
+        /// The number of polynomials.
+        num_polynomials: usize,
+        /// The number of commitment randomness values.
+        num_randomness: usize,
+    },
+
+    #[error("expected {expected} evaluations of polynomial \"{label}\", but found {found}")]
+    MismatchedEvaluations {
+        /// The label of the polynomial.
+        label: String,
+        /// The expected number of evaluations.
+        expected: usize,
+        /// The number of evaluations provided.
+        found: usize,
+    },
+
+    #[error("expected {expected} openings in the proof, but found {found}")]
+    MismatchedOpenings {
+        /// The expected number of openings.
+        expected: usize,
+        /// The number of openings in the proof.
+        found: usize,
+    },
 
     #[error(
         "the degree bound ({degree_bound}) for the polynomial {label} \
@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::*;
+
+/// `UniversalParams` are the transparent public parameters for the FRI scheme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams {
+    /// The maximum degree supported by the parameters.
+    pub max_degree: usize,
+    /// The logarithm of the ratio between the size of the evaluation domain and the degree bound.
+    pub log_blowup_factor: usize,
+    /// The number of queries made by the verifier.
+    pub num_queries: usize,
+}
+
+impl UniversalParams {
+    /// Returns the maximum degree supported by the parameters.
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey {
+    /// The maximum degree supported by the committer key.
+    pub supported_degree: usize,
+    /// The logarithm of the ratio between the size of the evaluation domain and the degree bound.
+    pub log_blowup_factor: usize,
+    /// The number of queries made by the verifier.
+    pub num_queries: usize,
+}
+
+impl CommitterKey {
+    /// Returns the maximum degree supported by the committer key.
+    pub fn supported_degree(&self) -> usize {
+        self.supported_degree
+    }
+
+    /// Returns the power-of-two bound on the number of coefficients that is enforced by the low-degree test.
+    pub fn degree_bound(&self) -> usize {
+        (self.supported_degree + 1).next_power_of_two()
+    }
+
+    /// Returns the size of the evaluation domain.
+    pub fn domain_size(&self) -> usize {
+        self.degree_bound() << self.log_blowup_factor
+    }
+
+    /// Returns the number of folding rounds of the low-degree test.
+    pub fn num_rounds(&self) -> usize {
+        self.degree_bound().trailing_zeros() as usize
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+///
+/// The FRI verifier only requires the parameters of the scheme, so this is the same as the `CommitterKey`.
+pub type VerifierKey = CommitterKey;
+
+/// `Commitment` commits to a polynomial. It is output by `FriPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment(
+    /// The commitment is the root of a Merkle tree over the evaluations of the polynomial.
+    pub [u8; 32],
+);
+
+/// An opening of a Merkle tree leaf, which holds the evaluations at a point `x` and at `-x`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MerkleOpening<F: PrimeField> {
+    /// The evaluation at `x`.
+    pub value: F,
+    /// The evaluation at `-x`.
+    pub negated_value: F,
+    /// The authentication path of the leaf.
+    pub path: Vec<[u8; 32]>,
+}
+
+/// The openings for a single query of the verifier.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct QueryProof<F: PrimeField> {
+    /// The openings of the committed polynomials, ordered by label.
+    pub polynomial_openings: Vec<MerkleOpening<F>>,
+    /// The openings of the committed layers of the low-degree test.
+    pub layer_openings: Vec<MerkleOpening<F>>,
+}
+
+/// `Proof` is an evaluation proof for a query set. It is output by `FriPC::batch_open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<F: PrimeField> {
+    /// Commitments to the layers of the low-degree test, excluding the first and the last.
+    pub layer_commitments: Vec<Commitment>,
+    /// The constant value of the last layer of the low-degree test.
+    pub final_value: F,
+    /// The openings for each query of the verifier.
+    pub query_proofs: Vec<QueryProof<F>>,
+}
@@ -0,0 +1,119 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::MerkleOpening;
+use crate::crypto_hash::sha256::sha256;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::cfg_into_iter;
+
+use anyhow::{Result, ensure};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The prefix of the hash of a leaf.
+const LEAF_PREFIX: u8 = 0;
+/// The prefix of the hash of an internal node.
+const NODE_PREFIX: u8 = 1;
+
+/// A Merkle tree over evaluations on a multiplicative coset of even size.
+///
+/// The `i`-th leaf holds the evaluations at indices `i` and `i + n / 2`, which are the evaluations
+/// at a point `x` and at `-x`, so that a single opening provides both inputs to a folding step.
+pub(super) struct MerkleTree<F: PrimeField> {
+    /// The evaluations at the leaves.
+    evaluations: Vec<F>,
+    /// The hashes of each level of the tree, from the leaves to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    /// Constructs the Merkle tree over the given evaluations, whose number must be a power of two.
+    pub fn new(evaluations: Vec<F>) -> Result<Self> {
+        ensure!(evaluations.len() >= 2, "A Merkle tree requires at least two evaluations");
+        ensure!(evaluations.len().is_power_of_two(), "The number of evaluations must be a power of two");
+
+        let half = evaluations.len() / 2;
+        let leaves = cfg_into_iter!(0..half)
+            .map(|i| hash_leaf(evaluations[i], evaluations[i + half]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let level = levels.last().unwrap();
+            let next =
+                cfg_into_iter!(0..level.len() / 2).map(|i| hash_node(&level[2 * i], &level[2 * i + 1])).collect();
+            levels.push(next);
+        }
+        Ok(Self { evaluations, levels })
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the evaluations at the leaves.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Returns the opening of the leaf at `index`.
+    pub fn open(&self, index: usize) -> MerkleOpening<F> {
+        let half = self.evaluations.len() / 2;
+        let (value, negated_value) = (self.evaluations[index], self.evaluations[index + half]);
+
+        let mut path = Vec::with_capacity(self.levels.len() - 1);
+        let mut node_index = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            path.push(level[node_index ^ 1]);
+            node_index /= 2;
+        }
+        MerkleOpening { value, negated_value, path }
+    }
+}
+
+impl<F: PrimeField> MerkleOpening<F> {
+    /// Returns `true` if the opening is valid for the leaf at `index` in the tree with the given `root`.
+    pub fn verify(&self, root: &[u8; 32], index: usize) -> Result<bool> {
+        let mut hash = hash_leaf(self.value, self.negated_value)?;
+        let mut index = index;
+        for sibling in &self.path {
+            hash = match index % 2 == 0 {
+                true => hash_node(&hash, sibling),
+                false => hash_node(sibling, &hash),
+            };
+            index /= 2;
+        }
+        Ok(index == 0 && &hash == root)
+    }
+}
+
+/// Returns the hash of a leaf holding the evaluations at `x` and at `-x`.
+fn hash_leaf<F: PrimeField>(value: F, negated_value: F) -> Result<[u8; 32]> {
+    let mut bytes = vec![LEAF_PREFIX];
+    value.write_le(&mut bytes)?;
+    negated_value.write_le(&mut bytes)?;
+    Ok(sha256(&bytes))
+}
+
+/// Returns the hash of an internal node with the given children.
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(65);
+    bytes.push(NODE_PREFIX);
+    bytes.extend_from_slice(left);
+    bytes.extend_from_slice(right);
+    sha256(&bytes)
+}
@@ -0,0 +1,521 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    AlgebraicSponge,
+    fft::EvaluationDomain,
+    polycommit::{
+        PCError,
+        sonic_pc::{Evaluations, LabeledCommitment, LabeledPolynomial, QuerySet},
+    },
+};
+use snarkvm_fields::{PrimeField, batch_inverse};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter};
+
+use anyhow::{Result, anyhow, ensure};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+use itertools::Itertools;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod merkle;
+use merkle::MerkleTree;
+
+/// Polynomial commitment scheme with a transparent setup, based on the FRI low-degree test of
+/// [[BBHR18, “Fast Reed-Solomon Interactive Oracle Proofs of Proximity”]][fri].
+///
+/// A commitment is the root of a Merkle tree over the evaluations of a polynomial on a
+/// multiplicative coset. To open polynomials at a query set, the prover runs the low-degree test
+/// on a random linear combination of the quotients `(p(X) - p(z)) / (X - z)`, following the
+/// batching technique of [[BGKS20, “DEEP-FRI”]][deep].
+///
+/// This scheme is intended for experimentation with transparent proof modes.
+/// Degree bounds and hiding commitments are not supported by this scheme.
+///
+/// [fri]: https://eccc.weizmann.ac.il/report/2017/134/
+/// [deep]: https://eprint.iacr.org/2019/336
+#[derive(Clone, Debug)]
+pub struct FriPC<F: PrimeField, S: AlgebraicSponge<F, 2>> {
+    _field: PhantomData<(F, S)>,
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, 2>> FriPC<F, S> {
+    /// Outputs the public parameters supporting polynomials of degree up to `max_degree`,
+    /// with an evaluation domain `2^log_blowup_factor` times larger than the degree bound,
+    /// and `num_queries` queries by the verifier.
+    pub fn setup(max_degree: usize, log_blowup_factor: usize, num_queries: usize) -> Result<UniversalParams, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        if log_blowup_factor < 1 || num_queries < 1 {
+            return Err(anyhow!("The log of the blowup factor and the number of queries must be at least 1").into());
+        }
+
+        let pp = UniversalParams { max_degree, log_blowup_factor, num_queries };
+        Self::domain(&CommitterKey { supported_degree: max_degree, log_blowup_factor, num_queries })?;
+        Ok(pp)
+    }
+
+    /// Specializes the public parameters for polynomials of degree up to `supported_degree`.
+    pub fn trim(pp: &UniversalParams, supported_degree: usize) -> Result<(CommitterKey, VerifierKey), PCError> {
+        if supported_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        if supported_degree > pp.max_degree() {
+            return Err(PCError::TrimmingDegreeTooLarge { degree: supported_degree, max_degree: pp.max_degree() });
+        }
+
+        let ck =
+            CommitterKey { supported_degree, log_blowup_factor: pp.log_blowup_factor, num_queries: pp.num_queries };
+        Ok((ck, ck))
+    }
+
+    /// Outputs commitments to `polynomials`.
+    pub fn commit<'a>(
+        ck: &CommitterKey,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+    ) -> Result<Vec<LabeledCommitment<Commitment>>, PCError> {
+        let commit_time = start_timer!(|| "Committing to polynomials");
+
+        let domain = Self::domain(ck)?;
+        let mut labeled_comms = Vec::new();
+        for p in polynomials {
+            let tree = Self::commit_to_evaluations(ck, &domain, p)?;
+            labeled_comms.push(LabeledCommitment::new(p.to_label(), Commitment(tree.root()), None));
+        }
+
+        end_timer!(commit_time);
+        Ok(labeled_comms)
+    }
+
+    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
+    /// of the polynomials at the points in the query set.
+    ///
+    /// The commitments to the polynomials are expected to have been absorbed into `fs_rng`.
+    pub fn batch_open<'a>(
+        ck: &CommitterKey,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+        query_set: &QuerySet<F>,
+        fs_rng: &mut S,
+    ) -> Result<Proof<F>> {
+        let polynomials: HashMap<_, _> = labeled_polynomials.into_iter().map(|p| (p.label(), p)).collect();
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            polynomials.len(),
+            query_set.len(),
+        ));
+
+        let domain = Self::domain(ck)?;
+        let query_to_labels_map = Self::query_to_labels_map(query_set);
+
+        // Recompute the evaluations of each queried polynomial.
+        let mut trees = BTreeMap::new();
+        for label in query_to_labels_map.values().flat_map(|(_, labels)| labels) {
+            if !trees.contains_key(label) {
+                let polynomial =
+                    polynomials.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                trees.insert(*label, Self::commit_to_evaluations(ck, &domain, polynomial)?);
+            }
+        }
+
+        // Compute the evaluations of the random linear combination of the quotients.
+        let quotient_time = start_timer!(|| "Computing the combined quotient");
+        let domain_elements = Self::domain_elements(&domain);
+        let mut quotient = vec![F::zero(); domain.size()];
+        for (point, labels) in query_to_labels_map.values() {
+            let values = labels.iter().map(|label| polynomials[label.as_str()].evaluate(**point)).collect::<Vec<_>>();
+            let (challenges, quotient_challenge) = Self::combination_challenges(**point, &values, fs_rng);
+            let combined_value = values.iter().zip_eq(&challenges).map(|(v, c)| *v * c).sum::<F>();
+
+            let mut denominators = cfg_iter!(domain_elements).map(|x| *x - **point).collect::<Vec<_>>();
+            ensure!(denominators.iter().all(|d| !d.is_zero()), "The evaluation point lies in the evaluation domain");
+            batch_inverse(&mut denominators);
+
+            let evaluations = labels.iter().map(|label| trees[label].evaluations()).collect::<Vec<_>>();
+            let terms = cfg_into_iter!(0..domain.size())
+                .map(|i| {
+                    let combined = evaluations.iter().zip_eq(&challenges).map(|(e, c)| e[i] * c).sum::<F>();
+                    quotient_challenge * (combined - combined_value) * denominators[i]
+                })
+                .collect::<Vec<_>>();
+            quotient.iter_mut().zip_eq(terms).for_each(|(q, t)| *q += t);
+        }
+        end_timer!(quotient_time);
+
+        // Fold the combined quotient into a constant, committing to each intermediate layer.
+        let fold_time = start_timer!(|| "Folding the combined quotient");
+        let num_rounds = ck.num_rounds();
+        let mut layer_trees: Vec<MerkleTree<F>> = Vec::with_capacity(num_rounds - 1);
+        let mut offset = F::multiplicative_generator();
+        let mut generator = domain.group_gen;
+        let mut final_value = F::zero();
+        for round in 0..num_rounds {
+            let challenge = fs_rng.squeeze_native_field_elements(1)[0];
+            let previous = layer_trees.last().map(|tree| tree.evaluations()).unwrap_or(&quotient);
+            let next = Self::fold(previous, offset, generator, challenge)?;
+            offset.square_in_place();
+            generator.square_in_place();
+
+            match round + 1 < num_rounds {
+                true => {
+                    let tree = MerkleTree::new(next)?;
+                    Self::absorb_commitment(fs_rng, &Commitment(tree.root()));
+                    layer_trees.push(tree);
+                }
+                false => final_value = next[0],
+            }
+        }
+        fs_rng.absorb_native_field_elements(&[final_value]);
+        end_timer!(fold_time);
+
+        // Open the committed evaluations at the queried indices.
+        let query_proofs = Self::query_indices(ck, fs_rng)
+            .into_iter()
+            .map(|index| {
+                let polynomial_openings = trees.values().map(|tree| tree.open(index)).collect();
+                let mut layer_index = index;
+                let layer_openings = layer_trees
+                    .iter()
+                    .map(|tree| {
+                        layer_index %= tree.evaluations().len() / 2;
+                        tree.open(layer_index)
+                    })
+                    .collect();
+                QueryProof { polynomial_openings, layer_openings }
+            })
+            .collect();
+
+        end_timer!(open_time);
+        Ok(Proof {
+            layer_commitments: layer_trees.iter().map(|tree| Commitment(tree.root())).collect(),
+            final_value,
+            query_proofs,
+        })
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `commitments`.
+    pub fn batch_check<'a>(
+        vk: &VerifierKey,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment>>,
+        query_set: &QuerySet<F>,
+        values: &Evaluations<F>,
+        proof: &Proof<F>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+
+        let domain = Self::domain(vk)?;
+        let query_to_labels_map = Self::query_to_labels_map(query_set);
+
+        // Collect the commitments to the queried polynomials, in the order of the openings.
+        let labels =
+            query_to_labels_map.values().flat_map(|(_, labels)| labels.iter().copied()).collect::<BTreeSet<_>>();
+        let label_commitments = labels
+            .iter()
+            .map(|label| commitments.get(*label).ok_or(PCError::MissingPolynomial { label: label.to_string() }))
+            .collect::<Result<Vec<_>, _>>()?;
+        let label_indices: HashMap<_, _> = labels.iter().enumerate().map(|(i, label)| (*label, i)).collect();
+
+        // Recompute the combination challenges, and the combined value for each point.
+        let mut combinations = Vec::with_capacity(query_to_labels_map.len());
+        for (point, point_labels) in query_to_labels_map.values() {
+            let point_values = point_labels
+                .iter()
+                .map(|label| {
+                    values
+                        .get(&(label.to_string(), **point))
+                        .copied()
+                        .ok_or(PCError::MissingEvaluation { label: label.to_string() })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let (challenges, quotient_challenge) = Self::combination_challenges(**point, &point_values, fs_rng);
+            let combined_value = point_values.iter().zip_eq(&challenges).map(|(v, c)| *v * c).sum::<F>();
+            let indexed_challenges =
+                point_labels.iter().map(|label| label_indices[label]).zip_eq(challenges).collect::<Vec<_>>();
+            combinations.push((**point, indexed_challenges, combined_value, quotient_challenge));
+        }
+
+        // Recompute the folding challenges and the queried indices.
+        let num_rounds = vk.num_rounds();
+        if proof.layer_commitments.len() + 1 != num_rounds || proof.query_proofs.len() != vk.num_queries {
+            return Ok(false);
+        }
+        let mut fold_challenges = Vec::with_capacity(num_rounds);
+        for round in 0..num_rounds {
+            fold_challenges.push(fs_rng.squeeze_native_field_elements(1)[0]);
+            if let Some(commitment) = proof.layer_commitments.get(round) {
+                Self::absorb_commitment(fs_rng, commitment);
+            }
+        }
+        fs_rng.absorb_native_field_elements(&[proof.final_value]);
+        let query_indices = Self::query_indices(vk, fs_rng);
+
+        for (index, query_proof) in query_indices.into_iter().zip_eq(&proof.query_proofs) {
+            let openings = &query_proof.polynomial_openings;
+            if openings.len() != label_commitments.len()
+                || query_proof.layer_openings.len() != proof.layer_commitments.len()
+            {
+                return Ok(false);
+            }
+            for (opening, commitment) in openings.iter().zip_eq(&label_commitments) {
+                if !opening.verify(&commitment.commitment().0, index)? {
+                    return Ok(false);
+                }
+            }
+
+            // Evaluate the combined quotient at `x` and at `-x`.
+            let x = F::multiplicative_generator() * domain.group_gen.pow([index as u64]);
+            let (mut value, mut negated_value) = (F::zero(), F::zero());
+            for (point, challenges, combined_value, quotient_challenge) in &combinations {
+                let (mut combined, mut negated_combined) = (F::zero(), F::zero());
+                for (i, challenge) in challenges {
+                    combined += openings[*i].value * challenge;
+                    negated_combined += openings[*i].negated_value * challenge;
+                }
+                let denominator = (x - point).inverse();
+                let negated_denominator = (-x - point).inverse();
+                let (denominator, negated_denominator) = denominator
+                    .zip(negated_denominator)
+                    .ok_or_else(|| anyhow!("The evaluation point lies in the evaluation domain"))?;
+                value += *quotient_challenge * (combined - combined_value) * denominator;
+                negated_value += *quotient_challenge * (negated_combined - combined_value) * negated_denominator;
+            }
+
+            // Check that each layer is the folding of the previous layer.
+            let mut layer_index = index;
+            let mut offset = F::multiplicative_generator();
+            let mut generator = domain.group_gen;
+            for (round, challenge) in fold_challenges.iter().enumerate() {
+                let x = offset * generator.pow([layer_index as u64]);
+                let x_inv = x.inverse().ok_or_else(|| anyhow!("The evaluation domain contains zero"))?;
+                let folded = Self::fold_pair(value, negated_value, x_inv, *challenge);
+                offset.square_in_place();
+                generator.square_in_place();
+
+                match (proof.layer_commitments.get(round), query_proof.layer_openings.get(round)) {
+                    (Some(commitment), Some(opening)) => {
+                        let half = domain.size() >> (round + 2);
+                        let expected = if layer_index < half { opening.value } else { opening.negated_value };
+                        layer_index %= half;
+                        if folded != expected || !opening.verify(&commitment.0, layer_index)? {
+                            return Ok(false);
+                        }
+                        value = opening.value;
+                        negated_value = opening.negated_value;
+                    }
+                    _ => {
+                        if folded != proof.final_value {
+                            return Ok(false);
+                        }
+                    }
+                }
+            }
+        }
+
+        end_timer!(batch_check_time);
+        Ok(true)
+    }
+
+    /// Returns the Merkle tree over the evaluations of `polynomial` on the evaluation domain.
+    fn commit_to_evaluations(
+        ck: &CommitterKey,
+        domain: &EvaluationDomain<F>,
+        polynomial: &LabeledPolynomial<F>,
+    ) -> Result<MerkleTree<F>, PCError> {
+        Self::check_degrees_and_bounds(ck, polynomial)?;
+        Ok(MerkleTree::new(domain.coset_fft(&polynomial.to_dense().coeffs))?)
+    }
+
+    /// Returns the evaluation domain, which is the coset of the subgroup of size `ck.domain_size()`
+    /// by the multiplicative generator of the field.
+    fn domain(ck: &CommitterKey) -> Result<EvaluationDomain<F>, PCError> {
+        EvaluationDomain::new(ck.domain_size()).ok_or_else(|| anyhow!("The evaluation domain is too large").into())
+    }
+
+    /// Returns the elements of the evaluation domain, in the order of the evaluations.
+    fn domain_elements(domain: &EvaluationDomain<F>) -> Vec<F> {
+        let generator = F::multiplicative_generator();
+        domain.elements().map(|element| element * generator).collect()
+    }
+
+    /// Groups the labels in the query set by the name of their point.
+    fn query_to_labels_map(query_set: &QuerySet<F>) -> BTreeMap<&String, (&F, BTreeSet<&String>)> {
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+        query_to_labels_map
+    }
+
+    /// Absorbs the point and the evaluations at it, and outputs the challenges which combine
+    /// the polynomials, and the challenge which combines the resulting quotient.
+    fn combination_challenges(point: F, values: &[F], fs_rng: &mut S) -> (Vec<F>, F) {
+        fs_rng.absorb_native_field_elements(&[point]);
+        fs_rng.absorb_native_field_elements(values);
+        let mut challenges = fs_rng.squeeze_native_field_elements(values.len() + 1).to_vec();
+        let quotient_challenge = challenges.pop().unwrap();
+        (challenges, quotient_challenge)
+    }
+
+    /// Absorbs the Merkle root of a commitment, split into two 128-bit field elements.
+    fn absorb_commitment(fs_rng: &mut S, commitment: &Commitment) {
+        let (low, high) = commitment.0.split_at(16);
+        fs_rng.absorb_native_field_elements(&[F::from_bytes_le_mod_order(low), F::from_bytes_le_mod_order(high)]);
+    }
+
+    /// Outputs `num_queries` indices of leaves in the first layer.
+    fn query_indices(ck: &CommitterKey, fs_rng: &mut S) -> Vec<usize> {
+        let num_leaves = ck.domain_size() / 2;
+        fs_rng
+            .squeeze_native_field_elements(ck.num_queries)
+            .into_iter()
+            .map(|element| (element.to_bigint().as_ref()[0] as usize) % num_leaves)
+            .collect()
+    }
+
+    /// Folds the evaluations on the coset `offset * <generator>` into evaluations on the coset
+    /// `offset^2 * <generator^2>`, using the given `challenge`.
+    fn fold(evaluations: &[F], offset: F, generator: F, challenge: F) -> Result<Vec<F>> {
+        let half = evaluations.len() / 2;
+        let offset_inv = offset.inverse().ok_or_else(|| anyhow!("The coset offset is zero"))?;
+        let generator_inv = generator.inverse().ok_or_else(|| anyhow!("The domain generator is zero"))?;
+
+        let mut x_invs = Vec::with_capacity(half);
+        let mut x_inv = offset_inv;
+        for _ in 0..half {
+            x_invs.push(x_inv);
+            x_inv *= generator_inv;
+        }
+        Ok(cfg_into_iter!(0..half)
+            .map(|i| Self::fold_pair(evaluations[i], evaluations[i + half], x_invs[i], challenge))
+            .collect())
+    }
+
+    /// Returns the evaluation at `x^2` of the folded polynomial, given the evaluations at `x` and at `-x`.
+    fn fold_pair(value: F, negated_value: F, x_inv: F, challenge: F) -> F {
+        let two_inv = F::half();
+        (value + negated_value + challenge * (value - negated_value) * x_inv) * two_inv
+    }
+
+    fn check_degrees_and_bounds(ck: &CommitterKey, p: &LabeledPolynomial<F>) -> Result<(), PCError> {
+        if let Some(degree_bound) = p.degree_bound() {
+            return Err(PCError::UnsupportedDegreeBound(degree_bound));
+        }
+        if p.is_hiding() {
+            return Err(anyhow!("Hiding commitments are not supported by FRI").into());
+        }
+        if p.degree() > ck.supported_degree() {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: p.degree() + 1,
+                num_powers: ck.supported_degree() + 1,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::*;
+    use crate::{crypto_hash::PoseidonSponge, fft::DensePolynomial};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    use rand::Rng;
+
+    type Sponge = PoseidonSponge<Fr, 2, 1>;
+    type PC_Fr = FriPC<Fr, Sponge>;
+
+    fn open_and_check(num_polynomials: usize, num_points: usize, rng: &mut TestRng) {
+        let max_degree = rng.gen_range(1..=64);
+        let supported_degree = rng.gen_range(1..=max_degree);
+
+        let pp = PC_Fr::setup(max_degree, 2, 16).unwrap();
+        let (ck, vk) = PC_Fr::trim(&pp, supported_degree).unwrap();
+
+        let polynomials = (0..num_polynomials)
+            .map(|i| {
+                let degree = rng.gen_range(0..=supported_degree);
+                LabeledPolynomial::new(format!("{i}"), DensePolynomial::<Fr>::rand(degree, rng), None, None)
+            })
+            .collect::<Vec<_>>();
+        let comms = PC_Fr::commit(&ck, &polynomials).unwrap();
+
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for i in 0..num_points {
+            let point = Fr::rand(rng);
+            for polynomial in &polynomials {
+                query_set.insert((polynomial.to_label(), (format!("{i}"), point)));
+                values.insert((polynomial.to_label(), point), polynomial.evaluate(point));
+            }
+        }
+
+        let proof = PC_Fr::batch_open(&ck, &polynomials, &query_set, &mut Sponge::new()).unwrap();
+        assert!(PC_Fr::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure a tampered proof is rejected.
+        let mut tampered = proof.clone();
+        tampered.final_value += Fr::one();
+        assert!(!PC_Fr::batch_check(&vk, &comms, &query_set, &values, &tampered, &mut Sponge::new()).unwrap());
+
+        // Ensure an incorrect evaluation is rejected.
+        let (key, value) = values.iter().next().map(|(k, v)| (k.clone(), *v)).unwrap();
+        values.insert(key, value + Fr::one());
+        assert!(!PC_Fr::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_single_poly() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(1, 1, rng);
+        }
+    }
+
+    #[test]
+    fn test_multiple_polys_multiple_queries() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(rng.gen_range(1..5), rng.gen_range(1..4), rng);
+        }
+    }
+
+    #[test]
+    fn test_degree_bound_is_unsupported() {
+        let rng = &mut TestRng::default();
+        let pp = PC_Fr::setup(8, 2, 16).unwrap();
+        let (ck, _vk) = PC_Fr::trim(&pp, 8).unwrap();
+
+        let polynomial = LabeledPolynomial::new("a", DensePolynomial::<Fr>::rand(4, rng), Some(4), None);
+        assert!(PC_Fr::commit(&ck, [&polynomial]).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::Zero;
+use snarkvm_utilities::serialize::*;
+
+/// `UniversalParams` are the transparent public parameters for the IPA scheme.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    pub comm_key: Vec<G>,
+    /// The generator used to bind the claimed evaluation in an opening proof.
+    pub h: G,
+    /// The generator used to hide a commitment.
+    pub s: G,
+}
+
+impl<G: AffineCurve> UniversalParams<G> {
+    /// Returns the maximum degree supported by the parameters.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    /// The number of generators is always a power of two.
+    pub comm_key: Vec<G>,
+    /// The generator used to bind the claimed evaluation in an opening proof.
+    pub h: G,
+    /// The generator used to hide a commitment.
+    pub s: G,
+    /// The maximum degree supported by the committer key.
+    pub supported_degree: usize,
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// Returns the maximum degree supported by the committer key.
+    pub fn supported_degree(&self) -> usize {
+        self.supported_degree
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+///
+/// The IPA verifier requires the full set of generators, so this is the same as the `CommitterKey`.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// `Commitment` commits to a polynomial. It is output by `InnerProductArgPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve>(
+    /// The commitment is a group element.
+    pub G,
+);
+
+/// `Randomness` hides the polynomial inside a commitment. It is output by `InnerProductArgPC::commit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<G: AffineCurve> {
+    /// For IPA, the commitment randomness is a random scalar.
+    pub rand: G::ScalarField,
+}
+
+impl<G: AffineCurve> Randomness<G> {
+    /// Returns the randomness for a non-hiding commitment.
+    pub fn empty() -> Self {
+        Self { rand: G::ScalarField::zero() }
+    }
+
+    /// Returns `true` if the randomness hides the commitment.
+    pub fn is_hiding(&self) -> bool {
+        !self.rand.is_zero()
+    }
+}
+
+/// `Proof` is an evaluation proof for a single (combined) polynomial at a single point.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<G: AffineCurve> {
+    /// The left cross-terms, one for each round of the argument.
+    pub l_vec: Vec<G>,
+    /// The right cross-terms, one for each round of the argument.
+    pub r_vec: Vec<G>,
+    /// The final (folded) coefficient of the polynomial.
+    pub c: G::ScalarField,
+    /// A commitment to a random polynomial that vanishes at the evaluation point, if the proof is hiding.
+    pub hiding_comm: Option<G>,
+    /// The combined commitment randomness, if the proof is hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Proof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_comm.is_some()
+    }
+}
+
+/// Evaluation proof at a query set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<G: AffineCurve>(pub(crate) Vec<Proof<G>>);
+
+impl<G: AffineCurve> BatchProof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.0.iter().any(|p| p.is_hiding())
+    }
+}
@@ -0,0 +1,553 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    AlgebraicSponge,
+    fft::DensePolynomial,
+    msm::VariableBase,
+    polycommit::{
+        PCError,
+        sonic_pc::{Evaluations, LabeledCommitment, LabeledPolynomial, QuerySet},
+    },
+};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, rand::Uniform};
+
+use anyhow::{Result, anyhow, ensure};
+use blake2::{Blake2s256, Digest};
+use core::{marker::PhantomData, ops::Mul};
+use hashbrown::HashMap;
+use itertools::Itertools;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use rand_core::RngCore;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+mod vector;
+pub use vector::*;
+
+/// Polynomial commitment based on the inner-product argument of [[BCCGP16, “Bulletproofs”]][bp],
+/// in the form presented in [[BCMS20, “Proof-Carrying Data from Accumulation Schemes”]][pcdas].
+///
+/// Unlike `SonicKZG10`, this scheme has a transparent setup: the public parameters are
+/// generators of unknown discrete logarithm, which are derived deterministically by hashing.
+/// The trade-off is that checking an evaluation proof requires a linear-size MSM.
+///
+/// Degree bounds are not supported by this scheme.
+///
+/// [bp]: https://eprint.iacr.org/2017/1066
+/// [pcdas]: https://eprint.iacr.org/2020/499
+#[derive(Clone, Debug)]
+pub struct InnerProductArgPC<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>>
+where
+    G::BaseField: PrimeField,
+{
+    _group: PhantomData<(G, S)>,
+}
+
+impl<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>> InnerProductArgPC<G, S>
+where
+    G::BaseField: PrimeField,
+{
+    /// The domain separator used to derive the public parameters.
+    const PROTOCOL_NAME: &'static [u8] = b"AleoIPAPC-2024";
+
+    /// Outputs the public parameters supporting polynomials of degree up to `max_degree`.
+    ///
+    /// The number of generators is rounded up to the next power of two.
+    pub fn setup(max_degree: usize) -> Result<UniversalParams<G>, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("IPA-PC::Setup with max degree {max_degree}"));
+
+        let num_generators = (max_degree + 1).next_power_of_two();
+        let mut generators = Self::sample_generators(num_generators + 2);
+        let s = generators.pop().unwrap();
+        let h = generators.pop().unwrap();
+
+        end_timer!(setup_time);
+        Ok(UniversalParams { comm_key: generators, h, s })
+    }
+
+    /// Specializes the public parameters for polynomials of degree up to `supported_degree`.
+    pub fn trim(
+        pp: &UniversalParams<G>,
+        supported_degree: usize,
+    ) -> Result<(CommitterKey<G>, VerifierKey<G>), PCError> {
+        let trim_time = start_timer!(|| "Trimming public parameters");
+        if supported_degree > pp.max_degree() {
+            return Err(PCError::TrimmingDegreeTooLarge { degree: supported_degree, max_degree: pp.max_degree() });
+        }
+
+        let num_generators = (supported_degree + 1).next_power_of_two();
+        let ck = CommitterKey { comm_key: pp.comm_key[..num_generators].to_vec(), h: pp.h, s: pp.s, supported_degree };
+
+        end_timer!(trim_time);
+        Ok((ck.clone(), ck))
+    }
+
+    /// Outputs commitments to `polynomials`.
+    ///
+    /// If for some `i`, `polynomials[i].is_hiding() == false`, then the
+    /// corresponding randomness is `Randomness::empty()`.
+    pub fn commit<'a>(
+        ck: &CommitterKey<G>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>), PCError> {
+        let commit_time = start_timer!(|| "Committing to polynomials");
+
+        let mut labeled_comms = Vec::new();
+        let mut randomness = Vec::new();
+        for p in polynomials {
+            Self::check_degrees_and_bounds(ck, p)?;
+
+            let rand = match p.is_hiding() {
+                true => Randomness { rand: G::ScalarField::rand(rng.as_mut().ok_or(PCError::MissingRng)?) },
+                false => Randomness::empty(),
+            };
+            let commitment = Self::cm_commit(&ck.comm_key, &p.to_dense().coeffs, Some((ck.s, rand.rand)));
+
+            labeled_comms.push(LabeledCommitment::new(p.to_label(), Commitment(commitment.to_affine()), None));
+            randomness.push(rand);
+        }
+
+        end_timer!(commit_time);
+        Ok((labeled_comms, randomness))
+    }
+
+    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
+    /// of the polynomials at the points in the query set.
+    ///
+    /// If any of the polynomials is hiding, `rng` is used to hide the evaluation proofs.
+    pub fn batch_open<'a>(
+        ck: &CommitterKey<G>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<BatchProof<G>> {
+        ensure!(labeled_polynomials.len() == rands.len());
+        let poly_rand: HashMap<_, _> =
+            labeled_polynomials.into_iter().zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            poly_rand.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&point, labels)) in query_to_labels_map.into_iter() {
+            let mut combined_polynomial = DensePolynomial::zero();
+            let mut combined_rand = G::ScalarField::zero();
+            let mut is_hiding = false;
+            for label in labels {
+                let (polynomial, rand) =
+                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                Self::check_degrees_and_bounds(ck, polynomial)?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_polynomial += (challenge, polynomial.to_dense().as_ref());
+                combined_rand += challenge * rand.rand;
+                is_hiding |= polynomial.is_hiding();
+            }
+
+            let rng = match is_hiding {
+                true => Some(rng.as_mut().map(|r| &mut **r as &mut dyn RngCore).ok_or(PCError::MissingRng)?),
+                false => None,
+            };
+
+            let proof_time = start_timer!(|| "Creating proof");
+            proofs.push(Self::open(ck, combined_polynomial, point, combined_rand, fs_rng, rng)?);
+            end_timer!(proof_time);
+        }
+
+        end_timer!(open_time);
+        Ok(BatchProof(proofs))
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `commitments`.
+    pub fn batch_check<'a>(
+        vk: &VerifierKey<G>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        values: &Evaluations<G::ScalarField>,
+        proof: &BatchProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        ensure!(query_to_labels_map.len() == proof.0.len());
+        let mut result = true;
+        for ((_point_name, (&point, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
+            let mut combined_commitment = G::Projective::zero();
+            let mut combined_value = G::ScalarField::zero();
+            for label in labels {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let value = values
+                    .get(&(label.clone(), point))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_commitment += commitment.commitment().0.mul(challenge);
+                combined_value += challenge * value;
+            }
+
+            result &= Self::check(vk, combined_commitment, point, combined_value, p, fs_rng)?;
+        }
+
+        end_timer!(batch_check_time);
+        Ok(result)
+    }
+
+    /// Outputs an evaluation proof for `polynomial` at `point`,
+    /// where `rand` is the randomness of the commitment to `polynomial`.
+    fn open(
+        ck: &CommitterKey<G>,
+        mut polynomial: DensePolynomial<G::ScalarField>,
+        point: G::ScalarField,
+        mut rand: G::ScalarField,
+        fs_rng: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Proof<G>, PCError> {
+        let mut hiding_comm = None;
+        if let Some(mut rng) = rng {
+            // Sample a random polynomial that vanishes at `point`, so that the evaluation is unchanged.
+            let mut hiding_polynomial = DensePolynomial::rand(ck.supported_degree(), &mut rng);
+            let hiding_evaluation = hiding_polynomial.evaluate(point);
+            hiding_polynomial.coeffs[0] -= hiding_evaluation;
+            let hiding_rand = G::ScalarField::rand(&mut rng);
+            let commitment =
+                Self::cm_commit(&ck.comm_key, &hiding_polynomial.coeffs, Some((ck.s, hiding_rand))).to_affine();
+
+            Self::absorb_points(fs_rng, &[commitment]);
+            let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            polynomial += (hiding_challenge, &hiding_polynomial);
+            rand += hiding_challenge * hiding_rand;
+            hiding_comm = Some(commitment);
+        }
+
+        let n = ck.comm_key.len();
+        let mut coeffs = polynomial.coeffs;
+        coeffs.resize(n, G::ScalarField::zero());
+        let mut point_powers = Vec::with_capacity(n);
+        let mut cur = G::ScalarField::one();
+        for _ in 0..n {
+            point_powers.push(cur);
+            cur *= point;
+        }
+        let value = Self::inner_product(&coeffs, &point_powers);
+
+        // Bind the commitment, the point, and the evaluation into the transcript.
+        let commitment = Self::cm_commit(&ck.comm_key, &coeffs, None).to_affine();
+        let h_prime = Self::bind_evaluation(ck, commitment, point, value, fs_rng);
+
+        let (l_vec, r_vec, c) = Self::fold(&ck.comm_key, coeffs, point_powers, h_prime, fs_rng)?;
+
+        let rand = hiding_comm.map(|_| rand);
+        Ok(Proof { l_vec, r_vec, c, hiding_comm, rand })
+    }
+
+    /// Runs the rounds of the inner-product argument for `<a, b>`, where `a` is committed under `comm_key`
+    /// and the inner product is bound by `h_prime`, and outputs the cross-terms and the folded `a`.
+    fn fold(
+        comm_key: &[G],
+        mut a: Vec<G::ScalarField>,
+        mut b: Vec<G::ScalarField>,
+        h_prime: G,
+        fs_rng: &mut S,
+    ) -> Result<(Vec<G>, Vec<G>, G::ScalarField), PCError> {
+        let mut comm_key = comm_key.to_vec();
+        let mut n = comm_key.len();
+        let mut l_vec = Vec::with_capacity(n.trailing_zeros() as usize);
+        let mut r_vec = Vec::with_capacity(n.trailing_zeros() as usize);
+        while n > 1 {
+            n /= 2;
+            let (a_l, a_r) = a.split_at(n);
+            let (b_l, b_r) = b.split_at(n);
+            let (key_l, key_r) = comm_key.split_at(n);
+
+            let l = Self::cm_commit(key_r, a_l, Some((h_prime, Self::inner_product(a_l, b_r))));
+            let r = Self::cm_commit(key_l, a_r, Some((h_prime, Self::inner_product(a_r, b_l))));
+            let [l, r]: [G; 2] = G::Projective::batch_normalization_into_affine(vec![l, r]).try_into().unwrap();
+
+            Self::absorb_points(fs_rng, &[l, r]);
+            let round_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            let round_challenge_inv =
+                round_challenge.inverse().ok_or_else(|| anyhow!("The round challenge is zero"))?;
+
+            // Fold the vectors and the generators.
+            a = cfg_into_iter!(0..n).map(|i| a[i] + round_challenge * a[n + i]).collect();
+            b = cfg_into_iter!(0..n).map(|i| b[i] + round_challenge_inv * b[n + i]).collect();
+            let folded_key =
+                cfg_into_iter!(0..n).map(|i| comm_key[i].to_projective() + comm_key[n + i].mul(round_challenge_inv));
+            comm_key = G::Projective::batch_normalization_into_affine(folded_key.collect());
+
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+        Ok((l_vec, r_vec, a[0]))
+    }
+
+    /// Checks that `proof` attests that the polynomial committed in `commitment` evaluates to `value` at `point`.
+    fn check(
+        vk: &VerifierKey<G>,
+        mut commitment: G::Projective,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        proof: &Proof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        match (proof.hiding_comm, proof.rand) {
+            (Some(hiding_comm), Some(rand)) => {
+                Self::absorb_points(fs_rng, &[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                commitment += hiding_comm.mul(hiding_challenge);
+                commitment -= vk.s.mul(rand);
+            }
+            (None, None) => (),
+            _ => return Ok(false),
+        }
+
+        let h_prime = Self::bind_evaluation(vk, commitment.to_affine(), point, value, fs_rng);
+        commitment += h_prime.mul(value);
+
+        Self::check_folding(vk, commitment, h_prime, proof, fs_rng, |round_challenges_inv, _| {
+            // Compute the folded power of the point, i.e. `\prod_i (1 + x_i^{-1} z^{2^{k - 1 - i}})`.
+            let mut folded_power = G::ScalarField::one();
+            let mut point_power = point;
+            for round_challenge_inv in round_challenges_inv.iter().rev() {
+                folded_power *= G::ScalarField::one() + *round_challenge_inv * point_power;
+                point_power.square_in_place();
+            }
+            folded_power
+        })
+    }
+
+    /// Checks the rounds of the inner-product argument in `proof` against `commitment`, which must already
+    /// include the claimed inner product bound by `h_prime`.
+    ///
+    /// On input the inverse round challenges and the coefficients `s` of the folded generator,
+    /// `folded_b` outputs the folded public vector of the argument.
+    fn check_folding(
+        vk: &VerifierKey<G>,
+        mut commitment: G::Projective,
+        h_prime: G,
+        proof: &Proof<G>,
+        fs_rng: &mut S,
+        folded_b: impl FnOnce(&[G::ScalarField], &[G::ScalarField]) -> G::ScalarField,
+    ) -> Result<bool, PCError> {
+        let num_rounds = vk.comm_key.len().trailing_zeros() as usize;
+        if proof.l_vec.len() != num_rounds || proof.r_vec.len() != num_rounds {
+            return Ok(false);
+        }
+
+        let mut round_challenges_inv = Vec::with_capacity(num_rounds);
+        for (l, r) in proof.l_vec.iter().zip_eq(&proof.r_vec) {
+            Self::absorb_points(fs_rng, &[*l, *r]);
+            let round_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            let round_challenge_inv =
+                round_challenge.inverse().ok_or_else(|| anyhow!("The round challenge is zero"))?;
+            commitment += l.mul(round_challenge_inv) + r.mul(round_challenge);
+            round_challenges_inv.push(round_challenge_inv);
+        }
+
+        // Compute the folded generator, i.e. `\sum_j s_j G_j`, where `s_j` is the product of the
+        // inverse round challenges corresponding to the set bits of `j`.
+        let mut s_vec = vec![G::ScalarField::one()];
+        for round_challenge_inv in round_challenges_inv.iter().rev() {
+            let next = s_vec.iter().map(|s| *s * round_challenge_inv).collect::<Vec<_>>();
+            s_vec.extend(next);
+        }
+        let folded_key = Self::cm_commit(&vk.comm_key, &s_vec, None);
+        let folded_b = folded_b(&round_challenges_inv, &s_vec);
+
+        let expected = folded_key.mul(proof.c) + h_prime.mul(proof.c * folded_b);
+        Ok(commitment == expected)
+    }
+
+    /// Absorbs the commitment, the point, and the evaluation, and outputs the generator
+    /// which binds the evaluation in the argument.
+    fn bind_evaluation(
+        ck: &CommitterKey<G>,
+        commitment: G,
+        point: G::ScalarField,
+        value: G::ScalarField,
+        fs_rng: &mut S,
+    ) -> G {
+        Self::absorb_points(fs_rng, &[commitment]);
+        fs_rng.absorb_nonnative_field_elements([point, value]);
+        let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+        ck.h.mul(challenge).to_affine()
+    }
+
+    /// Outputs the Pedersen commitment `\sum_i scalars_i bases_i + hiding.1 * hiding.0`.
+    fn cm_commit(bases: &[G], scalars: &[G::ScalarField], hiding: Option<(G, G::ScalarField)>) -> G::Projective {
+        let scalars = cfg_iter!(scalars).map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let mut commitment = VariableBase::msm(&bases[..scalars.len()], &scalars);
+        if let Some((generator, rand)) = hiding {
+            commitment += generator.mul(rand);
+        }
+        commitment
+    }
+
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+    }
+
+    fn absorb_points(fs_rng: &mut S, points: &[G]) {
+        for point in points {
+            fs_rng.absorb_native_field_elements(&[point.to_x_coordinate(), point.to_y_coordinate()]);
+        }
+    }
+
+    /// Derives `num` generators of unknown discrete logarithm.
+    fn sample_generators(num: usize) -> Vec<G> {
+        cfg_into_iter!(0..num)
+            .map(|i| {
+                let seed = Blake2s256::digest([Self::PROTOCOL_NAME, &(i as u64).to_le_bytes()].concat());
+                G::rand(&mut ChaChaRng::from_seed(seed.into()))
+            })
+            .collect()
+    }
+
+    fn check_degrees_and_bounds(ck: &CommitterKey<G>, p: &LabeledPolynomial<G::ScalarField>) -> Result<(), PCError> {
+        if let Some(degree_bound) = p.degree_bound() {
+            return Err(PCError::UnsupportedDegreeBound(degree_bound));
+        }
+        if p.degree() > ck.supported_degree() {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: p.degree() + 1,
+                num_powers: ck.supported_degree() + 1,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_utilities::rand::TestRng;
+
+    use rand::Rng;
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type PC_G1 = InnerProductArgPC<G1Affine, Sponge>;
+
+    fn open_and_check(num_polynomials: usize, num_points: usize, hiding: bool, rng: &mut TestRng) {
+        let max_degree = rng.gen_range(1..=64);
+        let supported_degree = rng.gen_range(1..=max_degree);
+
+        let pp = PC_G1::setup(max_degree).unwrap();
+        let (ck, vk) = PC_G1::trim(&pp, supported_degree).unwrap();
+
+        let hiding_bound = hiding.then_some(1);
+        let polynomials = (0..num_polynomials)
+            .map(|i| {
+                let degree = rng.gen_range(0..=supported_degree);
+                LabeledPolynomial::new(format!("{i}"), DensePolynomial::<Fr>::rand(degree, rng), None, hiding_bound)
+            })
+            .collect::<Vec<_>>();
+        let (comms, rands) = PC_G1::commit(&ck, &polynomials, Some(rng)).unwrap();
+
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for i in 0..num_points {
+            let point = Fr::rand(rng);
+            for polynomial in &polynomials {
+                query_set.insert((polynomial.to_label(), (format!("{i}"), point)));
+                values.insert((polynomial.to_label(), point), polynomial.evaluate(point));
+            }
+        }
+
+        let proof = PC_G1::batch_open(&ck, polynomials.iter(), &query_set, rands.iter(), &mut Sponge::new(), Some(rng))
+            .unwrap();
+        assert_eq!(proof.is_hiding(), hiding);
+        assert!(PC_G1::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure an incorrect evaluation is rejected.
+        let (key, value) = values.iter().next().map(|(k, v)| (k.clone(), *v)).unwrap();
+        values.insert(key, value + Fr::one());
+        assert!(!PC_G1::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_single_poly() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(1, 1, false, rng);
+        }
+    }
+
+    #[test]
+    fn test_multiple_polys_multiple_queries() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(rng.gen_range(1..5), rng.gen_range(1..4), false, rng);
+        }
+    }
+
+    #[test]
+    fn test_hiding() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(rng.gen_range(1..5), rng.gen_range(1..4), true, rng);
+        }
+    }
+
+    #[test]
+    fn test_degree_bound_is_unsupported() {
+        let rng = &mut TestRng::default();
+        let pp = PC_G1::setup(8).unwrap();
+        let (ck, _vk) = PC_G1::trim(&pp, 8).unwrap();
+
+        let polynomial = LabeledPolynomial::new("a", DensePolynomial::<Fr>::rand(4, rng), Some(4), None);
+        assert!(PC_G1::commit(&ck, [&polynomial], None).is_err());
+    }
+}
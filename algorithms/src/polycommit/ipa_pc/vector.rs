@@ -0,0 +1,234 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Commitment, CommitterKey, InnerProductArgPC, Proof, Randomness, VerifierKey};
+use crate::{AlgebraicSponge, polycommit::PCError};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::rand::Uniform;
+
+use anyhow::{Result, anyhow, ensure};
+use core::marker::PhantomData;
+use rand_core::RngCore;
+use std::collections::BTreeMap;
+
+/// A Pedersen commitment to a vector of field elements, with batch openings of any subset of its
+/// positions, for use as the committed-table backend of lookup and permutation arguments.
+///
+/// The commitment to a vector `v` is `\sum_i v_i G_i + r S`, which is the `InnerProductArgPC`
+/// commitment to the polynomial with coefficients `v`, so the two schemes share their keys.
+/// An opening of the positions `I` is an inner-product argument for `<v, b> = \sum_{i \in I} c_i v_i`,
+/// where `b = \sum_{i \in I} c_i e_i` for challenges `c_i` squeezed from the transcript.
+#[derive(Clone, Debug)]
+pub struct VectorCommitment<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>>
+where
+    G::BaseField: PrimeField,
+{
+    _group: PhantomData<(G, S)>,
+}
+
+impl<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>> VectorCommitment<G, S>
+where
+    G::BaseField: PrimeField,
+{
+    /// Outputs a commitment to `vector`, which is hiding if `rng` is given.
+    pub fn commit(
+        ck: &CommitterKey<G>,
+        vector: &[G::ScalarField],
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Commitment<G>, Randomness<G>), PCError> {
+        if vector.len() > ck.comm_key.len() {
+            return Err(PCError::TooManyCoefficients { num_coefficients: vector.len(), num_powers: ck.comm_key.len() });
+        }
+        let rand = match rng {
+            Some(rng) => Randomness { rand: G::ScalarField::rand(rng) },
+            None => Randomness::empty(),
+        };
+        let commitment = InnerProductArgPC::<G, S>::cm_commit(&ck.comm_key, vector, Some((ck.s, rand.rand)));
+        Ok((Commitment(commitment.to_affine()), rand))
+    }
+
+    /// Outputs a proof that the entries of the vector committed in `commitment` at the given `positions`
+    /// are the corresponding entries of `vector`, where `rand` is the randomness of the commitment.
+    ///
+    /// If the commitment is hiding, `rng` is used to hide the proof.
+    pub fn batch_open(
+        ck: &CommitterKey<G>,
+        commitment: &Commitment<G>,
+        vector: &[G::ScalarField],
+        rand: &Randomness<G>,
+        positions: &[usize],
+        fs_rng: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Proof<G>> {
+        let open_time = start_timer!(|| format!("Opening {} positions of a committed vector", positions.len()));
+        let n = ck.comm_key.len();
+        ensure!(vector.len() <= n, "The vector is longer than the committer key");
+
+        let mut a = vector.to_vec();
+        a.resize(n, G::ScalarField::zero());
+        let openings = positions
+            .iter()
+            .map(|&i| a.get(i).map(|value| (i, *value)).ok_or_else(|| anyhow!("Position {i} is out of bounds")))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        let b = Self::public_vector(commitment, &openings, n, fs_rng)?;
+        let mut rand = rand.rand;
+
+        let mut hiding_comm = None;
+        if !rand.is_zero() {
+            let rng = rng.ok_or(PCError::MissingRng)?;
+            // Sample a random vector that is orthogonal to `b`, so that the inner product is unchanged.
+            let mut hiding_vector = (0..n).map(|_| G::ScalarField::rand(rng)).collect::<Vec<_>>();
+            let (&pivot, _) = openings.iter().next().ok_or_else(|| anyhow!("No positions to open"))?;
+            let pivot_inv = b[pivot].inverse().ok_or_else(|| anyhow!("The opening challenge is zero"))?;
+            let hiding_product = InnerProductArgPC::<G, S>::inner_product(&hiding_vector, &b);
+            hiding_vector[pivot] -= hiding_product * pivot_inv;
+            let hiding_rand = G::ScalarField::rand(rng);
+            let hiding_commitment =
+                InnerProductArgPC::<G, S>::cm_commit(&ck.comm_key, &hiding_vector, Some((ck.s, hiding_rand)))
+                    .to_affine();
+
+            InnerProductArgPC::<G, S>::absorb_points(fs_rng, &[hiding_commitment]);
+            let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            a.iter_mut().zip(&hiding_vector).for_each(|(a, h)| *a += hiding_challenge * h);
+            rand += hiding_challenge * hiding_rand;
+            hiding_comm = Some(hiding_commitment);
+        }
+
+        // Bind the commitment and the inner product into the transcript.
+        let value = InnerProductArgPC::<G, S>::inner_product(&a, &b);
+        let non_hiding_commitment = InnerProductArgPC::<G, S>::cm_commit(&ck.comm_key, &a, None).to_affine();
+        let h_prime = Self::bind_inner_product(ck, non_hiding_commitment, value, fs_rng);
+
+        let (l_vec, r_vec, c) = InnerProductArgPC::<G, S>::fold(&ck.comm_key, a, b, h_prime, fs_rng)?;
+        end_timer!(open_time);
+
+        let rand = hiding_comm.map(|_| rand);
+        Ok(Proof { l_vec, r_vec, c, hiding_comm, rand })
+    }
+
+    /// Checks that `proof` attests that the vector committed in `commitment` takes the values in `openings`,
+    /// which map each opened position to its entry.
+    pub fn batch_check(
+        vk: &VerifierKey<G>,
+        commitment: &Commitment<G>,
+        openings: &BTreeMap<usize, G::ScalarField>,
+        proof: &Proof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let check_time = start_timer!(|| format!("Checking {} positions of a committed vector", openings.len()));
+        let n = vk.comm_key.len();
+        ensure!(!openings.is_empty(), "No positions to check");
+        ensure!(openings.keys().all(|i| *i < n), "The opened positions are out of bounds");
+        let b = Self::public_vector(commitment, openings, n, fs_rng)?;
+        let value = openings.iter().map(|(i, v)| b[*i] * v).sum::<G::ScalarField>();
+
+        let mut combined_commitment = commitment.0.to_projective();
+        match (proof.hiding_comm, proof.rand) {
+            (Some(hiding_comm), Some(rand)) => {
+                InnerProductArgPC::<G, S>::absorb_points(fs_rng, &[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_commitment += hiding_comm.mul(hiding_challenge);
+                combined_commitment -= vk.s.mul(rand);
+            }
+            (None, None) => (),
+            _ => return Ok(false),
+        }
+
+        let h_prime = Self::bind_inner_product(vk, combined_commitment.to_affine(), value, fs_rng);
+        combined_commitment += h_prime.mul(value);
+
+        // The public vector is folded like the generators, so its folded value is `\sum_{i \in I} c_i s_i`.
+        let result =
+            InnerProductArgPC::<G, S>::check_folding(vk, combined_commitment, h_prime, proof, fs_rng, |_, s| {
+                openings.keys().map(|i| b[*i] * s[*i]).sum()
+            });
+        end_timer!(check_time);
+        result.map_err(Into::into)
+    }
+
+    /// Absorbs the commitment and the openings, and outputs the public vector `b = \sum_{i \in I} c_i e_i`.
+    fn public_vector(
+        commitment: &Commitment<G>,
+        openings: &BTreeMap<usize, G::ScalarField>,
+        n: usize,
+        fs_rng: &mut S,
+    ) -> Result<Vec<G::ScalarField>> {
+        InnerProductArgPC::<G, S>::absorb_points(fs_rng, &[commitment.0]);
+        let positions = openings.keys().map(|i| G::ScalarField::from(*i as u64));
+        fs_rng.absorb_nonnative_field_elements(positions.chain(openings.values().copied()));
+
+        let mut b = vec![G::ScalarField::zero(); n];
+        for i in openings.keys() {
+            b[*i] = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+        }
+        Ok(b)
+    }
+
+    /// Absorbs the commitment and the inner product, and outputs the generator which binds the inner product.
+    fn bind_inner_product(ck: &CommitterKey<G>, commitment: G, value: G::ScalarField, fs_rng: &mut S) -> G {
+        InnerProductArgPC::<G, S>::absorb_points(fs_rng, &[commitment]);
+        fs_rng.absorb_nonnative_field_elements([value]);
+        let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+        ck.h.mul(challenge).to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::*;
+    use crate::{crypto_hash::PoseidonSponge, polycommit::ipa_pc::InnerProductArgPC};
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_fields::One;
+    use snarkvm_utilities::rand::TestRng;
+
+    use rand::{Rng, seq::index::sample};
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type VC_G1 = VectorCommitment<G1Affine, Sponge>;
+
+    fn open_and_check(hiding: bool, rng: &mut TestRng) {
+        let pp = InnerProductArgPC::<G1Affine, Sponge>::setup(63).unwrap();
+        let (ck, vk) = InnerProductArgPC::<G1Affine, Sponge>::trim(&pp, 63).unwrap();
+
+        let length = rng.gen_range(1..=64);
+        let vector = (0..length).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let (commitment, rand) = VC_G1::commit(&ck, &vector, hiding.then_some(&mut *rng as _)).unwrap();
+        assert_eq!(rand.is_hiding(), hiding);
+
+        let num_positions = rng.gen_range(1..=length);
+        let positions = sample(rng, length, num_positions).into_vec();
+        let proof =
+            VC_G1::batch_open(&ck, &commitment, &vector, &rand, &positions, &mut Sponge::new(), Some(rng)).unwrap();
+        assert_eq!(proof.is_hiding(), hiding);
+        let mut openings = positions.iter().map(|i| (*i, vector[*i])).collect::<BTreeMap<_, _>>();
+        assert!(VC_G1::batch_check(&vk, &commitment, &openings, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure an incorrect entry is rejected.
+        *openings.values_mut().next().unwrap() += Fr::one();
+        assert!(!VC_G1::batch_check(&vk, &commitment, &openings, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_vector_commitment() {
+        let rng = &mut TestRng::default();
+        for _ in 0..10 {
+            open_and_check(false, rng);
+            open_and_check(true, rng);
+        }
+    }
+}
@@ -15,10 +15,11 @@
 
 use crate::{
     AlgebraicSponge,
-    fft::{DensePolynomial, EvaluationDomain},
+    fft::{DensePolynomial, EvaluationDomain, MixedRadixEvaluationDomain},
+    msm::VariableBase,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, ToConstraintField, Zero};
+use snarkvm_fields::{ConstraintFieldError, Field, One, PrimeField, ToConstraintField, Zero};
 use snarkvm_parameters::mainnet::PowersOfG;
 use snarkvm_utilities::{
     FromBytes,
@@ -29,9 +30,11 @@ use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate},
 };
 
-use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
-use core::ops::{Add, AddAssign};
+#[cfg(feature = "prover")]
+use crate::srs::UniversalProver;
+use crate::srs::{LightVerifier, UniversalVerifier};
+use anyhow::{Result, anyhow};
+use core::ops::{Add, AddAssign, Mul, MulAssign};
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
 
@@ -71,6 +74,26 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(E::G1Projective::batch_normalization_into_affine(basis))
     }
 
+    /// Returns the Lagrange basis at `beta` of the coset `offset * H`, where `H` is the mixed-radix
+    /// subgroup of order `size`.
+    ///
+    /// The `i`-th Lagrange polynomial of the coset is `L_i(X / offset)`, where `L_i` is the `i`-th
+    /// Lagrange polynomial of `H`, so the basis is the IFFT over `H` of the powers `(beta / offset)^j G`.
+    pub fn coset_lagrange_basis(&self, size: usize, offset: E::Fr) -> Result<Vec<E::G1Affine>> {
+        let domain = MixedRadixEvaluationDomain::<E::Fr>::new(size)
+            .filter(|domain| domain.size() == size)
+            .ok_or_else(|| anyhow!("The field has no mixed-radix subgroup of order {size}"))?;
+        let offset_inv = offset.inverse().ok_or_else(|| anyhow!("The coset offset must be nonzero"))?;
+
+        let mut offset_inv_power = E::Fr::one();
+        let mut scaled_powers = Vec::with_capacity(size);
+        for power in self.powers_of_beta_g(0, size)? {
+            scaled_powers.push(power.mul(offset_inv_power));
+            offset_inv_power *= offset_inv;
+        }
+        Ok(E::G1Projective::batch_normalization_into_affine(domain.ifft(&scaled_powers)))
+    }
+
     pub fn power_of_beta_g(&self, index: usize) -> Result<E::G1Affine> {
         self.powers.power_of_beta_g(index)
     }
@@ -91,8 +114,15 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.max_num_powers() - 1
     }
 
+    #[cfg(feature = "prover")]
     pub fn to_universal_prover(&self) -> Result<UniversalProver<E>> {
-        Ok(UniversalProver::<E> { max_degree: self.max_degree(), _unused: None })
+        Ok(UniversalProver::<E> {
+            max_degree: self.max_degree(),
+            msm_backend: Default::default(),
+            max_threads: None,
+            low_memory: false,
+            _unused: None,
+        })
     }
 
     pub fn to_universal_verifier(&self) -> Result<UniversalVerifier<E>> {
@@ -108,6 +138,19 @@ impl<E: PairingEngine> UniversalParams<E> {
             prepared_negative_powers_of_beta_h: self.powers.prepared_negative_powers_of_beta_h(),
         })
     }
+
+    /// Returns the minimal verifier-side data of the SRS, which can be serialized compactly
+    /// and shipped to verifiers that never need to prove.
+    pub fn to_light_verifier(&self) -> Result<LightVerifier<E>> {
+        Ok(LightVerifier {
+            max_degree: self.max_degree(),
+            g: self.power_of_beta_g(0)?,
+            gamma_g: self.powers_of_beta_times_gamma_g()[&0],
+            h: self.h,
+            beta_h: self.beta_h(),
+            negative_powers_of_beta_h: self.powers.negative_powers_of_beta_h().clone(),
+        })
+    }
 }
 
 impl<E: PairingEngine> FromBytes for UniversalParams<E> {
@@ -273,6 +316,25 @@ pub struct KZGCommitment<E: PairingEngine>(
     pub E::G1Affine,
 );
 
+/// `KZGCommitmentShard` is the part of a commitment that is contributed by a contiguous range of the
+/// coefficients of a polynomial. It is output by `KZG10::commit_shard`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KZGCommitmentShard<E: PairingEngine> {
+    /// The degree of the first coefficient in the range.
+    pub offset: usize,
+    /// The number of coefficients in the range.
+    pub num_coefficients: usize,
+    /// The coefficients in the range, committed to with the corresponding powers of beta.
+    pub share: E::G1Affine,
+}
+
+impl<E: PairingEngine> KZGCommitmentShard<E> {
+    /// Returns the degree after the last coefficient in the range.
+    pub fn end(&self) -> usize {
+        self.offset + self.num_coefficients
+    }
+}
+
 impl<E: PairingEngine> FromBytes for KZGCommitment<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         CanonicalDeserialize::deserialize_compressed(&mut reader)
@@ -300,6 +362,70 @@ impl<E: PairingEngine> KZGCommitment<E> {
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         self.0.is_in_correct_subgroup_assuming_on_curve()
     }
+
+    /// Returns the commitment to `\sum_i c_i p_i`, given the commitments to the polynomials `p_i`.
+    ///
+    /// The randomness of the combined commitment is the same linear combination of the
+    /// randomness of each commitment, see `KZGRandomness::linear_combination`.
+    pub fn linear_combination<'a>(terms: impl IntoIterator<Item = (E::Fr, &'a Self)>) -> Self {
+        let (scalars, bases): (Vec<_>, Vec<_>) = terms.into_iter().map(|(c, comm)| (c.to_bigint(), comm.0)).unzip();
+        KZGCommitment(VariableBase::msm(&bases, &scalars).to_affine())
+    }
+}
+
+impl<'a, E: PairingEngine> Add<&'a KZGCommitment<E>> for KZGCommitment<E> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, E: PairingEngine> Add<(E::Fr, &'a KZGCommitment<E>)> for KZGCommitment<E> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: (E::Fr, &'a KZGCommitment<E>)) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, E: PairingEngine> AddAssign<&'a KZGCommitment<E>> for KZGCommitment<E> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        let mut sum = self.0.to_projective();
+        sum.add_assign_mixed(&other.0);
+        self.0 = sum.to_affine();
+    }
+}
+
+impl<'a, E: PairingEngine> AddAssign<(E::Fr, &'a KZGCommitment<E>)> for KZGCommitment<E> {
+    #[inline]
+    fn add_assign(&mut self, (f, other): (E::Fr, &'a KZGCommitment<E>)) {
+        let mut sum = other.0.mul(f);
+        sum.add_assign_mixed(&self.0);
+        self.0 = sum.to_affine();
+    }
+}
+
+impl<E: PairingEngine> Mul<E::Fr> for KZGCommitment<E> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, f: E::Fr) -> Self {
+        self *= f;
+        self
+    }
+}
+
+impl<E: PairingEngine> MulAssign<E::Fr> for KZGCommitment<E> {
+    #[inline]
+    fn mul_assign(&mut self, f: E::Fr) {
+        self.0 = self.0.mul(f).to_affine();
+    }
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for KZGCommitment<E> {
@@ -354,6 +480,29 @@ impl<E: PairingEngine> KZGRandomness<E> {
         randomness.blinding_polynomial = DensePolynomial::rand(hiding_poly_degree, rng);
         randomness
     }
+
+    /// Returns the randomness of the commitment to `\sum_i c_i p_i`,
+    /// given the randomness of the commitments to the polynomials `p_i`.
+    pub fn linear_combination<'a>(terms: impl IntoIterator<Item = (E::Fr, &'a Self)>) -> Self {
+        terms.into_iter().fold(Self::empty(), |combined, term| combined + term)
+    }
+}
+
+impl<E: PairingEngine> Mul<E::Fr> for KZGRandomness<E> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, f: E::Fr) -> Self {
+        self *= f;
+        self
+    }
+}
+
+impl<E: PairingEngine> MulAssign<E::Fr> for KZGRandomness<E> {
+    #[inline]
+    fn mul_assign(&mut self, f: E::Fr) {
+        self.blinding_polynomial *= f;
+    }
 }
 
 impl<'a, E: PairingEngine> Add<&'a KZGRandomness<E>> for KZGRandomness<E> {
@@ -20,27 +20,35 @@
 //! proposed by Kate, Zaverucha, and Goldberg ([KZG11](http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf)).
 //! This construction achieves extractability in the algebraic group model (AGM).
 
-use crate::{
-    fft::{DensePolynomial, Polynomial},
-    msm::VariableBase,
-    polycommit::PCError,
-};
+use crate::polycommit::PCError;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{BitIteratorBE, cfg_iter, cfg_iter_mut, rand::Uniform};
+use snarkvm_utilities::rand::Uniform;
 
-use anyhow::{Result, anyhow, ensure};
+use anyhow::{Result, ensure};
 use core::{marker::PhantomData, ops::Mul};
 use itertools::Itertools;
 use rand_core::RngCore;
 
-#[cfg(not(feature = "serial"))]
+#[cfg(feature = "prover")]
+use crate::{
+    fft::{DensePolynomial, Polynomial},
+    msm::{MSMBackend, VariableBase},
+    polycommit::sonic_pc::LabeledPolynomialWithBasis,
+};
+#[cfg(feature = "prover")]
+use anyhow::anyhow;
+#[cfg(feature = "prover")]
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+
+#[cfg(all(feature = "prover", not(feature = "serial")))]
 use rayon::prelude::*;
 
 mod data_structures;
 pub use data_structures::*;
 
-use super::sonic_pc::LabeledPolynomialWithBasis;
+/// The number of coefficients committed to at a time in `KZG10::commit_streaming`.
+pub const STREAMING_CHUNK_SIZE: usize = 1 << 16;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum KZGDegreeBounds {
@@ -95,10 +103,12 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit(
         powers: &Powers<E>,
         polynomial: &Polynomial<'_, E::Fr>,
         hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
@@ -116,55 +126,154 @@ impl<E: PairingEngine> KZG10<E> {
                 let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
 
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(bases, &plain_coeffs);
+                let commitment = VariableBase::msm_with_backend(msm_backend, bases, &plain_coeffs);
+                end_timer!(msm_time);
+
+                commitment
+            }
+            Polynomial::Sparse(polynomial) => {
+                // Only the non-zero terms contribute to the commitment, so the MSM skips the rest.
+                let (bases, plain_coeffs): (Vec<_>, Vec<_>) = polynomial
+                    .coeffs()
+                    .filter(|(_, coeff)| !coeff.is_zero())
+                    .map(|(i, coeff)| (powers.powers_of_beta_g[*i], coeff.to_bigint()))
+                    .unzip();
+
+                let msm_time = start_timer!(|| format!("MSM over {} non-zero terms of sparse poly", bases.len()));
+                let commitment = VariableBase::msm_with_backend(msm_backend, &bases, &plain_coeffs);
                 end_timer!(msm_time);
 
                 commitment
             }
-            Polynomial::Sparse(polynomial) => polynomial
-                .coeffs()
-                .map(|(i, coeff)| {
-                    powers.powers_of_beta_g[*i].mul_bits(BitIteratorBE::new_without_leading_zeros(coeff.to_bigint()))
-                })
-                .sum(),
         };
 
-        let mut randomness = KZGRandomness::empty();
-        if let Some(hiding_degree) = hiding_bound {
-            let mut rng = rng.ok_or(PCError::MissingRng)?;
-            let sample_random_poly_time =
-                start_timer!(|| format!("Sampling a random polynomial of degree {hiding_degree}"));
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(&powers.powers_of_beta_times_gamma_g, hiding_bound, msm_backend, rng)?;
+        commitment.add_assign_mixed(&random_commitment);
 
-            randomness = KZGRandomness::rand(hiding_degree, false, &mut rng);
-            Self::check_hiding_bound(
-                randomness.blinding_polynomial.degree(),
-                powers.powers_of_beta_times_gamma_g.len(),
-            )?;
-            end_timer!(sample_random_poly_time);
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.into()), randomness))
+    }
+
+    /// Outputs a commitment to the polynomial with the given `coefficients`, in increasing order of degree.
+    ///
+    /// The coefficients are consumed in chunks of `STREAMING_CHUNK_SIZE`, and each chunk is committed
+    /// to as soon as it is read, so the polynomial is never fully materialized in memory.
+    #[cfg(feature = "prover")]
+    pub fn commit_streaming(
+        powers: &Powers<E>,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        let commit_time =
+            start_timer!(|| format!("Committing to a streamed polynomial with hiding_bound: {hiding_bound:?}"));
+
+        let mut commitment = E::G1Projective::zero();
+        let mut coefficients = coefficients.into_iter().peekable();
+        let mut chunk = Vec::with_capacity(STREAMING_CHUNK_SIZE);
+        let mut offset = 0;
+        while coefficients.peek().is_some() {
+            chunk.clear();
+            chunk.extend(coefficients.by_ref().take(STREAMING_CHUNK_SIZE));
+
+            // Ensure the chunk does not contain non-zero coefficients beyond the supported degree.
+            let num_supported = powers.size().saturating_sub(offset).min(chunk.len());
+            if let Some(i) = chunk[num_supported..].iter().rposition(|c| !c.is_zero()) {
+                let num_coefficients = offset + num_supported + i + 1;
+                return Err(PCError::TooManyCoefficients { num_coefficients, num_powers: powers.size() });
+            }
+
+            let msm_time = start_timer!(|| format!("MSM to commit to a chunk of {num_supported} coefficients"));
+            let scalars = convert_to_bigints(&chunk[..num_supported]);
+            let bases = &powers.powers_of_beta_g[offset..(offset + num_supported)];
+            commitment += VariableBase::msm_with_backend(msm_backend, bases, &scalars);
+            end_timer!(msm_time);
+
+            offset += chunk.len();
         }
 
-        let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
-        let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
-        let random_commitment =
-            VariableBase::msm(&powers.powers_of_beta_times_gamma_g, random_ints.as_slice()).to_affine();
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(&powers.powers_of_beta_times_gamma_g, hiding_bound, msm_backend, rng)?;
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.into()), randomness))
+    }
+
+    /// Outputs the share of a commitment that is contributed by the given `coefficients`,
+    /// where the first coefficient is the coefficient of degree `offset` of the polynomial.
+    ///
+    /// The shards of a polynomial are independent of each other, so they may be computed on different machines,
+    /// and are then combined into the commitment to the polynomial by `combine_commitment_shards`.
+    #[cfg(feature = "prover")]
+    pub fn commit_shard(
+        powers: &Powers<E>,
+        offset: usize,
+        coefficients: &[E::Fr],
+        msm_backend: MSMBackend,
+    ) -> Result<KZGCommitmentShard<E>, PCError> {
+        let end = offset.saturating_add(coefficients.len());
+        if end > powers.size() {
+            return Err(PCError::TooManyCoefficients { num_coefficients: end, num_powers: powers.size() });
+        }
+
+        let msm_time = start_timer!(|| format!("MSM to commit to a shard of {} coefficients", coefficients.len()));
+        let scalars = convert_to_bigints(coefficients);
+        let share = VariableBase::msm_with_backend(msm_backend, &powers.powers_of_beta_g[offset..end], &scalars);
         end_timer!(msm_time);
 
+        Ok(KZGCommitmentShard { offset, num_coefficients: coefficients.len(), share: share.into() })
+    }
+
+    /// Outputs a commitment to the polynomial whose coefficients are covered by `shards`, as output by `commit_shard`.
+    ///
+    /// The shards must cover disjoint ranges of coefficients, and the coefficients that are not covered are zero.
+    #[cfg(feature = "prover")]
+    pub fn combine_commitment_shards(
+        powers: &Powers<E>,
+        shards: &[KZGCommitmentShard<E>],
+        hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        let mut ranges = shards.iter().map(|shard| (shard.offset, shard.end())).collect::<Vec<_>>();
+        ranges.sort_unstable();
+        if ranges.iter().tuple_windows().any(|((_, end), (start, _))| end > start) {
+            return Err(anyhow!("The commitment shards cover overlapping ranges of coefficients").into());
+        }
+        if let Some(&(_, end)) = ranges.last().filter(|(_, end)| *end > powers.size()) {
+            return Err(PCError::TooManyCoefficients { num_coefficients: end, num_powers: powers.size() });
+        }
+
+        let mut commitment = E::G1Projective::zero();
+        for shard in shards {
+            commitment.add_assign_mixed(&shard.share);
+        }
+
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(&powers.powers_of_beta_times_gamma_g, hiding_bound, msm_backend, rng)?;
         commitment.add_assign_mixed(&random_commitment);
 
-        end_timer!(commit_time);
         Ok((KZGCommitment(commitment.into()), randomness))
     }
 
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         evaluations: &[E::Fr],
         hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
         Self::check_degree_is_too_large(evaluations.len() - 1, lagrange_basis.size())?;
         assert_eq!(
-            evaluations.len().checked_next_power_of_two().ok_or(PCError::LagrangeBasisSizeIsTooLarge)?,
+            evaluations.len().checked_next_power_of_two().ok_or(PCError::LagrangeBasisSizeIsTooLarge {
+                size: evaluations.len(),
+                max_size: lagrange_basis.size()
+            })?,
             lagrange_basis.size()
         );
 
@@ -176,9 +285,64 @@ impl<E: PairingEngine> KZG10<E> {
 
         let evaluations = evaluations.iter().map(|e| e.to_bigint()).collect::<Vec<_>>();
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBase::msm(&lagrange_basis.lagrange_basis_at_beta_g, &evaluations);
+        let mut commitment =
+            VariableBase::msm_with_backend(msm_backend, &lagrange_basis.lagrange_basis_at_beta_g, &evaluations);
+        end_timer!(msm_time);
+
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(&lagrange_basis.powers_of_beta_times_gamma_g, hiding_bound, msm_backend, rng)?;
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.into()), randomness))
+    }
+
+    /// Outputs a commitment to the polynomial with the given `evaluations` over a coset,
+    /// using the Lagrange basis of the coset at `beta`.
+    #[cfg(feature = "prover")]
+    pub fn commit_coset_lagrange(
+        coset_lagrange_basis: &[E::G1Affine],
+        powers_of_beta_times_gamma_g: &[E::G1Affine],
+        evaluations: &[E::Fr],
+        hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        if evaluations.len() != coset_lagrange_basis.len() {
+            return Err(PCError::UnsupportedLagrangeBasisSize {
+                size: evaluations.len(),
+                supported_sizes: vec![coset_lagrange_basis.len()],
+            });
+        }
+
+        let commit_time = start_timer!(|| format!(
+            "Committing to coset evaluations of polynomial of degree {} with hiding_bound: {:?}",
+            evaluations.len() - 1,
+            hiding_bound,
+        ));
+
+        let evaluations = evaluations.iter().map(|e| e.to_bigint()).collect::<Vec<_>>();
+        let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
+        let mut commitment = VariableBase::msm_with_backend(msm_backend, coset_lagrange_basis, &evaluations);
         end_timer!(msm_time);
 
+        let (random_commitment, randomness) =
+            Self::commit_to_randomness(powers_of_beta_times_gamma_g, hiding_bound, msm_backend, rng)?;
+        commitment.add_assign_mixed(&random_commitment);
+
+        end_timer!(commit_time);
+        Ok((KZGCommitment(commitment.into()), randomness))
+    }
+
+    /// Samples a random blinding polynomial for the given `hiding_bound` (if any),
+    /// and outputs its commitment along with the blinding polynomial.
+    #[cfg(feature = "prover")]
+    fn commit_to_randomness(
+        powers_of_beta_times_gamma_g: &[E::G1Affine],
+        hiding_bound: Option<usize>,
+        msm_backend: MSMBackend,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(E::G1Affine, KZGRandomness<E>), PCError> {
         let mut randomness = KZGRandomness::empty();
         if let Some(hiding_degree) = hiding_bound {
             let mut rng = rng.ok_or(PCError::MissingRng)?;
@@ -186,23 +350,18 @@ impl<E: PairingEngine> KZG10<E> {
                 start_timer!(|| format!("Sampling a random polynomial of degree {hiding_degree}"));
 
             randomness = KZGRandomness::rand(hiding_degree, false, &mut rng);
-            Self::check_hiding_bound(
-                randomness.blinding_polynomial.degree(),
-                lagrange_basis.powers_of_beta_times_gamma_g.len(),
-            )?;
+            Self::check_hiding_bound(randomness.blinding_polynomial.degree(), powers_of_beta_times_gamma_g.len())?;
             end_timer!(sample_random_poly_time);
         }
 
         let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
         let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
         let random_commitment =
-            VariableBase::msm(&lagrange_basis.powers_of_beta_times_gamma_g, random_ints.as_slice()).to_affine();
+            VariableBase::msm_with_backend(msm_backend, powers_of_beta_times_gamma_g, random_ints.as_slice())
+                .to_affine();
         end_timer!(msm_time);
 
-        commitment.add_assign_mixed(&random_commitment);
-
-        end_timer!(commit_time);
-        Ok((KZGCommitment(commitment.into()), randomness))
+        Ok((random_commitment, randomness))
     }
 
     /// Compute witness polynomial.
@@ -210,6 +369,7 @@ impl<E: PairingEngine> KZG10<E> {
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
     /// Observe that this quotient does not change with z because
     /// p(z) is the remainder term. We can therefore omit p(z) when computing the quotient.
+    #[cfg(feature = "prover")]
     pub fn compute_witness_polynomial(
         polynomial: &DensePolynomial<E::Fr>,
         point: E::Fr,
@@ -235,12 +395,14 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((witness_polynomial, random_witness_polynomial))
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn open_with_witness_polynomial(
         powers: &Powers<E>,
         point: E::Fr,
         randomness: &KZGRandomness<E>,
         witness_polynomial: &DensePolynomial<E::Fr>,
         hiding_witness_polynomial: Option<&DensePolynomial<E::Fr>>,
+        msm_backend: MSMBackend,
     ) -> Result<KZGProof<E>, PCError> {
         Self::check_degree_is_too_large(witness_polynomial.degree(), powers.size())?;
         let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(witness_polynomial);
@@ -248,7 +410,7 @@ impl<E: PairingEngine> KZG10<E> {
         let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + witness_coeffs.len())];
 
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBase::msm(bases, &witness_coeffs);
+        let mut w = VariableBase::msm_with_backend(msm_backend, bases, &witness_coeffs);
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -259,7 +421,11 @@ impl<E: PairingEngine> KZG10<E> {
 
             let random_witness_coeffs = convert_to_bigints(&hiding_witness_polynomial.coeffs);
             let witness_comm_time = start_timer!(|| "Computing commitment to random witness polynomial");
-            w += &VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_witness_coeffs);
+            w += &VariableBase::msm_with_backend(
+                msm_backend,
+                &powers.powers_of_beta_times_gamma_g,
+                &random_witness_coeffs,
+            );
             end_timer!(witness_comm_time);
             Some(blinding_evaluation)
         } else {
@@ -271,12 +437,14 @@ impl<E: PairingEngine> KZG10<E> {
 
     /// On input a polynomial `p` in Lagrange basis, and a point `point`,
     /// outputs an evaluation proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         domain_elements: &[E::Fr],
         evaluations: &[E::Fr],
         point: E::Fr,
         evaluation_at_point: E::Fr,
+        msm_backend: MSMBackend,
     ) -> Result<KZGProof<E>> {
         Self::check_degree_is_too_large(evaluations.len() - 1, lagrange_basis.size())?;
         // Ensure that the point is not in the domain
@@ -290,22 +458,24 @@ impl<E: PairingEngine> KZG10<E> {
         }
 
         let mut divisor_evals = cfg_iter!(domain_elements).map(|&e| e - point).collect::<Vec<_>>();
-        snarkvm_fields::batch_inversion(&mut divisor_evals);
+        snarkvm_fields::batch_inverse(&mut divisor_evals);
         ensure!(divisor_evals.len() == evaluations.len());
         cfg_iter_mut!(divisor_evals).zip_eq(evaluations).for_each(|(divisor_eval, &eval)| {
             *divisor_eval *= eval - evaluation_at_point;
         });
-        let (witness_comm, _) = Self::commit_lagrange(lagrange_basis, &divisor_evals, None, None)?;
+        let (witness_comm, _) = Self::commit_lagrange(lagrange_basis, &divisor_evals, None, msm_backend, None)?;
 
         Ok(KZGProof { w: witness_comm.0, random_v: None })
     }
 
     /// On input a polynomial `p` and a point `point`, outputs a proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open(
         powers: &Powers<E>,
         polynomial: &DensePolynomial<E::Fr>,
         point: E::Fr,
         rand: &KZGRandomness<E>,
+        msm_backend: MSMBackend,
     ) -> Result<KZGProof<E>, PCError> {
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
         let open_time = start_timer!(|| format!("Opening polynomial of degree {}", polynomial.degree()));
@@ -314,8 +484,14 @@ impl<E: PairingEngine> KZG10<E> {
         let (witness_poly, hiding_witness_poly) = Self::compute_witness_polynomial(polynomial, point, rand)?;
         end_timer!(witness_time);
 
-        let proof =
-            Self::open_with_witness_polynomial(powers, point, rand, &witness_poly, hiding_witness_poly.as_ref());
+        let proof = Self::open_with_witness_polynomial(
+            powers,
+            point,
+            rand,
+            &witness_poly,
+            hiding_witness_poly.as_ref(),
+            msm_backend,
+        );
 
         end_timer!(open_time);
         proof
@@ -335,13 +511,13 @@ impl<E: PairingEngine> KZG10<E> {
         if let Some(random_v) = proof.random_v {
             inner -= &vk.gamma_g.mul(random_v);
         }
-        let lhs = E::pairing(inner, vk.h);
-
-        let inner = vk.beta_h.to_projective() - vk.h.mul(point);
-        let rhs = E::pairing(proof.w, inner);
+        let beta_h_minus_point_h = vk.beta_h.to_projective() - vk.h.mul(point);
 
-        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
-        Ok(lhs == rhs)
+        // Check that `e(inner, h) == e(w, beta_h - point * h)` with a single final exponentiation.
+        let result =
+            E::multi_pairing([inner, -proof.w.to_projective()], [vk.h.to_projective(), beta_h_minus_point_h]).is_one();
+        end_timer!(check_time, || format!("Result: {result}"));
+        Ok(result)
     }
 
     /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
@@ -404,6 +580,7 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -413,6 +590,7 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_hiding_bound(hiding_poly_degree: usize, num_powers: usize) -> Result<(), PCError> {
         if hiding_poly_degree == 0 {
             Err(PCError::HidingBoundIsZero)
@@ -425,12 +603,16 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degrees_and_bounds<'a>(
         max_degree: usize,
         enforced_degree_bounds: Option<&[usize]>,
         p: impl Into<LabeledPolynomialWithBasis<'a, E::Fr>>,
     ) -> Result<(), PCError> {
         let p = p.into();
+        if p.degree() > max_degree {
+            return Err(PCError::DegreeTooLarge { degree: p.degree(), max_degree, label: p.label().to_string() });
+        }
         if let Some(bound) = p.degree_bound() {
             let enforced_degree_bounds = enforced_degree_bounds.ok_or(PCError::UnsupportedDegreeBound(bound))?;
 
@@ -452,6 +634,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 }
 
+#[cfg(feature = "prover")]
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<F>) -> (usize, Vec<F::BigInteger>) {
     if p.coeffs.is_empty() {
         (0, vec![])
@@ -466,6 +649,7 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+#[cfg(feature = "prover")]
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
@@ -543,10 +727,10 @@ mod tests {
             let hiding_bound = Some(1);
             let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
             let p = DensePolynomial::rand(degree, rng);
-            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
+            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, MSMBackend::default(), Some(rng))?;
             let point = E::Fr::rand(rng);
             let value = p.evaluate(point);
-            let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+            let proof = KZG10::<E>::open(&ck, &p, point, &rand, MSMBackend::default())?;
             assert!(
                 KZG10::<E>::check(&vk, &comm, point, value, &proof)?,
                 "proof was incorrect for max_degree = {}, polynomial_degree = {}, hiding_bound = {:?}",
@@ -566,10 +750,10 @@ mod tests {
             let hiding_bound = Some(1);
             let (ck, vk) = KZG10::trim(&pp, 2, hiding_bound);
             let p = DensePolynomial::rand(1, rng);
-            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
+            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, MSMBackend::default(), Some(rng))?;
             let point = E::Fr::rand(rng);
             let value = p.evaluate(point);
-            let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+            let proof = KZG10::<E>::open(&ck, &p, point, &rand, MSMBackend::default())?;
             assert!(
                 KZG10::<E>::check(&vk, &comm, point, value, &proof)?,
                 "proof was incorrect for max_degree = {}, polynomial_degree = {}, hiding_bound = {:?}",
@@ -599,10 +783,11 @@ mod tests {
 
             for _ in 0..10 {
                 let p = DensePolynomial::rand(degree, rng);
-                let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
+                let (comm, rand) =
+                    KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, MSMBackend::default(), Some(rng))?;
                 let point = E::Fr::rand(rng);
                 let value = p.evaluate(point);
-                let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+                let proof = KZG10::<E>::open(&ck, &p, point, &rand, MSMBackend::default())?;
 
                 assert!(KZG10::<E>::check(&vk, &comm, point, value, &proof)?);
                 comms.push(comm);
@@ -630,6 +815,89 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_homomorphic_combination() {
+        let rng = &mut TestRng::default();
+        let degree = 16;
+        let hiding_bound = Some(1);
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, degree, hiding_bound);
+
+        let mut polynomials = Vec::new();
+        let mut comms = Vec::new();
+        let mut rands = Vec::new();
+        for _ in 0..3 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let (comm, rand) =
+                KZG_Bls12_377::commit(&ck, &(&p).into(), hiding_bound, MSMBackend::default(), Some(rng)).unwrap();
+            polynomials.push(p);
+            comms.push(comm);
+            rands.push(rand);
+        }
+
+        // Combine the polynomials, commitments, and randomness with the same coefficients.
+        let coeffs = [Fr::rand(rng), Fr::rand(rng), Fr::rand(rng)];
+        let mut combined_poly = DensePolynomial::zero();
+        for (c, p) in coeffs.iter().zip(&polynomials) {
+            combined_poly += (*c, p);
+        }
+        let combined_comm = KZGCommitment::linear_combination(coeffs.iter().copied().zip(&comms));
+        let combined_rand = KZGRandomness::linear_combination(coeffs.iter().copied().zip(&rands));
+
+        // Ensure the operators agree with the linear combination.
+        let expected_comm = (comms[0] * coeffs[0]) + (coeffs[1], &comms[1]) + &(comms[2] * coeffs[2]);
+        let expected_rand = (rands[0].clone() * coeffs[0]) + (coeffs[1], &rands[1]) + &(rands[2].clone() * coeffs[2]);
+        assert_eq!(combined_comm, expected_comm);
+        assert_eq!(combined_rand, expected_rand);
+
+        // Ensure the combined commitment opens to the combined polynomial.
+        let point = Fr::rand(rng);
+        let proof = KZG_Bls12_377::open(&ck, &combined_poly, point, &combined_rand, MSMBackend::default()).unwrap();
+        let value = combined_poly.evaluate(point);
+        assert!(KZG_Bls12_377::check(&vk, &combined_comm, point, value, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_commit_shards() {
+        let rng = &mut TestRng::default();
+        let degree = 50;
+        let pp = KZG_Bls12_377::load_srs(degree).unwrap();
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, degree, hiding_bound);
+
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+        let (expected, _) = KZG_Bls12_377::commit(&ck, &(&p).into(), None, MSMBackend::default(), None).unwrap();
+
+        // Ensure the shards combine into the commitment, in any order, and with uncovered zero coefficients.
+        let coeffs = p.coeffs();
+        let mut shards = [(0, 20), (20, 21), (21, 51)]
+            .map(|(start, end)| {
+                KZG_Bls12_377::commit_shard(&ck, start, &coeffs[start..end], MSMBackend::default()).unwrap()
+            })
+            .to_vec();
+        shards.reverse();
+        let (comm, _) =
+            KZG_Bls12_377::combine_commitment_shards(&ck, &shards, None, MSMBackend::default(), None).unwrap();
+        assert_eq!(comm, expected);
+        assert_eq!(
+            KZG_Bls12_377::combine_commitment_shards(&ck, &shards[1..], None, MSMBackend::default(), None).unwrap().0,
+            KZGCommitment(KZG_Bls12_377::commit_shard(&ck, 0, &coeffs[..21], MSMBackend::default()).unwrap().share)
+        );
+
+        // Ensure a hiding commitment from shards can be opened.
+        let (comm, rand) =
+            KZG_Bls12_377::combine_commitment_shards(&ck, &shards, hiding_bound, MSMBackend::default(), Some(rng))
+                .unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG_Bls12_377::open(&ck, &p, point, &rand, MSMBackend::default()).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+
+        // Ensure overlapping shards, and shards beyond the supported degree, are rejected.
+        shards.push(KZG_Bls12_377::commit_shard(&ck, 10, &coeffs[10..12], MSMBackend::default()).unwrap());
+        assert!(KZG_Bls12_377::combine_commitment_shards(&ck, &shards, None, MSMBackend::default(), None).is_err());
+        assert!(KZG_Bls12_377::commit_shard(&ck, degree, &coeffs[..2], MSMBackend::default()).is_err());
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
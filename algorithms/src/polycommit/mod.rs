@@ -34,6 +34,25 @@ pub mod kzg10;
 /// [al]: https://eprint.iacr.org/2019/601
 pub mod sonic_pc;
 
+/// Polynomial commitment scheme with a transparent setup, based on the inner-product argument
+/// in [[BCCGP16, “Bulletproofs”]][bp].
+///
+/// [bp]: https://eprint.iacr.org/2017/1066
+pub mod ipa_pc;
+
+/// Multilinear polynomial commitment scheme based on [[KT23, “Zeromorph”]][zeromorph],
+/// which reuses the univariate [\[KZG10\]][kzg] SRS.
+///
+/// [zeromorph]: https://eprint.iacr.org/2023/917
+/// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+pub mod multilinear_pc;
+
+/// Polynomial commitment scheme with a transparent setup, based on the FRI low-degree test
+/// in [[BBHR18, “Fast Reed-Solomon Interactive Oracle Proofs of Proximity”]][fri].
+///
+/// [fri]: https://eccc.weizmann.ac.il/report/2017/134/
+pub mod fri_pc;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
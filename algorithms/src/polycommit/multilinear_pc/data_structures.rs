@@ -0,0 +1,116 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::polycommit::kzg10;
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::Field;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{Result, ensure};
+use rand::Rng;
+use std::borrow::Cow;
+
+/// A multilinear polynomial in `num_vars` variables, represented by its evaluations over the boolean hypercube.
+///
+/// The evaluation at index `i` is the evaluation at the point whose `k`-th coordinate is the `k`-th bit of `i`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct MultilinearPolynomial<F: Field> {
+    num_vars: usize,
+    evaluations: Vec<F>,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// Initializes a new multilinear polynomial from its evaluations over the boolean hypercube.
+    pub fn new(num_vars: usize, evaluations: Vec<F>) -> Result<Self> {
+        ensure!(
+            evaluations.len() == 1 << num_vars,
+            "Expected {} evaluations, found {}",
+            1 << num_vars,
+            evaluations.len()
+        );
+        Ok(Self { num_vars, evaluations })
+    }
+
+    /// Samples a random multilinear polynomial in `num_vars` variables.
+    pub fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        Self { num_vars, evaluations: (0..1 << num_vars).map(|_| F::rand(rng)).collect() }
+    }
+
+    /// Returns the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the evaluations over the boolean hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Evaluates `self` at the given `point`.
+    pub fn evaluate(&self, point: &[F]) -> Result<F> {
+        ensure!(point.len() == self.num_vars, "Expected a point with {} coordinates", self.num_vars);
+        let mut evaluations = self.evaluations.clone();
+        for (k, u_k) in point.iter().enumerate().rev() {
+            let half = 1 << k;
+            for i in 0..half {
+                evaluations[i] = evaluations[i] + *u_k * (evaluations[i + half] - evaluations[i]);
+            }
+            evaluations.truncate(half);
+        }
+        Ok(evaluations[0])
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, multilinear polynomials.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<E: PairingEngine> {
+    /// Group elements of the form `β^i G`, for `i` ranging from `0` to `2^num_vars - 1`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// The number of variables supported by the committer key.
+    pub num_vars: usize,
+}
+
+impl<E: PairingEngine> CommitterKey<E> {
+    /// Returns the powers for use with the underlying KZG10 construction.
+    pub fn powers(&self) -> kzg10::Powers<E> {
+        kzg10::Powers {
+            powers_of_beta_g: Cow::Borrowed(&self.powers_of_beta_g),
+            powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+        }
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for multilinear polynomials.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifierKey<E: PairingEngine> {
+    /// The verification key for the underlying KZG10 scheme.
+    pub vk: kzg10::VerifierKey<E>,
+    /// The number of variables supported by the verifier key.
+    pub num_vars: usize,
+}
+
+/// `Commitment` commits to a multilinear polynomial. It is output by `ZeromorphPC::commit`.
+pub type Commitment<E> = kzg10::KZGCommitment<E>;
+
+/// `Proof` is an evaluation proof that is output by `ZeromorphPC::open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine> {
+    /// Commitments to the univariate images of the quotient polynomials, one for each variable.
+    pub quotient_commitments: Vec<E::G1Affine>,
+    /// A commitment to the batched and degree-shifted quotient polynomial.
+    pub batched_quotient_commitment: E::G1Affine,
+    /// A KZG10 proof that the linearized identity polynomial vanishes at the challenge point.
+    pub proof: kzg10::KZGProof<E>,
+}
@@ -0,0 +1,318 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    AlgebraicSponge,
+    msm::VariableBase,
+    polycommit::{
+        PCError,
+        kzg10::{KZG10, KZGCommitment, UniversalParams},
+    },
+};
+#[cfg(feature = "prover")]
+use crate::{
+    fft::{DensePolynomial, Polynomial},
+    msm::MSMBackend,
+    polycommit::kzg10::KZGRandomness,
+};
+use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+
+use anyhow::{Result, anyhow};
+use core::marker::PhantomData;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// Multilinear polynomial commitment based on [[KT23, “Zeromorph”]][zeromorph].
+///
+/// A multilinear polynomial in `n` variables is committed to as the univariate polynomial whose
+/// coefficients are its evaluations over the boolean hypercube, using the [\[KZG10\]][kzg] SRS.
+/// An evaluation proof consists of `n + 1` commitments and a single KZG10 opening proof.
+///
+/// Keys are specialized to exactly `n` variables, so that the committer key holds exactly the `2^n`
+/// powers that bound the degree of the batched quotient polynomial.
+///
+/// [zeromorph]: https://eprint.iacr.org/2023/917
+/// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+#[derive(Clone, Debug)]
+pub struct ZeromorphPC<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> {
+    _engine: PhantomData<(E, S)>,
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> ZeromorphPC<E, S> {
+    /// Specializes the public parameters for multilinear polynomials in exactly `num_vars` variables.
+    pub fn trim(pp: &UniversalParams<E>, num_vars: usize) -> Result<(CommitterKey<E>, VerifierKey<E>), PCError> {
+        let trim_time = start_timer!(|| format!("Trimming public parameters for {num_vars} variables"));
+        let too_large = |degree| PCError::TrimmingDegreeTooLarge { degree, max_degree: pp.max_degree() };
+        let num_powers = 1usize.checked_shl(num_vars as u32).ok_or_else(|| too_large(usize::MAX))?;
+        if num_powers - 1 > pp.max_degree() {
+            return Err(too_large(num_powers - 1));
+        }
+
+        let powers_of_beta_g = pp.powers_of_beta_g(0, num_powers)?;
+        let vk = pp.to_universal_verifier()?.vk;
+
+        end_timer!(trim_time);
+        Ok((CommitterKey { powers_of_beta_g, num_vars }, VerifierKey { vk, num_vars }))
+    }
+
+    /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
+    pub fn commit(ck: &CommitterKey<E>, polynomial: &MultilinearPolynomial<E::Fr>) -> Result<Commitment<E>, PCError> {
+        Self::check_num_vars(ck.num_vars, polynomial.num_vars())?;
+        Self::commit_univariate(ck, polynomial.evaluations().to_vec())
+    }
+
+    /// Outputs a proof that `polynomial`, committed in `commitment`, evaluates to `polynomial.evaluate(point)`.
+    #[cfg(feature = "prover")]
+    pub fn open(
+        ck: &CommitterKey<E>,
+        polynomial: &MultilinearPolynomial<E::Fr>,
+        commitment: &Commitment<E>,
+        point: &[E::Fr],
+        fs_rng: &mut S,
+    ) -> Result<Proof<E>, PCError> {
+        Self::check_num_vars(ck.num_vars, polynomial.num_vars())?;
+        Self::check_num_vars(polynomial.num_vars(), point.len())?;
+        let num_vars = polynomial.num_vars();
+        let num_evaluations = 1 << num_vars;
+        let open_time = start_timer!(|| format!("Opening multilinear polynomial in {num_vars} variables"));
+
+        // Compute the quotients `q_k` such that `f(X) - f(u) = \sum_k (X_k - u_k) q_k(X_0, ..., X_{k-1})`.
+        let mut quotients = vec![vec![]; num_vars];
+        let mut evaluations = polynomial.evaluations().to_vec();
+        for (k, u_k) in point.iter().enumerate().rev() {
+            let half = 1 << k;
+            let (low, high) = evaluations.split_at(half);
+            quotients[k] = low.iter().zip(high).map(|(l, h)| *h - l).collect::<Vec<_>>();
+            for (e, q) in evaluations.iter_mut().zip(&quotients[k]) {
+                *e += *u_k * q;
+            }
+            evaluations.truncate(half);
+        }
+        let value = evaluations[0];
+
+        let quotient_commitments = quotients
+            .iter()
+            .map(|q_k| Self::commit_univariate(ck, q_k.clone()).map(|c| c.0))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::absorb_statement(fs_rng, commitment, point, value, &quotient_commitments);
+        let y = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+
+        // Batch the quotients, shifting each to have degree `2^n - 1`.
+        let mut batched_quotient = vec![E::Fr::zero(); num_evaluations];
+        let mut y_power = E::Fr::one();
+        for q_k in &quotients {
+            let shift = num_evaluations - q_k.len();
+            for (c, q) in batched_quotient[shift..].iter_mut().zip(q_k) {
+                *c += y_power * q;
+            }
+            y_power *= y;
+        }
+        let batched_quotient_commitment = Self::commit_univariate(ck, batched_quotient.clone())?.0;
+
+        fs_rng.absorb_native_field_elements(&batched_quotient_commitment.to_field_elements().unwrap());
+        let [x, z] = Self::squeeze_challenges(fs_rng);
+
+        // Construct the polynomial `ζ_x + z Z_x`, which vanishes at `x`.
+        let scalars = Self::quotient_scalars(num_vars, point, y, x, z);
+        let mut coefficients = batched_quotient;
+        for (c, f) in coefficients.iter_mut().zip(polynomial.evaluations()) {
+            *c += z * f;
+        }
+        coefficients[0] -= z * value * Self::phi(num_vars, x);
+        for (q_k, scalar) in quotients.iter().zip(&scalars) {
+            for (c, q) in coefficients.iter_mut().zip(q_k) {
+                *c -= *scalar * q;
+            }
+        }
+        let linearized = DensePolynomial::from_coefficients_vec(coefficients);
+
+        let proof = KZG10::open(&ck.powers(), &linearized, x, &KZGRandomness::empty(), MSMBackend::default())?;
+
+        end_timer!(open_time);
+        Ok(Proof { quotient_commitments, batched_quotient_commitment, proof })
+    }
+
+    /// Verifies that `value` is the evaluation at `point` of the multilinear polynomial committed in `commitment`.
+    pub fn check(
+        vk: &VerifierKey<E>,
+        commitment: &Commitment<E>,
+        point: &[E::Fr],
+        value: E::Fr,
+        proof: &Proof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError> {
+        let num_vars = point.len();
+        Self::check_num_vars(vk.num_vars, num_vars)?;
+        if proof.quotient_commitments.len() != num_vars || proof.proof.is_hiding() {
+            return Ok(false);
+        }
+        let check_time = start_timer!(|| format!("Checking multilinear evaluation in {num_vars} variables"));
+
+        Self::absorb_statement(fs_rng, commitment, point, value, &proof.quotient_commitments);
+        let y = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        fs_rng.absorb_native_field_elements(&proof.batched_quotient_commitment.to_field_elements().unwrap());
+        let [x, z] = Self::squeeze_challenges(fs_rng);
+
+        // Compute the commitment to `ζ_x + z Z_x` as a single MSM.
+        let mut bases = vec![proof.batched_quotient_commitment, commitment.0, vk.vk.g];
+        let mut scalars = vec![E::Fr::one(), z, -(z * value * Self::phi(num_vars, x))];
+        bases.extend(&proof.quotient_commitments);
+        scalars.extend(Self::quotient_scalars(num_vars, point, y, x, z).into_iter().map(|s| -s));
+        let scalars = scalars.iter().map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let linearized_commitment = KZGCommitment(VariableBase::msm(&bases, &scalars).to_affine());
+
+        let result = KZG10::check(&vk.vk, &linearized_commitment, x, E::Fr::zero(), &proof.proof)?;
+
+        end_timer!(check_time);
+        Ok(result)
+    }
+
+    /// Returns the scalars by which the commitment to each quotient `q_k` is subtracted, i.e.
+    /// `y^k x^{2^n - 2^k} + z (x^{2^k} Φ_{n-k-1}(x^{2^{k+1}}) - u_k Φ_{n-k}(x^{2^k}))`.
+    fn quotient_scalars(num_vars: usize, point: &[E::Fr], y: E::Fr, x: E::Fr, z: E::Fr) -> Vec<E::Fr> {
+        let num_evaluations = 1u64 << num_vars;
+        let mut scalars = Vec::with_capacity(num_vars);
+        let mut y_power = E::Fr::one();
+        // The value of `x^{2^k}`.
+        let mut x_power = x;
+        for (k, u_k) in point.iter().enumerate() {
+            let shift = x.pow([num_evaluations - (1 << k)]);
+            let x_power_squared = x_power.square();
+            let identity =
+                x_power * Self::phi(num_vars - k - 1, x_power_squared) - *u_k * Self::phi(num_vars - k, x_power);
+            scalars.push(y_power * shift + z * identity);
+            y_power *= y;
+            x_power = x_power_squared;
+        }
+        scalars
+    }
+
+    /// Returns `Φ_n(x) = \sum_{i < 2^n} x^i`.
+    fn phi(num_vars: usize, x: E::Fr) -> E::Fr {
+        let mut result = E::Fr::one();
+        let mut x_power = x;
+        for _ in 0..num_vars {
+            result *= E::Fr::one() + x_power;
+            x_power.square_in_place();
+        }
+        result
+    }
+
+    #[cfg(feature = "prover")]
+    fn commit_univariate(ck: &CommitterKey<E>, coefficients: Vec<E::Fr>) -> Result<Commitment<E>, PCError> {
+        let polynomial = Polynomial::from(DensePolynomial::from_coefficients_vec(coefficients));
+        KZG10::commit(&ck.powers(), &polynomial, None, MSMBackend::default(), None).map(|(c, _)| c)
+    }
+
+    fn absorb_statement(
+        fs_rng: &mut S,
+        commitment: &Commitment<E>,
+        point: &[E::Fr],
+        value: E::Fr,
+        quotient_commitments: &[E::G1Affine],
+    ) {
+        fs_rng.absorb_native_field_elements(&commitment.0.to_field_elements().unwrap());
+        fs_rng.absorb_nonnative_field_elements(point.iter().copied().chain([value]));
+        for c in quotient_commitments {
+            fs_rng.absorb_native_field_elements(&c.to_field_elements().unwrap());
+        }
+    }
+
+    fn squeeze_challenges(fs_rng: &mut S) -> [E::Fr; 2] {
+        let challenges = fs_rng.squeeze_short_nonnative_field_elements::<E::Fr>(2);
+        [challenges[0], challenges[1]]
+    }
+
+    fn check_num_vars(expected: usize, num_vars: usize) -> Result<(), PCError> {
+        if num_vars != expected {
+            return Err(anyhow!("Expected {expected} variables, found {num_vars}").into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::*;
+    use crate::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type Sponge = PoseidonSponge<Fq, 2, 1>;
+    type PC_Bls12_377 = ZeromorphPC<Bls12_377, Sponge>;
+
+    #[test]
+    fn test_evaluate() {
+        let rng = &mut TestRng::default();
+        let polynomial = MultilinearPolynomial::<Fr>::rand(4, rng);
+        for (i, expected) in polynomial.evaluations().iter().enumerate() {
+            let point = (0..4).map(|k| if (i >> k) & 1 == 1 { Fr::one() } else { Fr::zero() }).collect::<Vec<_>>();
+            assert_eq!(polynomial.evaluate(&point).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_open_and_check() {
+        let rng = &mut TestRng::default();
+        let max_num_vars = 6;
+        let pp = KZG10::<Bls12_377>::load_srs(1 << max_num_vars).unwrap();
+
+        for num_vars in 0..=max_num_vars {
+            let (ck, vk) = PC_Bls12_377::trim(&pp, num_vars).unwrap();
+            let polynomial = MultilinearPolynomial::rand(num_vars, rng);
+            let point = (0..num_vars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let value = polynomial.evaluate(&point).unwrap();
+
+            let commitment = PC_Bls12_377::commit(&ck, &polynomial).unwrap();
+            let proof = PC_Bls12_377::open(&ck, &polynomial, &commitment, &point, &mut Sponge::new()).unwrap();
+            assert!(PC_Bls12_377::check(&vk, &commitment, &point, value, &proof, &mut Sponge::new()).unwrap());
+
+            // Ensure an incorrect evaluation is rejected.
+            let value = value + Fr::one();
+            assert!(!PC_Bls12_377::check(&vk, &commitment, &point, value, &proof, &mut Sponge::new()).unwrap());
+        }
+    }
+    #[test]
+    fn test_mismatched_num_vars() {
+        let rng = &mut TestRng::default();
+        let num_vars = 4;
+        let pp = KZG10::<Bls12_377>::load_srs(1 << num_vars).unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, num_vars).unwrap();
+
+        let polynomial = MultilinearPolynomial::rand(num_vars, rng);
+        let point = (0..num_vars).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let value = polynomial.evaluate(&point).unwrap();
+        let commitment = PC_Bls12_377::commit(&ck, &polynomial).unwrap();
+        let proof = PC_Bls12_377::open(&ck, &polynomial, &commitment, &point, &mut Sponge::new()).unwrap();
+
+        // Ensure a polynomial in fewer variables than the key is rejected.
+        let smaller = MultilinearPolynomial::rand(num_vars - 1, rng);
+        assert!(PC_Bls12_377::commit(&ck, &smaller).is_err());
+        assert!(PC_Bls12_377::open(&ck, &smaller, &commitment, &point[1..], &mut Sponge::new()).is_err());
+
+        // Ensure a point with too many or too few coordinates is rejected.
+        let longer = [point.clone(), vec![Fr::rand(rng)]].concat();
+        assert!(PC_Bls12_377::open(&ck, &polynomial, &commitment, &longer, &mut Sponge::new()).is_err());
+        assert!(PC_Bls12_377::open(&ck, &polynomial, &commitment, &point[1..], &mut Sponge::new()).is_err());
+        assert!(PC_Bls12_377::check(&vk, &commitment, &longer, value, &proof, &mut Sponge::new()).is_err());
+        assert!(PC_Bls12_377::check(&vk, &commitment, &point[1..], value, &proof, &mut Sponge::new()).is_err());
+    }
+}
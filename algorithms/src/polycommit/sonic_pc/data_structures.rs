@@ -19,10 +19,11 @@ use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{FromBytes, ToBytes, error, serialize::*};
 
+use anyhow::{Result, ensure};
 use hashbrown::HashMap;
 use std::{
     borrow::{Borrow, Cow},
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, btree_map::Entry},
     fmt,
     ops::{AddAssign, MulAssign, SubAssign},
 };
@@ -36,6 +37,43 @@ pub type Randomness<E> = kzg10::KZGRandomness<E>;
 /// `Commitment` is the commitment for the KZG10 scheme.
 pub type Commitment<E> = kzg10::KZGCommitment<E>;
 
+/// The domain separator for the derivation of hiding randomness from a `HidingSeed`.
+#[cfg(feature = "prover")]
+const HIDING_SEED_DOMAIN: &[u8] = b"AleoSonicKZG10HidingSeed";
+
+/// A secret seed from which the hiding randomness of commitments is derived, for use with
+/// `SonicKZG10::commit_with_seed`.
+///
+/// The randomness for a polynomial is derived from the seed and the label of the polynomial,
+/// so a seed must not be reused to commit to different polynomials with the same label.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct HidingSeed([u8; 32]);
+
+impl HidingSeed {
+    /// Initializes a seed from the given bytes, which must be sampled uniformly and kept secret.
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the seed of the randomness for the polynomial with the given `label`.
+    #[cfg(feature = "prover")]
+    pub(crate) fn derive_for(&self, label: &str) -> [u8; 32] {
+        let mut input = Vec::with_capacity(HIDING_SEED_DOMAIN.len() + 40 + label.len());
+        input.extend_from_slice(HIDING_SEED_DOMAIN);
+        input.extend_from_slice(&self.0);
+        input.extend_from_slice(&(label.len() as u64).to_le_bytes());
+        input.extend_from_slice(label.as_bytes());
+        sha256(&input)
+    }
+}
+
+impl fmt::Debug for HidingSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The seed is secret, so it is not printed.
+        write!(f, "HidingSeed(..)")
+    }
+}
+
 /// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
 #[derive(Debug)]
 pub struct CommitterKey<E: PairingEngine> {
@@ -280,6 +318,10 @@ pub struct CommitterUnionKey<'a, E: PairingEngine> {
     /// The key used to commit to polynomials in Lagrange basis.
     pub lagrange_bases_at_beta_g: BTreeMap<usize, &'a Vec<E::G1Affine>>,
 
+    /// The keys used to commit to polynomials in the Lagrange basis of a coset,
+    /// indexed by the size and the offset of the coset.
+    pub coset_lagrange_bases_at_beta_g: BTreeMap<(usize, E::Fr), Vec<E::G1Affine>>,
+
     /// The key used to commit to hiding polynomials.
     pub powers_of_beta_times_gamma_g: Option<&'a Vec<E::G1Affine>>,
 
@@ -340,10 +382,25 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         })
     }
 
+    /// Adds the Lagrange basis of the coset `offset * H`, where `H` is the mixed-radix subgroup of
+    /// order `size`, so that evaluations over the coset can be committed to without interpolation.
+    pub fn add_coset_lagrange_basis(&mut self, pp: &UniversalParams<E>, size: usize, offset: E::Fr) -> Result<()> {
+        if let Entry::Vacant(entry) = self.coset_lagrange_bases_at_beta_g.entry((size, offset)) {
+            entry.insert(pp.coset_lagrange_basis(size, offset)?);
+        }
+        Ok(())
+    }
+
+    /// Obtain the Lagrange basis at beta of the coset `offset * H`, where `H` is the subgroup of order `size`.
+    pub fn coset_lagrange_basis(&self, size: usize, offset: E::Fr) -> Option<&[E::G1Affine]> {
+        self.coset_lagrange_bases_at_beta_g.get(&(size, offset)).map(Vec::as_slice)
+    }
+
     pub fn union<T: IntoIterator<Item = &'a CommitterKey<E>>>(committer_keys: T) -> Self {
         let mut ck_union = CommitterUnionKey::<E> {
             powers_of_beta_g: None,
             lagrange_bases_at_beta_g: BTreeMap::new(),
+            coset_lagrange_bases_at_beta_g: BTreeMap::new(),
             powers_of_beta_times_gamma_g: None,
             shifted_powers_of_beta_g: None,
             shifted_powers_of_beta_times_gamma_g: None,
@@ -394,6 +451,38 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Writes the proof, with its group elements in the given point-compression mode.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> io::Result<()> {
+        self.serialize_with_mode(&mut writer, compress).map_err(|_| error("could not serialize BatchProof"))
+    }
+
+    /// Reads a proof written by `write_le_with_mode` with the same point-compression mode.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress) -> io::Result<Self> {
+        Self::deserialize_with_mode(&mut reader, compress, Validate::Yes)
+            .map_err(|_| error("could not deserialize BatchProof"))
+    }
+}
+
+/// An aggregation of the evaluation proofs of many `BatchProof`s, holding a single opening for
+/// each distinct query point, ordered by increasing point. It is output by `SonicKZG10::aggregate_batch_proofs`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct AggregatedBatchProof<E: PairingEngine>(pub(crate) Vec<kzg10::KZGProof<E>>);
+
+impl<E: PairingEngine> AggregatedBatchProof<E> {
+    pub fn is_hiding(&self) -> bool {
+        self.0.iter().any(|c| c.is_hiding())
+    }
+
+    /// Returns the number of openings, which is the number of distinct query points.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the proof holds no openings.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
@@ -635,6 +724,98 @@ impl<F: Field> MulAssign<F> for LinearCombination<F> {
 /// Added the third field: the point name.
 pub type QuerySet<T> = BTreeSet<(String, (String, T))>;
 
+/// A rotation of a query point by a power of the generator `\omega` of a multiplicative subgroup,
+/// which allows opening a polynomial at `\omega^i * z` alongside other polynomials opened at `z`,
+/// as in PLONK-style copy and rotation arguments.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rotation(pub i64);
+
+impl Rotation {
+    /// The rotation that leaves the point unchanged.
+    pub const fn cur() -> Self {
+        Self(0)
+    }
+
+    /// The rotation from `z` to `\omega * z`.
+    pub const fn next() -> Self {
+        Self(1)
+    }
+
+    /// The rotation from `z` to `\omega^{-1} * z`.
+    pub const fn prev() -> Self {
+        Self(-1)
+    }
+
+    /// Returns `\omega^self * point`, where `\omega` is the generator of `domain`.
+    pub fn rotate<F: PrimeField>(&self, point: F, domain: &EvaluationDomain<F>) -> F {
+        let generator = if self.0 >= 0 { domain.group_gen } else { domain.group_gen_inv };
+        point * generator.pow([self.0.unsigned_abs()])
+    }
+
+    /// Returns the name of the rotated point, given the name of the `point`.
+    pub fn point_name(&self, point_name: &str) -> String {
+        match self.0 {
+            0 => point_name.to_string(),
+            rotation => format!("{point_name}_omega^{rotation}"),
+        }
+    }
+}
+
+/// Inserts into `query_set` a query of the polynomial `label` at the point `(point_name, point)`,
+/// rotated by `rotation` over `domain`, and returns the rotated point.
+///
+/// The rotated queries are opened in the same batch proof as the unrotated ones.
+pub fn insert_rotated_query<F: PrimeField>(
+    query_set: &mut QuerySet<F>,
+    label: impl Into<String>,
+    (point_name, point): (&str, F),
+    rotation: Rotation,
+    domain: &EvaluationDomain<F>,
+) -> F {
+    let rotated_point = rotation.rotate(point, domain);
+    query_set.insert((label.into(), (rotation.point_name(point_name), rotated_point)));
+    rotated_point
+}
+
+/// A `QuerySet` in which the queries are grouped by point, so that each distinct point appears once,
+/// regardless of how many point names refer to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NormalizedQuerySet<F: PrimeField> {
+    /// The labels of the polynomials that are queried at each point.
+    queries: BTreeMap<F, BTreeSet<String>>,
+}
+
+impl<F: PrimeField> NormalizedQuerySet<F> {
+    /// Deduplicates the points of `query_set`, and groups the labels of the polynomials queried at each point.
+    pub fn normalize(query_set: &QuerySet<F>) -> Self {
+        let mut queries = BTreeMap::<_, BTreeSet<_>>::new();
+        for (label, (_point_name, point)) in query_set {
+            queries.entry(*point).or_default().insert(label.clone());
+        }
+        Self { queries }
+    }
+
+    /// Returns the number of distinct points in the query set.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Returns `true` if the query set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Returns `true` if all of the polynomials are queried at a single point.
+    pub fn is_single_point(&self) -> bool {
+        self.queries.len() == 1
+    }
+
+    /// Returns the points of the query set in increasing order, along with the labels queried at each point.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&F, &BTreeSet<String>)> {
+        self.queries.iter()
+    }
+}
+
 /// `Evaluations` is the result of querying a set of labeled polynomials or equations
 /// `p` at a `QuerySet` `Q`. It maps each element of `Q` to the resulting evaluation.
 /// That is, if `(label, query)` is an element of `Q`, then `evaluation.get((label, query))`
@@ -656,6 +837,89 @@ pub fn evaluate_query_set<'a, F: PrimeField>(
     evaluations
 }
 
+/// A multiplicative coset `offset * H` of the subgroup `H` of the given size, at which a polynomial can be opened.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coset<F: PrimeField> {
+    offset: F,
+    size: usize,
+}
+
+impl<F: PrimeField> Coset<F> {
+    /// Initializes the coset `offset * H`, where `H` is the subgroup of the given `size`.
+    pub fn new(offset: F, size: usize) -> Result<Self> {
+        ensure!(!offset.is_zero(), "The coset offset must be nonzero");
+        ensure!(size.is_power_of_two(), "The coset size must be a power of two");
+        ensure!(EvaluationDomain::<F>::new(size).is_some(), "The coset size {size} is too large");
+        Ok(Self { offset, size })
+    }
+
+    /// Returns the offset of the coset.
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    /// Returns the number of elements in the coset.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the subgroup `H`.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        // The size is checked in `Coset::new`.
+        EvaluationDomain::new(self.size).unwrap()
+    }
+
+    /// Returns the elements `offset * h_i` of the coset, where `h_i` are the elements of `H` in order.
+    pub fn elements(&self) -> Vec<F> {
+        self.domain().elements().map(|h| self.offset * h).collect()
+    }
+
+    /// Evaluates the vanishing polynomial of the coset, `X^size - offset^size`, at `point`.
+    pub fn evaluate_vanishing_polynomial(&self, point: F) -> F {
+        let size = [self.size as u64];
+        point.pow(size) - self.offset.pow(size)
+    }
+}
+
+/// `CosetQuerySet` is the set of queries that are to be made to a set of labeled polynomials
+/// over entire cosets. Each element is a `(label, (coset_name, coset))` pair, where `label` is
+/// the label of a polynomial that is to be queried at every element of `coset`.
+pub type CosetQuerySet<F> = BTreeSet<(String, (String, Coset<F>))>;
+
+/// `CosetEvaluations` is the result of querying a set of labeled polynomials at a `CosetQuerySet`.
+/// It maps each `(label, coset)` to the evaluations at `coset.elements()`, in order.
+pub type CosetEvaluations<F> = BTreeMap<(String, Coset<F>), Vec<F>>;
+
+/// Evaluate the given polynomials at `query_set`.
+pub fn evaluate_coset_query_set<'a, F: PrimeField>(
+    polys: impl IntoIterator<Item = &'a LabeledPolynomial<F>>,
+    query_set: &CosetQuerySet<F>,
+) -> CosetEvaluations<F> {
+    let polys: HashMap<_, _> = polys.into_iter().map(|p| (p.label(), p)).collect();
+    let mut evaluations = CosetEvaluations::new();
+    for (label, (_coset_name, coset)) in query_set {
+        let poly = polys.get(label as &str).expect("polynomial in evaluated lc is not found");
+        let evals = coset.elements().into_iter().map(|x| poly.evaluate(x)).collect();
+        evaluations.insert((label.clone(), *coset), evals);
+    }
+    evaluations
+}
+
+/// Evaluation proof at a coset query set.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CosetBatchProof<E: PairingEngine> {
+    /// Commitments to the quotients of the combined polynomials by the vanishing polynomials of the cosets.
+    pub quotient_commitments: Vec<kzg10::KZGCommitment<E>>,
+    /// Evaluation proof of the linearized polynomials at a single random point.
+    pub proof: BatchProof<E>,
+}
+
+impl<E: PairingEngine> CosetBatchProof<E> {
+    pub fn is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+}
+
 /// A proof of satisfaction of linear combinations.
 #[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchLCProof<E: PairingEngine> {
@@ -667,6 +931,19 @@ impl<E: PairingEngine> BatchLCProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.proof.is_hiding()
     }
+
+    /// Writes the proof, with its group elements in the given point-compression mode.
+    ///
+    /// `ToBytes` always writes compressed points; uncompressed points are larger, but are faster to read.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> io::Result<()> {
+        self.serialize_with_mode(&mut writer, compress).map_err(|_| error("could not serialize struct"))
+    }
+
+    /// Reads a proof written by `write_le_with_mode` with the same point-compression mode.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress) -> io::Result<Self> {
+        Self::deserialize_with_mode(&mut reader, compress, Validate::Yes)
+            .map_err(|_| error("could not deserialize struct"))
+    }
 }
 
 impl<E: PairingEngine> FromBytes for BatchLCProof<E> {
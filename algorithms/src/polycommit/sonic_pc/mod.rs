@@ -15,23 +15,33 @@
 
 use crate::{
     AlgebraicSponge,
-    fft::DensePolynomial,
     msm::variable_base::VariableBase,
-    polycommit::{PCError, kzg10, optional_rng::OptionalRng},
-    srs::{UniversalProver, UniversalVerifier},
+    polycommit::{PCError, kzg10},
+    srs::UniversalVerifier,
 };
-use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{One, Zero};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
-use anyhow::{Result, bail, ensure};
+use anyhow::{Result, anyhow, bail, ensure};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
-use rand_core::{RngCore, SeedableRng};
-use std::{
-    borrow::Borrow,
-    collections::{BTreeMap, BTreeSet},
-};
+use rand_core::RngCore;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(feature = "prover")]
+use crate::{fft::DensePolynomial, polycommit::optional_rng::OptionalRng, srs::UniversalProver};
+#[cfg(feature = "prover")]
+use hashbrown::HashMap;
+#[cfg(feature = "prover")]
+use rand_core::SeedableRng;
+#[cfg(feature = "prover")]
+use std::borrow::{Borrow, Cow};
+
+#[cfg(feature = "async")]
+use crate::{CancellationToken, cancellation::spawn_blocking_cancellable};
+#[cfg(feature = "async")]
+use std::sync::Arc;
 
 mod data_structures;
 pub use data_structures::*;
@@ -68,6 +78,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ) -> Result<(CommitterKey<E>, UniversalVerifier<E>)> {
         let trim_time = start_timer!(|| "Trimming public parameters");
         let max_degree = pp.max_degree();
+        if supported_degree > max_degree {
+            bail!(PCError::TrimmingDegreeTooLarge { degree: supported_degree, max_degree });
+        }
 
         let enforced_degree_bounds = enforced_degree_bounds.map(|bounds| {
             let mut v = bounds.to_vec();
@@ -76,47 +89,47 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             v
         });
 
-        let (shifted_powers_of_beta_g, shifted_powers_of_beta_times_gamma_g) = if let Some(enforced_degree_bounds) =
-            enforced_degree_bounds.as_ref()
-        {
-            if enforced_degree_bounds.is_empty() {
-                (None, None)
-            } else {
-                let highest_enforced_degree_bound = *enforced_degree_bounds.last().unwrap();
-                if highest_enforced_degree_bound > supported_degree {
-                    bail!(
-                        "The highest enforced degree bound {highest_enforced_degree_bound} is larger than the supported degree {supported_degree}"
-                    );
-                }
+        let (shifted_powers_of_beta_g, shifted_powers_of_beta_times_gamma_g) =
+            if let Some(enforced_degree_bounds) = enforced_degree_bounds.as_ref() {
+                if enforced_degree_bounds.is_empty() {
+                    (None, None)
+                } else {
+                    let highest_enforced_degree_bound = *enforced_degree_bounds.last().unwrap();
+                    if highest_enforced_degree_bound > supported_degree {
+                        bail!(PCError::DegreeBoundTooLarge {
+                            degree_bound: highest_enforced_degree_bound,
+                            supported_degree
+                        });
+                    }
 
-                let lowest_shift_degree = max_degree - highest_enforced_degree_bound;
+                    let lowest_shift_degree = max_degree - highest_enforced_degree_bound;
+
+                    let shifted_ck_time = start_timer!(|| format!(
+                        "Constructing `shifted_powers_of_beta_g` of size {}",
+                        max_degree - lowest_shift_degree + 1
+                    ));
+
+                    let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, pp.max_degree() + 1)?;
+                    let mut shifted_powers_of_beta_times_gamma_g = BTreeMap::new();
+                    // Also add degree 0.
+                    for degree_bound in enforced_degree_bounds {
+                        let shift_degree = max_degree - degree_bound;
+                        // We have an additional degree in `powers_of_beta_times_gamma_g` beyond `powers_of_beta_g`.
+                        let powers_for_degree_bound = pp
+                            .powers_of_beta_times_gamma_g()
+                            .range(shift_degree..max_degree.min(shift_degree + supported_hiding_bound) + 2)
+                            .map(|(_k, v)| *v)
+                            .collect();
+                        shifted_powers_of_beta_times_gamma_g.insert(*degree_bound, powers_for_degree_bound);
+                    }
 
-                let shifted_ck_time = start_timer!(|| format!(
-                    "Constructing `shifted_powers_of_beta_g` of size {}",
-                    max_degree - lowest_shift_degree + 1
-                ));
+                    end_timer!(shifted_ck_time);
 
-                let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, pp.max_degree() + 1)?;
-                let mut shifted_powers_of_beta_times_gamma_g = BTreeMap::new();
-                // Also add degree 0.
-                for degree_bound in enforced_degree_bounds {
-                    let shift_degree = max_degree - degree_bound;
-                    // We have an additional degree in `powers_of_beta_times_gamma_g` beyond `powers_of_beta_g`.
-                    let powers_for_degree_bound = pp
-                        .powers_of_beta_times_gamma_g()
-                        .range(shift_degree..max_degree.min(shift_degree + supported_hiding_bound) + 2)
-                        .map(|(_k, v)| *v)
-                        .collect();
-                    shifted_powers_of_beta_times_gamma_g.insert(*degree_bound, powers_for_degree_bound);
+                    (Some(shifted_powers_of_beta_g), Some(shifted_powers_of_beta_times_gamma_g))
                 }
-
-                end_timer!(shifted_ck_time);
-
-                (Some(shifted_powers_of_beta_g), Some(shifted_powers_of_beta_times_gamma_g))
-            }
-        } else {
-            (None, None)
-        };
+            } else {
+                (None, None)
+            };
 
         let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?;
         let powers_of_beta_times_gamma_g = pp
@@ -134,10 +147,10 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         for size in supported_lagrange_sizes {
             let lagrange_time = start_timer!(|| format!("Constructing `lagrange_bases` of size {size}"));
             if !size.is_power_of_two() {
-                bail!("The Lagrange basis size ({size}) is not a power of two")
+                bail!(PCError::LagrangeBasisSizeIsNotPowerOfTwo { size })
             }
             if size > pp.max_degree() + 1 {
-                bail!("The Lagrange basis size ({size}) is larger than the supported degree ({})", pp.max_degree() + 1)
+                bail!(PCError::LagrangeBasisSizeIsTooLarge { size, max_size: pp.max_degree() + 1 })
             }
             let domain = crate::fft::EvaluationDomain::new(size).unwrap();
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain)?;
@@ -173,7 +186,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ///
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
-    #[allow(clippy::format_push_string)]
+    #[cfg(feature = "prover")]
     pub fn commit<'b>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -181,15 +194,51 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
         let rng = &mut OptionalRng(rng);
-        let commit_time = start_timer!(|| "Committing to polynomials");
-
-        let mut pool = snarkvm_utilities::ExecutionPool::<Result<_, _>>::new();
-        for p in polynomials {
-            let seed = rng.0.as_mut().map(|r| {
+        Self::commit_with_seeds::<rand::rngs::StdRng>(universal_prover, ck, polynomials, |_| {
+            rng.0.as_mut().map(|r| {
                 let mut seed = [0u8; 32];
                 r.fill_bytes(&mut seed);
                 seed
-            });
+            })
+        })
+    }
+
+    /// Outputs a list of labeled commitments to the specified list of labeled polynomials,
+    /// as in `commit`, deriving the hiding randomness deterministically from `seed`.
+    ///
+    /// The randomness for each polynomial is derived from `seed` and the label of the polynomial,
+    /// so committing to the same polynomials with the same seed reproduces the same commitments.
+    /// The commitments remain hiding as long as the seed is sampled uniformly and kept secret.
+    ///
+    /// `batch_open` does not sample any fresh randomness, so proofs computed from the output
+    /// randomness are reproducible as well.
+    #[cfg(feature = "prover")]
+    pub fn commit_with_seed<'b>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>,
+        seed: &HidingSeed,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
+        Self::commit_with_seeds::<rand_chacha::ChaChaRng>(universal_prover, ck, polynomials, |label| {
+            Some(seed.derive_for(label))
+        })
+    }
+
+    /// Outputs a list of labeled commitments to the specified list of labeled polynomials,
+    /// where the hiding randomness of each polynomial is sampled from an `R` seeded by `seed_for(label)`.
+    #[allow(clippy::format_push_string)]
+    #[cfg(feature = "prover")]
+    fn commit_with_seeds<'b, R: RngCore + SeedableRng<Seed = [u8; 32]>>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>,
+        mut seed_for: impl FnMut(&str) -> Option<[u8; 32]>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
+        let commit_time = start_timer!(|| "Committing to polynomials");
+
+        let mut pool = snarkvm_utilities::ExecutionPool::<Result<_, _>>::new().in_sequence(universal_prover.low_memory);
+        for p in polynomials {
+            let seed = seed_for(p.label());
 
             kzg10::KZG10::<E>::check_degrees_and_bounds(
                 universal_prover.max_degree,
@@ -199,9 +248,10 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             let degree_bound = p.degree_bound();
             let hiding_bound = p.hiding_bound();
             let label = p.label().to_string();
+            let msm_backend = universal_prover.msm_backend;
 
             pool.add_job(move || {
-                let mut rng = seed.map(rand::rngs::StdRng::from_seed);
+                let mut rng = seed.map(R::from_seed);
                 add_to_trace!(|| "PC::Commit", || format!(
                     "Polynomial {} of degree {}, degree bound {:?}, and hiding bound {:?}",
                     label,
@@ -215,15 +265,40 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     match p.polynomial {
                         PolynomialWithBasis::Lagrange { evaluations } => {
                             let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
-                            let lagrange_basis = ck
-                                .lagrange_basis(domain)
-                                .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
+                            let lagrange_basis =
+                                ck.lagrange_basis(domain).ok_or_else(|| PCError::UnsupportedLagrangeBasisSize {
+                                    size: domain.size(),
+                                    supported_sizes: ck.lagrange_bases_at_beta_g.keys().copied().collect(),
+                                })?;
                             assert!(domain.size().is_power_of_two());
                             assert!(lagrange_basis.size().is_power_of_two());
                             kzg10::KZG10::commit_lagrange(
                                 &lagrange_basis,
                                 &evaluations.evaluations,
                                 hiding_bound,
+                                msm_backend,
+                                rng_ref,
+                            )?
+                        }
+                        PolynomialWithBasis::CosetLagrange { evaluations, offset } => {
+                            let size = evaluations.len();
+                            let coset_lagrange_basis = ck.coset_lagrange_basis(size, offset).ok_or_else(|| {
+                                PCError::UnsupportedLagrangeBasisSize {
+                                    size,
+                                    supported_sizes: ck
+                                        .coset_lagrange_bases_at_beta_g
+                                        .keys()
+                                        .filter(|(_, supported_offset)| *supported_offset == offset)
+                                        .map(|(supported_size, _)| *supported_size)
+                                        .collect(),
+                                }
+                            })?;
+                            kzg10::KZG10::commit_coset_lagrange(
+                                coset_lagrange_basis,
+                                ck.powers_of_beta_times_gamma_g.unwrap(),
+                                &evaluations,
+                                hiding_bound,
+                                msm_backend,
                                 rng_ref,
                             )?
                         }
@@ -234,7 +309,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                                 ck.powers()
                             };
 
-                            kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, rng_ref)?
+                            kzg10::KZG10::commit(&powers, &polynomial, hiding_bound, msm_backend, rng_ref)?
                         }
                     }
                 };
@@ -256,6 +331,67 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    /// Outputs a commitment to the polynomial described by `info`, whose coefficients are given
+    /// by `coefficients` in increasing order of degree.
+    ///
+    /// Unlike `commit`, the coefficients are consumed incrementally, which allows committing to
+    /// polynomials that are too large to be held in memory at once.
+    ///
+    /// If `info.degree_bound().is_some()`, then the degree bound is enforced on the commitment.
+    #[cfg(feature = "prover")]
+    pub fn commit_streaming(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        info: &PolynomialInfo,
+        coefficients: impl IntoIterator<Item = E::Fr>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(LabeledCommitment<Commitment<E>>, Randomness<E>), PCError> {
+        let commit_time = start_timer!(|| format!("Committing to streamed polynomial {}", info.label()));
+
+        let degree_bound = info.degree_bound();
+        let powers = match degree_bound {
+            Some(bound) => {
+                let enforced_degree_bounds = ck.enforced_degree_bounds.as_deref().unwrap_or(&[]);
+                if enforced_degree_bounds.binary_search(&bound).is_err() {
+                    return Err(PCError::UnsupportedDegreeBound(bound));
+                }
+                ck.shifted_powers_of_beta_g(bound).ok_or(PCError::UnsupportedDegreeBound(bound))?
+            }
+            None => ck.powers(),
+        };
+
+        // Track the degree of the polynomial as its coefficients are consumed.
+        let mut degree = 0;
+        let coefficients = coefficients.into_iter().enumerate().map(|(i, c)| {
+            if !c.is_zero() {
+                degree = i;
+            }
+            c
+        });
+        let (comm, rand) = kzg10::KZG10::commit_streaming(
+            &powers,
+            coefficients,
+            info.hiding_bound(),
+            universal_prover.msm_backend,
+            rng,
+        )?;
+
+        if let Some(bound) = degree_bound {
+            if bound < degree || bound > universal_prover.max_degree {
+                return Err(PCError::IncorrectDegreeBound {
+                    poly_degree: degree,
+                    degree_bound: bound,
+                    max_degree: universal_prover.max_degree,
+                    label: info.label().to_string(),
+                });
+            }
+        }
+
+        end_timer!(commit_time);
+        Ok((LabeledCommitment::new(info.label().to_string(), comm, degree_bound), rand))
+    }
+
+    #[cfg(feature = "prover")]
     pub fn combine_for_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -267,7 +403,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Randomness<E>: 'a,
         Commitment<E>: 'a,
     {
-        ensure!(labeled_polynomials.len() == rands.len());
+        if labeled_polynomials.len() != rands.len() {
+            bail!(PCError::MismatchedRandomness {
+                num_polynomials: labeled_polynomials.len(),
+                num_randomness: rands.len()
+            });
+        }
         let mut to_combine = Vec::with_capacity(labeled_polynomials.len());
 
         for (p, r) in labeled_polynomials.zip_eq(rands) {
@@ -283,6 +424,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
     /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
     /// of the polynomials at the points in the query set.
+    ///
+    /// The proofs are computed using at most `universal_prover.max_threads` threads, if set.
+    #[cfg(feature = "prover")]
     pub fn batch_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -295,7 +439,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Randomness<E>: 'a,
         Commitment<E>: 'a,
     {
-        ensure!(labeled_polynomials.len() == rands.len());
+        if labeled_polynomials.len() != rands.len() {
+            bail!(PCError::MismatchedRandomness {
+                num_polynomials: labeled_polynomials.len(),
+                num_randomness: rands.len()
+            });
+        }
         let poly_rand: HashMap<_, _> =
             labeled_polynomials.into_iter().zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
 
@@ -308,37 +457,86 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let mut query_to_labels_map = BTreeMap::new();
 
         for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            let labels = query_to_labels_map.entry(point_name).or_insert((*point, BTreeSet::new()));
             labels.1.insert(label);
         }
 
-        let mut pool = snarkvm_utilities::ExecutionPool::<_>::with_capacity(query_to_labels_map.len());
-        for (_point_name, (&query, labels)) in query_to_labels_map.into_iter() {
-            let mut query_polys = Vec::with_capacity(labels.len());
-            let mut query_rands = Vec::with_capacity(labels.len());
-
-            for label in labels {
-                let (polynomial, rand) =
-                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+        let batch_proof =
+            Self::open_groups(universal_prover, ck, &poly_rand, query_to_labels_map.into_values(), fs_rng);
+        end_timer!(open_time);
 
-                query_polys.push(*polynomial);
-                query_rands.push(*rand);
-            }
-            let (polynomial, rand) =
-                Self::combine_for_open(universal_prover, ck, query_polys.into_iter(), query_rands.into_iter(), fs_rng)?;
-            let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        batch_proof
+    }
 
-            pool.add_job(move || {
-                let proof_time = start_timer!(|| "Creating proof");
-                let proof = kzg10::KZG10::open(&ck.powers(), &polynomial, query, &rand);
-                end_timer!(proof_time);
-                proof
+    /// Opens the polynomials at the points of a normalized query set, with one opening per distinct point.
+    ///
+    /// If all of the polynomials are queried at a single point, the opening is computed directly with
+    /// `open_at_point`, skipping the bookkeeping of the general path.
+    /// The proof must be checked with `batch_check_normalized`.
+    #[cfg(feature = "prover")]
+    pub fn batch_open_normalized<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        query_set: &NormalizedQuerySet<E::Fr>,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchProof<E>>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        if labeled_polynomials.len() != rands.len() {
+            bail!(PCError::MismatchedRandomness {
+                num_polynomials: labeled_polynomials.len(),
+                num_randomness: rands.len()
             });
         }
-        let batch_proof = pool.execute_all().into_iter().collect::<Result<_, _>>().map(BatchProof).map_err(Into::into);
-        end_timer!(open_time);
+        let mut poly_rand = labeled_polynomials.zip_eq(rands).collect::<Vec<_>>();
+
+        if let Some((point, labels)) = query_set.iter().next().filter(|_| query_set.is_single_point()) {
+            poly_rand.sort_unstable_by(|(a, _), (b, _)| a.label().cmp(b.label()));
+            if poly_rand.len() == labels.len() && poly_rand.iter().zip(labels).all(|((p, _), l)| p.label() == l) {
+                let (polys, rands): (Vec<_>, Vec<_>) = poly_rand.into_iter().unzip();
+                let proof =
+                    Self::open_at_point(universal_prover, ck, polys.into_iter(), *point, rands.into_iter(), fs_rng)?;
+                return Ok(BatchProof(vec![proof]));
+            }
+        }
 
-        batch_proof
+        let poly_rand: HashMap<_, _> = poly_rand.into_iter().map(|(poly, r)| (poly.label(), (poly, r))).collect();
+        Self::open_groups(
+            universal_prover,
+            ck,
+            &poly_rand,
+            query_set.iter().map(|(point, labels)| (*point, labels)),
+            fs_rng,
+        )
+    }
+
+    /// Opens the given polynomials at a single `point`, combining them in the given order.
+    ///
+    /// This is equivalent to `batch_open` on a query set with a single point, if the polynomials are given
+    /// in the order of their labels, but avoids the bookkeeping of the general path.
+    #[cfg(feature = "prover")]
+    pub fn open_at_point<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        point: E::Fr,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<kzg10::KZGProof<E>>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let open_time = start_timer!(|| format!("Opening {} polynomials at a single point", labeled_polynomials.len()));
+        let (polynomial, rand) = Self::combine_for_open(universal_prover, ck, labeled_polynomials, rands, fs_rng)?;
+        let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        let proof = kzg10::KZG10::open(&ck.powers(), &polynomial, point, &rand, universal_prover.msm_backend);
+        end_timer!(open_time);
+        proof.map_err(Into::into)
     }
 
     pub fn batch_check<'a>(
@@ -352,57 +550,233 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     where
         Commitment<E>: 'a,
     {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
-        let batch_check_time = start_timer!(|| format!(
-            "Checking {} commitments at query set of size {}",
-            commitments.len(),
-            query_set.len(),
-        ));
-        let mut query_to_labels_map = BTreeMap::new();
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
 
-        for (label, (point_name, point)) in query_set.iter() {
-            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
-            labels.1.insert(label);
+        let batch_check_time =
+            start_timer!(|| format!("Checking commitments at query set of size {}", query_set.len()));
+        Self::accumulate_batch(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            vk,
+            commitments,
+            query_set,
+            values,
+            proof,
+            E::Fr::one(),
+            fs_rng,
+        )?;
+
+        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(batch_check_time);
+        result.map_err(Into::into)
+    }
+
+    /// Checks a batch proof output by `batch_open_normalized` for the same normalized query set.
+    pub fn batch_check_normalized<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &NormalizedQuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let mut commitments = commitments.into_iter().collect::<Vec<_>>();
+
+        if let Some((point, labels)) = query_set.iter().next().filter(|_| query_set.is_single_point()) {
+            commitments.sort_unstable_by(|a, b| a.label().cmp(b.label()));
+            if commitments.len() == labels.len() && commitments.iter().zip(labels).all(|(c, l)| c.label() == l) {
+                if proof.0.len() != 1 {
+                    bail!(PCError::MismatchedOpenings { expected: 1, found: proof.0.len() });
+                }
+                let values = labels
+                    .iter()
+                    .map(|label| {
+                        values
+                            .get(&(label.clone(), *point))
+                            .copied()
+                            .ok_or(PCError::MissingEvaluation { label: label.to_string() })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Self::check_at_point(
+                    vk,
+                    commitments.into_iter(),
+                    *point,
+                    values.into_iter(),
+                    &proof.0[0],
+                    fs_rng,
+                );
+            }
         }
 
-        assert_eq!(proof.0.len(), query_to_labels_map.len());
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        let batch_check_time =
+            start_timer!(|| format!("Checking commitments at normalized query set of size {}", query_set.len()));
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label(), c)).collect();
+        Self::accumulate_groups(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            vk,
+            &commitments,
+            query_set.iter().map(|(point, labels)| (*point, labels)),
+            values,
+            proof,
+            E::Fr::one(),
+            fs_rng,
+        )?;
 
-        let mut randomizer = E::Fr::one();
+        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(batch_check_time);
+        result
+    }
 
+    /// Checks an opening output by `open_at_point` of the given commitments at a single `point`,
+    /// where `values` are the evaluations of the committed polynomials in the same order.
+    pub fn check_at_point<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl ExactSizeIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        point: E::Fr,
+        values: impl ExactSizeIterator<Item = E::Fr>,
+        proof: &kzg10::KZGProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
         let mut combined_comms = BTreeMap::new();
         let mut combined_witness = E::G1Projective::zero();
         let mut combined_adjusted_witness = E::G1Projective::zero();
 
-        ensure!(query_to_labels_map.len() == proof.0.len());
-        for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
-            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
-            let mut values_to_combine = Vec::new();
-            for label in labels.into_iter() {
-                let commitment =
-                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+        let check_time = start_timer!(|| format!("Checking {} commitments at a single point", commitments.len()));
+        Self::accumulate_elems(
+            &mut combined_comms,
+            &mut combined_witness,
+            &mut combined_adjusted_witness,
+            vk,
+            commitments,
+            point,
+            values,
+            proof,
+            Some(E::Fr::one()),
+            fs_rng,
+        )?;
+        let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
 
-                let v_i = values
-                    .get(&(label.clone(), *query))
-                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(check_time);
+        result
+    }
 
-                comms_to_combine.push(commitment);
-                values_to_combine.push(*v_i);
+    /// Checks a batch proof whose queries are consumed from an iterator, accumulating the pairing check
+    /// incrementally so that the commitments and evaluations never need to be collected in memory.
+    ///
+    /// Each item of `queries` is a query point together with the commitments queried at it and their values.
+    /// For the check to agree with `batch_check`, the points must be supplied in the order of their point names
+    /// in the query set, and the commitments of each point must be supplied in the order of their labels.
+    pub fn batch_check_streaming<'a, I>(
+        vk: &UniversalVerifier<E>,
+        queries: impl IntoIterator<Item = (E::Fr, I)>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        I: IntoIterator<Item = (&'a LabeledCommitment<Commitment<E>>, E::Fr)>,
+        Commitment<E>: 'a,
+    {
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        let batch_check_time = start_timer!(|| "Checking streamed commitments");
+        let mut openings = proof.0.iter();
+        let mut num_queries = 0;
+        let mut randomizer = E::Fr::one();
+        for (point, commitments_and_values) in queries {
+            num_queries += 1;
+            let opening =
+                openings.next().ok_or(PCError::MismatchedOpenings { expected: num_queries, found: proof.0.len() })?;
+
+            let mut combined_values = E::Fr::zero();
+            for (labeled_comm, value) in commitments_and_values {
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                combined_values += value * challenge;
+                *combined_comms.entry(labeled_comm.degree_bound()).or_insert_with(E::G1Projective::zero) +=
+                    labeled_comm.commitment().0.mul(randomizer * challenge);
             }
+            Self::accumulate_opening(
+                &mut combined_witness,
+                &mut combined_adjusted_witness,
+                vk,
+                point,
+                combined_values,
+                opening,
+                Some(randomizer),
+            );
 
-            Self::accumulate_elems(
+            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        }
+        if num_queries != proof.0.len() {
+            bail!(PCError::MismatchedOpenings { expected: num_queries, found: proof.0.len() });
+        }
+
+        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(batch_check_time);
+        result
+    }
+
+    /// Checks many unrelated batch proofs at once, using a single product of pairings.
+    ///
+    /// Each instance consists of `(commitments, query_set, values, proof, fs_rng)`, as in `batch_check`,
+    /// where `fs_rng` is the sponge for the transcript of that instance. The accumulated checks of the
+    /// instances are combined with randomizers sampled from `rng`, so the verifier must supply
+    /// randomness that is unknown to the provers.
+    pub fn batch_check_independent<'a, R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        instances: impl IntoIterator<
+            Item = (
+                &'a [LabeledCommitment<Commitment<E>>],
+                &'a QuerySet<E::Fr>,
+                &'a Evaluations<E::Fr>,
+                &'a BatchProof<E>,
+                &'a mut S,
+            ),
+        >,
+        rng: &mut R,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+        S: 'a,
+    {
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        let batch_check_time = start_timer!(|| "Checking independent batch proofs");
+        // The first instance does not need to be randomized.
+        let mut instance_randomizer = E::Fr::one();
+        for (commitments, query_set, values, proof, fs_rng) in instances {
+            Self::accumulate_batch(
                 &mut combined_comms,
                 &mut combined_witness,
                 &mut combined_adjusted_witness,
                 vk,
-                comms_to_combine.into_iter(),
-                *query,
-                values_to_combine.into_iter(),
-                p,
-                Some(randomizer),
+                commitments,
+                query_set,
+                values,
+                proof,
+                instance_randomizer,
                 fs_rng,
             )?;
-
-            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            instance_randomizer = E::Fr::rand(rng);
         }
 
         let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
@@ -410,40 +784,378 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         result.map_err(Into::into)
     }
 
-    pub fn open_combinations<'a>(
-        universal_prover: &UniversalProver<E>,
-        ck: &CommitterUnionKey<E>,
-        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
-        polynomials: impl IntoIterator<Item = LabeledPolynomial<E::Fr>>,
-        rands: impl IntoIterator<Item = &'a Randomness<E>>,
-        query_set: &QuerySet<E::Fr>,
+    /// Aggregates the batch proofs of many instances into a single proof with one opening per distinct query point.
+    ///
+    /// Each instance consists of `(commitments, query_set, values, proof, fs_rng)`, as in `batch_check`,
+    /// where `fs_rng` is the sponge for the transcript of that instance. The instances are combined with
+    /// randomizers squeezed from `fs_rng` after absorbing the instances, so the same sponge must be used
+    /// to check the aggregated proof with `check_aggregated_batch_proof`.
+    #[cfg(feature = "prover")]
+    pub fn aggregate_batch_proofs<'a>(
+        instances: impl IntoIterator<
+            Item = (
+                &'a [LabeledCommitment<Commitment<E>>],
+                &'a QuerySet<E::Fr>,
+                &'a Evaluations<E::Fr>,
+                &'a BatchProof<E>,
+                &'a mut S,
+            ),
+        >,
         fs_rng: &mut S,
-    ) -> Result<BatchLCProof<E>>
+    ) -> Result<AggregatedBatchProof<E>>
     where
-        Randomness<E>: 'a,
         Commitment<E>: 'a,
+        S: 'a,
     {
-        let label_map =
-            polynomials.into_iter().zip_eq(rands).map(|(p, r)| (p.to_label(), (p, r))).collect::<BTreeMap<_, _>>();
-
-        let mut lc_polynomials = Vec::new();
-        let mut lc_randomness = Vec::new();
-        let mut lc_info = Vec::new();
+        let aggregate_time = start_timer!(|| "Aggregating batch proofs");
+        let mut witnesses = BTreeMap::<E::Fr, (E::G1Projective, Option<E::Fr>)>::new();
+        for (commitments, query_set, values, proof, instance_fs_rng) in instances {
+            let queries = Self::combine_batch_queries(commitments, query_set, values, instance_fs_rng)?;
+            if queries.len() != proof.0.len() {
+                bail!(PCError::MismatchedOpenings { expected: queries.len(), found: proof.0.len() });
+            }
+            let instance_randomizer = Self::absorb_instance(fs_rng, commitments, query_set, values)?;
+
+            for (query, p) in queries.iter().zip_eq(&proof.0) {
+                let coeff = instance_randomizer * query.randomizer;
+                let (witness, random_v) = witnesses.entry(query.point).or_insert((E::G1Projective::zero(), None));
+                *witness += p.w.mul(coeff);
+                if let Some(v) = p.random_v {
+                    *random_v.get_or_insert_with(E::Fr::zero) += v * coeff;
+                }
+            }
+        }
 
-        for lc in linear_combinations {
-            let lc_label = lc.label().to_string();
-            let mut poly = DensePolynomial::zero();
-            let mut randomness = Randomness::empty();
-            let mut degree_bound = None;
-            let mut hiding_bound = None;
+        let (witnesses, random_vs): (Vec<_>, Vec<_>) = witnesses.into_values().unzip();
+        let proof = E::G1Projective::batch_normalization_into_affine(witnesses)
+            .into_iter()
+            .zip_eq(random_vs)
+            .map(|(w, random_v)| kzg10::KZGProof { w, random_v })
+            .collect();
+        end_timer!(aggregate_time);
+        Ok(AggregatedBatchProof(proof))
+    }
 
-            let num_polys = lc.len();
-            // We filter out l.is_one() entries because those constants are not committed to and used directly by the verifier.
-            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
-                let label: &String = label.try_into().expect("cannot be one!");
-                let (cur_poly, cur_rand) =
-                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
-                if let Some(cur_degree_bound) = cur_poly.degree_bound() {
+    /// Checks a proof output by `aggregate_batch_proofs` for the given instances, using a single product of pairings.
+    ///
+    /// Each instance consists of `(commitments, query_set, values, fs_rng)`, as in `batch_check`,
+    /// and `fs_rng` must be in the same state as the sponge that was used for the aggregation.
+    pub fn check_aggregated_batch_proof<'a>(
+        vk: &UniversalVerifier<E>,
+        instances: impl IntoIterator<
+            Item = (&'a [LabeledCommitment<Commitment<E>>], &'a QuerySet<E::Fr>, &'a Evaluations<E::Fr>, &'a mut S),
+        >,
+        proof: &AggregatedBatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+        S: 'a,
+    {
+        let check_time = start_timer!(|| "Checking aggregated batch proof");
+        let mut instance_queries = Vec::new();
+        let mut points = BTreeSet::new();
+        for (commitments, query_set, values, instance_fs_rng) in instances {
+            let queries = Self::combine_batch_queries(commitments, query_set, values, instance_fs_rng)?;
+            let instance_randomizer = Self::absorb_instance(fs_rng, commitments, query_set, values)?;
+            points.extend(queries.iter().map(|query| query.point));
+            instance_queries.push((instance_randomizer, queries));
+        }
+        if points.len() != proof.0.len() {
+            bail!(PCError::MismatchedOpenings { expected: points.len(), found: proof.0.len() });
+        }
+
+        // Squeeze a randomizer for each opening only after the openings are fixed,
+        // so the openings at different points cannot cancel each other out.
+        fs_rng
+            .absorb_native_field_elements(&proof.0.iter().map(|p| kzg10::KZGCommitment::<E>(p.w)).collect::<Vec<_>>());
+        fs_rng.absorb_nonnative_field_elements(proof.0.iter().filter_map(|p| p.random_v));
+        let point_randomizers: BTreeMap<_, _> =
+            points.iter().map(|point| (*point, fs_rng.squeeze_short_nonnative_field_element::<E::Fr>())).collect();
+
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_values = BTreeMap::<E::Fr, E::Fr>::new();
+        for (instance_randomizer, queries) in instance_queries {
+            for query in queries {
+                let coeff = point_randomizers[&query.point] * instance_randomizer * query.randomizer;
+                for (challenge, labeled_comm) in query.commitments {
+                    let comm_with_challenge: E::G1Projective = labeled_comm.commitment().0.mul(coeff * challenge);
+                    *combined_comms.entry(labeled_comm.degree_bound()).or_insert_with(E::G1Projective::zero) +=
+                        &comm_with_challenge;
+                }
+                *combined_values.entry(query.point).or_insert_with(E::Fr::zero) += query.value * coeff;
+            }
+        }
+
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+        for ((point, value), p) in combined_values.into_iter().zip_eq(&proof.0) {
+            let randomizer = point_randomizers[&point];
+            let mut bases = vec![vk.vk.g, -p.w];
+            let mut coeffs = vec![value.into(), (point * randomizer).into()];
+            if let Some(random_v) = p.random_v {
+                bases.push(vk.vk.gamma_g);
+                coeffs.push((random_v * randomizer).into());
+            }
+            combined_witness += p.w.mul(randomizer);
+            combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
+        }
+
+        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        end_timer!(check_time);
+        result
+    }
+
+    /// On input a list of labeled polynomials and a coset query set, outputs a proof of evaluation
+    /// of the polynomials at every element of the cosets in the query set.
+    ///
+    /// For each coset `C`, the queried polynomials are combined into a polynomial `p`, and the prover
+    /// commits to the quotient `q = (p - I) / Z_C`, where `I` interpolates `p` over `C` and `Z_C` is
+    /// the vanishing polynomial of `C`. A single evaluation proof at a random point `zeta` then attests
+    /// that `p(zeta) - Z_C(zeta) * q(zeta) = I(zeta)` for all the cosets at once.
+    ///
+    /// The evaluations of the polynomials over the cosets are absorbed into `fs_rng` before the
+    /// polynomials are combined, so they are bound to the batching challenges.
+    ///
+    /// If any of the polynomials is hiding, `rng` is used to hide the commitments to the quotients.
+    /// Degree bounds are not supported for coset queries.
+    #[cfg(feature = "prover")]
+    pub fn batch_open_cosets<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        query_set: &CosetQuerySet<E::Fr>,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<CosetBatchProof<E>>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        if labeled_polynomials.len() != rands.len() {
+            bail!(PCError::MismatchedRandomness {
+                num_polynomials: labeled_polynomials.len(),
+                num_randomness: rands.len()
+            });
+        }
+        let poly_rand: HashMap<_, _> =
+            labeled_polynomials.into_iter().zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at coset query set of size {}",
+            poly_rand.len(),
+            query_set.len(),
+        ));
+
+        // Evaluate the polynomials over the cosets, and absorb the evaluations.
+        let mut values = CosetEvaluations::new();
+        for (label, (_coset_name, coset)) in query_set {
+            let (polynomial, _) =
+                poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+            let evaluations = coset.elements().into_iter().map(|x| polynomial.evaluate(x)).collect();
+            values.insert((label.clone(), *coset), evaluations);
+        }
+        Self::absorb_coset_evaluations(fs_rng, query_set, &values)?;
+
+        let query_to_labels_map = Self::coset_query_to_labels_map(query_set);
+
+        // Combine the polynomials queried at each coset, and divide by the vanishing polynomial of the coset.
+        let mut combined = Vec::with_capacity(query_to_labels_map.len());
+        let mut quotients = Vec::with_capacity(query_to_labels_map.len());
+        for (coset_name, (coset, labels)) in query_to_labels_map {
+            let mut query_polys = Vec::with_capacity(labels.len());
+            let mut query_rands = Vec::with_capacity(labels.len());
+            let mut is_hiding = false;
+
+            for label in labels {
+                let (polynomial, rand) =
+                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if let Some(degree_bound) = polynomial.degree_bound() {
+                    bail!(PCError::UnsupportedDegreeBound(degree_bound));
+                }
+                is_hiding |= polynomial.is_hiding();
+
+                query_polys.push(*polynomial);
+                query_rands.push(*rand);
+            }
+            let (polynomial, rand) =
+                Self::combine_for_open(universal_prover, ck, query_polys.into_iter(), query_rands.into_iter(), fs_rng)?;
+            let quotient = Self::divide_by_coset_vanishing_polynomial(&polynomial, coset);
+
+            quotients.push(LabeledPolynomial::new(
+                format!("{coset_name}_quotient"),
+                quotient,
+                None,
+                is_hiding.then_some(1),
+            ));
+            combined.push((coset_name, coset, polynomial, rand));
+        }
+
+        let (quotient_commitments, quotient_rands) =
+            Self::commit(universal_prover, ck, quotients.iter().map(Into::into), rng)?;
+        fs_rng.absorb_native_field_elements(&quotient_commitments);
+        let zeta = fs_rng.squeeze_nonnative_field_elements(1)[0];
+
+        // Linearize the quotient relation at `zeta`, and open the resulting polynomials at `zeta`.
+        let mut linearized_polys = Vec::with_capacity(combined.len());
+        let mut linearized_rands = Vec::with_capacity(combined.len());
+        let mut linearized_query_set = QuerySet::new();
+        for ((coset_name, coset, polynomial, rand), (quotient, quotient_rand)) in
+            combined.into_iter().zip_eq(quotients.iter().zip_eq(&quotient_rands))
+        {
+            let vanishing_at_zeta = coset.evaluate_vanishing_polynomial(zeta);
+            let (polynomial, rand) = Self::combine_polynomials([
+                (E::Fr::one(), Cow::Borrowed(&polynomial), &rand),
+                (-vanishing_at_zeta, quotient.to_dense(), quotient_rand),
+            ]);
+            linearized_polys.push(LabeledPolynomial::new(coset_name.clone(), polynomial, None, None));
+            linearized_rands.push(rand);
+            linearized_query_set.insert((coset_name.clone(), ("zeta".to_string(), zeta)));
+        }
+        let proof = Self::batch_open(
+            universal_prover,
+            ck,
+            linearized_polys.iter(),
+            &linearized_query_set,
+            linearized_rands.iter(),
+            fs_rng,
+        )?;
+
+        end_timer!(open_time);
+        let quotient_commitments = quotient_commitments.into_iter().map(|c| *c.commitment()).collect();
+        Ok(CosetBatchProof { quotient_commitments, proof })
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `commitments`.
+    ///
+    /// The `values` are absorbed into `fs_rng` before the commitments are combined, matching `batch_open_cosets`.
+    pub fn batch_check_cosets<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &CosetQuerySet<E::Fr>,
+        values: &CosetEvaluations<E::Fr>,
+        proof: &CosetBatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at coset query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+
+        let query_to_labels_map = Self::coset_query_to_labels_map(query_set);
+        if query_to_labels_map.len() != proof.quotient_commitments.len() {
+            bail!(PCError::MismatchedOpenings {
+                expected: query_to_labels_map.len(),
+                found: proof.quotient_commitments.len()
+            });
+        }
+
+        Self::absorb_coset_evaluations(fs_rng, query_set, values)?;
+
+        // Combine the commitments and the evaluations at each coset.
+        let mut combined = Vec::with_capacity(query_to_labels_map.len());
+        for (coset_name, (coset, labels)) in query_to_labels_map {
+            let mut coeffs_and_comms = Vec::with_capacity(labels.len() + 1);
+            let mut combined_values = vec![E::Fr::zero(); coset.size()];
+            for label in labels {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if let Some(degree_bound) = commitment.degree_bound() {
+                    bail!(PCError::UnsupportedDegreeBound(degree_bound));
+                }
+                let evaluations = values
+                    .get(&(label.clone(), *coset))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                coeffs_and_comms.push((challenge, commitment.commitment()));
+                combined_values.iter_mut().zip_eq(evaluations).for_each(|(c, v)| *c += challenge * v);
+            }
+            combined.push((coset_name, coset, coeffs_and_comms, combined_values));
+        }
+
+        let quotient_commitments = combined
+            .iter()
+            .zip_eq(&proof.quotient_commitments)
+            .map(|((coset_name, ..), c)| LabeledCommitment::new(format!("{coset_name}_quotient"), *c, None))
+            .collect::<Vec<_>>();
+        fs_rng.absorb_native_field_elements(&quotient_commitments);
+        let zeta = fs_rng.squeeze_nonnative_field_elements(1)[0];
+
+        // Linearize the quotient relation at `zeta`, and check the evaluations of the resulting polynomials.
+        let mut lc_commitments = Vec::with_capacity(combined.len());
+        let mut lc_labels = Vec::with_capacity(combined.len());
+        let mut evaluations = Evaluations::new();
+        let mut linearized_query_set = QuerySet::new();
+        for ((coset_name, coset, mut coeffs_and_comms, combined_values), quotient_commitment) in
+            combined.into_iter().zip_eq(&proof.quotient_commitments)
+        {
+            let vanishing_at_zeta = coset.evaluate_vanishing_polynomial(zeta);
+            coeffs_and_comms.push((-vanishing_at_zeta, quotient_commitment));
+            lc_commitments.push(Self::combine_commitments(coeffs_and_comms));
+
+            // Evaluate the interpolant of the combined values at `zeta`.
+            let offset_inv = coset.offset().inverse().ok_or_else(|| anyhow!("The coset offset is zero"))?;
+            let lagrange_coefficients = coset.domain().evaluate_all_lagrange_coefficients(zeta * offset_inv);
+            let value = lagrange_coefficients.iter().zip_eq(&combined_values).map(|(l, v)| *l * v).sum::<E::Fr>();
+
+            evaluations.insert((coset_name.clone(), zeta), value);
+            linearized_query_set.insert((coset_name.clone(), ("zeta".to_string(), zeta)));
+            lc_labels.push(coset_name.clone());
+        }
+        let lc_commitments = lc_labels
+            .into_iter()
+            .zip_eq(Self::normalize_commitments(lc_commitments))
+            .map(|(label, c)| LabeledCommitment::new(label, c, None))
+            .collect::<Vec<_>>();
+
+        let result = Self::batch_check(vk, &lc_commitments, &linearized_query_set, &evaluations, &proof.proof, fs_rng);
+        end_timer!(batch_check_time);
+        result
+    }
+
+    #[cfg(feature = "prover")]
+    pub fn open_combinations<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomial<E::Fr>>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+        query_set: &QuerySet<E::Fr>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<E>>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let label_map =
+            polynomials.into_iter().zip_eq(rands).map(|(p, r)| (p.to_label(), (p, r))).collect::<BTreeMap<_, _>>();
+
+        let mut lc_polynomials = Vec::new();
+        let mut lc_randomness = Vec::new();
+        let mut lc_info = Vec::new();
+
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let mut poly = DensePolynomial::zero();
+            let mut randomness = Randomness::empty();
+            let mut degree_bound = None;
+            let mut hiding_bound = None;
+
+            let num_polys = lc.len();
+            // We filter out l.is_one() entries because those constants are not committed to and used directly by the verifier.
+            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
+                let label: &String = label.try_into().expect("cannot be one!");
+                let (cur_poly, cur_rand) =
+                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if let Some(cur_degree_bound) = cur_poly.degree_bound() {
                     if num_polys != 1 {
                         bail!(PCError::EquationHasDegreeBounds(lc_label));
                     }
@@ -487,6 +1199,138 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Commitment<E>: 'a,
     {
         let BatchLCProof { proof } = proof;
+        let (lc_commitments, evaluations) =
+            Self::combine_lc_commitments(linear_combinations, commitments, evaluations)?;
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+}
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Opens the polynomials in `poly_rand` at each group of a query set, where each group consists of
+    /// a query point and the labels of the polynomials queried at it.
+    #[cfg(feature = "prover")]
+    fn open_groups<'a, 'b>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        poly_rand: &HashMap<&str, (&'a LabeledPolynomial<E::Fr>, &'a Randomness<E>)>,
+        groups: impl ExactSizeIterator<Item = (E::Fr, impl IntoIterator<Item = &'b String>)>,
+        fs_rng: &mut S,
+    ) -> Result<BatchProof<E>> {
+        let mut pool =
+            snarkvm_utilities::ExecutionPool::<_>::with_capacity(groups.len()).in_sequence(universal_prover.low_memory);
+        for (query, labels) in groups {
+            let mut query_polys = Vec::new();
+            let mut query_rands = Vec::new();
+
+            for label in labels {
+                let (polynomial, rand) =
+                    poly_rand.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                query_polys.push(*polynomial);
+                query_rands.push(*rand);
+            }
+            let (polynomial, rand) =
+                Self::combine_for_open(universal_prover, ck, query_polys.into_iter(), query_rands.into_iter(), fs_rng)?;
+            let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+            let msm_backend = universal_prover.msm_backend;
+
+            pool.add_job(move || {
+                let proof_time = start_timer!(|| "Creating proof");
+                let proof = kzg10::KZG10::open(&ck.powers(), &polynomial, query, &rand, msm_backend);
+                end_timer!(proof_time);
+                proof
+            });
+        }
+        pool.execute_all_with_max_threads(universal_prover.max_threads)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .map(BatchProof)
+            .map_err(Into::into)
+    }
+
+    #[cfg(feature = "prover")]
+    fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
+        coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
+    ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
+        let mut combined_poly = DensePolynomial::zero();
+        let mut combined_rand = Randomness::empty();
+        for (coeff, poly, rand) in coeffs_polys_rands {
+            let poly = poly.borrow();
+            if coeff.is_one() {
+                combined_poly += poly;
+                combined_rand += rand;
+            } else {
+                combined_poly += (coeff, poly);
+                combined_rand += (coeff, rand);
+            }
+        }
+        (combined_poly, combined_rand)
+    }
+
+    /// Groups the labels in the coset query set by the name of their coset.
+    fn coset_query_to_labels_map(
+        query_set: &CosetQuerySet<E::Fr>,
+    ) -> BTreeMap<&String, (&Coset<E::Fr>, BTreeSet<&String>)> {
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (coset_name, coset)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(coset_name).or_insert((coset, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+        query_to_labels_map
+    }
+
+    /// Absorbs the evaluations of the polynomials over the cosets in `query_set` into the sponge.
+    fn absorb_coset_evaluations(
+        fs_rng: &mut S,
+        query_set: &CosetQuerySet<E::Fr>,
+        values: &CosetEvaluations<E::Fr>,
+    ) -> Result<()> {
+        for (label, (_coset_name, coset)) in query_set {
+            let evaluations =
+                values.get(&(label.clone(), *coset)).ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+            if evaluations.len() != coset.size() {
+                bail!(PCError::MismatchedEvaluations {
+                    label: label.to_string(),
+                    expected: coset.size(),
+                    found: evaluations.len()
+                });
+            }
+            fs_rng.absorb_nonnative_field_elements(evaluations.iter().copied());
+        }
+        Ok(())
+    }
+
+    /// Returns the quotient of `polynomial` by the vanishing polynomial `X^n - offset^n` of `coset`.
+    #[cfg(feature = "prover")]
+    fn divide_by_coset_vanishing_polynomial(
+        polynomial: &DensePolynomial<E::Fr>,
+        coset: &Coset<E::Fr>,
+    ) -> DensePolynomial<E::Fr> {
+        let n = coset.size();
+        if polynomial.coeffs.len() <= n {
+            return DensePolynomial::zero();
+        }
+        let offset_pow_n = coset.offset().pow([n as u64]);
+        let mut remainder = polynomial.coeffs.clone();
+        let mut quotient = vec![E::Fr::zero(); remainder.len() - n];
+        for i in (n..remainder.len()).rev() {
+            let coeff = remainder[i];
+            quotient[i - n] = coeff;
+            remainder[i - n] += offset_pow_n * coeff;
+        }
+        DensePolynomial::from_coefficients_vec(quotient)
+    }
+
+    /// Returns the commitments to the given linear combinations, together with their evaluations,
+    /// from which the constant terms of the linear combinations are subtracted.
+    pub(crate) fn combine_lc_commitments<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<E::Fr>)>
+    where
+        Commitment<E>: 'a,
+    {
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
         let mut lc_commitments = Vec::new();
@@ -540,27 +1384,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
-    }
-}
-
-impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
-    fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
-        coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
-    ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
-        let mut combined_poly = DensePolynomial::zero();
-        let mut combined_rand = Randomness::empty();
-        for (coeff, poly, rand) in coeffs_polys_rands {
-            let poly = poly.borrow();
-            if coeff.is_one() {
-                combined_poly += poly;
-                combined_rand += rand;
-            } else {
-                combined_poly += (coeff, poly);
-                combined_rand += (coeff, rand);
-            }
-        }
-        (combined_poly, combined_rand)
+        Ok((lc_commitments, evaluations))
     }
 
     /// MSM for `commitments` and `coeffs`
@@ -577,7 +1401,227 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2> + Send + 'static> SonicKZG10<E, S> {
+    /// Commits to the given polynomials as in `commit`, on the blocking thread pool of the current Tokio runtime.
+    ///
+    /// Fails with `SNARKError::Cancelled` if `cancellation` is cancelled before the commitments are returned.
+    pub async fn commit_async<R: RngCore + Send + 'static>(
+        universal_prover: Arc<UniversalProver<E>>,
+        ck: Arc<CommitterKey<E>>,
+        polynomials: Vec<LabeledPolynomial<E::Fr>>,
+        rng: Option<R>,
+        cancellation: CancellationToken,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>)> {
+        spawn_blocking_cancellable(cancellation, move |_| {
+            let ck = CommitterUnionKey::union(std::iter::once(ck.as_ref()));
+            let mut rng = rng;
+            let rng = rng.as_mut().map(|rng| rng as &mut dyn RngCore);
+            Self::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), rng).map_err(Into::into)
+        })
+        .await
+    }
+
+    /// Opens the given polynomials as in `batch_open`, on the blocking thread pool of the current Tokio runtime.
+    /// Returns the proof along with `fs_rng`, so that the caller can continue the transcript.
+    ///
+    /// Fails with `SNARKError::Cancelled` if `cancellation` is cancelled before the proof is returned.
+    pub async fn batch_open_async(
+        universal_prover: Arc<UniversalProver<E>>,
+        ck: Arc<CommitterKey<E>>,
+        labeled_polynomials: Vec<LabeledPolynomial<E::Fr>>,
+        query_set: QuerySet<E::Fr>,
+        rands: Vec<Randomness<E>>,
+        mut fs_rng: S,
+        cancellation: CancellationToken,
+    ) -> Result<(BatchProof<E>, S)> {
+        spawn_blocking_cancellable(cancellation, move |_| {
+            let ck = CommitterUnionKey::union(std::iter::once(ck.as_ref()));
+            let proof = Self::batch_open(
+                &universal_prover,
+                &ck,
+                labeled_polynomials.iter(),
+                &query_set,
+                rands.iter(),
+                &mut fs_rng,
+            )?;
+            Ok((proof, fs_rng))
+        })
+        .await
+    }
+}
+
+/// The combination of the commitments and values that are queried at a single point of a batch proof.
+struct CombinedQuery<'a, E: PairingEngine> {
+    /// The query point.
+    point: E::Fr,
+    /// The randomizer of the opening at the point.
+    randomizer: E::Fr,
+    /// The commitments to combine, along with their challenges.
+    commitments: Vec<(E::Fr, &'a LabeledCommitment<Commitment<E>>)>,
+    /// The combination of the values at the point.
+    value: E::Fr,
+}
+
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Accumulates the pairing check elements of a single batch proof,
+    /// scaling them by `instance_randomizer`.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_batch<'a>(
+        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: &mut E::G1Projective,
+        combined_adjusted_witness: &mut E::G1Projective,
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        instance_randomizer: E::Fr,
+        fs_rng: &mut S,
+    ) -> Result<()>
+    where
+        Commitment<E>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label(), c)).collect();
+        let accumulate_time = start_timer!(|| format!(
+            "Accumulating {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((*point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        Self::accumulate_groups(
+            combined_comms,
+            combined_witness,
+            combined_adjusted_witness,
+            vk,
+            &commitments,
+            query_to_labels_map.into_values(),
+            values,
+            proof,
+            instance_randomizer,
+            fs_rng,
+        )?;
+
+        end_timer!(accumulate_time);
+        Ok(())
+    }
+
+    /// Accumulates the pairing check elements of the openings of a batch proof, where each
+    /// group consists of a query point and the labels of the commitments queried at it.
+    #[allow(clippy::too_many_arguments)]
+    fn accumulate_groups<'a, 'b>(
+        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: &mut E::G1Projective,
+        combined_adjusted_witness: &mut E::G1Projective,
+        vk: &UniversalVerifier<E>,
+        commitments: &BTreeMap<&str, &'a LabeledCommitment<Commitment<E>>>,
+        groups: impl ExactSizeIterator<Item = (E::Fr, impl IntoIterator<Item = &'b String>)>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        instance_randomizer: E::Fr,
+        fs_rng: &mut S,
+    ) -> Result<()>
+    where
+        Commitment<E>: 'a,
+    {
+        if groups.len() != proof.0.len() {
+            bail!(PCError::MismatchedOpenings { expected: groups.len(), found: proof.0.len() });
+        }
+
+        let mut randomizer = E::Fr::one();
+        for ((query, labels), p) in groups.zip_eq(&proof.0) {
+            let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
+            let mut values_to_combine = Vec::new();
+            for label in labels {
+                let commitment =
+                    commitments.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                let v_i = values
+                    .get(&(label.clone(), query))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                comms_to_combine.push(*commitment);
+                values_to_combine.push(*v_i);
+            }
+
+            Self::accumulate_elems(
+                combined_comms,
+                combined_witness,
+                combined_adjusted_witness,
+                vk,
+                comms_to_combine.into_iter(),
+                query,
+                values_to_combine.into_iter(),
+                p,
+                Some(randomizer * instance_randomizer),
+                fs_rng,
+            )?;
+
+            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        }
+        Ok(())
+    }
+
+    /// Returns the combination of the commitments and values at each query point of a batch proof,
+    /// squeezing the challenges from `fs_rng` in the same order as `accumulate_batch`.
+    fn combine_batch_queries<'a>(
+        commitments: &'a [LabeledCommitment<Commitment<E>>],
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        fs_rng: &mut S,
+    ) -> Result<Vec<CombinedQuery<'a, E>>> {
+        let commitments: BTreeMap<_, _> = commitments.iter().map(|c| (c.label(), c)).collect();
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut queries = Vec::with_capacity(query_to_labels_map.len());
+        let mut randomizer = E::Fr::one();
+        for (_query_name, (point, labels)) in query_to_labels_map {
+            let mut query = CombinedQuery { point: *point, randomizer, commitments: Vec::new(), value: E::Fr::zero() };
+            for label in labels {
+                let commitment =
+                    commitments.get(label.as_str()).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let value = values
+                    .get(&(label.clone(), *point))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                query.value += *value * challenge;
+                query.commitments.push((challenge, *commitment));
+            }
+            queries.push(query);
+            randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+        }
+        Ok(queries)
+    }
+
+    /// Absorbs an instance into the aggregation sponge, and returns the randomizer for the instance.
+    fn absorb_instance(
+        fs_rng: &mut S,
+        commitments: &[LabeledCommitment<Commitment<E>>],
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+    ) -> Result<E::Fr> {
+        fs_rng.absorb_native_field_elements(commitments);
+        let mut elements = Vec::with_capacity(2 * query_set.len());
+        for (label, (_point_name, point)) in query_set {
+            let value =
+                values.get(&(label.clone(), *point)).ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+            elements.extend([*point, *value]);
+        }
+        fs_rng.absorb_nonnative_field_elements(elements);
+        Ok(fs_rng.squeeze_short_nonnative_field_element::<E::Fr>())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn accumulate_elems<'a>(
         combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
@@ -615,6 +1659,29 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             end_timer!(acc_timer);
         }
 
+        Self::accumulate_opening(
+            combined_witness,
+            combined_adjusted_witness,
+            vk,
+            point,
+            combined_values,
+            proof,
+            randomizer,
+        );
+        end_timer!(acc_time);
+        Ok(())
+    }
+
+    /// Accumulates the witness of an opening at `point` to the combined `value`, scaled by `randomizer`.
+    fn accumulate_opening(
+        combined_witness: &mut E::G1Projective,
+        combined_adjusted_witness: &mut E::G1Projective,
+        vk: &UniversalVerifier<E>,
+        point: E::Fr,
+        combined_values: E::Fr,
+        proof: &kzg10::KZGProof<E>,
+        randomizer: Option<E::Fr>,
+    ) {
         // Push expected results into list of elems. Power will be the negative of the expected power
         let mut bases = vec![vk.vk.g, -proof.w];
         let mut coeffs = vec![combined_values, point];
@@ -630,8 +1697,6 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         };
         let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
         *combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
-        end_timer!(acc_time);
-        Ok(())
     }
 
     fn check_elems(
@@ -682,12 +1747,37 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq};
-    use snarkvm_utilities::{FromBytes, ToBytes, rand::TestRng};
+    use super::{
+        BatchProof,
+        CommitterKey,
+        CommitterUnionKey,
+        Coset,
+        CosetQuerySet,
+        HidingSeed,
+        LabeledPolynomial,
+        LabeledPolynomialWithBasis,
+        NormalizedQuerySet,
+        PCError,
+        PolynomialInfo,
+        QuerySet,
+        Rotation,
+        SonicKZG10,
+        evaluate_coset_query_set,
+        evaluate_query_set,
+        insert_rotated_query,
+    };
+    use crate::{
+        AlgebraicSponge,
+        crypto_hash::PoseidonSponge,
+        fft::{DensePolynomial, EvaluationDomain, Evaluations, MixedRadixEvaluationDomain, SparsePolynomial},
+        polycommit::test_templates::*,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::{FftField, One, Zero};
+    use snarkvm_utilities::{FromBytes, ToBytes, Uniform, rand::TestRng};
 
     use rand::distributions::Distribution;
+    use std::collections::BTreeMap;
 
     type Sponge = PoseidonSponge<Fq, 2, 1>;
     type PC_Bls12_377 = SonicKZG10<Bls12_377, Sponge>;
@@ -711,6 +1801,608 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_commit_streaming() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 8;
+        let degree_bound = max_degree / 2;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 0, Some(&[degree_bound])).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        for bound in [None, Some(degree_bound)] {
+            let degree = bound.unwrap_or(max_degree);
+            let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+            let labeled = LabeledPolynomial::new("a".to_string(), polynomial.clone(), bound, None);
+
+            let (expected, _) = PC_Bls12_377::commit(universal_prover, &ck, [(&labeled).into()], None).unwrap();
+            let (candidate, _) =
+                PC_Bls12_377::commit_streaming(universal_prover, &ck, labeled.info(), polynomial.coeffs, None).unwrap();
+            assert_eq!(expected[0], candidate);
+        }
+
+        // Ensure polynomials exceeding the degree bound are rejected.
+        let polynomial = DensePolynomial::<Fr>::rand(degree_bound + 1, rng);
+        let info = PolynomialInfo::new("b".to_string(), Some(degree_bound), None);
+        assert!(PC_Bls12_377::commit_streaming(universal_prover, &ck, &info, polynomial.coeffs, None).is_err());
+    }
+
+    #[test]
+    fn test_commit_sparse_polynomial() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 8;
+        let degree_bound = max_degree / 2;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 0, Some(&[degree_bound])).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        for bound in [None, Some(degree_bound)] {
+            // Sample a polynomial in which only a few of the coefficients are non-zero.
+            let degree = bound.unwrap_or(max_degree);
+            let mut coeffs = vec![Fr::zero(); degree + 1];
+            for i in (0..=degree).step_by(17).chain([degree]) {
+                coeffs[i] = Fr::rand(rng);
+            }
+            let dense = DensePolynomial::from_coefficients_vec(coeffs);
+            let sparse = SparsePolynomial::from_coefficients(dense.coeffs.iter().copied().enumerate());
+
+            let dense = LabeledPolynomial::new("a".to_string(), dense, bound, None);
+            let sparse = LabeledPolynomialWithBasis::new_sparse_monomial_basis("a".to_string(), sparse, bound, None);
+            let (expected, _) = PC_Bls12_377::commit(universal_prover, &ck, [(&dense).into()], None).unwrap();
+            let (candidate, _) = PC_Bls12_377::commit(universal_prover, &ck, [sparse], None).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_commit_and_open_async() {
+        use crate::{CancellationToken, SNARKError};
+        use std::sync::Arc;
+
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = Arc::new(pp.to_universal_prover().unwrap());
+        let vk = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = Arc::new(ck);
+
+        let polynomials = ["a", "b"].map(|label| {
+            LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, Some(1))
+        });
+        let mut query_set = QuerySet::new();
+        for label in ["a", "b"] {
+            query_set.insert((label.to_string(), ("z".to_string(), Fr::from(5u64))));
+        }
+        let values = evaluate_query_set(&polynomials, &query_set);
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let (comms, rands) = runtime
+            .block_on(PC_Bls12_377::commit_async(
+                universal_prover.clone(),
+                ck.clone(),
+                polynomials.to_vec(),
+                Some(TestRng::default()),
+                CancellationToken::new(),
+            ))
+            .unwrap();
+        let (proof, _) = runtime
+            .block_on(PC_Bls12_377::batch_open_async(
+                universal_prover.clone(),
+                ck.clone(),
+                polynomials.to_vec(),
+                query_set.clone(),
+                rands,
+                Sponge::new(),
+                CancellationToken::new(),
+            ))
+            .unwrap();
+        assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure a cancelled commitment is not returned.
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        let result = runtime.block_on(PC_Bls12_377::commit_async(
+            universal_prover,
+            ck,
+            polynomials.to_vec(),
+            None::<TestRng>,
+            cancellation,
+        ));
+        assert!(matches!(result.unwrap_err().downcast::<SNARKError>(), Ok(SNARKError::Cancelled)));
+    }
+
+    #[test]
+    fn test_batch_check_independent() {
+        let rng = &mut TestRng::default();
+        let mut components = single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
+        components.truncate(10);
+
+        let vk = components[0].verification_key.clone();
+        let mut sponges = vec![Sponge::new(); components.len()];
+        let instances = components.iter().zip(&mut sponges).map(|(c, sponge)| {
+            (&c.commitments[..], &c.query_set, &c.evaluations, c.batch_proof.as_ref().unwrap(), sponge)
+        });
+        assert!(PC_Bls12_377::batch_check_independent(&vk, instances, rng).unwrap());
+
+        // Ensure an incorrect evaluation in any one of the instances is rejected.
+        let evaluation = components[5].evaluations.values_mut().next().unwrap();
+        *evaluation += Fr::from(1u64);
+        let mut sponges = vec![Sponge::new(); components.len()];
+        let instances = components.iter().zip(&mut sponges).map(|(c, sponge)| {
+            (&c.commitments[..], &c.query_set, &c.evaluations, c.batch_proof.as_ref().unwrap(), sponge)
+        });
+        assert!(!PC_Bls12_377::batch_check_independent(&vk, instances, rng).unwrap());
+    }
+
+    #[test]
+    fn test_batch_check_streaming() {
+        let mut components = quadratic_poly_degree_bound_multiple_queries_test::<Bls12_377, Sponge>()
+            .expect("test failed for bls12-377");
+        components.truncate(5);
+
+        for c in &components {
+            let vk = &c.verification_key;
+            let proof = c.batch_proof.as_ref().unwrap();
+            let commitments: BTreeMap<_, _> = c.commitments.iter().map(|c| (c.label(), c)).collect();
+
+            // Stream the queries grouped by point name, and ordered by label within each point.
+            let mut queries = BTreeMap::<_, (Fr, Vec<_>)>::new();
+            for (label, (point_name, point)) in &c.query_set {
+                let value = c.evaluations[&(label.clone(), *point)];
+                queries.entry(point_name).or_insert((*point, Vec::new())).1.push((label, value));
+            }
+            let stream = || {
+                queries.values().map(|(point, values)| {
+                    (*point, values.iter().map(|(label, value)| (commitments[label.as_str()], *value)))
+                })
+            };
+
+            let expected =
+                PC_Bls12_377::batch_check(vk, &c.commitments, &c.query_set, &c.evaluations, proof, &mut Sponge::new())
+                    .unwrap();
+            assert!(expected);
+            assert!(PC_Bls12_377::batch_check_streaming(vk, stream(), proof, &mut Sponge::new()).unwrap());
+
+            // Ensure an incorrect value is rejected, and a missing query is an error.
+            let tampered =
+                stream().map(|(point, values)| (point, values.map(|(comm, value)| (comm, value + Fr::one()))));
+            assert!(!PC_Bls12_377::batch_check_streaming(vk, tampered, proof, &mut Sponge::new()).unwrap());
+            let truncated = stream().take(queries.len() - 1);
+            assert!(PC_Bls12_377::batch_check_streaming(vk, truncated, proof, &mut Sponge::new()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_aggregate_batch_proofs() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let vk = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        // Each instance queries its polynomials at two shared points and at one point of its own.
+        let mut instances = Vec::new();
+        for i in 0..3u64 {
+            let polynomials = [("a", Some(1)), ("b", None)].map(|(label, hiding_bound)| {
+                LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, hiding_bound)
+            });
+            let (comms, rands) =
+                PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+            let mut query_set = QuerySet::new();
+            query_set.insert(("a".to_string(), ("z_0".to_string(), Fr::from(2u64))));
+            query_set.insert(("b".to_string(), ("z_1".to_string(), Fr::from(3u64))));
+            query_set.insert(("a".to_string(), ("z_2".to_string(), Fr::from(10 + i))));
+            let values = evaluate_query_set(&polynomials, &query_set);
+            let proof = PC_Bls12_377::batch_open(
+                &universal_prover,
+                &ck,
+                polynomials.iter(),
+                &query_set,
+                rands.iter(),
+                &mut Sponge::new(),
+            )
+            .unwrap();
+            instances.push((comms, query_set, values, proof));
+        }
+
+        let mut sponges = vec![Sponge::new(); instances.len()];
+        let proof = PC_Bls12_377::aggregate_batch_proofs(
+            instances.iter().zip(&mut sponges).map(|((c, q, v, p), sponge)| (&c[..], q, v, p, sponge)),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        assert_eq!(proof.len(), 5);
+        assert!(proof.is_hiding());
+
+        let check = |instances: &[(Vec<_>, QuerySet<_>, _, BatchProof<_>)]| {
+            let mut sponges = vec![Sponge::new(); instances.len()];
+            let instances = instances.iter().zip(&mut sponges).map(|((c, q, v, _), sponge)| (&c[..], q, v, sponge));
+            PC_Bls12_377::check_aggregated_batch_proof(&vk, instances, &proof, &mut Sponge::new())
+        };
+        assert!(check(&instances).unwrap());
+
+        // Ensure an incorrect evaluation in any one of the instances is rejected.
+        let mut tampered = instances.clone();
+        *tampered[1].2.values_mut().next().unwrap() += Fr::one();
+        assert!(!check(&tampered).unwrap());
+
+        // Ensure the proof is rejected for a different set of instances.
+        assert!(check(&instances[..2]).is_err());
+        tampered = instances.clone();
+        tampered.swap(0, 2);
+        assert!(!check(&tampered).unwrap());
+    }
+
+    #[test]
+    fn test_commit_with_seed() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let vk = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = ["a", "b"].map(|label| {
+            LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, Some(1))
+        });
+        let mut query_set = QuerySet::new();
+        query_set.insert(("a".to_string(), ("z".to_string(), Fr::from(2u64))));
+        query_set.insert(("b".to_string(), ("z".to_string(), Fr::from(2u64))));
+        let values = evaluate_query_set(&polynomials, &query_set);
+
+        let commit_and_open = |seed: &HidingSeed| {
+            let (comms, rands) =
+                PC_Bls12_377::commit_with_seed(&universal_prover, &ck, polynomials.iter().map(Into::into), seed)
+                    .unwrap();
+            let proof = PC_Bls12_377::batch_open(
+                &universal_prover,
+                &ck,
+                polynomials.iter(),
+                &query_set,
+                rands.iter(),
+                &mut Sponge::new(),
+            )
+            .unwrap();
+            (comms, proof)
+        };
+
+        // Ensure the commitments and proofs are reproducible from the seed.
+        let seed = HidingSeed::new([7u8; 32]);
+        let (comms, proof) = commit_and_open(&seed);
+        assert!(proof.is_hiding());
+        assert_eq!((comms.clone(), proof.clone()), commit_and_open(&seed));
+        assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure a different seed yields different commitments.
+        assert_ne!(comms, commit_and_open(&HidingSeed::new([8u8; 32])).0);
+    }
+
+    #[test]
+    fn test_rotated_queries() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let vk = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        // Let `q(X) = p(\omega * X)`, so that `p` opened at `\omega * z` must agree with `q` opened at `z`.
+        let domain = EvaluationDomain::new(max_degree).unwrap();
+        let p = DensePolynomial::rand(max_degree, rng);
+        let mut power = Fr::one();
+        let q_coeffs = p.coeffs.iter().map(|c| {
+            let coeff = *c * power;
+            power *= domain.group_gen;
+            coeff
+        });
+        let q = DensePolynomial::from_coefficients_vec(q_coeffs.collect());
+        let polynomials = [("p", p), ("q", q)]
+            .map(|(label, polynomial)| LabeledPolynomial::new(label.to_string(), polynomial, None, Some(1)));
+        let (comms, rands) =
+            PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        let z = Fr::from(5u64);
+        let mut query_set = QuerySet::new();
+        for (label, rotation) in [("p", Rotation::cur()), ("p", Rotation::next()), ("q", Rotation::cur())] {
+            insert_rotated_query(&mut query_set, label, ("z", z), rotation, &domain);
+        }
+        assert_eq!(Rotation::prev().rotate(Rotation::next().rotate(z, &domain), &domain), z);
+        let mut values = evaluate_query_set(&polynomials, &query_set);
+        let omega_z = Rotation::next().rotate(z, &domain);
+        assert_eq!(values[&("p".to_string(), omega_z)], values[&("q".to_string(), z)]);
+
+        let proof = PC_Bls12_377::batch_open(
+            &universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        assert!(PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+
+        // Ensure an incorrect evaluation at the rotated point is rejected.
+        *values.get_mut(&("p".to_string(), omega_z)).unwrap() += Fr::one();
+        assert!(!PC_Bls12_377::batch_check(&vk, &comms, &query_set, &values, &proof, &mut Sponge::new()).unwrap());
+    }
+
+    #[test]
+    fn test_normalized_query_set() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let vk = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = ["a", "b", "c"].map(|label| {
+            LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, Some(1))
+        });
+        let (comms, rands) =
+            PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        // Query the same point under two different names, which are merged by the normalization.
+        let (z, w) = (Fr::from(5u64), Fr::from(7u64));
+        let mut query_set = QuerySet::new();
+        for (label, point_name) in [("a", "beta"), ("b", "beta"), ("c", "gamma")] {
+            query_set.insert((label.to_string(), (point_name.to_string(), z)));
+        }
+        let normalized = NormalizedQuerySet::normalize(&query_set);
+        assert!(normalized.is_single_point());
+        let values = evaluate_query_set(&polynomials, &query_set);
+
+        // The single-point fast path agrees with the general path.
+        let mut single_query_set = QuerySet::new();
+        for label in ["a", "b", "c"] {
+            single_query_set.insert((label.to_string(), ("z".to_string(), z)));
+        }
+        let expected = PC_Bls12_377::batch_open(
+            &universal_prover,
+            &ck,
+            polynomials.iter(),
+            &single_query_set,
+            rands.iter(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        let proof = PC_Bls12_377::batch_open_normalized(
+            &universal_prover,
+            &ck,
+            polynomials.iter().rev(),
+            &normalized,
+            rands.iter().rev(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        assert_eq!(proof, expected);
+        assert!(
+            PC_Bls12_377::batch_check_normalized(&vk, &comms, &normalized, &values, &proof, &mut Sponge::new())
+                .unwrap()
+        );
+        let point_values = polynomials.iter().map(|p| p.evaluate(z));
+        assert!(
+            PC_Bls12_377::check_at_point(&vk, comms.iter(), z, point_values, &proof.0[0], &mut Sponge::new()).unwrap()
+        );
+
+        // Query a second point, which requires the general path.
+        query_set.insert(("a".to_string(), ("omega".to_string(), w)));
+        let normalized = NormalizedQuerySet::normalize(&query_set);
+        assert_eq!(normalized.len(), 2);
+        let mut values = evaluate_query_set(&polynomials, &query_set);
+        let proof = PC_Bls12_377::batch_open_normalized(
+            &universal_prover,
+            &ck,
+            polynomials.iter(),
+            &normalized,
+            rands.iter(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        assert_eq!(proof.0.len(), 2);
+        assert!(
+            PC_Bls12_377::batch_check_normalized(&vk, &comms, &normalized, &values, &proof, &mut Sponge::new())
+                .unwrap()
+        );
+
+        // Ensure an incorrect evaluation is rejected.
+        *values.get_mut(&("c".to_string(), z)).unwrap() += Fr::one();
+        assert!(
+            !PC_Bls12_377::batch_check_normalized(&vk, &comms, &normalized, &values, &proof, &mut Sponge::new())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_batch_open_with_max_threads() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 0, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = ["a", "b", "c"]
+            .map(|label| LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, None));
+        let (_, rands) =
+            PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+        let mut query_set = QuerySet::new();
+        for (i, polynomial) in polynomials.iter().enumerate() {
+            let point = (format!("z_{}", i % 2), Fr::from((i % 2) as u64 + 2));
+            query_set.insert((polynomial.label().to_string(), point));
+        }
+
+        let expected = PC_Bls12_377::batch_open(
+            &universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut Sponge::new(),
+        )
+        .unwrap();
+        for max_threads in [0, 1, 2] {
+            let universal_prover = universal_prover.clone().with_max_threads(max_threads);
+            let candidate = PC_Bls12_377::batch_open(
+                &universal_prover,
+                &ck,
+                polynomials.iter(),
+                &query_set,
+                rands.iter(),
+                &mut Sponge::new(),
+            )
+            .unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_batch_open_cosets() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let universal_verifier = pp.to_universal_verifier().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let polynomials = [("a", None), ("b", Some(1)), ("c", None)].map(|(label, hiding_bound)| {
+            LabeledPolynomial::new(label.to_string(), DensePolynomial::rand(max_degree, rng), None, hiding_bound)
+        });
+        let (comms, rands) =
+            PC_Bls12_377::commit(&universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        let mut query_set = CosetQuerySet::new();
+        let first = Coset::new(Fr::multiplicative_generator(), 8).unwrap();
+        let second = Coset::new(Fr::from(5u64), 4).unwrap();
+        for label in ["a", "b"] {
+            query_set.insert((label.to_string(), ("first".to_string(), first)));
+        }
+        for label in ["a", "c"] {
+            query_set.insert((label.to_string(), ("second".to_string(), second)));
+        }
+        let mut values = evaluate_coset_query_set(&polynomials, &query_set);
+
+        let proof = PC_Bls12_377::batch_open_cosets(
+            &universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut Sponge::new(),
+            Some(rng),
+        )
+        .unwrap();
+        assert_eq!(proof.quotient_commitments.len(), 2);
+        assert_eq!(proof.proof.0.len(), 1);
+        assert!(
+            PC_Bls12_377::batch_check_cosets(
+                &universal_verifier,
+                &comms,
+                &query_set,
+                &values,
+                &proof,
+                &mut Sponge::new()
+            )
+            .unwrap()
+        );
+
+        // Ensure an incorrect evaluation at any element of a coset is rejected.
+        values.get_mut(&("c".to_string(), second)).unwrap()[3] += Fr::one();
+        assert!(
+            !PC_Bls12_377::batch_check_cosets(
+                &universal_verifier,
+                &comms,
+                &query_set,
+                &values,
+                &proof,
+                &mut Sponge::new()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_commit_coset_lagrange_basis() {
+        let rng = &mut TestRng::default();
+        let max_degree = 1 << 6;
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, max_degree, None, 1, None).unwrap();
+        let mut ck = CommitterUnionKey::union(std::iter::once(&ck));
+        let offset = Fr::multiplicative_generator();
+
+        // Commit over a coset of a radix-2 subgroup, and over cosets of mixed-radix subgroups.
+        for size in [32, 24, 48] {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(size).unwrap();
+            assert_eq!(domain.size(), size);
+            let polynomial = DensePolynomial::rand(size - 1, rng);
+            let elements = domain.elements().map(|h| offset * h).collect::<Vec<_>>();
+            let evaluations = elements.iter().map(|x| polynomial.evaluate(*x)).collect::<Vec<_>>();
+            let lagrange = LabeledPolynomialWithBasis::new_coset_lagrange_basis(
+                "a".to_string(),
+                evaluations.clone(),
+                offset,
+                Some(1),
+            )
+            .unwrap();
+
+            // Ensure the evaluations are interpolated correctly, both inside and outside the coset.
+            let point = Fr::from(7u64);
+            assert_eq!(lagrange.evaluate(point), polynomial.evaluate(point));
+            assert_eq!(lagrange.evaluate(elements[3]), evaluations[3]);
+            assert_eq!(lagrange.polynomial.to_dense_polynomial(), polynomial);
+
+            // Ensure the Lagrange basis of the coset is required to commit.
+            let candidate = std::iter::once(lagrange.clone());
+            assert!(PC_Bls12_377::commit(&universal_prover, &ck, candidate, Some(&mut TestRng::fixed(0))).is_err());
+            ck.add_coset_lagrange_basis(&pp, size, offset).unwrap();
+
+            // Ensure the commitment matches the hiding commitment in the monomial basis with the same randomness.
+            let monomial = LabeledPolynomial::new("a".to_string(), polynomial, None, Some(1));
+            let expected = std::iter::once((&monomial).into());
+            let (expected, expected_rands) =
+                PC_Bls12_377::commit(&universal_prover, &ck, expected, Some(&mut TestRng::fixed(1))).unwrap();
+            let candidate = std::iter::once(lagrange);
+            let (comms, rands) =
+                PC_Bls12_377::commit(&universal_prover, &ck, candidate, Some(&mut TestRng::fixed(1))).unwrap();
+            assert_eq!(comms, expected);
+            assert_eq!(rands, expected_rands);
+        }
+
+        // Ensure coset sizes without a mixed-radix subgroup, and zero offsets, are rejected on construction.
+        for size in [5, 7, 10] {
+            let evaluations = vec![Fr::one(); size];
+            let result =
+                LabeledPolynomialWithBasis::new_coset_lagrange_basis("b".to_string(), evaluations, Fr::one(), None);
+            assert!(result.is_err());
+        }
+        let evaluations = vec![Fr::one(); 24];
+        assert!(
+            LabeledPolynomialWithBasis::new_coset_lagrange_basis("c".to_string(), evaluations, Fr::from(0u64), None)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -778,6 +2470,33 @@ mod tests {
         println!("Finished bls12-377");
     }
 
+    #[test]
+    fn test_structured_errors() {
+        let max_degree = 1 << 6;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = pp.to_universal_prover().unwrap();
+        let pc_error = |error: anyhow::Error| error.downcast::<PCError>().unwrap();
+
+        let srs_degree = pp.max_degree();
+        let error = PC_Bls12_377::trim(&pp, srs_degree + 1, None, 1, None).unwrap_err();
+        assert!(matches!(pc_error(error), PCError::TrimmingDegreeTooLarge { degree, max_degree }
+            if degree == srs_degree + 1 && max_degree == srs_degree));
+        let error = PC_Bls12_377::trim(&pp, 8, [3], 1, None).unwrap_err();
+        assert!(matches!(pc_error(error), PCError::LagrangeBasisSizeIsNotPowerOfTwo { size: 3 }));
+        let error = PC_Bls12_377::trim(&pp, 8, None, 1, Some(&[4, 9])).unwrap_err();
+        assert!(matches!(pc_error(error), PCError::DegreeBoundTooLarge { degree_bound: 9, supported_degree: 8 }));
+
+        // Ensure committing to evaluations over a domain without a Lagrange basis names the supported sizes.
+        let (ck, _) = PC_Bls12_377::trim(&pp, max_degree, [8], 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+        let domain = EvaluationDomain::new(16).unwrap();
+        let evaluations = Evaluations::from_vec_and_domain(vec![Fr::one(); 16], domain);
+        let polynomial = LabeledPolynomialWithBasis::new_lagrange_basis("a".to_string(), evaluations, None);
+        let error = PC_Bls12_377::commit(&universal_prover, &ck, [polynomial], None).unwrap_err();
+        assert!(matches!(error, PCError::UnsupportedLagrangeBasisSize { size: 16, supported_sizes }
+            if supported_sizes == [8]));
+    }
+
     #[test]
     fn test_lagrange_commitment() {
         crate::polycommit::test_templates::lagrange_test_template::<Bls12_377, Sponge>()
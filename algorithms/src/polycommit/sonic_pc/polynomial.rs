@@ -14,11 +14,18 @@
 // limitations under the License.
 
 use super::PolynomialLabel;
-use crate::fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, Polynomial, SparsePolynomial};
+use crate::fft::{
+    DensePolynomial,
+    EvaluationDomain,
+    Evaluations as EvaluationsOnDomain,
+    MixedRadixEvaluationDomain,
+    Polynomial,
+    SparsePolynomial,
+};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, cfg_iter, cfg_iter_mut};
 
-use anyhow::Result;
+use anyhow::{Result, ensure};
 use std::borrow::Cow;
 
 #[cfg(feature = "serial")]
@@ -158,6 +165,33 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
         Self { info, polynomial }
     }
 
+    /// Construct a new labeled polynomial from its non-zero coefficients.
+    ///
+    /// Committing to a polynomial in this form only performs work for its non-zero terms,
+    /// which is cheaper than the dense representation when most coefficients are zero.
+    pub fn new_sparse_monomial_basis(
+        label: PolynomialLabel,
+        polynomial: SparsePolynomial<F>,
+        degree_bound: Option<usize>,
+        hiding_bound: Option<usize>,
+    ) -> Self {
+        let polynomial = PolynomialWithBasis::new_sparse_monomial_basis(polynomial, degree_bound);
+        let info = PolynomialInfo::new(label, degree_bound, hiding_bound);
+        Self { info, polynomial }
+    }
+
+    /// Construct a new labeled polynomial from its non-zero coefficients, without cloning them.
+    pub fn new_sparse_monomial_basis_ref(
+        label: PolynomialLabel,
+        polynomial: &'a SparsePolynomial<F>,
+        degree_bound: Option<usize>,
+        hiding_bound: Option<usize>,
+    ) -> Self {
+        let polynomial = PolynomialWithBasis::new_sparse_monomial_basis_ref(polynomial, degree_bound);
+        let info = PolynomialInfo::new(label, degree_bound, hiding_bound);
+        Self { info, polynomial }
+    }
+
     pub fn new_lagrange_basis(
         label: PolynomialLabel,
         polynomial: EvaluationsOnDomain<F>,
@@ -178,6 +212,32 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
         Self { info, polynomial }
     }
 
+    /// Construct a new labeled polynomial from its `evaluations` over the coset `offset * H`,
+    /// where `H` is the multiplicative subgroup of order `evaluations.len()`.
+    pub fn new_coset_lagrange_basis(
+        label: PolynomialLabel,
+        evaluations: Vec<F>,
+        offset: F,
+        hiding_bound: Option<usize>,
+    ) -> Result<Self> {
+        let polynomial = PolynomialWithBasis::new_coset_lagrange_basis(evaluations, offset)?;
+        let info = PolynomialInfo::new(label, None, hiding_bound);
+        Ok(Self { info, polynomial })
+    }
+
+    /// Construct a new labeled polynomial from its `evaluations` over the coset `offset * H`,
+    /// where `H` is the multiplicative subgroup of order `evaluations.len()`.
+    pub fn new_coset_lagrange_basis_ref(
+        label: PolynomialLabel,
+        evaluations: &'a [F],
+        offset: F,
+        hiding_bound: Option<usize>,
+    ) -> Result<Self> {
+        let polynomial = PolynomialWithBasis::new_coset_lagrange_basis_ref(evaluations, offset)?;
+        let info = PolynomialInfo::new(label, None, hiding_bound);
+        Ok(Self { info, polynomial })
+    }
+
     /// Return the label for `self`.
     pub fn label(&self) -> &str {
         &self.info.label
@@ -191,6 +251,7 @@ impl<'a, F: PrimeField> LabeledPolynomialWithBasis<'a, F> {
     pub fn degree(&self) -> usize {
         match &self.polynomial {
             PolynomialWithBasis::Lagrange { evaluations } => evaluations.domain().size() - 1,
+            PolynomialWithBasis::CosetLagrange { evaluations, .. } => evaluations.len() - 1,
             PolynomialWithBasis::Monomial { polynomial, .. } => polynomial.degree(),
         }
     }
@@ -248,6 +309,10 @@ pub enum PolynomialWithBasis<'a, F: PrimeField> {
     /// A polynomial in Lagrange basis, along with information about
     /// its degree bound (if any).
     Lagrange { evaluations: Cow<'a, EvaluationsOnDomain<F>> },
+
+    /// A polynomial given by its evaluations over the coset `offset * H`, where `H` is the mixed-radix
+    /// multiplicative subgroup of order `evaluations.len()`, which must be of the form `2^a * 3^b`.
+    CosetLagrange { evaluations: Cow<'a, [F]>, offset: F },
 }
 
 impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
@@ -287,6 +352,24 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
         Self::Lagrange { evaluations: Cow::Borrowed(evaluations) }
     }
 
+    pub fn new_coset_lagrange_basis(evaluations: Vec<F>, offset: F) -> Result<Self> {
+        Self::check_coset(evaluations.len(), offset)?;
+        Ok(Self::CosetLagrange { evaluations: Cow::Owned(evaluations), offset })
+    }
+
+    pub fn new_coset_lagrange_basis_ref(evaluations: &'a [F], offset: F) -> Result<Self> {
+        Self::check_coset(evaluations.len(), offset)?;
+        Ok(Self::CosetLagrange { evaluations: Cow::Borrowed(evaluations), offset })
+    }
+
+    /// Ensures that the field has a mixed-radix multiplicative subgroup of order `size`, and that `offset` is nonzero.
+    fn check_coset(size: usize, offset: F) -> Result<()> {
+        ensure!(size > 0, "Cannot interpolate an empty set of evaluations");
+        ensure!(!offset.is_zero(), "The coset offset must be nonzero");
+        ensure!(coset_domain::<F>(size).is_some(), "The field has no mixed-radix subgroup of order {size}");
+        Ok(())
+    }
+
     pub fn is_in_monomial_basis(&self) -> bool {
         matches!(self, Self::Monomial { .. })
     }
@@ -308,7 +391,7 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
     }
 
     pub fn is_in_lagrange_basis(&self) -> bool {
-        matches!(self, Self::Lagrange { .. })
+        matches!(self, Self::Lagrange { .. } | Self::CosetLagrange { .. })
     }
 
     pub fn domain(&self) -> Option<EvaluationDomain<F>> {
@@ -327,7 +410,7 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
                 let multiplier = (point.pow([degree]) - F::one()) / F::from(degree);
                 let powers: Vec<_> = domain.elements().collect();
                 let mut denominators = cfg_iter!(powers).map(|pow| point - pow).collect::<Vec<_>>();
-                snarkvm_fields::batch_inversion(&mut denominators);
+                snarkvm_fields::batch_inverse(&mut denominators);
                 cfg_iter_mut!(denominators)
                     .zip_eq(powers)
                     .zip_eq(&evaluations.evaluations)
@@ -335,6 +418,63 @@ impl<'a, F: PrimeField> PolynomialWithBasis<'a, F> {
                     .sum::<F>()
                     * multiplier
             }
+            Self::CosetLagrange { evaluations, offset } => {
+                // The barycentric formula over `offset * H`, which uses that the derivative of the
+                // vanishing polynomial `X^n - offset^n` at `h` is `n * offset^n / h`.
+                let elements = coset_elements(evaluations.len(), *offset);
+                if let Some(i) = elements.iter().position(|h| *h == point) {
+                    return evaluations[i];
+                }
+                let size = [evaluations.len() as u64];
+                let offset_pow = offset.pow(size);
+                let multiplier = (point.pow(size) - offset_pow) / (F::from(size[0]) * offset_pow);
+                let mut denominators = cfg_iter!(elements).map(|h| point - h).collect::<Vec<_>>();
+                snarkvm_fields::batch_inverse(&mut denominators);
+                cfg_iter_mut!(denominators)
+                    .zip_eq(elements)
+                    .zip_eq(evaluations.as_ref())
+                    .map(|((denom, h), eval)| *denom * h * eval)
+                    .sum::<F>()
+                    * multiplier
+            }
         }
     }
+
+    /// Returns the coefficients of `self` in the monomial basis.
+    pub fn to_dense_polynomial(&self) -> DensePolynomial<F> {
+        match self {
+            Self::Monomial { polynomial, .. } => polynomial.to_dense().into_owned(),
+            Self::Lagrange { evaluations } => evaluations.interpolate_by_ref(),
+            Self::CosetLagrange { evaluations, offset } => {
+                // The subgroup was checked to exist on construction.
+                let mut coeffs = coset_domain::<F>(evaluations.len()).unwrap().ifft(evaluations);
+                // Undo the scaling by the coset offset: `p(offset * X) = \sum_k (p_k * offset^k) X^k`.
+                let offset_inv = offset.inverse().unwrap();
+                let mut power = F::one();
+                for coeff in &mut coeffs {
+                    *coeff *= power;
+                    power *= offset_inv;
+                }
+                DensePolynomial::from_coefficients_vec(coeffs)
+            }
+        }
+    }
+}
+
+/// Returns the mixed-radix domain of exactly the given `size`, if the field has a subgroup of that order.
+fn coset_domain<F: PrimeField>(size: usize) -> Option<MixedRadixEvaluationDomain<F>> {
+    MixedRadixEvaluationDomain::new(size).filter(|domain| domain.size() == size)
+}
+
+/// Returns the elements `offset * \omega^i` of the coset of order `size`, where `\omega` generates the subgroup.
+fn coset_elements<F: PrimeField>(size: usize, offset: F) -> Vec<F> {
+    // The subgroup was checked to exist on construction.
+    let root = coset_domain::<F>(size).unwrap().group_gen;
+    let mut elements = Vec::with_capacity(size);
+    let mut element = offset;
+    for _ in 0..size {
+        elements.push(element);
+        element *= root;
+    }
+    elements
 }
@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CancellationToken, errors::SNARKError};
+
+use std::{fmt, sync::Arc};
+
+/// An event that a prover reports to its [`ProvingHandle`] as the proof progresses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProvingEvent {
+    /// The prover completed round `round` of `num_rounds`, counting from 1.
+    /// The last round computes the evaluation proof.
+    RoundCompleted { round: usize, num_rounds: usize },
+    /// The prover committed to `completed` of the `total` polynomials it commits to,
+    /// each of which requires a multi-scalar multiplication.
+    CommitmentsCompleted { completed: usize, total: usize },
+}
+
+impl ProvingEvent {
+    /// Returns the fraction of the proof that is completed, as a percentage, measured in
+    /// rounds or in commitments, depending on the event.
+    pub fn percent(&self) -> f64 {
+        let (completed, total) = match *self {
+            Self::RoundCompleted { round, num_rounds } => (round, num_rounds),
+            Self::CommitmentsCompleted { completed, total } => (completed, total),
+        };
+        match total {
+            0 => 100.0,
+            _ => 100.0 * completed as f64 / total as f64,
+        }
+    }
+}
+
+/// A handle to a proof that is in progress, which receives the [`ProvingEvent`]s of the prover,
+/// and through which the caller can cancel the proof, as with a [`CancellationToken`].
+///
+/// The callback is invoked on the thread of the prover, and should return quickly.
+#[derive(Clone, Default)]
+pub struct ProvingHandle {
+    /// The token that is checked between the rounds of the prover.
+    cancellation: CancellationToken,
+    /// The callback that receives the events of the prover.
+    on_event: Option<Arc<dyn Fn(ProvingEvent) + Send + Sync>>,
+}
+
+impl ProvingHandle {
+    /// Initializes a new handle that ignores the events of the prover.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the handle with `on_event` as the callback for the events of the prover.
+    pub fn with_callback(mut self, on_event: impl Fn(ProvingEvent) + Send + Sync + 'static) -> Self {
+        self.on_event = Some(Arc::new(on_event));
+        self
+    }
+
+    /// Returns the cancellation token of the handle.
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Requests the cancellation of the proof.
+    pub fn cancel(&self) {
+        self.cancellation.cancel()
+    }
+
+    /// Returns an error if cancellation has been requested.
+    pub fn check(&self) -> Result<(), SNARKError> {
+        self.cancellation.check()
+    }
+
+    /// Reports `event` to the callback of the handle, if any.
+    pub(crate) fn report(&self, event: ProvingEvent) {
+        if let Some(on_event) = &self.on_event {
+            on_event(event);
+        }
+    }
+}
+
+impl From<CancellationToken> for ProvingHandle {
+    fn from(cancellation: CancellationToken) -> Self {
+        Self { cancellation, on_event: None }
+    }
+}
+
+impl fmt::Debug for ProvingHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProvingHandle")
+            .field("cancellation", &self.cancellation)
+            .field("on_event", &self.on_event.is_some())
+            .finish()
+    }
+}
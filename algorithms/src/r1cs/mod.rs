@@ -31,6 +31,9 @@ pub use linear_combination::*;
 mod namespace;
 pub use namespace::*;
 
+mod witness;
+pub use witness::*;
+
 #[cfg(feature = "test")]
 mod optional_vec;
 #[cfg(feature = "test")]
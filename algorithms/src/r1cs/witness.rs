@@ -0,0 +1,176 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::r1cs::{
+    ConstraintSynthesizer,
+    ConstraintSystem,
+    Index,
+    LinearCombination,
+    Variable,
+    errors::SynthesisError,
+};
+use snarkvm_fields::Field;
+use snarkvm_utilities::{FromBytes, ToBytes, error, serialize::*};
+
+/// The assignment to the variables of a circuit, which is produced by synthesizing the circuit
+/// separately from proving, so that it can be serialized and proven elsewhere.
+///
+/// A witness synthesizes its recorded assignment together with placeholders for the constraints
+/// of its circuit. It can therefore only be proven with the proving key of the circuit that
+/// it was generated from, and fails to synthesize in setup mode.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<F: Field> {
+    /// The assignment to the public variables, starting with the `one` variable.
+    public_variables: Vec<F>,
+    /// The assignment to the private variables.
+    private_variables: Vec<F>,
+    /// The number of constraints of the circuit.
+    num_constraints: u64,
+}
+
+impl<F: Field> Witness<F> {
+    /// Synthesizes `circuit`, and returns the assignment to its variables.
+    pub fn generate<C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self, SynthesisError> {
+        let mut cs = WitnessRecorder::default();
+        circuit.generate_constraints(&mut cs)?;
+        Ok(cs.witness)
+    }
+
+    /// Returns the assignment to the public variables, starting with the `one` variable,
+    /// which is the public input with which to verify a proof for the witness.
+    pub fn public_inputs(&self) -> &[F] {
+        &self.public_variables
+    }
+
+    /// Returns the assignment to the private variables.
+    pub fn private_variables(&self) -> &[F] {
+        &self.private_variables
+    }
+
+    /// Returns the number of constraints of the circuit.
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints as usize
+    }
+}
+
+impl<F: Field> ConstraintSynthesizer<F> for Witness<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        if cs.is_in_setup_mode() {
+            return Err(anyhow::anyhow!("A witness cannot be synthesized in setup mode").into());
+        }
+        // The `one` variable is allocated by the constraint system.
+        for (i, value) in self.public_variables.iter().enumerate().skip(1) {
+            cs.alloc_input(|| format!("public_{i}"), || Ok(*value))?;
+        }
+        for (i, value) in self.private_variables.iter().enumerate() {
+            cs.alloc(|| format!("private_{i}"), || Ok(*value))?;
+        }
+        for i in 0..self.num_constraints {
+            cs.enforce(|| format!("constraint_{i}"), |lc| lc, |lc| lc, |lc| lc);
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> ToBytes for Witness<F> {
+    fn write_le<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.serialize_compressed(writer).map_err(|_| error("could not serialize Witness"))
+    }
+}
+
+impl<F: Field> FromBytes for Witness<F> {
+    fn read_le<R: Read>(reader: R) -> io::Result<Self> {
+        Self::deserialize_compressed(reader).map_err(|_| error("could not deserialize Witness"))
+    }
+}
+
+/// A constraint system that records the assignment to the variables of a circuit, and counts its constraints.
+struct WitnessRecorder<F: Field> {
+    witness: Witness<F>,
+}
+
+impl<F: Field> Default for WitnessRecorder<F> {
+    fn default() -> Self {
+        Self {
+            witness: Witness { public_variables: vec![F::one()], private_variables: Vec::new(), num_constraints: 0 },
+        }
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for WitnessRecorder<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.witness.private_variables.len();
+        self.witness.private_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Private(index)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.witness.public_variables.len();
+        self.witness.public_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Public(index)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.witness.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.witness.num_constraints as usize
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.witness.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.witness.private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{Result, ensure};
+use std::sync::Mutex;
+
+#[cfg(not(feature = "serial"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A scheduler that runs many independent proving jobs on a single shared thread pool.
+///
+/// Each job runs on a thread of the pool, and the parallel work within the job, such as its FFTs and MSMs,
+/// is split into tasks on the same pool. Threads that are idle in one job therefore steal the tasks of the
+/// other jobs, instead of every job competing for the machine with a thread pool of its own.
+pub struct ProvingScheduler {
+    /// The thread pool on which the jobs are run.
+    #[cfg(not(feature = "serial"))]
+    pool: rayon::ThreadPool,
+    /// The maximum number of jobs that are run at once, which bounds the memory used by the scheduler.
+    max_concurrent_jobs: usize,
+}
+
+impl ProvingScheduler {
+    /// Initializes a scheduler with a pool of `num_threads` threads, or of all available threads if `None`,
+    /// which runs at most `max_concurrent_jobs` jobs at once.
+    pub fn new(num_threads: Option<usize>, max_concurrent_jobs: usize) -> Result<Self> {
+        ensure!(max_concurrent_jobs > 0, "The scheduler must run at least one job at a time");
+        #[cfg(not(feature = "serial"))]
+        {
+            let num_threads = num_threads.unwrap_or_else(snarkvm_utilities::max_available_threads).max(1);
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build()?;
+            Ok(Self { pool, max_concurrent_jobs })
+        }
+        #[cfg(feature = "serial")]
+        {
+            let _ = num_threads;
+            Ok(Self { max_concurrent_jobs })
+        }
+    }
+
+    /// Returns the number of threads in the pool of the scheduler.
+    pub fn num_threads(&self) -> usize {
+        #[cfg(not(feature = "serial"))]
+        {
+            self.pool.current_num_threads()
+        }
+        #[cfg(feature = "serial")]
+        {
+            1
+        }
+    }
+
+    /// Returns the maximum number of jobs that are run at once.
+    pub fn max_concurrent_jobs(&self) -> usize {
+        self.max_concurrent_jobs
+    }
+
+    /// Runs `job(i)` for every `i` in `0..num_jobs`, and returns the outputs in order.
+    ///
+    /// The jobs are started in order, and a new job is started as soon as a running job completes.
+    pub fn execute<T: Send, F: Fn(usize) -> T + Sync>(&self, num_jobs: usize, job: F) -> Vec<T> {
+        let outputs = (0..num_jobs).map(|_| Mutex::new(None)).collect::<Vec<_>>();
+
+        #[cfg(not(feature = "serial"))]
+        {
+            let next_job = AtomicUsize::new(0);
+            self.pool.scope(|scope| {
+                for _ in 0..self.max_concurrent_jobs.min(num_jobs) {
+                    scope.spawn(|_| {
+                        loop {
+                            let index = next_job.fetch_add(1, Ordering::Relaxed);
+                            if index >= num_jobs {
+                                break;
+                            }
+                            let output = job(index);
+                            *outputs[index].lock().unwrap() = Some(output);
+                        }
+                    });
+                }
+            });
+        }
+        #[cfg(feature = "serial")]
+        for (index, output) in outputs.iter().enumerate() {
+            *output.lock().unwrap() = Some(job(index));
+        }
+
+        outputs.into_iter().map(|output| output.into_inner().unwrap().expect("Every job is run exactly once")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execute_returns_outputs_in_order() {
+        for max_concurrent_jobs in [1, 3, 16] {
+            let scheduler = ProvingScheduler::new(Some(4), max_concurrent_jobs).unwrap();
+            assert_eq!(scheduler.max_concurrent_jobs(), max_concurrent_jobs);
+            let outputs = scheduler.execute(10, |i| i * i);
+            assert_eq!(outputs, (0..10).map(|i| i * i).collect::<Vec<_>>());
+            assert!(scheduler.execute(0, |i| i).is_empty());
+        }
+        assert!(ProvingScheduler::new(None, 0).is_err());
+    }
+}
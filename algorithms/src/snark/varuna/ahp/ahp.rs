@@ -16,6 +16,7 @@
 use crate::{
     fft::{
         EvaluationDomain,
+        PrecomputedDomain,
         domain::{FFTPrecomputation, IFFTPrecomputation},
     },
     polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
@@ -49,6 +50,7 @@ pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> Stri
     label
 }
 
+#[cfg_attr(not(feature = "prover"), allow(dead_code))]
 pub(crate) struct NonZeroDomains<F: PrimeField> {
     pub(crate) max_non_zero_domain: Option<EvaluationDomain<F>>,
     pub(crate) domain_a: EvaluationDomain<F>,
@@ -156,11 +158,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         ]
         .into_iter()
         .max()?;
-        let largest_mul_domain = EvaluationDomain::new(largest_domain_size)?;
-
-        let fft_precomputation = largest_mul_domain.precompute_fft();
-        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
-        Some((fft_precomputation, ifft_precomputation))
+        // Reuse the roots of unity of earlier circuits with the same domain size.
+        let largest_mul_domain = PrecomputedDomain::cached(largest_domain_size)?;
+        Some((largest_mul_domain.fft_precomputation().clone(), largest_mul_domain.ifft_precomputation().clone()))
     }
 
     /// Construct the linear combinations that are checked by the AHP.
@@ -0,0 +1,118 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::snark::varuna::{Circuit, Matrix, SNARKMode};
+use snarkvm_fields::PrimeField;
+
+use std::io::{self, Write};
+
+/// The layout of the variables of an indexed circuit, which index the columns of its constraint matrices.
+///
+/// Columns `0..num_public_inputs` hold the constant `one` at column 0, followed by the public inputs,
+/// and the zero padding that extends them to a power of two. Columns `num_public_inputs..num_variables`
+/// hold the private variables.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PublicInputLayout {
+    /// The number of public inputs after padding, including the constant `one`.
+    pub num_public_inputs: usize,
+    /// The number of public and private variables.
+    pub num_variables: usize,
+}
+
+impl PublicInputLayout {
+    /// Returns the column of the constant `one`.
+    pub const fn one_column(&self) -> usize {
+        0
+    }
+
+    /// Returns the columns of the public inputs, including the constant `one` and the padding.
+    pub const fn public_columns(&self) -> std::ops::Range<usize> {
+        0..self.num_public_inputs
+    }
+
+    /// Returns the columns of the private variables.
+    pub const fn private_columns(&self) -> std::ops::Range<usize> {
+        self.num_public_inputs..self.num_variables
+    }
+}
+
+/// The constraint matrices of an indexed circuit, in sparse `(row, column, value)` triplet format.
+///
+/// The matrices include the padding and, in hiding mode, the randomizing constraints added by the indexer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintMatrices<F: PrimeField> {
+    /// The layout of the columns of the matrices.
+    pub layout: PublicInputLayout,
+    /// The number of constraints, which is the number of rows of the matrices.
+    pub num_constraints: usize,
+    /// The non-zero entries of the A matrix.
+    pub a: Vec<(usize, usize, F)>,
+    /// The non-zero entries of the B matrix.
+    pub b: Vec<(usize, usize, F)>,
+    /// The non-zero entries of the C matrix.
+    pub c: Vec<(usize, usize, F)>,
+}
+
+impl<F: PrimeField> ConstraintMatrices<F> {
+    /// Returns the triplets of the given matrix, ordered by row and then by column.
+    fn triplets(matrix: &Matrix<F>) -> Vec<(usize, usize, F)> {
+        let mut triplets = Vec::with_capacity(super::num_non_zero(matrix));
+        for (row, entries) in matrix.iter().enumerate() {
+            let start = triplets.len();
+            triplets.extend(entries.iter().map(|(value, col)| (row, *col, *value)));
+            triplets[start..].sort_unstable_by_key(|(_, col, _)| *col);
+        }
+        triplets
+    }
+
+    /// Writes the matrix with the given label (`a`, `b`, or `c`) in the Matrix Market coordinate format,
+    /// with 1-based indices, and with values as decimal integers modulo the field characteristic.
+    pub fn write_matrix_market<W: Write>(&self, label: &str, mut writer: W) -> io::Result<()> {
+        let triplets = match label {
+            "a" => &self.a,
+            "b" => &self.b,
+            "c" => &self.c,
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Unknown matrix '{label}'"))),
+        };
+        writeln!(writer, "%%MatrixMarket matrix coordinate integer general")?;
+        writeln!(writer, "% The first {} columns are the public inputs.", self.layout.num_public_inputs)?;
+        writeln!(writer, "{} {} {}", self.num_constraints, self.layout.num_variables, triplets.len())?;
+        for (row, col, value) in triplets {
+            writeln!(writer, "{} {} {value}", row + 1, col + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField, SM: SNARKMode> Circuit<F, SM> {
+    /// Returns the layout of the variables of the circuit.
+    pub fn public_input_layout(&self) -> PublicInputLayout {
+        PublicInputLayout {
+            num_public_inputs: self.index_info.num_public_inputs,
+            num_variables: self.index_info.num_public_and_private_variables,
+        }
+    }
+
+    /// Returns the constraint matrices of the circuit, in sparse triplet format.
+    pub fn constraint_matrices(&self) -> ConstraintMatrices<F> {
+        ConstraintMatrices {
+            layout: self.public_input_layout(),
+            num_constraints: self.index_info.num_constraints,
+            a: ConstraintMatrices::triplets(&self.a),
+            b: ConstraintMatrices::triplets(&self.b),
+            c: ConstraintMatrices::triplets(&self.c),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::EvaluationDomain,
+    r1cs::{ConstraintSynthesizer, SynthesisError},
+    snark::varuna::{SNARKMode, ahp::AHPForR1CS, indexer::CircuitInfo},
+};
+use snarkvm_fields::PrimeField;
+
+use anyhow::Result;
+
+/// The degrees and domain sizes that are required to prove a set of circuits, which determine
+/// the parameters with which to trim the universal SRS.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DegreeInfo {
+    /// The maximum degree of the polynomials produced by the indexer and prover.
+    pub max_degree: usize,
+    /// The size of the largest constraint domain.
+    pub max_constraint_domain_size: usize,
+    /// The size of the largest variable domain.
+    pub max_variable_domain_size: usize,
+    /// The size of the largest domain of non-zero entries of a constraint matrix.
+    pub max_non_zero_domain_size: usize,
+    /// The size of the largest domain over which the prover computes FFTs.
+    pub max_fft_size: usize,
+    /// The strict degree bounds enforced in the AHP, in increasing order.
+    pub degree_bounds: Vec<usize>,
+    /// The sizes of the domains over which the index polynomials are evaluated, in increasing order.
+    pub lagrange_sizes: Vec<usize>,
+    /// The degree of the random polynomials used to hide the prover's polynomials.
+    pub hiding_bound: usize,
+}
+
+impl DegreeInfo {
+    /// Returns the degree information for the given circuit, without computing its index polynomials.
+    pub fn from_circuit<F: PrimeField, SM: SNARKMode, C: ConstraintSynthesizer<F>>(circuit: &C) -> Result<Self> {
+        Self::from_circuit_info::<F, SM>(&AHPForR1CS::<F, SM>::index_info(circuit)?)
+    }
+
+    /// Returns the degree information for the circuit described by `info`.
+    pub fn from_circuit_info<F: PrimeField, SM: SNARKMode>(info: &CircuitInfo) -> Result<Self> {
+        let domain_size = |num_elements| {
+            EvaluationDomain::<F>::compute_size_of_domain(num_elements).ok_or(SynthesisError::PolyTooLarge)
+        };
+        let constraint_domain_size = domain_size(info.num_constraints)?;
+        let variable_domain_size = domain_size(info.num_public_and_private_variables)?;
+        let non_zero_domain_sizes =
+            [domain_size(info.num_non_zero_a)?, domain_size(info.num_non_zero_b)?, domain_size(info.num_non_zero_c)?];
+        let max_non_zero_domain_size = non_zero_domain_sizes.into_iter().max().unwrap_or_default();
+
+        let mut degree_bounds = AHPForR1CS::<F, SM>::get_degree_bounds(info)?.to_vec();
+        degree_bounds.sort_unstable();
+        degree_bounds.dedup();
+        let mut lagrange_sizes = non_zero_domain_sizes.to_vec();
+        lagrange_sizes.sort_unstable();
+        lagrange_sizes.dedup();
+
+        Ok(Self {
+            max_degree: info.max_degree::<F, SM>()?,
+            max_constraint_domain_size: constraint_domain_size,
+            max_variable_domain_size: variable_domain_size,
+            max_non_zero_domain_size,
+            // This should correspond with the largest domain in `AHPForR1CS::fft_precomputation`.
+            max_fft_size: 2 * constraint_domain_size.max(variable_domain_size).max(max_non_zero_domain_size),
+            degree_bounds,
+            lagrange_sizes,
+            // Varuna only needs degree 2 random polynomials.
+            hiding_bound: 1,
+        })
+    }
+
+    /// Returns the degree information that supports both the circuits of `self` and of `other`.
+    pub fn union(mut self, other: &Self) -> Self {
+        self.max_degree = self.max_degree.max(other.max_degree);
+        self.max_constraint_domain_size = self.max_constraint_domain_size.max(other.max_constraint_domain_size);
+        self.max_variable_domain_size = self.max_variable_domain_size.max(other.max_variable_domain_size);
+        self.max_non_zero_domain_size = self.max_non_zero_domain_size.max(other.max_non_zero_domain_size);
+        self.max_fft_size = self.max_fft_size.max(other.max_fft_size);
+        self.degree_bounds.extend_from_slice(&other.degree_bounds);
+        self.degree_bounds.sort_unstable();
+        self.degree_bounds.dedup();
+        self.lagrange_sizes.extend_from_slice(&other.lagrange_sizes);
+        self.lagrange_sizes.sort_unstable();
+        self.lagrange_sizes.dedup();
+        self.hiding_bound = self.hiding_bound.max(other.hiding_bound);
+        self
+    }
+}
@@ -45,6 +45,17 @@ use super::Matrix;
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Generate the index polynomials for this constraint system.
     pub fn index<C: ConstraintSynthesizer<F>>(c: &C) -> Result<Circuit<F, SM>> {
+        let (a, b, c, index_info) = Self::index_matrices(c)?;
+        Self::index_from_matrices(a, b, c, index_info)
+    }
+
+    /// Generate the index polynomials from the padded constraint matrices of a constraint system.
+    pub(crate) fn index_from_matrices(
+        a: Matrix<F>,
+        b: Matrix<F>,
+        c: Matrix<F>,
+        index_info: CircuitInfo,
+    ) -> Result<Circuit<F, SM>> {
         let IndexerState {
             constraint_domain,
             variable_domain,
@@ -63,7 +74,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
             index_info,
             id,
-        } = Self::index_helper(c).map_err(|e| anyhow!("{e:?}"))?;
+        } = Self::index_state(a, b, c, index_info).map_err(|e| anyhow!("{e:?}"))?;
 
         let fft_precomp_time = start_timer!(|| format!("Precomputing roots of unity {id}"));
 
@@ -122,11 +133,21 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         ids.flat_map(move |id| matrices.iter().flat_map(move |matrix| Self::index_polynomial_labels_single(matrix, id)))
     }
 
-    /// Generate the indexed circuit evaluations for this constraint system.
-    /// Used by both the Prover and Verifier
-    pub(crate) fn index_helper<C: ConstraintSynthesizer<F>>(c: &C) -> Result<IndexerState<F>> {
-        let index_time = start_timer!(|| "AHP::Index");
+    /// Returns the information about the constraint system, without computing the index polynomials.
+    pub fn index_info<C: ConstraintSynthesizer<F>>(c: &C) -> Result<CircuitInfo> {
+        Self::index_matrices(c).map(|(_, _, _, index_info)| index_info)
+    }
 
+    /// Returns the identifier of the constraint system, without computing the index polynomials.
+    pub fn circuit_id<C: ConstraintSynthesizer<F>>(c: &C) -> Result<CircuitId> {
+        let (a, b, c, index_info) = Self::index_matrices(c)?;
+        Ok(Circuit::<F, SM>::hash(&index_info, &a, &b, &c)?)
+    }
+
+    /// Generate the padded constraint matrices for this constraint system, along with information about them.
+    pub(crate) fn index_matrices<C: ConstraintSynthesizer<F>>(
+        c: &C,
+    ) -> Result<(Matrix<F>, Matrix<F>, Matrix<F>, CircuitInfo)> {
         let constraint_time = start_timer!(|| "Generating constraints");
         let mut ics = IndexerConstraintSystem::new();
         c.generate_constraints(&mut ics)?;
@@ -176,6 +197,26 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             num_non_zero_c,
         };
 
+        Ok((a, b, c, index_info))
+    }
+
+    /// Generate the indexed circuit evaluations for this constraint system.
+    /// Used by both the Prover and Verifier
+    pub(crate) fn index_helper<C: ConstraintSynthesizer<F>>(c: &C) -> Result<IndexerState<F>> {
+        let (a, b, c, index_info) = Self::index_matrices(c)?;
+        Self::index_state(a, b, c, index_info)
+    }
+
+    /// Generate the indexed circuit evaluations from the padded constraint matrices of a constraint system.
+    fn index_state(a: Matrix<F>, b: Matrix<F>, c: Matrix<F>, index_info: CircuitInfo) -> Result<IndexerState<F>> {
+        let index_time = start_timer!(|| "AHP::Index");
+
+        let num_constraints = index_info.num_constraints;
+        let num_variables = index_info.num_public_and_private_variables;
+        let num_padded_public_variables = index_info.num_public_inputs;
+        let (num_non_zero_a, num_non_zero_b, num_non_zero_c) =
+            (index_info.num_non_zero_a, index_info.num_non_zero_b, index_info.num_non_zero_c);
+
         let constraint_domain = EvaluationDomain::new(num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
         let variable_domain = EvaluationDomain::new(num_variables).ok_or(SynthesisError::PolyTooLarge)?;
         let input_domain = EvaluationDomain::new(num_padded_public_variables).ok_or(SynthesisError::PolyTooLarge)?;
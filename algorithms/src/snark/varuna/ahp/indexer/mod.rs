@@ -21,9 +21,15 @@ pub(crate) use circuit::*;
 mod circuit_info;
 pub(crate) use circuit_info::*;
 
+mod constraint_matrices;
+pub use constraint_matrices::*;
+
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
+mod degree_info;
+pub use degree_info::*;
+
 mod indexer;
 
 /// Represents a matrix.
@@ -247,6 +247,7 @@ impl<F: PrimeField> MatrixArithmetization<F> {
 }
 
 /// Compute the transpose of a sparse matrix
+#[cfg(feature = "prover")]
 pub(crate) fn transpose<F: PrimeField>(
     matrix: &Matrix<F>,
     variable_domain: &EvaluationDomain<F>,
@@ -25,6 +25,7 @@ pub(crate) struct ConstraintSystem<F: Field> {
 }
 
 impl<F: Field> ConstraintSystem<F> {
+    #[cfg(feature = "prover")]
     pub(crate) fn new() -> Self {
         Self {
             public_variables: vec![F::one()],
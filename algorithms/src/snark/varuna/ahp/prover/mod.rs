@@ -21,10 +21,17 @@ pub(crate) use constraint_system::*;
 mod message;
 pub(crate) use message::*;
 
+#[cfg(feature = "prover")]
 mod oracles;
+#[cfg(feature = "prover")]
 pub(crate) use oracles::*;
 
+mod polynomial_info;
+
+#[cfg(feature = "prover")]
 mod round_functions;
 
+#[cfg(feature = "prover")]
 mod state;
+#[cfg(feature = "prover")]
 use state::*;
@@ -0,0 +1,115 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::EvaluationDomain,
+    polycommit::sonic_pc::{PolynomialInfo, PolynomialLabel},
+    snark::varuna::{
+        CircuitId,
+        SNARKMode,
+        ahp::{AHPForR1CS, indexer::CircuitInfo},
+        witness_label,
+    },
+};
+use snarkvm_fields::PrimeField;
+
+use std::collections::BTreeMap;
+
+/// The number and degree bounds of the oracles sent by the prover in each round.
+///
+/// These are kept apart from the round functions, since the verifier needs them without the prover.
+impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
+    /// Output the number of oracles sent by the prover in the first round.
+    pub fn num_first_round_oracles(total_batch_size: usize) -> usize {
+        total_batch_size + (SM::ZK as usize)
+    }
+
+    /// Output the degree bounds of oracles in the first round.
+    pub fn first_round_polynomial_info<'a>(
+        circuits: impl Iterator<Item = (&'a CircuitId, &'a usize)>,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        let mut polynomials = circuits
+            .flat_map(|(&circuit_id, &batch_size)| {
+                (0..batch_size)
+                    .flat_map(move |i| [PolynomialInfo::new(witness_label(circuit_id, "w", i), None, Self::zk_bound())])
+            })
+            .collect::<Vec<_>>();
+        if SM::ZK {
+            polynomials.push(PolynomialInfo::new("mask_poly".to_string(), None, None));
+        }
+        polynomials.into_iter().map(|info| (info.label().into(), info)).collect()
+    }
+
+    /// Output the number of oracles sent by the prover in the second round.
+    pub const fn num_second_round_oracles() -> usize {
+        1
+    }
+
+    /// Output the degree bounds of oracles in the second round.
+    pub fn second_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        [PolynomialInfo::new("h_0".into(), None, None)].into_iter().map(|info| (info.label().into(), info)).collect()
+    }
+
+    /// Output the number of oracles sent by the prover in the third round.
+    pub const fn num_third_round_oracles() -> usize {
+        2
+    }
+
+    /// Output the degree bounds of oracles in the first round.
+    pub fn third_round_polynomial_info(variable_domain_size: usize) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        [
+            PolynomialInfo::new("g_1".into(), Some(variable_domain_size - 2), Self::zk_bound()),
+            PolynomialInfo::new("h_1".into(), None, None),
+        ]
+        .into_iter()
+        .map(|info| (info.label().into(), info))
+        .collect()
+    }
+
+    /// Output the number of oracles sent by the prover in the fourth round.
+    pub const fn num_fourth_round_oracles(circuits: usize) -> usize {
+        circuits * 3
+    }
+
+    /// Output the degree bounds of oracles in the fourth round.
+    pub fn fourth_round_polynomial_info<'a>(
+        circuits: impl Iterator<Item = (CircuitId, &'a CircuitInfo)>,
+    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        circuits
+            .flat_map(|(circuit_id, info)| {
+                let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
+                let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
+                let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
+                [
+                    PolynomialInfo::new(witness_label(circuit_id, "g_a", 0), Some(non_zero_a_size - 2), None),
+                    PolynomialInfo::new(witness_label(circuit_id, "g_b", 0), Some(non_zero_b_size - 2), None),
+                    PolynomialInfo::new(witness_label(circuit_id, "g_c", 0), Some(non_zero_c_size - 2), None),
+                ]
+                .into_iter()
+                .map(|info| (info.label().into(), info))
+            })
+            .collect()
+    }
+
+    /// Output the number of oracles sent by the prover in this round.
+    pub const fn num_fifth_round_oracles() -> usize {
+        1
+    }
+
+    /// Output the degree bounds of oracles in the last round.
+    pub fn fifth_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
+        [("h_2".into(), PolynomialInfo::new("h_2".into(), None, None))].into()
+    }
+}
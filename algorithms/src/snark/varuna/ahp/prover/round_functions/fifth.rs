@@ -13,11 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
-
 use crate::{
     fft::DensePolynomial,
-    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         SNARKMode,
         ahp::{AHPError, AHPForR1CS, verifier},
@@ -34,11 +32,6 @@ use rand_core::RngCore;
 use rayon::prelude::*;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
-    /// Output the number of oracles sent by the prover in this round.
-    pub const fn num_fifth_round_oracles() -> usize {
-        1
-    }
-
     /// Output the fifth round message and the next state.
     pub fn prover_fifth_round<R: RngCore>(
         verifier_message: verifier::FourthMessage<F>,
@@ -69,9 +62,4 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         end_timer!(round_time);
         Ok(oracles)
     }
-
-    /// Output the degree bounds of oracles in the last round.
-    pub fn fifth_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        [("h_2".into(), PolynomialInfo::new("h_2".into(), None, None))].into()
-    }
 }
@@ -15,10 +15,9 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, SparsePolynomial},
-    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         Circuit,
-        CircuitId,
         SNARKMode,
         ahp::{AHPError, AHPForR1CS},
         prover,
@@ -36,34 +35,14 @@ use std::collections::BTreeMap;
 use rayon::prelude::*;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
-    /// Output the number of oracles sent by the prover in the first round.
-    pub fn num_first_round_oracles(total_batch_size: usize) -> usize {
-        total_batch_size + (SM::ZK as usize)
-    }
-
-    /// Output the degree bounds of oracles in the first round.
-    pub fn first_round_polynomial_info<'a>(
-        circuits: impl Iterator<Item = (&'a CircuitId, &'a usize)>,
-    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        let mut polynomials = circuits
-            .flat_map(|(&circuit_id, &batch_size)| {
-                (0..batch_size)
-                    .flat_map(move |i| [PolynomialInfo::new(witness_label(circuit_id, "w", i), None, Self::zk_bound())])
-            })
-            .collect::<Vec<_>>();
-        if SM::ZK {
-            polynomials.push(PolynomialInfo::new("mask_poly".to_string(), None, None));
-        }
-        polynomials.into_iter().map(|info| (info.label().into(), info)).collect()
-    }
-
     /// Output the first round message and the next state.
     pub fn prover_first_round<'a, R: RngCore>(
         mut state: prover::State<'a, F, SM>,
         rng: &mut R,
     ) -> Result<prover::State<'a, F, SM>, AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
-        let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
+        let mut job_pool =
+            snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances).in_sequence(state.low_memory);
         for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
             let batch_size = circuit_state.batch_size;
 
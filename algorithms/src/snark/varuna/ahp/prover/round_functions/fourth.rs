@@ -21,10 +21,10 @@ use crate::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         polynomial::PolyMultiplier,
     },
-    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         SNARKMode,
-        ahp::{AHPError, AHPForR1CS, CircuitId, indexer::CircuitInfo, verifier},
+        ahp::{AHPError, AHPForR1CS, CircuitId, verifier},
         matrices::MatrixEvals,
         prover,
         selectors::apply_randomized_selector,
@@ -50,31 +50,6 @@ type Bpoly<F> = LabeledPolynomial<F>;
 type Gpoly<F> = LabeledPolynomial<F>;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
-    /// Output the number of oracles sent by the prover in the fourth round.
-    pub const fn num_fourth_round_oracles(circuits: usize) -> usize {
-        circuits * 3
-    }
-
-    /// Output the degree bounds of oracles in the fourth round.
-    pub fn fourth_round_polynomial_info<'a>(
-        circuits: impl Iterator<Item = (CircuitId, &'a CircuitInfo)>,
-    ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        circuits
-            .flat_map(|(circuit_id, info)| {
-                let non_zero_a_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_a).unwrap();
-                let non_zero_b_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_b).unwrap();
-                let non_zero_c_size = EvaluationDomain::<F>::compute_size_of_domain(info.num_non_zero_c).unwrap();
-                [
-                    PolynomialInfo::new(witness_label(circuit_id, "g_a", 0), Some(non_zero_a_size - 2), None),
-                    PolynomialInfo::new(witness_label(circuit_id, "g_b", 0), Some(non_zero_b_size - 2), None),
-                    PolynomialInfo::new(witness_label(circuit_id, "g_c", 0), Some(non_zero_c_size - 2), None),
-                ]
-                .into_iter()
-                .map(|info| (info.label().into(), info))
-            })
-            .collect()
-    }
-
     /// Output the fourth round message and the next state.
     pub fn prover_fourth_round<'a, R: RngCore>(
         second_message: &verifier::SecondMessage<F>,
@@ -87,7 +62,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let verifier::SecondMessage { alpha, .. } = second_message;
         let verifier::ThirdMessage { beta } = third_message;
 
-        let mut pool = ExecutionPool::with_capacity(3 * state.circuit_specific_states.len());
+        let mut pool =
+            ExecutionPool::with_capacity(3 * state.circuit_specific_states.len()).in_sequence(state.low_memory);
 
         let max_non_zero_domain_size = state.max_non_zero_domain;
         let matrix_labels = ["a", "b", "c"];
@@ -17,7 +17,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain, polynomial::PolyMultiplier},
-    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         Circuit,
         CircuitId,
@@ -37,16 +37,6 @@ use snarkvm_utilities::{ExecutionPool, cfg_into_iter, cfg_iter_mut, cfg_reduce};
 use rayon::prelude::*;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
-    /// Output the number of oracles sent by the prover in the second round.
-    pub const fn num_second_round_oracles() -> usize {
-        1
-    }
-
-    /// Output the degree bounds of oracles in the second round.
-    pub fn second_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        [PolynomialInfo::new("h_0".into(), None, None)].into_iter().map(|info| (info.label().into(), info)).collect()
-    }
-
     /// Output the second round message and the next state.
     pub fn prover_second_round<'a, R: RngCore>(
         verifier_message: &verifier::FirstMessage<F>,
@@ -77,7 +67,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         state: &mut prover::State<F, SM>,
         batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
     ) -> Result<DensePolynomial<F>> {
-        let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
+        let mut job_pool =
+            ExecutionPool::with_capacity(state.circuit_specific_states.len()).in_sequence(state.low_memory);
         let max_constraint_domain = state.max_constraint_domain;
 
         for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
@@ -21,7 +21,7 @@ use crate::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         polynomial::PolyMultiplier,
     },
-    polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
+    polycommit::sonic_pc::LabeledPolynomial,
     snark::varuna::{
         AHPError,
         Matrix,
@@ -50,22 +50,6 @@ struct LinevalInstance<F: PrimeField> {
 }
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
-    /// Output the number of oracles sent by the prover in the third round.
-    pub const fn num_third_round_oracles() -> usize {
-        2
-    }
-
-    /// Output the degree bounds of oracles in the first round.
-    pub fn third_round_polynomial_info(variable_domain_size: usize) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
-        [
-            PolynomialInfo::new("g_1".into(), Some(variable_domain_size - 2), Self::zk_bound()),
-            PolynomialInfo::new("h_1".into(), None, None),
-        ]
-        .into_iter()
-        .map(|info| (info.label().into(), info))
-        .collect()
-    }
-
     /// Output the third round message and the next state.
     pub fn prover_third_round<'a, R: RngCore>(
         verifier_message: &verifier::FirstMessage<F>,
@@ -139,7 +123,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
         // Compute lineval sumcheck witnesses
-        let mut job_pool = ExecutionPool::with_capacity(total_instances * 3);
+        let mut job_pool = ExecutionPool::with_capacity(total_instances * 3).in_sequence(state.low_memory);
         for ((((circuit, circuit_specific_state), batch_combiner), assignments_i), matrix_transposes_i) in state
             .circuit_specific_states
             .iter_mut()
@@ -252,7 +236,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         state: &mut prover::State<F, SM>,
     ) -> Result<BTreeMap<CircuitId, BTreeMap<String, Matrix<F>>>> {
         let transpose_time = start_timer!(|| "Transpose of matrices");
-        let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len() * 3);
+        let mut job_pool =
+            ExecutionPool::with_capacity(state.circuit_specific_states.len() * 3).in_sequence(state.low_memory);
         state.circuit_specific_states.iter().for_each(|(circuit, circuit_specific_state)| {
             let variable_domain = &circuit_specific_state.variable_domain;
             let input_domain = &circuit_specific_state.input_domain;
@@ -85,6 +85,8 @@ pub struct State<'a, F: PrimeField, SM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// Whether the independent jobs of each round are computed one after another, to reduce peak memory.
+    pub(in crate::snark) low_memory: bool,
 }
 
 /// The public inputs for a single instance.
@@ -187,6 +189,7 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            low_memory: false,
         })
     }
 
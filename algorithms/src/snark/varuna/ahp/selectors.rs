@@ -14,14 +14,20 @@
 // limitations under the License.
 
 use super::verifier::QueryPoints;
-use crate::fft::{DensePolynomial, EvaluationDomain};
-use snarkvm_fields::{PrimeField, batch_inversion};
-use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, serialize::*};
+use crate::fft::EvaluationDomain;
+use snarkvm_fields::{PrimeField, batch_inverse};
+use snarkvm_utilities::{cfg_into_iter, serialize::*};
 
-use anyhow::{Result, ensure};
 use itertools::Itertools;
 use std::collections::{BTreeMap, HashSet};
 
+#[cfg(feature = "prover")]
+use crate::fft::DensePolynomial;
+#[cfg(feature = "prover")]
+use anyhow::{Result, ensure};
+#[cfg(feature = "prover")]
+use snarkvm_utilities::cfg_iter_mut;
+
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
@@ -58,7 +64,7 @@ pub(crate) fn precompute_selectors<F: PrimeField>(
             })
         })
         .multiunzip::<(Vec<F>, Vec<F>, Vec<(u64, u64, F)>)>();
-    batch_inversion(&mut denominators);
+    batch_inverse(&mut denominators);
     cfg_into_iter!(numerators).zip_eq(denominators).zip_eq(keys).map(|((num, denom), key)| (key, num * denom)).collect()
 }
 
@@ -67,6 +73,7 @@ pub(crate) fn precompute_selectors<F: PrimeField>(
 /// These can be combined into a single check by taking a random linear combination
 /// of the polynomials and multiplying them by an appropriate selector polynomial.
 /// This function applies the random combiner and selector in an optimized way
+#[cfg(feature = "prover")]
 pub(crate) fn apply_randomized_selector<F: PrimeField>(
     poly: &mut DensePolynomial<F>,
     combiner: F,
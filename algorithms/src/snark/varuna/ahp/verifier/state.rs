@@ -79,4 +79,39 @@ impl<F: PrimeField, MM: SNARKMode> State<F, MM> {
             .flat_map(|s| [s.non_zero_a_domain, s.non_zero_b_domain, s.non_zero_c_domain])
             .collect()
     }
+
+    /// Returns the challenges that the verifier has sampled so far, by name, in the order in which they were sampled.
+    /// This includes the combiners that are fixed to one rather than sampled.
+    pub(crate) fn challenges(&self) -> Vec<(String, F)> {
+        let mut challenges = Vec::new();
+        if let Some(first) = &self.first_round_message {
+            for (circuit_id, combiners) in &first.batch_combiners {
+                challenges.push((format!("circuit_combiner_{circuit_id}"), combiners.circuit_combiner));
+                for (j, combiner) in combiners.instance_combiners.iter().enumerate() {
+                    challenges.push((format!("instance_combiner_{circuit_id}_{j}"), *combiner));
+                }
+            }
+        }
+        if let Some(second) = &self.second_round_message {
+            challenges.extend([
+                ("alpha".to_string(), second.alpha),
+                ("eta_b".to_string(), second.eta_b),
+                ("eta_c".to_string(), second.eta_c),
+            ]);
+        }
+        if let Some(third) = &self.third_round_message {
+            challenges.push(("beta".to_string(), third.beta));
+        }
+        if let Some(fourth) = &self.fourth_round_message {
+            for (name, deltas) in
+                [("delta_a", &fourth.delta_a), ("delta_b", &fourth.delta_b), ("delta_c", &fourth.delta_c)]
+            {
+                challenges.extend(deltas.iter().enumerate().map(|(i, delta)| (format!("{name}_{i}"), *delta)));
+            }
+        }
+        if let Some(gamma) = self.gamma {
+            challenges.push(("gamma".to_string(), gamma));
+        }
+        challenges
+    }
 }
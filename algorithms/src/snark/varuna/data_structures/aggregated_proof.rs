@@ -0,0 +1,69 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{polycommit::sonic_pc, snark::varuna::Proof};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    FromBytes,
+    ToBytes,
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+};
+
+/// An aggregation of many Varuna proofs, whose evaluation proofs are checked with a single product of pairings.
+///
+/// The aggregation is not succinct: it contains every proof apart from its evaluation proof,
+/// and both its size and the time to verify it are linear in the number of proofs.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct AggregatedProof<E: PairingEngine> {
+    /// The aggregated proofs, without their evaluation proofs.
+    pub proofs: Vec<Proof<E>>,
+    /// An aggregation of the evaluation proofs of all of the proofs.
+    pub pc_proof: sonic_pc::AggregatedBatchProof<E>,
+}
+
+impl<E: PairingEngine> AggregatedProof<E> {
+    /// Construct a new aggregated proof.
+    pub fn new(proofs: Vec<Proof<E>>, pc_proof: sonic_pc::AggregatedBatchProof<E>) -> Self {
+        Self { proofs, pc_proof }
+    }
+
+    /// Returns the number of aggregated proofs.
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns `true` if no proofs are aggregated.
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    pub fn is_hiding(&self) -> bool {
+        self.pc_proof.is_hiding()
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregatedProof<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize AggregatedProof"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregatedProof<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize AggregatedProof"))
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::CircuitVerifyingKey;
+use crate::{polycommit::sonic_pc, snark::varuna::ahp::indexer::*};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    FromBytes,
+    ToBytes,
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+};
+
+use anyhow::{Result, anyhow, ensure};
+use std::sync::{Arc, OnceLock};
+
+/// A verifying key whose commitments are held in their compressed encoding.
+///
+/// The commitments are only decompressed when they are requested, either individually with
+/// [`CompressedCircuitVerifyingKey::commitment`], or all at once with [`CompressedCircuitVerifyingKey::materialize`],
+/// which caches the decompressed key until [`CompressedCircuitVerifyingKey::dematerialize`] is called.
+/// The byte representation is identical to that of [`CircuitVerifyingKey`].
+#[derive(Debug, Clone)]
+pub struct CompressedCircuitVerifyingKey<E: PairingEngine> {
+    /// Stores information about the size of the circuit, as well as its defined field.
+    pub circuit_info: CircuitInfo,
+    /// The compressed commitments to the indexed polynomials, concatenated.
+    compressed_commitments: Vec<u8>,
+    /// The number of commitments to the indexed polynomials.
+    num_commitments: usize,
+    pub id: CircuitId,
+    /// The decompressed verifying key, if it has been materialized.
+    materialized: OnceLock<Arc<CircuitVerifyingKey<E>>>,
+}
+
+impl<E: PairingEngine> CompressedCircuitVerifyingKey<E> {
+    /// Returns the size in bytes of a compressed commitment.
+    fn commitment_size() -> usize {
+        sonic_pc::Commitment::<E>::empty().compressed_size()
+    }
+
+    /// Compresses the given verifying key.
+    pub fn compress(verifying_key: &CircuitVerifyingKey<E>) -> Result<Self> {
+        let mut compressed_commitments =
+            Vec::with_capacity(verifying_key.circuit_commitments.len() * Self::commitment_size());
+        for commitment in &verifying_key.circuit_commitments {
+            commitment.serialize_compressed(&mut compressed_commitments)?;
+        }
+        Ok(Self {
+            circuit_info: verifying_key.circuit_info,
+            compressed_commitments,
+            num_commitments: verifying_key.circuit_commitments.len(),
+            id: verifying_key.id,
+            materialized: OnceLock::new(),
+        })
+    }
+
+    /// Returns the number of commitments to the indexed polynomials.
+    pub fn num_commitments(&self) -> usize {
+        self.num_commitments
+    }
+
+    /// Decompresses and returns the commitment at the given index.
+    pub fn commitment(&self, index: usize) -> Result<sonic_pc::Commitment<E>> {
+        if let Some(verifying_key) = self.materialized.get() {
+            return verifying_key.circuit_commitments.get(index).copied().ok_or_else(|| anyhow!("Invalid index"));
+        }
+        ensure!(
+            index < self.num_commitments,
+            "Index {index} is out of bounds for {} commitments",
+            self.num_commitments
+        );
+        let size = Self::commitment_size();
+        Ok(sonic_pc::Commitment::deserialize_compressed(&self.compressed_commitments[index * size..][..size])?)
+    }
+
+    /// Returns the decompressed verifying key, decompressing it on the first call.
+    pub fn materialize(&self) -> Result<Arc<CircuitVerifyingKey<E>>> {
+        if let Some(verifying_key) = self.materialized.get() {
+            return Ok(verifying_key.clone());
+        }
+        let circuit_commitments = (0..self.num_commitments).map(|i| self.commitment(i)).collect::<Result<Vec<_>>>()?;
+        let verifying_key =
+            Arc::new(CircuitVerifyingKey { circuit_info: self.circuit_info, circuit_commitments, id: self.id });
+        Ok(self.materialized.get_or_init(|| verifying_key).clone())
+    }
+
+    /// Returns `true` if the decompressed verifying key is cached.
+    pub fn is_materialized(&self) -> bool {
+        self.materialized.get().is_some()
+    }
+
+    /// Drops the cached decompressed verifying key, if any.
+    pub fn dematerialize(&mut self) {
+        self.materialized.take();
+    }
+}
+
+impl<E: PairingEngine> CircuitVerifyingKey<E> {
+    /// Returns the compressed form of `self`.
+    pub fn compress(&self) -> Result<CompressedCircuitVerifyingKey<E>> {
+        CompressedCircuitVerifyingKey::compress(self)
+    }
+}
+
+impl<E: PairingEngine> PartialEq for CompressedCircuitVerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.circuit_info == other.circuit_info
+            && self.id == other.id
+            && self.compressed_commitments == other.compressed_commitments
+    }
+}
+
+impl<E: PairingEngine> Eq for CompressedCircuitVerifyingKey<E> {}
+
+impl<E: PairingEngine> FromBytes for CompressedCircuitVerifyingKey<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let circuit_info = CircuitInfo::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize CompressedCircuitVerifyingKey"))?;
+        let num_commitments = u64::read_le(&mut reader)? as usize;
+        // Read the commitments without decompressing them, reading incrementally to avoid trusting the length.
+        let mut compressed_commitments = Vec::new();
+        let mut commitment = vec![0u8; Self::commitment_size()];
+        for _ in 0..num_commitments {
+            reader.read_exact(&mut commitment)?;
+            compressed_commitments.extend_from_slice(&commitment);
+        }
+        let id = CircuitId::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize CompressedCircuitVerifyingKey"))?;
+        Ok(Self { circuit_info, compressed_commitments, num_commitments, id, materialized: OnceLock::new() })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for CompressedCircuitVerifyingKey<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.circuit_info
+            .serialize_compressed(&mut writer)
+            .map_err(|_| error("could not serialize CompressedCircuitVerifyingKey"))?;
+        (self.num_commitments as u64).write_le(&mut writer)?;
+        writer.write_all(&self.compressed_commitments)?;
+        self.id
+            .serialize_compressed(&mut writer)
+            .map_err(|_| error("could not serialize CompressedCircuitVerifyingKey"))
+    }
+}
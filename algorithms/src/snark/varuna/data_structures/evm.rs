@@ -0,0 +1,173 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    fft::EvaluationDomain,
+    snark::varuna::{AHPForR1CS, CircuitVerifyingKey, SNARKMode},
+    srs::LightVerifier,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::ToBytes;
+
+use anyhow::{Result, anyhow, ensure};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// A point in G1, with its coordinates encoded as in [`to_evm_word`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EvmG1Point {
+    pub x: String,
+    pub y: String,
+}
+
+/// A point in G2, with the coefficients of its coordinates in the quadratic extension encoded as in [`to_evm_word`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EvmG2Point {
+    pub x: [String; 2],
+    pub y: [String; 2],
+}
+
+/// An evaluation domain, with its field elements encoded as in [`to_evm_word`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EvmDomain {
+    /// The size of the domain.
+    pub size: u64,
+    /// The generator of the domain.
+    pub generator: String,
+    /// The inverse of the size of the domain.
+    pub size_inverse: String,
+}
+
+/// The constants that a verifier on the EVM requires to check proofs for a circuit.
+///
+/// It combines the circuit verifying key with the domains that the verifier derives from it, and with the
+/// group elements of the universal verifier. It can be serialized to JSON, to generate contracts from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EvmVerifyingKey {
+    /// The ID of the circuit.
+    pub circuit_id: String,
+    /// Whether proofs for the circuit are hiding.
+    pub is_hiding: bool,
+    /// The number of public inputs after padding.
+    pub num_public_inputs: usize,
+    /// The input, constraint, variable, and non-zero domains of the circuit.
+    pub domains: BTreeMap<String, EvmDomain>,
+    /// The commitments to the index polynomials, by label.
+    pub commitments: BTreeMap<String, EvmG1Point>,
+    /// The generator of G1.
+    pub g: EvmG1Point,
+    /// The generator of G1 that is used for making a commitment hiding.
+    pub gamma_g: EvmG1Point,
+    /// The generator of G2.
+    pub h: EvmG2Point,
+    /// \beta times the generator of G2.
+    pub beta_h: EvmG2Point,
+    /// The powers of \beta in G2 that enforce each degree bound.
+    pub negative_powers_of_beta_h: BTreeMap<usize, EvmG2Point>,
+}
+
+impl EvmVerifyingKey {
+    /// Returns the EVM constants for the given circuit verifying key and light verifier.
+    pub fn new<E: PairingEngine, SM: SNARKMode>(
+        verifying_key: &CircuitVerifyingKey<E>,
+        verifier: &LightVerifier<E>,
+    ) -> Result<Self> {
+        let info = &verifying_key.circuit_info;
+        let mut domains = BTreeMap::new();
+        for (name, num_elements) in [
+            ("input", info.num_public_inputs),
+            ("constraint", info.num_constraints),
+            ("variable", info.num_public_and_private_variables),
+            ("non_zero_a", info.num_non_zero_a),
+            ("non_zero_b", info.num_non_zero_b),
+            ("non_zero_c", info.num_non_zero_c),
+        ] {
+            let domain = EvaluationDomain::<E::Fr>::new(num_elements)
+                .ok_or_else(|| anyhow!("Failed to create the {name} domain"))?;
+            let domain = EvmDomain {
+                size: domain.size,
+                generator: to_evm_word(&domain.group_gen)?,
+                size_inverse: to_evm_word(&domain.size_inv)?,
+            };
+            domains.insert(name.to_string(), domain);
+        }
+
+        let labels = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(std::iter::once(&verifying_key.id)).into_keys();
+        ensure!(labels.len() == verifying_key.circuit_commitments.len(), "Mismatched number of index commitments");
+        let commitments = labels
+            .zip(&verifying_key.circuit_commitments)
+            .map(|(label, commitment)| Ok((label, to_evm_g1::<E>(&commitment.0)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            circuit_id: verifying_key.id.to_string(),
+            is_hiding: SM::ZK,
+            num_public_inputs: info.num_public_inputs,
+            domains,
+            commitments,
+            g: to_evm_g1::<E>(&verifier.g)?,
+            gamma_g: to_evm_g1::<E>(&verifier.gamma_g)?,
+            h: to_evm_g2::<E>(&verifier.h)?,
+            beta_h: to_evm_g2::<E>(&verifier.beta_h)?,
+            negative_powers_of_beta_h: verifier
+                .negative_powers_of_beta_h
+                .iter()
+                .map(|(degree_bound, power)| Ok((*degree_bound, to_evm_g2::<E>(power)?)))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// A reference transcript of the verification of a proof, for testing verifiers on the EVM.
+///
+/// It records the Fiat-Shamir challenges of the verifier in the order in which they are squeezed,
+/// the evaluations that the proof claims at the queried points, and the outcome of the verification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EvmTranscript {
+    /// The challenges of the verifier, by name, in the order in which they are squeezed.
+    pub challenges: Vec<(String, String)>,
+    /// The claimed evaluations, as `(label, point, value)`.
+    pub evaluations: Vec<(String, String, String)>,
+    /// Whether the proof is accepted.
+    pub is_valid: bool,
+}
+
+/// Returns the big-endian hexadecimal encoding of `element`, prefixed with `0x`,
+/// and padded to the number of bytes required to represent the modulus.
+pub fn to_evm_word<F: PrimeField>(element: &F) -> Result<String> {
+    let mut bytes = element.to_bigint().to_bytes_le()?;
+    bytes.truncate((F::size_in_bits() + 7) / 8);
+    bytes.reverse();
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+/// Returns the coordinates of the affine point `point` in G1.
+fn to_evm_g1<E: PairingEngine>(point: &E::G1Affine) -> Result<EvmG1Point> {
+    // The coordinates are followed by the flag for the point at infinity.
+    let [x, y, _] = <E::G1Affine as ToConstraintField<E::Fq>>::to_field_elements(point)?[..] else {
+        return Err(anyhow!("Expected two coordinates for a point in G1"));
+    };
+    Ok(EvmG1Point { x: to_evm_word(&x)?, y: to_evm_word(&y)? })
+}
+
+/// Returns the coordinates of the affine point `point` in G2.
+fn to_evm_g2<E: PairingEngine>(point: &E::G2Affine) -> Result<EvmG2Point> {
+    // The coordinates are followed by the flag for the point at infinity.
+    let [x_0, x_1, y_0, y_1, _] = <E::G2Affine as ToConstraintField<E::Fq>>::to_field_elements(point)?[..] else {
+        return Err(anyhow!("Expected two coordinates in a quadratic extension for a point in G2"));
+    };
+    Ok(EvmG2Point { x: [to_evm_word(&x_0)?, to_evm_word(&x_1)?], y: [to_evm_word(&y_0)?, to_evm_word(&y_1)?] })
+}
@@ -13,18 +13,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+/// The Varuna aggregated proof.
+pub(super) mod aggregated_proof;
+pub use aggregated_proof::*;
+
 /// The Varuna certificate.
 pub(super) mod certificate;
 pub use certificate::*;
 
 /// The Varuna circuit proving key.
+#[cfg(feature = "prover")]
 pub(super) mod circuit_proving_key;
+#[cfg(feature = "prover")]
 pub use circuit_proving_key::*;
 
 /// The Varuna circuit verifying key.
 pub(super) mod circuit_verifying_key;
 pub use circuit_verifying_key::*;
 
+/// The Varuna compressed circuit verifying key.
+pub(super) mod compressed_verifying_key;
+pub use compressed_verifying_key::*;
+
+/// The EVM representation of Varuna verifying keys and verification transcripts.
+pub(super) mod evm;
+pub use evm::*;
+
 /// The Varuna zkSNARK proof.
 pub(super) mod proof;
 pub use proof::*;
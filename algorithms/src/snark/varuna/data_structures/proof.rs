@@ -161,6 +161,7 @@ impl<F: PrimeField> Evaluations<F> {
 }
 
 impl<F: PrimeField> Evaluations<F> {
+    #[cfg(feature = "prover")]
     pub(crate) fn from_map(
         map: &std::collections::BTreeMap<String, F>,
         batch_sizes: BTreeMap<CircuitId, usize>,
@@ -300,6 +301,20 @@ impl<E: PairingEngine> Proof<E> {
         }
         Ok(())
     }
+
+    /// Writes the proof, with its group elements in the given point-compression mode.
+    ///
+    /// `ToBytes` always writes compressed points, which suits network transport. Uncompressed
+    /// points are roughly twice as large, but skip the cost of decompression when read.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> io::Result<()> {
+        self.serialize_with_mode(&mut writer, compress).map_err(|_| error("could not serialize Proof"))
+    }
+
+    /// Reads a proof written by `write_le_with_mode` with the same point-compression mode.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress) -> io::Result<Self> {
+        Self::deserialize_with_mode(&mut reader, compress, Validate::Yes)
+            .map_err(|_| error("could not deserialize Proof"))
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
@@ -512,6 +527,15 @@ mod test {
                     let de = Proof::deserialize_with_mode(&serialized[..], compress, validate).unwrap();
                     assert_eq!(proof, de);
                 }
+
+                let mut compressed = Vec::new();
+                proof.write_le_with_mode(&mut compressed, Compress::Yes).unwrap();
+                let mut uncompressed = Vec::new();
+                proof.write_le_with_mode(&mut uncompressed, Compress::No).unwrap();
+                assert_eq!(compressed, proof.to_bytes_le().unwrap());
+                assert!(compressed.len() < uncompressed.len());
+                assert_eq!(proof, Proof::read_le_with_mode(&compressed[..], Compress::Yes).unwrap());
+                assert_eq!(proof, Proof::read_le_with_mode(&uncompressed[..], Compress::No).unwrap());
             }
         }
     }
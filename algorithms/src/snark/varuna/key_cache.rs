@@ -0,0 +1,167 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    crypto_hash::sha256::sha256,
+    r1cs::ConstraintSynthesizer,
+    snark::varuna::{
+        AHPForR1CS,
+        CircuitId,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        SNARKMode,
+        UniversalSRS,
+        VarunaSNARK,
+        ahp::indexer::Circuit,
+    },
+    traits::AlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{FromBytes, ToBytes, serialize::*};
+
+use anyhow::Result;
+use std::{
+    fs,
+    io::{BufReader, BufWriter, Read, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// The version of the on-disk key format.
+/// This must be incremented whenever the serialization or derivation of the circuit keys changes,
+/// so that keys written by a previous version are re-derived instead of being loaded.
+pub const KEY_CACHE_VERSION: u16 = 2;
+
+/// An on-disk cache of circuit proving and verifying keys, keyed by the circuit ID.
+///
+/// Each entry is prefixed with a header that records the cache version, the SNARK mode, the circuit ID,
+/// the universal SRS from which the keys were trimmed, and the SHA-256 digest of the serialized keys.
+/// An entry whose header does not match, whose keys do not match the digest, or which fails to deserialize,
+/// is treated as stale and overwritten with freshly derived keys.
+pub struct KeyCache<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> {
+    /// The directory in which the keys are stored.
+    directory: PathBuf,
+    _phantom: PhantomData<(E, FS, SM)>,
+}
+
+impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> KeyCache<E, FS, SM> {
+    /// Opens the key cache in the given directory, creating the directory if it does not exist.
+    pub fn open<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        fs::create_dir_all(directory.as_ref())?;
+        Ok(Self { directory: directory.as_ref().to_path_buf(), _phantom: PhantomData })
+    }
+
+    /// Returns the directory in which the keys are stored.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the keys for the given circuit, loading them from the cache if a valid entry exists,
+    /// and otherwise deriving them from the universal SRS and storing them in the cache.
+    pub fn get_or_setup<C: ConstraintSynthesizer<E::Fr>>(
+        &self,
+        universal_srs: &UniversalSRS<E>,
+        circuit: &C,
+    ) -> Result<(CircuitProvingKey<E, SM>, CircuitVerifyingKey<E>)> {
+        let (a, b, c, index_info) = AHPForR1CS::<E::Fr, SM>::index_matrices(circuit)?;
+        let id = Circuit::<E::Fr, SM>::hash(&index_info, &a, &b, &c)?;
+        if let Some(keys) = self.get(universal_srs, &id)? {
+            return Ok(keys);
+        }
+
+        // Index the matrices from which the circuit ID was derived, so that the circuit is only synthesized once.
+        let indexed_circuit = AHPForR1CS::<E::Fr, SM>::index_from_matrices(a, b, c, index_info)?;
+        let universal_prover = universal_srs.to_universal_prover()?;
+        let (proving_key, verifying_key) =
+            VarunaSNARK::<E, FS, SM>::indexed_circuit_setup(universal_srs, &universal_prover, indexed_circuit)?;
+        self.insert(universal_srs, &proving_key)?;
+        Ok((proving_key, verifying_key))
+    }
+
+    /// Returns the cached keys for the circuit with the given ID, or `None` if there is no valid entry.
+    pub fn get(
+        &self,
+        universal_srs: &UniversalSRS<E>,
+        id: &CircuitId,
+    ) -> Result<Option<(CircuitProvingKey<E, SM>, CircuitVerifyingKey<E>)>> {
+        let path = self.path(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let mut reader = BufReader::new(fs::File::open(&path)?);
+        let expected_header = Self::header(universal_srs, id)?;
+        let mut header = vec![0u8; expected_header.len()];
+        if reader.read_exact(&mut header).is_err() || header != expected_header {
+            return Ok(None);
+        }
+        let mut digest = [0u8; 32];
+        let mut body = Vec::new();
+        if reader.read_exact(&mut digest).is_err() || reader.read_to_end(&mut body).is_err() || sha256(&body) != digest
+        {
+            return Ok(None);
+        }
+        match CircuitProvingKey::<E, SM>::read_le(&body[..]) {
+            Ok(proving_key) if &proving_key.circuit.id == id => {
+                let verifying_key = proving_key.circuit_verifying_key.clone();
+                Ok(Some((proving_key, verifying_key)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Stores the given proving key, which contains its verifying key, in the cache.
+    pub fn insert(&self, universal_srs: &UniversalSRS<E>, proving_key: &CircuitProvingKey<E, SM>) -> Result<()> {
+        let id = &proving_key.circuit.id;
+        let path = self.path(id);
+        let body = proving_key.to_bytes_le()?;
+        // Write to a temporary file first, so that a concurrent reader never observes a partial entry.
+        let temporary_path = path.with_extension(format!("tmp-{}", std::process::id()));
+        {
+            let mut writer = BufWriter::new(fs::File::create(&temporary_path)?);
+            writer.write_all(&Self::header(universal_srs, id)?)?;
+            writer.write_all(&sha256(&body))?;
+            writer.write_all(&body)?;
+            writer.flush()?;
+        }
+        fs::rename(temporary_path, path)?;
+        Ok(())
+    }
+
+    /// Removes the cached keys for the circuit with the given ID, if they exist.
+    pub fn remove(&self, id: &CircuitId) -> Result<()> {
+        let path = self.path(id);
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the entry for the circuit with the given ID.
+    fn path(&self, id: &CircuitId) -> PathBuf {
+        let mode = if SM::ZK { "hiding" } else { "nonhiding" };
+        self.directory.join(format!("{id}.{mode}.key"))
+    }
+
+    /// Returns the header of the entry for the circuit with the given ID.
+    fn header(universal_srs: &UniversalSRS<E>, id: &CircuitId) -> Result<Vec<u8>> {
+        let mut header = Vec::new();
+        KEY_CACHE_VERSION.write_le(&mut header)?;
+        SM::ZK.write_le(&mut header)?;
+        id.0.write_le(&mut header)?;
+        universal_srs.beta_h().serialize_compressed(&mut header)?;
+        Ok(header)
+    }
+}
@@ -24,6 +24,12 @@ pub use data_structures::*;
 mod varuna;
 pub use varuna::*;
 
+/// Implements an on-disk cache of circuit keys.
+#[cfg(feature = "prover")]
+mod key_cache;
+#[cfg(feature = "prover")]
+pub use key_cache::*;
+
 /// Specifies the SNARK mode.
 mod mode;
 pub use mode::*;
@@ -29,6 +29,10 @@ impl SNARKMode for VarunaHidingMode {
 }
 
 /// This mode produces a non-hiding SNARK proof.
+///
+/// The prover skips the randomizing variables and the masking polynomials of the hiding mode, which makes
+/// proving faster, but the proof may reveal information about the witness. It should only be used for
+/// statements whose witness is public, such as puzzle solutions or public computations.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct VarunaNonHidingMode;
 
@@ -303,24 +303,40 @@ mod varuna {
 #[cfg(any(test, feature = "test"))]
 mod varuna_hiding {
     use crate::{
+        CancellationToken,
+        ProvingEvent,
+        ProvingHandle,
+        ProvingScheduler,
+        SNARKError,
         crypto_hash::PoseidonSponge,
+        r1cs::Witness,
         snark::varuna::{
+            AggregatedProof,
             CircuitVerifyingKey,
+            CompressedCircuitVerifyingKey,
+            EvmVerifyingKey,
+            KeyCache,
             VarunaHidingMode,
             VarunaSNARK,
-            ahp::AHPForR1CS,
+            ahp::{AHPForR1CS, indexer::DegreeInfo},
             test_circuit::TestCircuit,
         },
         traits::{AlgebraicSponge, SNARK},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::{One, Zero};
     use snarkvm_utilities::{
         FromBytes,
         ToBytes,
         rand::{TestRng, Uniform},
     };
 
-    use std::str::FromStr;
+    use rand::Rng;
+    use std::{
+        collections::BTreeMap,
+        str::FromStr,
+        sync::{Arc, Mutex},
+    };
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -460,6 +476,541 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 1);
     }
 
+    #[test]
+    fn prove_and_verify_with_degree_info() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let circuits = [(100, 25), (25, 100)]
+            .map(|(num_constraints, num_variables)| TestCircuit::gen_rand(2, num_constraints, num_variables, rng));
+        let degree_info = circuits
+            .iter()
+            .map(|(circuit, _)| DegreeInfo::from_circuit::<Fr, VarunaHidingMode, _>(circuit).unwrap())
+            .reduce(|acc, info| acc.union(&info))
+            .unwrap();
+        let indexed = AHPForR1CS::<Fr, VarunaHidingMode>::index(&circuits[0].0).unwrap();
+        assert!(degree_info.max_degree >= indexed.max_degree().unwrap());
+
+        // Ensure an SRS of exactly the derived degree suffices for every circuit.
+        let universal_srs = VarunaInst::universal_setup(degree_info.max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        for (circuit, public_inputs) in circuits {
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+        }
+    }
+
+    #[test]
+    fn aggregate_proofs() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+
+        let mut instances = Vec::new();
+        for (num_constraints, num_variables) in [(100, 25), (25, 100), (100, 25)] {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            instances.push((index_vk, vec![public_inputs], proof));
+        }
+        let keys_to_inputs = instances
+            .iter()
+            .map(|(index_vk, inputs, _)| BTreeMap::from([(index_vk, inputs.as_slice())]))
+            .collect::<Vec<_>>();
+        let proofs = keys_to_inputs.iter().zip(&instances).map(|(map, (_, _, proof))| (map, proof)).collect::<Vec<_>>();
+
+        let aggregated = VarunaInst::aggregate(&fs_parameters, &proofs).unwrap();
+        assert_eq!(aggregated.len(), 3);
+        let aggregated = AggregatedProof::from_bytes_le(&aggregated.to_bytes_le().unwrap()).unwrap();
+        let inputs = keys_to_inputs.iter().collect::<Vec<_>>();
+        assert!(VarunaInst::verify_aggregated(universal_verifier, &fs_parameters, &inputs, &aggregated).unwrap());
+
+        // Ensure the aggregated proof does not verify for other inputs, or for a subset of the proofs.
+        let mut fake_inputs = instances[1].1.clone();
+        fake_inputs[0][1] = Fr::rand(rng);
+        let fake_keys_to_inputs = BTreeMap::from([(&instances[1].0, fake_inputs.as_slice())]);
+        let inputs = [&keys_to_inputs[0], &fake_keys_to_inputs, &keys_to_inputs[2]];
+        assert!(!VarunaInst::verify_aggregated(universal_verifier, &fs_parameters, &inputs, &aggregated).unwrap());
+        let inputs = [&keys_to_inputs[0], &keys_to_inputs[1]];
+        assert!(VarunaInst::verify_aggregated(universal_verifier, &fs_parameters, &inputs, &aggregated).is_err());
+    }
+
+    #[test]
+    fn key_cache() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+
+        let directory = std::env::temp_dir().join(format!("snarkvm-key-cache-{}", std::process::id()));
+        let cache = KeyCache::<Bls12_377, FS, VarunaHidingMode>::open(&directory).unwrap();
+        let id = AHPForR1CS::<Fr, VarunaHidingMode>::circuit_id(&circuit).unwrap();
+        assert!(cache.get(&universal_srs, &id).unwrap().is_none());
+
+        // Ensure the keys are stored on the first setup, and loaded thereafter.
+        let (index_pk, index_vk) = cache.get_or_setup(&universal_srs, &circuit).unwrap();
+        assert_eq!(index_vk.id, id);
+        let (cached_pk, cached_vk) = cache.get(&universal_srs, &id).unwrap().unwrap();
+        assert_eq!(cached_vk, index_vk);
+        assert_eq!(cached_pk.to_bytes_le().unwrap(), index_pk.to_bytes_le().unwrap());
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &cached_pk, &circuit, rng).unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &cached_vk, public_inputs, &proof).unwrap());
+
+        // Ensure a corrupted entry is re-derived.
+        let path = std::fs::read_dir(&directory).unwrap().next().unwrap().unwrap().path();
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[0] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(cache.get(&universal_srs, &id).unwrap().is_none());
+        assert_eq!(cache.get_or_setup(&universal_srs, &circuit).unwrap().1, index_vk);
+        assert!(cache.get(&universal_srs, &id).unwrap().is_some());
+
+        // Ensure an entry whose keys do not match the digest is re-derived.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(cache.get(&universal_srs, &id).unwrap().is_none());
+        assert_eq!(cache.get_or_setup(&universal_srs, &circuit).unwrap().1, index_vk);
+        assert!(cache.get(&universal_srs, &id).unwrap().is_some());
+
+        cache.remove(&id).unwrap();
+        assert!(cache.get(&universal_srs, &id).unwrap().is_none());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn prove_scheduled() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+
+        let mut instances = Vec::new();
+        for (num_constraints, num_variables) in [(100, 25), (25, 100), (100, 25), (50, 50)] {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2, num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            instances.push((circuit, public_inputs, index_pk, index_vk));
+        }
+        let jobs = instances.iter().map(|(circuit, _, index_pk, _)| (index_pk, circuit)).collect::<Vec<_>>();
+
+        let scheduler = ProvingScheduler::new(Some(4), 2).unwrap();
+        let cancellation = CancellationToken::new();
+        let proofs =
+            VarunaInst::prove_scheduled(&scheduler, universal_prover, &fs_parameters, &jobs, rng, &cancellation);
+        assert_eq!(proofs.len(), instances.len());
+        for (proof, (_, public_inputs, _, index_vk)) in proofs.into_iter().zip(&instances) {
+            let proof = proof.unwrap();
+            assert!(
+                VarunaInst::verify(universal_verifier, &fs_parameters, index_vk, public_inputs.as_slice(), &proof)
+                    .unwrap()
+            );
+        }
+
+        // Ensure every job of a cancelled scheduler fails.
+        cancellation.cancel();
+        let proofs =
+            VarunaInst::prove_scheduled(&scheduler, universal_prover, &fs_parameters, &jobs, rng, &cancellation);
+        assert!(proofs.into_iter().all(|proof| proof.is_err()));
+    }
+
+    #[test]
+    fn prove_with_low_memory() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap().with_low_memory(true);
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_with_seed() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let keys_to_constraints = BTreeMap::from([(&index_pk, std::slice::from_ref(&circuit))]);
+
+        let seed = rng.gen();
+        let prove = |universal_prover, seed| {
+            VarunaInst::prove_batch_with_seed(universal_prover, &fs_parameters, &keys_to_constraints, seed).unwrap()
+        };
+        let proof = prove(universal_prover, seed);
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure the proof only depends on the seed, and not on how the prover schedules its work.
+        let low_memory_prover = &universal_prover.clone().with_low_memory(true);
+        assert_eq!(proof.to_bytes_le().unwrap(), prove(universal_prover, seed).to_bytes_le().unwrap());
+        assert_eq!(proof.to_bytes_le().unwrap(), prove(low_memory_prover, seed).to_bytes_le().unwrap());
+        assert_ne!(proof.to_bytes_le().unwrap(), prove(universal_prover, rng.gen()).to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn verify_many() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, _) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mut inputs_and_proofs = Vec::new();
+        for _ in 0..3 {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            inputs_and_proofs.push((public_inputs, proof));
+        }
+        let instances = inputs_and_proofs.iter().map(|(inputs, proof)| (inputs.as_slice(), proof)).collect::<Vec<_>>();
+        assert!(VarunaInst::verify_many(universal_verifier, &fs_parameters, &index_vk, &instances, rng).unwrap());
+
+        // Ensure a single proof with incorrect inputs fails the whole batch.
+        let mut fake_inputs = inputs_and_proofs[1].0.clone();
+        fake_inputs[1] = Fr::rand(rng);
+        let mut fake_instances = instances.clone();
+        fake_instances[1].0 = &fake_inputs;
+        assert!(!VarunaInst::verify_many(universal_verifier, &fs_parameters, &index_vk, &fake_instances, rng).unwrap());
+        let empty: &[(&[Fr], _)] = &[];
+        assert!(VarunaInst::verify_many(universal_verifier, &fs_parameters, &index_vk, empty, rng).is_err());
+    }
+
+    #[test]
+    fn circuit_patch() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, _) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 50, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Patching with the same constraints reuses every commitment.
+        let (_, patched_vk) = VarunaInst::circuit_patch(&universal_srs, &index_pk, &circuit).unwrap();
+        assert_eq!(patched_vk, index_vk);
+
+        // Patching with different constraints yields the keys of a full setup.
+        for (mul_depth, num_constraints, num_variables) in [(3, 100, 25), (2, 200, 50)] {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+            let (expected_pk, expected_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let (patched_pk, patched_vk) = VarunaInst::circuit_patch(&universal_srs, &index_pk, &circuit).unwrap();
+            assert_eq!(patched_vk, expected_vk);
+            assert_eq!(patched_pk.to_bytes_le().unwrap(), expected_pk.to_bytes_le().unwrap());
+
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &patched_pk, &circuit, rng).unwrap();
+            assert!(
+                VarunaInst::verify(universal_verifier, &fs_parameters, &patched_vk, public_inputs, &proof).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn evm_export() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let light_verifier = universal_srs.to_light_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let evm_vk = EvmVerifyingKey::new::<Bls12_377, VarunaHidingMode>(&index_vk, &light_verifier).unwrap();
+        assert_eq!(evm_vk.circuit_id, index_vk.id.to_string());
+        assert_eq!(evm_vk.commitments.len(), index_vk.circuit_commitments.len());
+        assert_eq!(evm_vk.domains.len(), 6);
+        // Coordinates of BLS12-377 are encoded in 48 bytes.
+        assert_eq!(evm_vk.g.x.len(), 2 + 2 * 48);
+        assert!(serde_json::to_string(&evm_vk).is_ok());
+
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        let transcript =
+            VarunaInst::evm_transcript(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof)
+                .unwrap();
+        assert!(transcript.is_valid);
+        assert_eq!(transcript.challenges.last().unwrap().0, "gamma");
+        assert!(!transcript.evaluations.is_empty());
+
+        // The transcript is a deterministic function of the proof.
+        let again =
+            VarunaInst::evm_transcript(universal_verifier, &fs_parameters, &index_vk, public_inputs.as_slice(), &proof)
+                .unwrap();
+        assert_eq!(transcript, again);
+
+        let mut fake_inputs = public_inputs.clone();
+        fake_inputs[1] = Fr::rand(rng);
+        let transcript =
+            VarunaInst::evm_transcript(universal_verifier, &fs_parameters, &index_vk, fake_inputs.as_slice(), &proof)
+                .unwrap();
+        assert!(!transcript.is_valid);
+    }
+
+    #[test]
+    fn constraint_matrices() {
+        let rng = &mut TestRng::default();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let matrices = index_pk.circuit.constraint_matrices();
+        let info = index_pk.circuit.index_info;
+        assert_eq!(matrices.num_constraints, info.num_constraints);
+        assert_eq!(matrices.layout.num_public_inputs, info.num_public_inputs);
+        assert_eq!(matrices.layout.num_variables, info.num_public_and_private_variables);
+        assert_eq!(matrices.a.len(), info.num_non_zero_a);
+        assert_eq!(matrices.b.len(), info.num_non_zero_b);
+        assert_eq!(matrices.c.len(), info.num_non_zero_c);
+
+        // Lay out the full assignment: the public inputs with their padding, the private variables,
+        // and the randomizing variables that the indexer adds in hiding mode.
+        let witness = Witness::generate(&circuit).unwrap();
+        assert_eq!(witness.public_inputs(), public_inputs.as_slice());
+        let mut assignment = witness.public_inputs().to_vec();
+        assignment.resize(matrices.layout.num_public_inputs, Fr::zero());
+        assignment.extend_from_slice(witness.private_variables());
+        assignment.extend([Fr::one(); 3]);
+        assert_eq!(assignment.len(), matrices.layout.num_variables);
+        assert_eq!(matrices.layout.one_column(), 0);
+
+        // The exported matrices are satisfied by the assignment.
+        let product = |triplets: &[(usize, usize, Fr)]| {
+            let mut result = vec![Fr::zero(); matrices.num_constraints];
+            for (row, col, value) in triplets {
+                result[*row] += *value * assignment[*col];
+            }
+            result
+        };
+        let (a, b, c) = (product(&matrices.a), product(&matrices.b), product(&matrices.c));
+        assert!(a.iter().zip(&b).zip(&c).all(|((a, b), c)| *a * b == *c));
+
+        let mut bytes = Vec::new();
+        matrices.write_matrix_market("b", &mut bytes).unwrap();
+        let exported = String::from_utf8(bytes).unwrap();
+        assert_eq!(exported.lines().count(), 3 + matrices.b.len());
+        assert!(matrices.write_matrix_market("d", std::io::sink()).is_err());
+    }
+
+    #[test]
+    fn compressed_verifying_key() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // The compressed key shares the byte representation of the verifying key.
+        let vk_bytes = index_vk.to_bytes_le().unwrap();
+        let compressed = CompressedCircuitVerifyingKey::<Bls12_377>::from_bytes_le(&vk_bytes).unwrap();
+        assert_eq!(compressed, index_vk.compress().unwrap());
+        assert_eq!(compressed.to_bytes_le().unwrap(), vk_bytes);
+        assert_eq!(compressed.circuit_info, index_vk.circuit_info);
+        assert!(!compressed.is_materialized());
+
+        // Individual commitments are decompressed on demand.
+        assert_eq!(compressed.num_commitments(), index_vk.circuit_commitments.len());
+        assert_eq!(compressed.commitment(1).unwrap(), index_vk.circuit_commitments[1]);
+        assert!(compressed.commitment(compressed.num_commitments()).is_err());
+        assert!(!compressed.is_materialized());
+
+        // The materialized key verifies proofs.
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+        let mut compressed = compressed;
+        let vk = compressed.materialize().unwrap();
+        assert!(compressed.is_materialized());
+        assert_eq!(*vk, index_vk);
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &vk, public_inputs, &proof).unwrap());
+        compressed.dematerialize();
+        assert!(!compressed.is_materialized());
+
+        // Invalid commitments are rejected upon decompression.
+        let mut corrupted = vk_bytes.clone();
+        let offset = corrupted.len() - 32 - 1;
+        corrupted[offset] ^= 0x0f;
+        let compressed = CompressedCircuitVerifyingKey::<Bls12_377>::from_bytes_le(&corrupted).unwrap();
+        assert!(compressed.materialize().is_err());
+    }
+
+    #[test]
+    fn prove_with_cancellation() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let keys_to_constraints = BTreeMap::from([(&index_pk, std::slice::from_ref(&circuit))]);
+
+        let cancellation = CancellationToken::new();
+        let proof = VarunaInst::prove_batch_with_cancellation(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            rng,
+            &cancellation,
+        )
+        .unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure a cancelled prover stops.
+        cancellation.cancel();
+        let result = VarunaInst::prove_batch_with_cancellation(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            rng,
+            &cancellation,
+        );
+        assert!(matches!(result.unwrap_err().downcast::<SNARKError>(), Ok(SNARKError::Cancelled)));
+    }
+
+    #[test]
+    fn prove_with_witness() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Generate the witness, and prove it after a roundtrip through its serialization.
+        let witness = Witness::generate(&circuit).unwrap();
+        assert_eq!(witness.public_inputs(), public_inputs.as_slice());
+        let witness = Witness::<Fr>::read_le(&witness.to_bytes_le().unwrap()[..]).unwrap();
+
+        let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &witness, rng).unwrap();
+        assert!(
+            VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, witness.public_inputs(), &proof).unwrap()
+        );
+
+        // Ensure a witness cannot be used to derive keys.
+        assert!(VarunaInst::circuit_setup(&universal_srs, &witness).is_err());
+    }
+
+    #[test]
+    fn prove_with_handle() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let keys_to_constraints = BTreeMap::from([(&index_pk, std::slice::from_ref(&circuit))]);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let handle = ProvingHandle::new().with_callback({
+            let events = events.clone();
+            move |event| events.lock().unwrap().push(event)
+        });
+        let proof =
+            VarunaInst::prove_batch_with_handle(universal_prover, &fs_parameters, &keys_to_constraints, rng, &handle)
+                .unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure every round is reported, and the commitments are counted up to their total.
+        let events = events.lock().unwrap();
+        let rounds = events
+            .iter()
+            .filter_map(|event| match event {
+                ProvingEvent::RoundCompleted { round, num_rounds } => Some((*round, *num_rounds)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rounds, (1..=6).map(|round| (round, 6)).collect::<Vec<_>>());
+        let commitments = events.iter().filter(|event| matches!(event, ProvingEvent::CommitmentsCompleted { .. }));
+        assert_eq!(commitments.clone().count(), 5);
+        assert_eq!(commitments.last().unwrap().percent(), 100.0);
+
+        // Ensure a prover that is cancelled from its callback stops at the next round.
+        let handle = ProvingHandle::new();
+        let handle = handle.clone().with_callback(move |event| {
+            if let ProvingEvent::RoundCompleted { round: 2, .. } = event {
+                handle.cancel();
+            }
+        });
+        let result =
+            VarunaInst::prove_batch_with_handle(universal_prover, &fs_parameters, &keys_to_constraints, rng, &handle);
+        assert!(matches!(result.unwrap_err().downcast::<SNARKError>(), Ok(SNARKError::Cancelled)));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn prove_async() {
+        let rng = &mut TestRng::default();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit, public_inputs) = TestCircuit::gen_rand(2, 100, 25, rng);
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = Arc::new(universal_srs.to_universal_prover().unwrap());
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let index_pk = Arc::new(index_pk);
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let prove = |cancellation| {
+            runtime.block_on(VarunaInst::prove_async(
+                universal_prover.clone(),
+                fs_parameters.clone(),
+                index_pk.clone(),
+                circuit.clone(),
+                TestRng::default(),
+                cancellation,
+            ))
+        };
+        let proof = prove(CancellationToken::new()).unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+
+        // Ensure a cancelled prover does not return a proof.
+        let cancellation = CancellationToken::new();
+        cancellation.cancel();
+        assert!(matches!(prove(cancellation).unwrap_err().downcast::<SNARKError>(), Ok(SNARKError::Cancelled)));
+    }
+
     #[test]
     fn check_indexing() {
         let rng = &mut TestRng::default();
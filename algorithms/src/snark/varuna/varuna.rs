@@ -19,30 +19,36 @@ use crate::{
     SNARK,
     SNARKError,
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{
-        Commitment,
-        CommitterUnionKey,
-        Evaluations,
-        LabeledCommitment,
-        QuerySet,
-        Randomness,
-        SonicKZG10,
-    },
+    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, LinearCombination, QuerySet, SonicKZG10},
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
-        CircuitProvingKey,
+        AggregatedProof,
         CircuitVerifyingKey,
+        EvmTranscript,
         Proof,
         SNARKMode,
         UniversalSRS,
-        ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
-        proof,
+        ahp::{AHPError, AHPForR1CS, CircuitId},
         prover,
+        to_evm_word,
         witness_label,
     },
     srs::UniversalVerifier,
 };
-use rand::RngCore;
+#[cfg(feature = "prover")]
+use crate::{
+    CancellationToken,
+    ProvingEvent,
+    ProvingHandle,
+    ProvingScheduler,
+    polycommit::sonic_pc::{BatchLCProof, BatchProof, CommitterUnionKey, Randomness},
+    snark::varuna::{
+        CircuitProvingKey,
+        ahp::{DegreeInfo, EvaluationsProvider, indexer::Circuit, matrices::MatrixArithmetization},
+        proof,
+    },
+    srs::UniversalProver,
+};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{ToBytes, to_bytes_le};
@@ -50,12 +56,17 @@ use snarkvm_utilities::{ToBytes, to_bytes_le};
 use anyhow::{Result, anyhow, bail, ensure};
 use core::marker::PhantomData;
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap};
 
-use crate::srs::UniversalProver;
+use rand::{CryptoRng, Rng};
+#[cfg(feature = "prover")]
+use rand::{RngCore, SeedableRng};
+#[cfg(feature = "prover")]
+use rand_chacha::ChaChaRng;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
+#[cfg(feature = "prover")]
+use std::{ops::Deref, sync::Arc};
 
 /// The Varuna proof system.
 #[derive(Clone, Debug)]
@@ -63,6 +74,25 @@ pub struct VarunaSNARK<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNAR
     #[doc(hidden)] PhantomData<(E, FS, SM)>,
 );
 
+/// The claims about the committed polynomials of a proof that remain after the AHP verifier.
+struct EvaluationClaims<E: PairingEngine, FS> {
+    /// The linear combinations of the committed polynomials.
+    lc_s: BTreeMap<String, LinearCombination<E::Fr>>,
+    /// The commitments to the polynomials.
+    commitments: Vec<LabeledCommitment<Commitment<E>>>,
+    /// The points at which the linear combinations are queried.
+    query_set: QuerySet<E::Fr>,
+    /// The claimed evaluations of the linear combinations.
+    evaluations: Evaluations<E::Fr>,
+    /// The sponge, in the state in which the evaluation proof is checked.
+    sponge: FS,
+    /// The challenges of the verifier, by name, in the order in which they were sampled.
+    challenges: Vec<(String, E::Fr)>,
+    /// The final challenge of the verifier.
+    #[cfg(debug_assertions)]
+    gamma: Option<E::Fr>,
+}
+
 impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK<E, FS, SM> {
     /// The personalization string for this protocol.
     /// Used to personalize the Fiat-Shamir RNG.
@@ -70,6 +100,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
 
     // TODO: implement optimizations resulting from batching
     //       (e.g. computing a common set of Lagrange powers, FFT precomputations, etc)
+    #[cfg(feature = "prover")]
     pub fn batch_circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &UniversalSRS<E>,
         circuits: &[&C],
@@ -80,266 +111,195 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
 
         let mut circuit_keys = Vec::with_capacity(circuits.len());
         for circuit in circuits {
-            let mut indexed_circuit = AHPForR1CS::<_, SM>::index(*circuit)?;
-            // TODO: Add check that c is in the correct mode.
-            // Ensure the universal SRS supports the circuit size.
-            universal_srs.download_powers_for(0..indexed_circuit.max_degree()?).map_err(|e| {
-                anyhow!("Failed to download powers for degree {}: {e}", indexed_circuit.max_degree().unwrap())
-            })?;
-            let coefficient_support = AHPForR1CS::<E::Fr, SM>::get_degree_bounds(&indexed_circuit.index_info)?;
-
-            // Varuna only needs degree 2 random polynomials.
-            let supported_hiding_bound = 1;
-            let supported_lagrange_sizes = [].into_iter(); // TODO: consider removing lagrange_bases_at_beta_g from CommitterKey
-            let (committer_key, _) = SonicKZG10::<E, FS>::trim(
-                universal_srs,
-                indexed_circuit.max_degree()?,
-                supported_lagrange_sizes,
-                supported_hiding_bound,
-                Some(coefficient_support.as_slice()),
-            )?;
-
-            let ck = CommitterUnionKey::union(std::iter::once(&committer_key));
-
-            let commit_time = start_timer!(|| format!("Commit to index polynomials for {}", indexed_circuit.id));
-            let setup_rng = None::<&mut dyn RngCore>; // We do not randomize the commitments
-
-            let (mut circuit_commitments, commitment_randomnesses): (_, _) = SonicKZG10::<E, FS>::commit(
-                universal_prover,
-                &ck,
-                indexed_circuit.interpolate_matrix_evals()?.map(Into::into),
-                setup_rng,
-            )?;
-            let empty_randomness = Randomness::<E>::empty();
-            ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
-            end_timer!(commit_time);
-
-            circuit_commitments.sort_by(|c1, c2| c1.label().cmp(c2.label()));
-            let circuit_commitments = circuit_commitments.into_iter().map(|c| *c.commitment()).collect();
-            indexed_circuit.prune_row_col_evals();
-            let circuit_verifying_key = CircuitVerifyingKey {
-                circuit_info: indexed_circuit.index_info,
-                circuit_commitments,
-                id: indexed_circuit.id,
-            };
-            let circuit_proving_key = CircuitProvingKey {
-                circuit_verifying_key: circuit_verifying_key.clone(),
-                circuit: Arc::new(indexed_circuit),
-                committer_key: Arc::new(committer_key),
-            };
-            circuit_keys.push((circuit_proving_key, circuit_verifying_key));
+            let indexed_circuit = AHPForR1CS::<_, SM>::index(*circuit)?;
+            circuit_keys.push(Self::indexed_circuit_setup(universal_srs, universal_prover, indexed_circuit)?);
         }
 
         end_timer!(index_time);
         Ok(circuit_keys)
     }
 
-    fn init_sponge<'a>(
-        fs_parameters: &FS::Parameters,
-        inputs_and_batch_sizes: &BTreeMap<CircuitId, (usize, &[Vec<E::Fr>])>,
-        circuit_commitments: impl Iterator<Item = &'a [crate::polycommit::sonic_pc::Commitment<E>]>,
-    ) -> FS {
-        let mut sponge = FS::new_with_parameters(fs_parameters);
-        sponge.absorb_bytes(Self::PROTOCOL_NAME);
-        for (batch_size, inputs) in inputs_and_batch_sizes.values() {
-            sponge.absorb_bytes(&(*batch_size as u64).to_le_bytes());
-            for input in inputs.iter() {
-                sponge.absorb_nonnative_field_elements(input.iter().copied());
-            }
-        }
-        for circuit_specific_commitments in circuit_commitments {
-            sponge.absorb_native_field_elements(circuit_specific_commitments);
-        }
-        sponge
-    }
-
-    fn init_sponge_for_certificate(
-        fs_parameters: &FS::Parameters,
-        verifying_key: &CircuitVerifyingKey<E>,
-    ) -> Result<FS> {
-        let mut sponge = FS::new_with_parameters(fs_parameters);
-        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME]?);
-        sponge.absorb_bytes(&verifying_key.circuit_info.to_bytes_le()?);
-        sponge.absorb_native_field_elements(&verifying_key.circuit_commitments);
-        sponge.absorb_bytes(&verifying_key.id.0);
-        Ok(sponge)
-    }
-
-    fn absorb_labeled_with_sums(
-        comms: &[LabeledCommitment<Commitment<E>>],
-        sums: &[prover::MatrixSums<E::Fr>],
-        sponge: &mut FS,
-    ) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb_with_sums(&commitments, sums, sponge)
-    }
-
-    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb(&commitments, sponge);
-    }
-
-    fn absorb(commitments: &[Commitment<E>], sponge: &mut FS) {
-        let sponge_time = start_timer!(|| "Absorbing commitments");
-        sponge.absorb_native_field_elements(commitments);
-        end_timer!(sponge_time);
-    }
+    /// Returns the keys for a circuit that has already been indexed.
+    #[cfg(feature = "prover")]
+    pub(crate) fn indexed_circuit_setup(
+        universal_srs: &UniversalSRS<E>,
+        universal_prover: &UniversalProver<E>,
+        mut indexed_circuit: Circuit<E::Fr, SM>,
+    ) -> Result<(CircuitProvingKey<E, SM>, CircuitVerifyingKey<E>)> {
+        // TODO: Add check that c is in the correct mode.
+        // Ensure the universal SRS supports the circuit size.
+        universal_srs.download_powers_for(0..indexed_circuit.max_degree()?).map_err(|e| {
+            anyhow!("Failed to download powers for degree {}: {e}", indexed_circuit.max_degree().unwrap())
+        })?;
+        let degree_info = DegreeInfo::from_circuit_info::<E::Fr, SM>(&indexed_circuit.index_info)?;
+
+        let supported_lagrange_sizes = [].into_iter(); // TODO: consider removing lagrange_bases_at_beta_g from CommitterKey
+        let (committer_key, _) = SonicKZG10::<E, FS>::trim(
+            universal_srs,
+            degree_info.max_degree,
+            supported_lagrange_sizes,
+            degree_info.hiding_bound,
+            Some(degree_info.degree_bounds.as_slice()),
+        )?;
 
-    fn absorb_with_sums(commitments: &[Commitment<E>], sums: &[prover::MatrixSums<E::Fr>], sponge: &mut FS) {
-        let sponge_time = start_timer!(|| "Absorbing commitments and message");
-        Self::absorb(commitments, sponge);
-        for sum in sums.iter() {
-            sponge.absorb_nonnative_field_elements([sum.sum_a, sum.sum_b, sum.sum_c]);
-        }
-        end_timer!(sponge_time);
-    }
-}
+        let ck = CommitterUnionKey::union(std::iter::once(&committer_key));
 
-impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: AlgebraicSponge<E::Fq, 2>,
-    SM: SNARKMode,
-{
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, SM>;
-    type ScalarField = E::Fr;
-    type UniversalProver = UniversalProver<E>;
-    type UniversalSRS = UniversalSRS<E>;
-    type UniversalVerifier = UniversalVerifier<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E>;
+        let commit_time = start_timer!(|| format!("Commit to index polynomials for {}", indexed_circuit.id));
+        let setup_rng = None::<&mut dyn RngCore>; // We do not randomize the commitments
 
-    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS> {
-        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
-        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
+        let (mut circuit_commitments, commitment_randomnesses): (_, _) = SonicKZG10::<E, FS>::commit(
+            universal_prover,
+            &ck,
+            indexed_circuit.interpolate_matrix_evals()?.map(Into::into),
+            setup_rng,
+        )?;
+        let empty_randomness = Randomness::<E>::empty();
+        ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
+        end_timer!(commit_time);
+
+        circuit_commitments.sort_by(|c1, c2| c1.label().cmp(c2.label()));
+        let circuit_commitments = circuit_commitments.into_iter().map(|c| *c.commitment()).collect();
+        indexed_circuit.prune_row_col_evals();
+        let circuit_verifying_key = CircuitVerifyingKey {
+            circuit_info: indexed_circuit.index_info,
+            circuit_commitments,
+            id: indexed_circuit.id,
+        };
+        let circuit_proving_key = CircuitProvingKey {
+            circuit_verifying_key: circuit_verifying_key.clone(),
+            circuit: Arc::new(indexed_circuit),
+            committer_key: Arc::new(committer_key),
+        };
+        Ok((circuit_proving_key, circuit_verifying_key))
     }
 
-    /// Generates the circuit proving and verifying keys.
-    /// This is a deterministic algorithm that anyone can rerun.
-    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
-        universal_srs: &Self::UniversalSRS,
+    /// Returns the keys for `circuit`, reusing the commitments of `proving_key` to the index polynomials
+    /// whose evaluations are unchanged, so that only the polynomials affected by a change to the circuit
+    /// are interpolated and committed to. This is intended for circuits that differ from the circuit of
+    /// `proving_key` in a few constants or constraints, and is equivalent to `circuit_setup` otherwise.
+    #[cfg(feature = "prover")]
+    pub fn circuit_patch<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &UniversalSRS<E>,
+        proving_key: &CircuitProvingKey<E, SM>,
         circuit: &C,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
-        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
-        ensure!(circuit_keys.len() == 1);
-        Ok(circuit_keys.pop().unwrap())
-    }
+    ) -> Result<(CircuitProvingKey<E, SM>, CircuitVerifyingKey<E>)> {
+        let patch_time = start_timer!(|| "Varuna::CircuitPatch");
 
-    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
-    fn prove_vk(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+        let mut indexed_circuit = AHPForR1CS::<_, SM>::index(circuit)?;
+        let old_circuit = &proving_key.circuit;
+        let degree_info = DegreeInfo::from_circuit_info::<E::Fr, SM>(&indexed_circuit.index_info)?;
 
-        let circuit_id = std::iter::once(&verifying_key.id);
-        let circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id);
+        // Reuse the committer key if the circuit requires the same degrees.
+        let committer_key = if degree_info == DegreeInfo::from_circuit_info::<E::Fr, SM>(&old_circuit.index_info)? {
+            proving_key.committer_key.clone()
+        } else {
+            universal_srs
+                .download_powers_for(0..degree_info.max_degree)
+                .map_err(|e| anyhow!("Failed to download powers for degree {}: {e}", degree_info.max_degree))?;
+            let (committer_key, _) = SonicKZG10::<E, FS>::trim(
+                universal_srs,
+                degree_info.max_degree,
+                [].into_iter(),
+                degree_info.hiding_bound,
+                Some(degree_info.degree_bounds.as_slice()),
+            )?;
+            Arc::new(committer_key)
+        };
 
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (label, &c) in circuit_poly_info.keys().zip(linear_combination_challenges) {
-            lc.add(c, label.clone());
+        // Collect the previous commitments, which are sorted by label.
+        let old_labels = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(std::iter::once(&old_circuit.id)).into_keys();
+        let old_commitments: BTreeMap<_, _> =
+            old_labels.zip_eq(&proving_key.circuit_verifying_key.circuit_commitments).collect();
+
+        // Interpolate the polynomials of the matrices whose evaluations have changed.
+        let mut commitments = BTreeMap::new();
+        let mut changed_polynomials = Vec::new();
+        for (matrix, new_evals, old_evals) in [
+            ("a", &indexed_circuit.a_arith, &old_circuit.a_arith),
+            ("b", &indexed_circuit.b_arith, &old_circuit.b_arith),
+            ("c", &indexed_circuit.c_arith, &old_circuit.c_arith),
+        ] {
+            let row_changed = new_evals.row != old_evals.row;
+            let col_changed = new_evals.col != old_evals.col;
+            // The `row_col` polynomial is the entry-wise product of `row` and `col`.
+            let changed =
+                [row_changed, col_changed, row_changed || col_changed, new_evals.row_col_val != old_evals.row_col_val];
+
+            let new_labels = AHPForR1CS::<E::Fr, SM>::index_polynomial_labels_single(matrix, &indexed_circuit.id);
+            let old_labels = AHPForR1CS::<E::Fr, SM>::index_polynomial_labels_single(matrix, &old_circuit.id);
+            let mut changed_labels = Vec::new();
+            for ((new_label, old_label), changed) in new_labels.zip_eq(old_labels).zip_eq(changed) {
+                if changed {
+                    changed_labels.push(new_label);
+                } else {
+                    let commitment =
+                        old_commitments.get(&old_label).ok_or(anyhow!("Missing commitment to {old_label}"))?;
+                    commitments.insert(new_label, **commitment);
+                }
+            }
+            if !changed_labels.is_empty() {
+                let arithmetization = MatrixArithmetization::new(&indexed_circuit.id, matrix, new_evals)?;
+                changed_polynomials.extend(
+                    arithmetization
+                        .into_iter()
+                        .filter(|polynomial| changed_labels.iter().any(|label| label == polynomial.label())),
+                );
+            }
         }
 
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
-
-        let empty_randomness = vec![Randomness::<E>::empty(); 12];
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
+        let commit_time = start_timer!(|| format!("Commit to {} index polynomials", changed_polynomials.len()));
+        let universal_prover = &universal_srs.to_universal_prover()?;
+        let ck = CommitterUnionKey::union(std::iter::once(committer_key.as_ref()));
+        let (new_commitments, _) = SonicKZG10::<E, FS>::commit(
             universal_prover,
-            &committer_key,
-            &[lc],
-            proving_key.circuit.interpolate_matrix_evals()?,
-            &empty_randomness,
-            &query_set,
-            &mut sponge,
+            &ck,
+            changed_polynomials.into_iter().map(Into::into),
+            None::<&mut dyn RngCore>,
         )?;
+        commitments.extend(new_commitments.into_iter().map(|c| (c.label().to_string(), *c.commitment())));
+        end_timer!(commit_time);
+
+        indexed_circuit.prune_row_col_evals();
+        let circuit_verifying_key = CircuitVerifyingKey {
+            circuit_info: indexed_circuit.index_info,
+            circuit_commitments: commitments.into_values().collect(),
+            id: indexed_circuit.id,
+        };
+        let circuit_proving_key = CircuitProvingKey {
+            circuit_verifying_key: circuit_verifying_key.clone(),
+            circuit: Arc::new(indexed_circuit),
+            committer_key,
+        };
 
-        Ok(Self::Certificate::new(certificate))
+        end_timer!(patch_time);
+        Ok((circuit_proving_key, circuit_verifying_key))
     }
 
-    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
-    /// Verify that the verifying key's circuit_info is correct
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool> {
-        // Ensure the VerifyingKey encodes the expected circuit.
-        let circuit_id = &verifying_key.id;
-        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
-        if state.index_info != verifying_key.circuit_info {
-            bail!(SNARKError::CircuitNotFound);
-        }
-        if state.id != *circuit_id {
-            bail!(SNARKError::CircuitNotFound);
-        }
-
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let (lc, evaluation) =
-            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
-
-        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(lc.terms.keys())
-            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
-            .collect_vec();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
+    /// Creates a proof as in `prove_batch`, checking `cancellation` between the rounds of the prover.
+    ///
+    /// If cancellation is requested, the prover stops at the next round and fails with `SNARKError::Cancelled`.
+    #[cfg(feature = "prover")]
+    pub fn prove_batch_with_cancellation<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        zk_rng: &mut R,
+        cancellation: &CancellationToken,
+    ) -> Result<Proof<E>> {
+        let handle = ProvingHandle::from(cancellation.clone());
+        Self::prove_batch_with_handle(universal_prover, fs_parameters, keys_to_constraints, zk_rng, &handle)
     }
 
-    /// This is the main entrypoint for creating proofs.
-    /// You can find a specification of the prover algorithm in:
-    /// https://github.com/AleoNet/protocol-docs
-    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
+    /// Creates a proof as in `prove_batch_with_cancellation`, reporting the progress of the prover to `handle`
+    /// after each round and after each set of commitments, and checking `handle` for cancellation between the rounds.
+    #[cfg(feature = "prover")]
+    pub fn prove_batch_with_handle<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof> {
+        handle: &ProvingHandle,
+    ) -> Result<Proof<E>> {
         let prover_time = start_timer!(|| "Varuna::Prover");
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
@@ -349,7 +309,8 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let mut prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        prover_state.low_memory = universal_prover.low_memory;
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -382,6 +343,27 @@ where
 
         let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments.clone());
 
+        // The prover commits to the oracles of the five rounds of the AHP, and then computes the evaluation proof.
+        let num_rounds = 6;
+        let num_commitments = AHPForR1CS::<E::Fr, SM>::num_first_round_oracles(total_instances)
+            + AHPForR1CS::<E::Fr, SM>::num_second_round_oracles()
+            + AHPForR1CS::<E::Fr, SM>::num_third_round_oracles()
+            + AHPForR1CS::<E::Fr, SM>::num_fourth_round_oracles(num_unique_circuits)
+            + AHPForR1CS::<E::Fr, SM>::num_fifth_round_oracles();
+        let mut completed_commitments = 0;
+        let mut report_round = |round: usize, commitments: usize| {
+            if commitments > 0 {
+                completed_commitments += commitments;
+                handle.report(ProvingEvent::CommitmentsCompleted {
+                    completed: completed_commitments,
+                    total: num_commitments,
+                });
+            }
+            handle.report(ProvingEvent::RoundCompleted { round, num_rounds });
+        };
+
+        handle.check()?;
+
         // --------------------------------------------------------------------
         // First round
 
@@ -398,6 +380,7 @@ where
             )?
         };
         end_timer!(first_round_comm_time);
+        report_round(1, first_commitments.len());
 
         Self::absorb_labeled(&first_commitments, &mut sponge);
 
@@ -411,6 +394,8 @@ where
         )?;
         // --------------------------------------------------------------------
 
+        handle.check()?;
+
         // --------------------------------------------------------------------
         // Second round
 
@@ -425,6 +410,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(second_round_comm_time);
+        report_round(2, second_commitments.len());
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
 
@@ -432,6 +418,8 @@ where
             AHPForR1CS::<_, SM>::verifier_second_round(verifier_state, &mut sponge)?;
         // --------------------------------------------------------------------
 
+        handle.check()?;
+
         // --------------------------------------------------------------------
         // Third round
 
@@ -450,6 +438,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(third_round_comm_time);
+        report_round(3, third_commitments.len());
 
         Self::absorb_labeled_with_sums(
             &third_commitments,
@@ -461,6 +450,8 @@ where
             AHPForR1CS::<_, SM>::verifier_third_round(verifier_state, &mut sponge)?;
         // --------------------------------------------------------------------
 
+        handle.check()?;
+
         // --------------------------------------------------------------------
         // Fourth round
 
@@ -475,6 +466,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(fourth_round_comm_time);
+        report_round(4, fourth_commitments.len());
 
         Self::absorb_labeled_with_sums(&fourth_commitments, &prover_fourth_message.sums, &mut sponge);
 
@@ -489,6 +481,8 @@ where
         let index_b_polys =
             prover_state.circuit_specific_states.values_mut().flat_map(|s| s.b_polys.take().unwrap()).collect_vec();
 
+        handle.check()?;
+
         // --------------------------------------------------------------------
         // Fifth round
         let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
@@ -501,6 +495,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(fifth_round_comm_time);
+        report_round(5, fifth_commitments.len());
 
         Self::absorb_labeled(&fifth_commitments, &mut sponge);
 
@@ -570,6 +565,8 @@ where
             ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
         }
 
+        handle.check()?;
+
         // Compute the AHP verifier's query set.
         let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
         let lc_s = AHPForR1CS::<_, SM>::construct_linear_combinations(
@@ -615,25 +612,241 @@ where
         )?;
         proof.check_batch_sizes()?;
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
+        report_round(num_rounds, 0);
 
         end_timer!(prover_time);
         Ok(proof)
     }
 
-    /// This is the main entrypoint for verifying proofs.
-    /// You can find a specification of the verifier algorithm in:
-    /// https://github.com/AleoNet/protocol-docs
-    fn verify_batch<B: Borrow<Self::VerifierInput>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
-        proof: &Self::Proof,
-    ) -> Result<bool> {
-        if keys_to_inputs.is_empty() {
-            bail!(SNARKError::EmptyBatch);
-        }
-
-        proof.check_batch_sizes()?;
+    /// Creates a proof as in `prove_batch`, deriving all of the randomness of the prover from `seed`.
+    ///
+    /// Proving the same constraints with the same seed produces byte-identical proofs, so that proofs can be
+    /// compared across runs, for example in tests and audits. The proof only hides the witness if the seed is
+    /// sampled uniformly and kept secret, so a fixed seed must not be used for private witnesses.
+    #[cfg(feature = "prover")]
+    pub fn prove_batch_with_seed<C: ConstraintSynthesizer<E::Fr>>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        seed: [u8; 32],
+    ) -> Result<Proof<E>> {
+        Self::prove_batch(universal_prover, fs_parameters, keys_to_constraints, &mut ChaChaRng::from_seed(seed))
+    }
+
+    /// Creates a proof for `constraints` on the blocking thread pool of the current Tokio runtime,
+    /// as in `prove_batch_with_cancellation`.
+    #[cfg(feature = "async")]
+    pub async fn prove_async<C, R>(
+        universal_prover: Arc<UniversalProver<E>>,
+        fs_parameters: FS::Parameters,
+        proving_key: Arc<CircuitProvingKey<E, SM>>,
+        constraints: C,
+        mut zk_rng: R,
+        cancellation: CancellationToken,
+    ) -> Result<Proof<E>>
+    where
+        C: ConstraintSynthesizer<E::Fr> + Send + 'static,
+        R: Rng + CryptoRng + Send + 'static,
+        FS: 'static,
+        FS::Parameters: Send + 'static,
+        SM: 'static,
+    {
+        crate::cancellation::spawn_blocking_cancellable(cancellation, move |cancellation| {
+            let mut keys_to_constraints = BTreeMap::new();
+            keys_to_constraints.insert(proving_key.as_ref(), std::slice::from_ref(&constraints));
+            Self::prove_batch_with_cancellation(
+                &universal_prover,
+                &fs_parameters,
+                &keys_to_constraints,
+                &mut zk_rng,
+                cancellation,
+            )
+        })
+        .await
+    }
+
+    /// Creates a proof for each of the given proving keys and constraints on the shared thread pool of `scheduler`,
+    /// as in `prove_batch_with_cancellation`. The proofs are returned in the order of `jobs`,
+    /// and the failure of one job does not affect the others.
+    #[cfg(feature = "prover")]
+    pub fn prove_scheduled<C: ConstraintSynthesizer<E::Fr> + Sync, R: Rng + CryptoRng>(
+        scheduler: &ProvingScheduler,
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        jobs: &[(&CircuitProvingKey<E, SM>, &C)],
+        zk_rng: &mut R,
+        cancellation: &CancellationToken,
+    ) -> Vec<Result<Proof<E>>>
+    where
+        FS::Parameters: Sync,
+    {
+        // Sample the randomness of each job upfront, so that the proofs do not depend on the order in which they run.
+        let seeds = jobs.iter().map(|_| zk_rng.gen::<[u8; 32]>()).collect::<Vec<_>>();
+        scheduler.execute(jobs.len(), |index| {
+            let (proving_key, constraints) = jobs[index];
+            let keys_to_constraints = BTreeMap::from([(proving_key, std::slice::from_ref(constraints))]);
+            Self::prove_batch_with_cancellation(
+                universal_prover,
+                fs_parameters,
+                &keys_to_constraints,
+                &mut ChaChaRng::from_seed(seeds[index]),
+                cancellation,
+            )
+        })
+    }
+
+    /// Aggregates many proofs output by `prove_batch` into a single proof.
+    ///
+    /// Each instance consists of the verifying keys and public inputs of a proof, as in `verify_batch`, and the proof.
+    /// The evaluation proofs of all of the proofs are aggregated, so that `verify_aggregated` checks them with a single
+    /// product of pairings. The aggregation is not succinct: the proofs are kept apart from their evaluation proofs,
+    /// so the size of the aggregated proof and the time to verify it remain linear in the number of proofs.
+    #[cfg(feature = "prover")]
+    pub fn aggregate<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        instances: &[(&BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+    ) -> Result<AggregatedProof<E>> {
+        if instances.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+        let aggregate_time = start_timer!(|| format!("Varuna::Aggregate {} proofs", instances.len()));
+
+        let mut claims = Vec::with_capacity(instances.len());
+        for (keys_to_inputs, proof) in instances {
+            let claim = Self::verify_ahp(fs_parameters, keys_to_inputs, proof, proof.pc_proof.is_hiding())?
+                .ok_or(anyhow!("The proof has an incorrect hiding mode"))?;
+            claims.push(Self::combine_claims(claim)?);
+        }
+        let pc_proof = SonicKZG10::<E, FS>::aggregate_batch_proofs(
+            claims.iter_mut().zip_eq(instances).map(|((commitments, query_set, evaluations, sponge), (_, proof))| {
+                (commitments.as_slice(), &*query_set, &*evaluations, &proof.pc_proof.proof, sponge)
+            }),
+            &mut Self::init_sponge_for_aggregation(fs_parameters),
+        )?;
+
+        // The evaluation proofs of the proofs are superseded by the aggregated evaluation proof.
+        let proofs = instances
+            .iter()
+            .map(|(_, proof)| {
+                let mut proof = (*proof).clone();
+                proof.pc_proof = BatchLCProof { proof: BatchProof(Vec::new()) };
+                proof
+            })
+            .collect();
+
+        end_timer!(aggregate_time);
+        Ok(AggregatedProof::new(proofs, pc_proof))
+    }
+
+    /// Checks a proof output by `aggregate` for the given instances, which consist of the verifying keys
+    /// and public inputs of each proof, in the order in which the proofs were aggregated.
+    ///
+    /// Verification is linear in the number of proofs, as the AHP of each proof is checked individually;
+    /// only the pairing checks of the evaluation proofs are replaced by a single product of pairings.
+    pub fn verify_aggregated<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        instances: &[&BTreeMap<&CircuitVerifyingKey<E>, &[B]>],
+        proof: &AggregatedProof<E>,
+    ) -> Result<bool> {
+        if instances.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+        if instances.len() != proof.len() {
+            bail!(SNARKError::BatchSizeMismatch);
+        }
+        let verify_time = start_timer!(|| format!("Varuna::VerifyAggregated {} proofs", instances.len()));
+
+        let mut claims = Vec::with_capacity(instances.len());
+        for (keys_to_inputs, ahp_proof) in instances.iter().zip_eq(&proof.proofs) {
+            if !ahp_proof.pc_proof.proof.0.is_empty() {
+                return Ok(false);
+            }
+            let Some(claim) = Self::verify_ahp(fs_parameters, keys_to_inputs, ahp_proof, proof.is_hiding())? else {
+                return Ok(false);
+            };
+            claims.push(Self::combine_claims(claim)?);
+        }
+        let result = SonicKZG10::<E, FS>::check_aggregated_batch_proof(
+            universal_verifier,
+            claims.iter_mut().map(|(commitments, query_set, evaluations, sponge)| {
+                (commitments.as_slice(), &*query_set, &*evaluations, sponge)
+            }),
+            &proof.pc_proof,
+            &mut Self::init_sponge_for_aggregation(fs_parameters),
+        )?;
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    /// Replays the verification of `proof` for a single circuit, and returns the challenges of the verifier,
+    /// the claimed evaluations, and the outcome, as a reference for verifiers on the EVM.
+    pub fn evm_transcript<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        verifying_key: &CircuitVerifyingKey<E>,
+        public_inputs: B,
+        proof: &Proof<E>,
+    ) -> Result<EvmTranscript> {
+        let keys_to_inputs = BTreeMap::from([(verifying_key, std::slice::from_ref(&public_inputs))]);
+        let Some(claims) = Self::verify_ahp(fs_parameters, &keys_to_inputs, proof, proof.pc_proof.is_hiding())? else {
+            return Ok(EvmTranscript { challenges: vec![], evaluations: vec![], is_valid: false });
+        };
+
+        let challenges = claims
+            .challenges
+            .iter()
+            .map(|(name, challenge)| Ok((name.clone(), to_evm_word(challenge)?)))
+            .collect::<Result<_>>()?;
+        let evaluations = claims
+            .evaluations
+            .iter()
+            .map(|((label, point), value)| Ok((label.clone(), to_evm_word(point)?, to_evm_word(value)?)))
+            .collect::<Result<_>>()?;
+
+        let EvaluationClaims { lc_s, commitments, query_set, evaluations: claimed, mut sponge, .. } = claims;
+        let is_valid = SonicKZG10::<E, FS>::check_combinations(
+            universal_verifier,
+            lc_s.values(),
+            &commitments,
+            &query_set,
+            &claimed,
+            &proof.pc_proof,
+            &mut sponge,
+        )?;
+        Ok(EvmTranscript { challenges, evaluations, is_valid })
+    }
+
+    /// Returns the commitments to the linear combinations in `claims`, together with their query set,
+    /// evaluations, and sponge, as checked by `SonicKZG10::batch_check`.
+    fn combine_claims(
+        claims: EvaluationClaims<E, FS>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, QuerySet<E::Fr>, Evaluations<E::Fr>, FS)> {
+        let (commitments, evaluations) = SonicKZG10::<E, FS>::combine_lc_commitments(
+            claims.lc_s.values(),
+            &claims.commitments,
+            &claims.evaluations,
+        )?;
+        Ok((commitments, claims.query_set, evaluations, claims.sponge))
+    }
+
+    /// Replays the AHP verifier for `proof` on the given inputs, and returns the claims about the
+    /// committed polynomials that remain to be checked with the polynomial commitment scheme.
+    ///
+    /// Returns `None` if the proof is not in the zero-knowledge mode of `SM`, where `is_hiding`
+    /// indicates whether the evaluation proof that accompanies `proof` is hiding.
+    fn verify_ahp<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+        is_hiding: bool,
+    ) -> Result<Option<EvaluationClaims<E, FS>>> {
+        if keys_to_inputs.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        proof.check_batch_sizes()?;
         let batch_sizes_vec = proof.batch_sizes();
         let mut batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
@@ -714,17 +927,13 @@ where
         let max_non_zero_domain = max_non_zero_domain.ok_or(SynthesisError::PolyTooLarge)?;
 
         let comms = &proof.commitments;
-        let proof_has_correct_zk_mode = if SM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
-        } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
-        };
+        let proof_has_correct_zk_mode =
+            if SM::ZK { is_hiding & comms.mask_poly.is_some() } else { !is_hiding & comms.mask_poly.is_none() };
         if !proof_has_correct_zk_mode {
             eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
+                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({is_hiding})"
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -896,13 +1105,268 @@ where
             &verifier_state,
         )?;
         end_timer!(lc_time);
+        end_timer!(verifier_time);
+
+        Ok(Some(EvaluationClaims {
+            lc_s,
+            commitments,
+            query_set: query_set.to_set(),
+            evaluations,
+            sponge,
+            challenges: verifier_state.challenges(),
+            #[cfg(debug_assertions)]
+            gamma: verifier_state.gamma,
+        }))
+    }
+
+    fn init_sponge<'a>(
+        fs_parameters: &FS::Parameters,
+        inputs_and_batch_sizes: &BTreeMap<CircuitId, (usize, &[Vec<E::Fr>])>,
+        circuit_commitments: impl Iterator<Item = &'a [crate::polycommit::sonic_pc::Commitment<E>]>,
+    ) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(Self::PROTOCOL_NAME);
+        for (batch_size, inputs) in inputs_and_batch_sizes.values() {
+            sponge.absorb_bytes(&(*batch_size as u64).to_le_bytes());
+            for input in inputs.iter() {
+                sponge.absorb_nonnative_field_elements(input.iter().copied());
+            }
+        }
+        for circuit_specific_commitments in circuit_commitments {
+            sponge.absorb_native_field_elements(circuit_specific_commitments);
+        }
+        sponge
+    }
+
+    fn init_sponge_for_aggregation(fs_parameters: &FS::Parameters) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(Self::PROTOCOL_NAME);
+        sponge.absorb_bytes(b"aggregation");
+        sponge
+    }
+
+    fn init_sponge_for_certificate(
+        fs_parameters: &FS::Parameters,
+        verifying_key: &CircuitVerifyingKey<E>,
+    ) -> Result<FS> {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME]?);
+        sponge.absorb_bytes(&verifying_key.circuit_info.to_bytes_le()?);
+        sponge.absorb_native_field_elements(&verifying_key.circuit_commitments);
+        sponge.absorb_bytes(&verifying_key.id.0);
+        Ok(sponge)
+    }
+
+    fn absorb_labeled_with_sums(
+        comms: &[LabeledCommitment<Commitment<E>>],
+        sums: &[prover::MatrixSums<E::Fr>],
+        sponge: &mut FS,
+    ) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb_with_sums(&commitments, sums, sponge)
+    }
+
+    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb(&commitments, sponge);
+    }
+
+    fn absorb(commitments: &[Commitment<E>], sponge: &mut FS) {
+        let sponge_time = start_timer!(|| "Absorbing commitments");
+        sponge.absorb_native_field_elements(commitments);
+        end_timer!(sponge_time);
+    }
+
+    fn absorb_with_sums(commitments: &[Commitment<E>], sums: &[prover::MatrixSums<E::Fr>], sponge: &mut FS) {
+        let sponge_time = start_timer!(|| "Absorbing commitments and message");
+        Self::absorb(commitments, sponge);
+        for sum in sums.iter() {
+            sponge.absorb_nonnative_field_elements([sum.sum_a, sum.sum_b, sum.sum_c]);
+        }
+        end_timer!(sponge_time);
+    }
+}
+
+impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    #[cfg(feature = "prover")]
+    type ProvingKey = CircuitProvingKey<E, SM>;
+    type ScalarField = E::Fr;
+    #[cfg(feature = "prover")]
+    type UniversalProver = UniversalProver<E>;
+    type UniversalSRS = UniversalSRS<E>;
+    type UniversalVerifier = UniversalVerifier<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E>;
+
+    #[cfg(feature = "prover")]
+    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS> {
+        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
+        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    #[cfg(feature = "prover")]
+    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &Self::UniversalSRS,
+        circuit: &C,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
+        ensure!(circuit_keys.len() == 1);
+        Ok(circuit_keys.pop().unwrap())
+    }
+
+    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
+    #[cfg(feature = "prover")]
+    fn prove_vk(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        let circuit_id = std::iter::once(&verifying_key.id);
+        let circuit_poly_info = AHPForR1CS::<E::Fr, SM>::index_polynomial_info(circuit_id);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (label, &c) in circuit_poly_info.keys().zip(linear_combination_challenges) {
+            lc.add(c, label.clone());
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
+
+        let empty_randomness = vec![Randomness::<E>::empty(); 12];
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            universal_prover,
+            &committer_key,
+            &[lc],
+            proving_key.circuit.interpolate_matrix_evals()?,
+            &empty_randomness,
+            &query_set,
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
+    /// Verify that the verifying key's circuit_info is correct
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool> {
+        // Ensure the VerifyingKey encodes the expected circuit.
+        let circuit_id = &verifying_key.id;
+        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
+        if state.index_info != verifying_key.circuit_info {
+            bail!(SNARKError::CircuitNotFound);
+        }
+        if state.id != *circuit_id {
+            bail!(SNARKError::CircuitNotFound);
+        }
+
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let (lc, evaluation) =
+            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
+
+        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(lc.terms.keys())
+            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
+            .collect_vec();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    /// This is the main entrypoint for creating proofs.
+    /// You can find a specification of the prover algorithm in:
+    /// https://github.com/AleoNet/protocol-docs
+    #[cfg(feature = "prover")]
+    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof> {
+        Self::prove_batch_with_cancellation(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            zk_rng,
+            &CancellationToken::new(),
+        )
+    }
+
+    /// This is the main entrypoint for verifying proofs.
+    /// You can find a specification of the verifier algorithm in:
+    /// https://github.com/AleoNet/protocol-docs
+    fn verify_batch<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        let Some(claims) = Self::verify_ahp(fs_parameters, keys_to_inputs, proof, proof.pc_proof.is_hiding())? else {
+            return Ok(false);
+        };
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let EvaluationClaims { lc_s, commitments, query_set, evaluations, mut sponge, .. } = claims;
         let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
-            &query_set.to_set(),
+            &query_set,
             &evaluations,
             &proof.pc_proof,
             &mut sponge,
@@ -911,13 +1375,48 @@ where
 
         if !evaluations_are_correct {
             #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", claims.gamma);
         }
+        Ok(evaluations_are_correct)
+    }
+
+    /// Verifies many proofs for the same verifying key, by checking the evaluation proofs of all of the proofs
+    /// with a single product of pairings, which is combined with randomness sampled from `rng`.
+    fn verify_many<B: Borrow<Self::VerifierInput>, R: Rng + CryptoRng>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        inputs_and_proofs: &[(B, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        if inputs_and_proofs.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+        let verify_time = start_timer!(|| format!("Varuna::VerifyMany {} proofs", inputs_and_proofs.len()));
+
+        let mut claims = Vec::with_capacity(inputs_and_proofs.len());
+        for (input, proof) in inputs_and_proofs {
+            let keys_to_inputs = BTreeMap::from([(verifying_key, std::slice::from_ref(input))]);
+            let Some(claim) = Self::verify_ahp(fs_parameters, &keys_to_inputs, proof, proof.pc_proof.is_hiding())?
+            else {
+                return Ok(false);
+            };
+            claims.push(Self::combine_claims(claim)?);
+        }
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let result = SonicKZG10::<E, FS>::batch_check_independent(
+            universal_verifier,
+            claims.iter_mut().zip_eq(inputs_and_proofs).map(
+                |((commitments, query_set, evaluations, sponge), (_, proof))| {
+                    (commitments.as_slice(), &*query_set, &*evaluations, &proof.pc_proof.proof, sponge)
+                },
+            ),
+            rng,
+        )?;
+        end_timer!(pc_time);
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        end_timer!(verify_time);
+        Ok(result)
     }
 }
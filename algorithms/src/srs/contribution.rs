@@ -0,0 +1,258 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contributions to a powers-of-tau trusted-setup ceremony.
+//!
+//! Each participant takes the SRS output by the previous participant, raises the secret `\beta`
+//! to a fresh secret `\tau` derived from their entropy, and publishes a proof of contribution.
+//! As long as one participant discards their `\tau`, the final `\beta` is unknown to everyone.
+
+use crate::msm::VariableBase;
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{ToBytes, cfg_iter, rand::Uniform, serialize::*};
+
+use anyhow::{Result, ensure};
+use blake2::{Blake2s256, Digest};
+use core::ops::Mul;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The domain separator for the challenge of the proof of knowledge.
+const CONTRIBUTION_DOMAIN: &[u8] = b"AleoSRSContribution-2024";
+/// The domain separator for the nonce of the proof of knowledge.
+const NONCE_DOMAIN: &[u8] = b"AleoSRSContributionNonce-2024";
+
+/// The powers-of-tau SRS that is updated by each contribution to the ceremony.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `num_powers - 1`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// \beta times the generator of G2.
+    pub beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Returns the initial SRS of the ceremony, for which `\beta = 1`.
+    pub fn initial(num_powers: usize) -> Result<Self> {
+        ensure!(num_powers >= 2, "The SRS must contain at least two powers");
+        let powers_of_beta_g = vec![E::G1Affine::prime_subgroup_generator(); num_powers];
+        Ok(Self { powers_of_beta_g, beta_h: E::G2Affine::prime_subgroup_generator() })
+    }
+
+    /// Returns the number of powers of beta G in the SRS.
+    pub fn num_powers(&self) -> usize {
+        self.powers_of_beta_g.len()
+    }
+
+    /// Returns `true` if the powers of beta G are consecutive powers of the same `\beta` as in `beta_h`.
+    ///
+    /// This samples random scalars from `rng` to check all the powers with a single pairing equation.
+    pub fn is_well_formed<R: Rng>(&self, rng: &mut R) -> bool {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        if self.num_powers() < 2 || self.powers_of_beta_g[0] != g || self.powers_of_beta_g.iter().any(|p| p.is_zero()) {
+            return false;
+        }
+
+        // Check that `e(\sum_i \rho_i \beta^{i+1} G, H) == e(\sum_i \rho_i \beta^i G, \beta H)`.
+        let num_powers = self.num_powers();
+        let randomizers = (0..num_powers - 1).map(|_| E::Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        let lhs = VariableBase::msm(&self.powers_of_beta_g[1..], &randomizers);
+        let rhs = VariableBase::msm(&self.powers_of_beta_g[..num_powers - 1], &randomizers);
+        E::multi_pairing([lhs, -rhs], [h, self.beta_h]).is_one()
+    }
+
+    /// Returns a digest of the SRS, which binds a contribution to the SRS it was made on.
+    fn digest(&self) -> Result<[u8; 32]> {
+        let mut hasher = Blake2s256::new();
+        hasher.update(self.to_bytes_le()?);
+        Ok(hasher.finalize().into())
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PowersOfTau<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        self.serialize_compressed(&mut writer).map_err(|e| e.into())
+    }
+}
+
+/// A proof that a contribution raised the previous `\beta` to a secret `\tau` known to the contributor.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ContributionProof<E: PairingEngine> {
+    /// \tau times the generator of G1.
+    pub tau_g: E::G1Affine,
+    /// \tau times the generator of G2.
+    pub tau_h: E::G2Affine,
+    /// The commitment of the Schnorr proof of knowledge of `\tau`.
+    pub commitment: E::G1Affine,
+    /// The response of the Schnorr proof of knowledge of `\tau`.
+    pub response: E::Fr,
+}
+
+/// Contributes to the ceremony by updating `previous` with a secret derived from `entropy`.
+///
+/// The `entropy` must be kept secret and discarded after the contribution.
+pub fn contribute<E: PairingEngine>(
+    previous: &PowersOfTau<E>,
+    entropy: &[u8],
+) -> Result<(PowersOfTau<E>, ContributionProof<E>)> {
+    let contribute_time = start_timer!(|| format!("Contributing to an SRS of {} powers", previous.num_powers()));
+    ensure!(previous.num_powers() >= 2, "The SRS must contain at least two powers");
+
+    // Derive the secret from the entropy.
+    let rng = &mut ChaChaRng::from_seed(Blake2s256::digest([CONTRIBUTION_DOMAIN, entropy].concat()).into());
+    let tau = sample_nonzero::<E, _>(rng);
+
+    // Compute the powers of tau, and update the SRS.
+    let mut powers_of_tau = Vec::with_capacity(previous.num_powers());
+    let mut cur = E::Fr::one();
+    for _ in 0..previous.num_powers() {
+        powers_of_tau.push(cur);
+        cur *= tau;
+    }
+    let powers_of_beta_g = cfg_iter!(previous.powers_of_beta_g)
+        .zip(cfg_iter!(powers_of_tau))
+        .map(|(power, tau_i)| power.mul(*tau_i))
+        .collect::<Vec<_>>();
+    let next = PowersOfTau {
+        powers_of_beta_g: E::G1Projective::batch_normalization_into_affine(powers_of_beta_g),
+        beta_h: previous.beta_h.mul(tau).to_affine(),
+    };
+
+    // Prove knowledge of tau, bound to the previous SRS.
+    let tau_g = E::G1Affine::prime_subgroup_generator().mul(tau).to_affine();
+    let tau_h = E::G2Affine::prime_subgroup_generator().mul(tau).to_affine();
+    // The nonce is derived from a separate stream bound to the previous SRS, so that reusing the
+    // entropy on a different SRS does not reuse the nonce for the same tau.
+    let previous_digest = previous.digest()?;
+    let nonce_seed = Blake2s256::digest([NONCE_DOMAIN, entropy, &previous_digest[..]].concat());
+    let nonce = sample_nonzero::<E, _>(&mut ChaChaRng::from_seed(nonce_seed.into()));
+    let commitment = E::G1Affine::prime_subgroup_generator().mul(nonce).to_affine();
+    let challenge = proof_challenge::<E>(&previous_digest, &tau_g, &commitment)?;
+    let response = nonce + challenge * tau;
+
+    end_timer!(contribute_time);
+    Ok((next, ContributionProof { tau_g, tau_h, commitment, response }))
+}
+
+/// Returns `true` if `next` is the result of a valid contribution to `previous`, as attested by `proof`.
+pub fn verify_contribution<E: PairingEngine, R: Rng>(
+    previous: &PowersOfTau<E>,
+    next: &PowersOfTau<E>,
+    proof: &ContributionProof<E>,
+    rng: &mut R,
+) -> Result<bool> {
+    let verify_time = start_timer!(|| "Verifying contribution");
+    let g = E::G1Affine::prime_subgroup_generator();
+    let h = E::G2Affine::prime_subgroup_generator();
+
+    let result = previous.num_powers() == next.num_powers()
+        && !proof.tau_g.is_zero()
+        // Ensure the contributor knows tau.
+        && {
+            let challenge = proof_challenge::<E>(&previous.digest()?, &proof.tau_g, &proof.commitment)?;
+            g.mul(proof.response) == proof.commitment.to_projective() + proof.tau_g.mul(challenge)
+        }
+        // Ensure `tau_g` and `tau_h` have the same discrete logarithm.
+        && E::multi_pairing([proof.tau_g, -g], [h, proof.tau_h]).is_one()
+        // Ensure the new beta is the previous beta times tau.
+        && E::multi_pairing([next.powers_of_beta_g[1], -previous.powers_of_beta_g[1]], [h, proof.tau_h]).is_one()
+        // Ensure the new SRS is well-formed.
+        && next.is_well_formed(rng);
+
+    end_timer!(verify_time);
+    Ok(result)
+}
+
+/// Returns `true` if each SRS in `contributions` is the result of a valid contribution to the SRS
+/// that precedes it, starting from the `initial` SRS.
+pub fn verify_contribution_chain<E: PairingEngine, R: Rng>(
+    initial: &PowersOfTau<E>,
+    contributions: &[(PowersOfTau<E>, ContributionProof<E>)],
+    rng: &mut R,
+) -> Result<bool> {
+    let mut previous = initial;
+    for (next, proof) in contributions {
+        if !verify_contribution(previous, next, proof, rng)? {
+            return Ok(false);
+        }
+        previous = next;
+    }
+    Ok(true)
+}
+
+/// Samples a nonzero scalar from `rng`.
+fn sample_nonzero<E: PairingEngine, R: Rng>(rng: &mut R) -> E::Fr {
+    loop {
+        let scalar = E::Fr::rand(rng);
+        if !scalar.is_zero() {
+            break scalar;
+        }
+    }
+}
+
+/// Returns the challenge for the proof of knowledge of `\tau`.
+fn proof_challenge<E: PairingEngine>(
+    previous_digest: &[u8; 32],
+    tau_g: &E::G1Affine,
+    commitment: &E::G1Affine,
+) -> Result<E::Fr> {
+    let mut hasher = Blake2s256::new();
+    hasher.update(CONTRIBUTION_DOMAIN);
+    hasher.update(previous_digest);
+    hasher.update(tau_g.to_bytes_le()?);
+    hasher.update(commitment.to_bytes_le()?);
+    Ok(E::Fr::from_bytes_le_mod_order(&hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::rand::TestRng;
+
+    #[test]
+    fn test_contribution_chain() {
+        let rng = &mut TestRng::default();
+        let initial = PowersOfTau::<Bls12_377>::initial(32).unwrap();
+        assert!(initial.is_well_formed(rng));
+
+        let mut contributions = Vec::new();
+        for i in 0..3u8 {
+            let previous = contributions.last().map(|(srs, _)| srs).unwrap_or(&initial);
+            let entropy = [i; 32];
+            contributions.push(contribute(previous, &entropy).unwrap());
+        }
+        assert!(verify_contribution_chain(&initial, &contributions, rng).unwrap());
+
+        // Ensure a contribution cannot be replayed on a different SRS.
+        let (next, proof) = contribute(&contributions[2].0, &[3u8; 32]).unwrap();
+        assert!(!verify_contribution(&contributions[1].0, &next, &proof, rng).unwrap());
+
+        // Ensure reusing the entropy on a different SRS does not reuse the nonce.
+        let (_, reused) = contribute(&contributions[1].0, &[3u8; 32]).unwrap();
+        assert_eq!(reused.tau_g, proof.tau_g);
+        assert_ne!(reused.commitment, proof.commitment);
+
+        // Ensure a tampered SRS is rejected.
+        let mut tampered = contributions.clone();
+        tampered[1].0.powers_of_beta_g[5] = tampered[1].0.powers_of_beta_g[4];
+        assert!(!verify_contribution_chain(&initial, &tampered, rng).unwrap());
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{polycommit::kzg10, srs::UniversalVerifier};
+use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_utilities::{FromBytes, ToBytes, error, serialize::*};
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    sync::Arc,
+};
+
+/// `LightVerifier` is a minimal, serializable description of the verifier side of the universal SRS.
+///
+/// It holds only the group elements that are needed to check evaluation proofs, in affine form,
+/// so that embedded and WebAssembly verifiers need not carry any prover-side data.
+/// The pairing-friendly representations are recomputed by [`LightVerifier::to_universal_verifier`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LightVerifier<E: PairingEngine> {
+    /// The maximum degree supported by the SRS this verifier was exported from.
+    pub max_degree: usize,
+    /// The generator of G1.
+    pub g: E::G1Affine,
+    /// The generator of G1 that is used for making a commitment hiding.
+    pub gamma_g: E::G1Affine,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    pub beta_h: E::G2Affine,
+    /// Information required to enforce degree bounds, in affine form.
+    /// Each pair is in the form `(degree_bound, \beta^{max_degree - i} H)`, where `H` is the generator of G2.
+    pub negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+}
+
+impl<E: PairingEngine> LightVerifier<E> {
+    /// Returns the maximum degree supported by the SRS this verifier was exported from.
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Returns the degree bounds that can be enforced by this verifier.
+    pub fn supported_degree_bounds(&self) -> impl Iterator<Item = usize> + '_ {
+        self.negative_powers_of_beta_h.keys().copied()
+    }
+
+    /// Prepares the group elements for use in pairings, and returns the resulting universal verifier.
+    pub fn to_universal_verifier(&self) -> UniversalVerifier<E> {
+        let prepared_negative_powers_of_beta_h =
+            self.negative_powers_of_beta_h.iter().map(|(degree, power)| (*degree, power.prepare())).collect();
+        UniversalVerifier {
+            vk: kzg10::VerifierKey {
+                g: self.g,
+                gamma_g: self.gamma_g,
+                h: self.h,
+                beta_h: self.beta_h,
+                prepared_h: self.h.prepare(),
+                prepared_beta_h: self.beta_h.prepare(),
+            },
+            prepared_negative_powers_of_beta_h: Arc::new(prepared_negative_powers_of_beta_h),
+        }
+    }
+}
+
+impl<E: PairingEngine> FromBytes for LightVerifier<E> {
+    fn read_le<R: Read>(r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize LightVerifier"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for LightVerifier<E> {
+    fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
+        self.serialize_compressed(w).map_err(|_| error("could not serialize LightVerifier"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    #[test]
+    fn test_light_verifier_roundtrip() {
+        let srs = kzg10::UniversalParams::<Bls12_377>::load().unwrap();
+        let light_verifier = srs.to_light_verifier().unwrap();
+        assert_eq!(light_verifier.max_degree(), srs.max_degree());
+        assert_eq!(light_verifier.to_universal_verifier(), srs.to_universal_verifier().unwrap());
+
+        let bytes = light_verifier.to_bytes_le().unwrap();
+        assert_eq!(bytes.len(), light_verifier.compressed_size());
+        let candidate = LightVerifier::<Bls12_377>::read_le(&bytes[..]).unwrap();
+        assert_eq!(candidate, light_verifier);
+        assert!(LightVerifier::<Bls12_377>::read_le(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{polycommit::kzg10, srs::UniversalVerifier};
+use snarkvm_curves::{PairingCurve, PairingEngine};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Compress, FromBytes, Validate};
+
+use anyhow::{Result, anyhow, ensure};
+use memmap2::Mmap;
+use std::{collections::BTreeMap, fs::File, path::Path, sync::Arc};
+
+#[cfg(feature = "prover")]
+use crate::srs::UniversalProver;
+
+/// A universal SRS that is backed by a memory-mapped file, in the format written by
+/// `kzg10::UniversalParams::write_le`.
+///
+/// Only the small, fixed-size components of the SRS are deserialized when the file is opened.
+/// The powers of beta G are read from the mapping on demand, so the operating system pages
+/// them in lazily, and verifier-only nodes never load them at all.
+#[derive(Clone, Debug)]
+pub struct MmapUniversalSRS<E: PairingEngine> {
+    /// The memory-mapped SRS file.
+    mmap: Arc<Mmap>,
+    /// The byte offset of the first power of beta G in the mapping.
+    powers_offset: usize,
+    /// The number of contiguous powers of beta G starting from the 0-th power.
+    num_powers: usize,
+    /// The size in bytes of a serialized power of beta G.
+    element_size: usize,
+    /// Group elements of the form `{ \beta^i \gamma G }`.
+    powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+    /// Information required to enforce degree bounds.
+    prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
+    /// The generator of G2.
+    h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    beta_h: E::G2Affine,
+}
+
+impl<E: PairingEngine> MmapUniversalSRS<E> {
+    /// Memory-maps the SRS stored at the given `path`.
+    ///
+    /// The file must not be modified while it is mapped.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        // Safety: The SRS file is treated as read-only for the lifetime of the mapping.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let element_size = E::G1Affine::default().serialized_size(Compress::No);
+
+        // Locate the powers of beta G, followed by the shifted powers of beta G.
+        let num_powers = Self::read_length(&mmap, 0)?;
+        let powers_offset = 8;
+        let shifted_offset = Self::skip_elements(&mmap, powers_offset, num_powers, element_size)?;
+        let num_shifted_powers = Self::read_length(&mmap, shifted_offset)?;
+        let tail_offset = Self::skip_elements(&mmap, shifted_offset + 8, num_shifted_powers, element_size)?;
+
+        // Deserialize the remaining components of the SRS.
+        let mut reader = &mmap[tail_offset..];
+        let powers_of_beta_times_gamma_g = BTreeMap::deserialize_with_mode(&mut reader, Compress::No, Validate::No)?;
+        let negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine> =
+            BTreeMap::deserialize_with_mode(&mut reader, Compress::No, Validate::No)?;
+        let beta_h = E::G2Affine::deserialize_with_mode(&mut reader, Compress::No, Validate::No)?;
+        let h = E::G2Affine::read_le(&mut reader)?;
+
+        let prepared_negative_powers_of_beta_h =
+            Arc::new(negative_powers_of_beta_h.iter().map(|(d, affine)| (*d, affine.prepare())).collect());
+
+        Ok(Self {
+            mmap: Arc::new(mmap),
+            powers_offset,
+            num_powers,
+            element_size,
+            powers_of_beta_times_gamma_g,
+            prepared_negative_powers_of_beta_h,
+            h,
+            beta_h,
+        })
+    }
+
+    /// Returns the number of contiguous powers of beta G starting from the 0-th power.
+    pub fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns the maximum degree supported by the mapped powers.
+    pub fn max_degree(&self) -> usize {
+        self.num_powers.saturating_sub(1)
+    }
+
+    /// Returns the `index`-th power of beta G.
+    pub fn power_of_beta_g(&self, index: usize) -> Result<E::G1Affine> {
+        self.powers_of_beta_g(index, index + 1).map(|powers| powers[0])
+    }
+
+    /// Returns the powers of beta G from `lower` (inclusive) to `upper` (exclusive).
+    pub fn powers_of_beta_g(&self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>> {
+        ensure!(lower <= upper, "Lower power must be less than upper power");
+        ensure!(upper <= self.num_powers, "Requested powers up to {upper}, but the SRS contains {}", self.num_powers);
+
+        let start = self.powers_offset + lower * self.element_size;
+        let end = self.powers_offset + upper * self.element_size;
+        self.mmap[start..end]
+            .chunks_exact(self.element_size)
+            .map(|mut bytes| Ok(E::G1Affine::deserialize_with_mode(&mut bytes, Compress::No, Validate::No)?))
+            .collect()
+    }
+
+    /// Returns the powers of beta * gamma G.
+    pub fn powers_of_beta_times_gamma_g(&self) -> &BTreeMap<usize, E::G1Affine> {
+        &self.powers_of_beta_times_gamma_g
+    }
+
+    /// Returns beta times the generator of G2.
+    pub fn beta_h(&self) -> E::G2Affine {
+        self.beta_h
+    }
+
+    #[cfg(feature = "prover")]
+    pub fn to_universal_prover(&self) -> Result<UniversalProver<E>> {
+        Ok(UniversalProver::<E> {
+            max_degree: self.max_degree(),
+            msm_backend: Default::default(),
+            max_threads: None,
+            low_memory: false,
+            _unused: None,
+        })
+    }
+
+    pub fn to_universal_verifier(&self) -> Result<UniversalVerifier<E>> {
+        let g = self.power_of_beta_g(0)?;
+        let gamma_g = self.powers_of_beta_times_gamma_g[&0];
+
+        Ok(UniversalVerifier {
+            vk: kzg10::VerifierKey::<E> {
+                g,
+                gamma_g,
+                h: self.h,
+                beta_h: self.beta_h,
+                prepared_h: self.h.prepare(),
+                prepared_beta_h: self.beta_h.prepare(),
+            },
+            prepared_negative_powers_of_beta_h: self.prepared_negative_powers_of_beta_h.clone(),
+        })
+    }
+
+    /// Reads the little-endian length prefix of a serialized vector at `offset`.
+    fn read_length(mmap: &Mmap, offset: usize) -> Result<usize> {
+        let bytes = mmap.get(offset..offset + 8).ok_or_else(|| anyhow!("The SRS file is truncated"))?;
+        Ok(u64::from_le_bytes(bytes.try_into()?) as usize)
+    }
+
+    /// Returns the offset after `num_elements` elements of `element_size` bytes, starting at `offset`.
+    fn skip_elements(mmap: &Mmap, offset: usize, num_elements: usize, element_size: usize) -> Result<usize> {
+        let end = num_elements
+            .checked_mul(element_size)
+            .and_then(|size| size.checked_add(offset))
+            .ok_or_else(|| anyhow!("The SRS file is malformed"))?;
+        ensure!(end <= mmap.len(), "The SRS file is truncated");
+        Ok(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::ToBytes;
+
+    #[test]
+    fn test_mmap_matches_loaded_srs() {
+        let srs = kzg10::UniversalParams::<Bls12_377>::load().unwrap();
+        let path = std::env::temp_dir().join(format!("snarkvm-mmap-srs-{}", std::process::id()));
+        std::fs::write(&path, srs.to_bytes_le().unwrap()).unwrap();
+
+        let mmap_srs = MmapUniversalSRS::<Bls12_377>::open(&path).unwrap();
+        let num_powers = 1 << 10;
+        assert_eq!(srs.powers_of_beta_g(0, num_powers).unwrap(), mmap_srs.powers_of_beta_g(0, num_powers).unwrap());
+        assert_eq!(srs.power_of_beta_g(7).unwrap(), mmap_srs.power_of_beta_g(7).unwrap());
+        assert_eq!(srs.powers_of_beta_times_gamma_g(), mmap_srs.powers_of_beta_times_gamma_g());
+        assert_eq!(srs.to_universal_verifier().unwrap().vk, mmap_srs.to_universal_verifier().unwrap().vk);
+        assert!(mmap_srs.powers_of_beta_g(0, mmap_srs.num_powers() + 1).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
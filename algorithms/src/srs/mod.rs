@@ -13,8 +13,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod contribution;
+
+pub mod light_verifier;
+pub use light_verifier::*;
+
+#[cfg(feature = "prover")]
 pub mod universal_prover;
+#[cfg(feature = "prover")]
 pub use universal_prover::*;
 
 pub mod universal_verifier;
 pub use universal_verifier::*;
+
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
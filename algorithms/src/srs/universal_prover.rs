@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::msm::MSMBackend;
 use snarkvm_curves::PairingEngine;
 
 /// `UniversalProver` is used to compute evaluation proofs for a given commitment.
@@ -20,5 +21,33 @@ use snarkvm_curves::PairingEngine;
 pub struct UniversalProver<E: PairingEngine> {
     /// The maximum degree supported by the universal SRS.
     pub max_degree: usize,
+    /// The backend used to compute the MSMs in commitments and evaluation proofs.
+    pub msm_backend: MSMBackend,
+    /// The maximum number of threads used to compute evaluation proofs, or all available threads if `None`.
+    pub max_threads: Option<usize>,
+    /// Whether the prover computes its independent jobs, such as the polynomials of each circuit,
+    /// one after another rather than in parallel, trading proving time for peak memory.
+    pub low_memory: bool,
     pub _unused: Option<E>,
 }
+
+impl<E: PairingEngine> UniversalProver<E> {
+    /// Returns `self` with the given MSM backend.
+    pub fn with_msm_backend(mut self, msm_backend: MSMBackend) -> Self {
+        self.msm_backend = msm_backend;
+        self
+    }
+
+    /// Returns `self` with the computation of evaluation proofs bounded to at most `max_threads` threads.
+    pub fn with_max_threads(mut self, max_threads: usize) -> Self {
+        self.max_threads = Some(max_threads);
+        self
+    }
+
+    /// Returns `self` in low-memory mode, in which the independent jobs of the prover are computed
+    /// one after another, so that only the intermediate buffers of a single job are live at a time.
+    pub fn with_low_memory(mut self, low_memory: bool) -> Self {
+        self.low_memory = low_memory;
+        self
+    }
+}
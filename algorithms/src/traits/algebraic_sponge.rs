@@ -67,6 +67,15 @@ pub trait AlgebraicSponge<F: PrimeField, const RATE: usize>: Clone + Debug {
         self.absorb_native_field_elements(&elements);
     }
 
+    /// Takes in a domain separation tag.
+    ///
+    /// The tag is prefixed with its length plus one, so that distinct sequences of tags are never
+    /// absorbed identically, and even an empty tag changes the state of the sponge.
+    fn absorb_domain(&mut self, domain: &str) {
+        self.absorb_native_field_elements(&[F::from(domain.len() as u64 + 1)]);
+        self.absorb_bytes(domain.as_bytes());
+    }
+
     /// Takes in field elements.
     fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]>;
 
@@ -43,9 +43,11 @@ pub trait SNARK {
         + Send
         + Sync;
     type Proof: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Send + Sync;
+    #[cfg(feature = "prover")]
     type ProvingKey: Clone + ToBytes + FromBytes + Send + Sync + Ord;
 
     type UniversalSRS: Clone;
+    #[cfg(feature = "prover")]
     type UniversalProver;
     type UniversalVerifier;
 
@@ -55,13 +57,16 @@ pub trait SNARK {
     type FiatShamirRng: AlgebraicSponge<Self::BaseField, 2, Parameters = Self::FSParameters>;
     type FSParameters;
 
+    #[cfg(feature = "prover")]
     fn universal_setup(config: usize) -> Result<Self::UniversalSRS>;
 
+    #[cfg(feature = "prover")]
     fn circuit_setup<C: ConstraintSynthesizer<Self::ScalarField>>(
         srs: &Self::UniversalSRS,
         circuit: &C,
     ) -> Result<(Self::ProvingKey, Self::VerifyingKey)>;
 
+    #[cfg(feature = "prover")]
     fn prove_vk(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -69,6 +74,7 @@ pub trait SNARK {
         proving_key: &Self::ProvingKey,
     ) -> Result<Self::Certificate>;
 
+    #[cfg(feature = "prover")]
     fn prove<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -81,6 +87,7 @@ pub trait SNARK {
         Self::prove_batch(universal_prover, fs_parameters, &keys_to_constraints, rng)
     }
 
+    #[cfg(feature = "prover")]
     fn prove_batch<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -115,4 +122,23 @@ pub trait SNARK {
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool>;
+
+    /// Verifies many proofs for the same verifying key, each for its own input.
+    ///
+    /// Implementations may combine the checks of the proofs with randomness sampled from `rng`,
+    /// which must be unknown to the provers. By default, each proof is verified on its own.
+    fn verify_many<B: Borrow<Self::VerifierInput>, R: Rng + CryptoRng>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        inputs_and_proofs: &[(B, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool> {
+        for (input, proof) in inputs_and_proofs {
+            if !Self::verify(universal_verifier, fs_parameters, verifying_key, input.borrow(), proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -31,5 +31,8 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 pub mod traits;
 pub use traits::*;
@@ -42,7 +42,7 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Absorbs the input elements into state.
     #[inline]
-    fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
+    pub(super) fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
         if !input.is_empty() {
             // Determine the absorb index.
             let (mut absorb_index, should_permute) = match *mode {
@@ -88,7 +88,12 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Squeeze the specified number of state elements into the output.
     #[inline]
-    fn squeeze(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, num_outputs: u16) -> Vec<Field<E>> {
+    pub(super) fn squeeze(
+        &self,
+        state: &mut [Field<E>],
+        mode: &mut DuplexSpongeMode,
+        num_outputs: u16,
+    ) -> Vec<Field<E>> {
         let mut output = vec![Field::zero(); num_outputs as usize];
         if num_outputs != 0 {
             self.squeeze_internal(state, mode, &mut output);
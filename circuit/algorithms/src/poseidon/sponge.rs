@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A duplex sponge over the Poseidon permutation, which absorbs and squeezes field elements in any order.
+///
+/// Unlike `hash_many`, the sponge does not add a domain separator or the length of the input,
+/// so callers that absorb variable-length data must absorb their own domain separator and lengths.
+#[derive(Clone)]
+pub struct PoseidonSponge<'a, E: Environment, const RATE: usize> {
+    /// The Poseidon parameters.
+    poseidon: &'a Poseidon<E, RATE>,
+    /// The current state of the sponge.
+    state: Vec<Field<E>>,
+    /// Whether the sponge is absorbing or squeezing.
+    mode: DuplexSpongeMode,
+}
+
+impl<'a, E: Environment, const RATE: usize> PoseidonSponge<'a, E, RATE> {
+    /// Initializes a new sponge with an all-zero state.
+    pub fn new(poseidon: &'a Poseidon<E, RATE>) -> Self {
+        Self {
+            poseidon,
+            state: vec![Field::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Absorbs the given elements into the sponge.
+    pub fn absorb(&mut self, input: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, input)
+    }
+
+    /// Squeezes the given number of elements from the sponge.
+    pub fn squeeze(&mut self, num_outputs: u16) -> Vec<Field<E>> {
+        self.poseidon.squeeze(&mut self.state, &mut self.mode, num_outputs)
+    }
+}
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns a new sponge over the Poseidon permutation.
+    pub fn sponge(&self) -> PoseidonSponge<E, RATE> {
+        PoseidonSponge::new(self)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const RATE: u16 = 4;
+
+    #[test]
+    fn test_sponge_matches_hash_many() -> Result<()> {
+        use console::HashMany as H;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, { RATE as usize }>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, { RATE as usize }>::constant(native.clone());
+
+        for num_inputs in 0..=(2 * RATE as usize) {
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            let num_outputs = 2 * RATE + 1;
+            let expected = native.hash_many(&native_input, num_outputs);
+
+            // Absorb the preimage of `hash_many` one element at a time, and squeeze in uneven chunks.
+            let mut sponge = poseidon.sponge();
+            sponge.absorb(&[poseidon.domain.clone()]);
+            sponge.absorb(&[Field::constant(console::Field::from_u128(num_inputs as u128))]);
+            for _ in 2..RATE {
+                sponge.absorb(&[Field::zero()]);
+            }
+            for element in &input {
+                sponge.absorb(&[element.clone()]);
+            }
+            let mut candidate = sponge.squeeze(1);
+            candidate.extend(sponge.squeeze(RATE));
+            candidate.extend(sponge.squeeze(num_outputs - RATE - 1));
+
+            assert_eq!(expected.len(), candidate.len());
+            for (expected_element, candidate_element) in expected.iter().zip(&candidate) {
+                assert_eq!(*expected_element, candidate_element.eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
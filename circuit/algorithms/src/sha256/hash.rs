@@ -0,0 +1,201 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The hash value `H` is initialized to `H(0)`.
+        let mut state = Self::INITIAL_HASH.iter().map(|word| Self::constant_word(*word)).collect::<Vec<_>>();
+
+        // Process the padded message, one block at a time.
+        for block in Self::pad(input).chunks(BLOCK_SIZE) {
+            state = self.compress(&state, block);
+        }
+
+        // Return the hash value as big-endian words.
+        state.iter().flat_map(|word| Self::word_to_bytes_be(word)).collect()
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// Returns the padded message, defined as `pad(M) = M || 0x80 || 0x00…0x00 || LENGTH(M)`,
+    /// where `LENGTH(M)` is the 64-bit big-endian length of `M` in bits.
+    /// The padding extends the message to a multiple of the block size.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+        let length = padded_input.len() as u64;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend(Self::constant_byte(0x80));
+
+        // Step 2: Append "0x00" bytes until the length of the message is congruent to 448 mod 512.
+        while padded_input.len() % BLOCK_SIZE != BLOCK_SIZE - 64 {
+            padded_input.extend(Self::constant_byte(0x00));
+        }
+
+        // Step 3: Append the length of the message as a 64-bit big-endian integer.
+        for byte in length.to_be_bytes() {
+            padded_input.extend(Self::constant_byte(byte));
+        }
+        padded_input
+    }
+
+    /// Returns the hash value after compressing the given block into the given hash value.
+    fn compress(&self, state: &[Vec<Boolean<E>>], block: &[Boolean<E>]) -> Vec<Vec<Boolean<E>>> {
+        // Prepare the message schedule `W`.
+        let mut w = block.chunks(WORD_SIZE).map(Self::word_from_bytes_be).collect::<Vec<_>>();
+        for t in 16..NUM_ROUNDS {
+            // W[t] = σ1(W[t-2]) + W[t-7] + σ0(W[t-15]) + W[t-16]
+            let sigma_1 = Self::xor3(&Self::rotr(&w[t - 2], 17), &Self::rotr(&w[t - 2], 19), &Self::shr(&w[t - 2], 10));
+            let sigma_0 =
+                Self::xor3(&Self::rotr(&w[t - 15], 7), &Self::rotr(&w[t - 15], 18), &Self::shr(&w[t - 15], 3));
+            w.push(Self::add(&[&sigma_1, &w[t - 7], &sigma_0, &w[t - 16]], 0));
+        }
+
+        // Initialize the working variables with the hash value.
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = match <[_; 8]>::try_from(state.to_vec()) {
+            Ok(state) => state,
+            Err(_) => E::halt("The SHA-256 state must consist of 8 words"),
+        };
+
+        for (word, round_constant) in w.iter().zip_eq(Self::ROUND_CONSTANTS) {
+            // T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+            let sum_1 = Self::xor3(&Self::rotr(&e, 6), &Self::rotr(&e, 11), &Self::rotr(&e, 25));
+            // Ch(e, f, g) = (e ∧ f) ⊕ (¬e ∧ g) = g ⊕ (e ∧ (f ⊕ g))
+            let choice = g.iter().zip_eq(&e).zip_eq(&f).map(|((g, e), f)| g ^ (e & (f ^ g))).collect::<Vec<_>>();
+            let t_1 = Self::add(&[&h, &sum_1, &choice, word], round_constant);
+
+            // T2 = Σ0(a) + Maj(a, b, c)
+            let sum_0 = Self::xor3(&Self::rotr(&a, 2), &Self::rotr(&a, 13), &Self::rotr(&a, 22));
+            // Maj(a, b, c) = (a ∧ b) ⊕ (a ∧ c) ⊕ (b ∧ c) = (a ∧ b) ⊕ (c ∧ (a ⊕ b))
+            let majority =
+                a.iter().zip_eq(&b).zip_eq(&c).map(|((a, b), c)| (a & b) ^ (c & (a ^ b))).collect::<Vec<_>>();
+
+            h = g;
+            g = f;
+            f = e;
+            e = Self::add(&[&d, &t_1], 0);
+            d = c;
+            c = b;
+            b = a;
+            a = Self::add(&[&t_1, &sum_0, &majority], 0);
+        }
+
+        // Add the working variables to the hash value.
+        [a, b, c, d, e, f, g, h].iter().zip_eq(state).map(|(variable, word)| Self::add(&[word, variable], 0)).collect()
+    }
+
+    /// Returns the sum of the given words and constant, modulo `2^32`.
+    fn add(words: &[&Vec<Boolean<E>>], constant: u32) -> Vec<Boolean<E>> {
+        // Sum the words in the base field, which cannot overflow as there are only a few 32-bit words.
+        let mut sum = Field::constant(console::Field::from_u64(constant as u64));
+        for word in words {
+            sum += Field::from_bits_le(word);
+        }
+
+        // Extract the lower 32 bits of the sum, including enough bits for the carry.
+        let num_terms = words.len() as u64 + 1;
+        let num_carry_bits = num_terms.next_power_of_two().trailing_zeros() as usize;
+        let mut bits_le = sum.to_lower_bits_le(WORD_SIZE + num_carry_bits);
+        bits_le.truncate(WORD_SIZE);
+        bits_le
+    }
+
+    /// Returns `a ⊕ b ⊕ c`.
+    fn xor3(a: &[Boolean<E>], b: &[Boolean<E>], c: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        a.iter().zip_eq(b).zip_eq(c).map(|((a, b), c)| (a ^ b) ^ c).collect()
+    }
+
+    /// Returns the word rotated right by `n` bits.
+    fn rotr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..WORD_SIZE).map(|i| word[(i + n) % WORD_SIZE].clone()).collect()
+    }
+
+    /// Returns the word shifted right by `n` bits.
+    fn shr(word: &[Boolean<E>], n: usize) -> Vec<Boolean<E>> {
+        (0..WORD_SIZE).map(|i| word.get(i + n).cloned().unwrap_or_else(|| Boolean::constant(false))).collect()
+    }
+
+    /// Returns the little-endian bits of the word from its big-endian bytes.
+    fn word_from_bytes_be(bytes: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        bytes.chunks(8).rev().flatten().cloned().collect()
+    }
+
+    /// Returns the big-endian bytes of the word from its little-endian bits.
+    fn word_to_bytes_be(word: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        word.chunks(8).rev().flatten().cloned().collect()
+    }
+
+    /// Returns the word as constant little-endian bits.
+    fn constant_word(word: u32) -> Vec<Boolean<E>> {
+        (0..WORD_SIZE).map(|i| Boolean::constant((word >> i) & 1 == 1)).collect()
+    }
+
+    /// Returns the byte as constant little-endian bits.
+    fn constant_byte(byte: u8) -> Vec<Boolean<E>> {
+        (0..8).map(|i| Boolean::constant((byte >> i) & 1 == 1)).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Hash as H;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    #[test]
+    fn test_sha256_abc() {
+        // Reference value from FIPS 180-4.
+        let input = b"abc".iter().flat_map(|byte| Sha256::<Circuit>::constant_byte(*byte)).collect::<Vec<_>>();
+        let candidate = Sha256::<Circuit>::new().hash(&input);
+
+        let expected = console::Sha256.hash_bytes(b"abc");
+        assert_eq!(snarkvm_utilities::bits_from_bytes_le(&expected).collect::<Vec<_>>(), candidate.eject_value());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_sha256_equivalence() {
+        let mut rng = TestRng::default();
+
+        // Check inputs that are not whole bytes, and inputs around the boundary of a second block.
+        for num_inputs in [0, 1, 8, 100, 440, 447, 448, 512, 1000] {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<bool>>();
+
+            // Compute the native hash.
+            let expected = console::Sha256.hash(&native_input).unwrap();
+
+            // Compute the circuit hash.
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+                Circuit::scope(format!("SHA-256 {mode} {num_inputs}"), || {
+                    let candidate = Sha256::<Circuit>::new().hash(&input);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+}
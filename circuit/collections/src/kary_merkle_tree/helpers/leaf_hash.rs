@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_circuit_algorithms::{BHP, Hash, Keccak, Poseidon};
+use snarkvm_circuit_algorithms::{BHP, Hash, Keccak, Poseidon, Sha256};
 
 /// A trait for a Merkle leaf hash function.
 pub trait LeafHash {
@@ -74,6 +74,25 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> LeafHash for Keccak<E
     }
 }
 
+impl<E: Environment> LeafHash for Sha256<E> {
+    type Hash = BooleanHash<E, 256>;
+    type Leaf = Vec<Boolean<E>>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Self::Hash {
+        let mut input = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `false` bit.
+        input.push(Boolean::constant(false));
+        input.extend_from_slice(leaf);
+        // Hash the input.
+        let output = Hash::hash(self, &input);
+        // Read the 256 bits.
+        let mut result = BooleanHash::default();
+        result.0.clone_from_slice(&output);
+        result
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_circuit_algorithms::{BHP, Hash, Keccak, Poseidon};
+use snarkvm_circuit_algorithms::{BHP, Hash, Keccak, Poseidon, Sha256};
 
 /// A trait for a Merkle path hash function.
 pub trait PathHash<E: Environment> {
@@ -91,6 +91,27 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> PathHash<E> for Kecca
     }
 }
 
+impl<E: Environment> PathHash<E> for Sha256<E> {
+    type Hash = BooleanHash<E, 256>;
+    type Primitive = console::algorithms::Sha256;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, children: &[Self::Hash]) -> Self::Hash {
+        let mut input = Vec::new();
+        // Prepend the nodes with a `true` bit.
+        input.push(Boolean::constant(true));
+        for child in children {
+            child.write_bits_le(&mut input);
+        }
+        // Hash the input.
+        let output = Hash::hash(self, &input);
+        // Read the 256 bits.
+        let mut result = BooleanHash::default();
+        result.0.clone_from_slice(&output);
+        result
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -106,7 +106,7 @@ impl<E: Environment, PH: PathHash<E>, const DEPTH: u8, const ARITY: u8> KaryMerk
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
-    use snarkvm_circuit_algorithms::{BHP512, BHP1024, Keccak256, Poseidon2, Poseidon4, Sha3_256};
+    use snarkvm_circuit_algorithms::{BHP512, BHP1024, Keccak256, Poseidon2, Poseidon4, Sha3_256, Sha256};
     use snarkvm_circuit_types::environment::Circuit;
     use snarkvm_utilities::{TestRng, Uniform};
 
@@ -331,4 +331,58 @@ mod tests {
     fn test_verify_sha3_256_private() -> Result<()> {
         check_verify_keccak!(Sha3_256, Sha3_256, Private, 10, 4, 256, (7648, 0, 1696439, 1696519))
     }
+
+    fn check_verify_sha256<const DEPTH: u8, const ARITY: u8>(mode: Mode) -> Result<()> {
+        let native_hasher = snarkvm_console_algorithms::Sha256;
+        let circuit_hasher = Sha256::<Circuit>::new();
+
+        let mut rng = TestRng::default();
+
+        // Compute the leaves.
+        let num_leaves = ARITY as usize + 1;
+        let leaves =
+            (0..num_leaves).map(|_| (0..256).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        // Compute the Merkle tree.
+        let merkle_tree = console::kary_merkle_tree::KaryMerkleTree::<_, _, DEPTH, ARITY>::new(
+            &native_hasher,
+            &native_hasher,
+            &leaves,
+        )?;
+
+        for (index, merkle_leaf) in leaves.iter().enumerate() {
+            // Initialize the Merkle path.
+            let merkle_path = merkle_tree.prove(index, merkle_leaf)?;
+            let path = KaryMerklePath::<Circuit, Sha256<Circuit>, DEPTH, ARITY>::new(mode, merkle_path.clone());
+            assert_eq!(merkle_path, path.eject_value());
+
+            // Initialize the Merkle root and leaf.
+            let root = <Sha256<Circuit> as PathHash<Circuit>>::Hash::new(mode, *merkle_tree.root());
+            let leaf: Vec<_> = Inject::new(mode, merkle_leaf.clone());
+
+            Circuit::scope(format!("Verify {mode}"), || {
+                let candidate = path.verify(&circuit_hasher, &circuit_hasher, &root, &leaf);
+                assert!(candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Initialize an incorrect Merkle root.
+            let incorrect_root = <Sha256<Circuit> as PathHash<Circuit>>::Hash::new(mode, Default::default());
+
+            Circuit::scope(format!("Verify (Incorrect Root) {mode}"), || {
+                let candidate = path.verify(&circuit_hasher, &circuit_hasher, &incorrect_root, &leaf);
+                assert!(!candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_sha256() -> Result<()> {
+        check_verify_sha256::<3, 2>(Mode::Private)?;
+        check_verify_sha256::<2, 4>(Mode::Private)?;
+        check_verify_sha256::<2, 8>(Mode::Public)
+    }
 }
@@ -24,6 +24,30 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         root: &PH::Hash,
         leaf: &LH::Leaf,
     ) -> Boolean<E> {
+        // Compute the leaf hash to start.
+        let leaf_hash = leaf_hasher.hash_leaf(leaf);
+        // Ensure the final hash matches the given root.
+        root.is_equal(&self.compute_root(path_hasher, leaf_hash))
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and the empty leaf.
+    ///
+    /// In a sparse Merkle tree, where every unset leaf holds the empty hash, this proves
+    /// that no leaf is set at the leaf index of the path. The caller is responsible for
+    /// binding the leaf index to the key being checked.
+    pub fn verify_non_membership<PH: PathHash<E, Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &PH::Hash,
+    ) -> Boolean<E> {
+        // Compute the empty hash to start.
+        let empty_hash = path_hasher.hash_empty();
+        // Ensure the final hash matches the given root.
+        root.is_equal(&self.compute_root(path_hasher, empty_hash))
+    }
+
+    /// Returns the Merkle root obtained by hashing the given leaf hash along the path.
+    fn compute_root<PH: PathHash<E, Hash = Field<E>>>(&self, path_hasher: &PH, leaf_hash: Field<E>) -> Field<E> {
         // Ensure the leaf index is within the tree depth.
         if (*self.leaf_index.eject_value() as u128) >= (1u128 << DEPTH) {
             E::halt("Found an out of bounds Merkle leaf index")
@@ -33,8 +57,8 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
             E::halt("Found an incorrect Merkle path length")
         }
 
-        // Initialize a tracker for the current hash, by computing the leaf hash to start.
-        let mut current_hash = leaf_hasher.hash_leaf(leaf);
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash;
 
         // Compute the ordering of the current hash and sibling hash on each level.
         // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
@@ -51,8 +75,7 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
             current_hash = path_hasher.hash_children(&left, &right);
         }
 
-        // Ensure the final hash matches the given root.
-        root.is_equal(&current_hash)
+        current_hash
     }
 }
 
@@ -174,4 +197,70 @@ mod tests {
     fn test_verify_poseidon2_private() -> Result<()> {
         check_verify!(Poseidon4, Poseidon2, Private, 32, 4, (33, 0, 18046, 18046))
     }
+
+    fn check_verify_non_membership(mode: Mode) -> Result<()> {
+        const DEPTH: u8 = 4;
+
+        // Initialize the hashers.
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon4::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::Network>::setup(DOMAIN)?;
+        let circuit_path_hasher = Poseidon2::<Circuit>::constant(native_path_hasher.clone());
+
+        let mut rng = TestRng::default();
+
+        // Compute the leaf hashes, setting every third leaf and leaving the rest as the empty hash.
+        let empty_hash = console::merkle_tree::PathHash::hash_empty(&native_path_hasher)?;
+        let is_set = |index: usize| index % 3 == 0;
+        let mut level = (0..1usize << DEPTH)
+            .map(|index| match is_set(index) {
+                true => {
+                    let leaf = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+                    console::merkle_tree::LeafHash::hash_leaf(&native_leaf_hasher, &leaf)
+                }
+                false => Ok(empty_hash),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the levels of the sparse Merkle tree, from the leaf hashes to the root.
+        let mut levels = vec![level.clone()];
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| console::merkle_tree::PathHash::hash_children(&native_path_hasher, &pair[0], &pair[1]))
+                .collect::<Result<Vec<_>>>()?;
+            levels.push(level.clone());
+        }
+        let root = levels[DEPTH as usize][0];
+
+        for index in 0..1usize << DEPTH {
+            // Compute the Merkle path from the sibling on each level.
+            let siblings = (0..DEPTH as usize).map(|depth| levels[depth][(index >> depth) ^ 1]).collect();
+            let merkle_path = console::merkle_tree::MerklePath::<<Circuit as Environment>::Network, DEPTH>::try_from(
+                (console::U64::new(index as u64), siblings),
+            )?;
+            assert_eq!(!is_set(index), merkle_path.verify_non_membership(&native_path_hasher, &root));
+
+            // Initialize the Merkle path and root.
+            let path = MerklePath::<Circuit, DEPTH>::new(mode, merkle_path);
+            let circuit_root = Field::new(mode, root);
+
+            Circuit::scope(format!("Verify Non-Membership {mode}"), || {
+                let candidate = path.verify_non_membership(&circuit_path_hasher, &circuit_root);
+                assert_eq!(!is_set(index), candidate.eject_value());
+                assert_eq!(index as u64, *path.leaf_index().eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_non_membership() -> Result<()> {
+        check_verify_non_membership(Mode::Constant)?;
+        check_verify_non_membership(Mode::Public)?;
+        check_verify_non_membership(Mode::Private)
+    }
 }
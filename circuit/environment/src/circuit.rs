@@ -13,12 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Mode, helpers::Constraint, *};
+use crate::{
+    Mode,
+    helpers::{Constraint, LookupConstraint},
+    *,
+};
 
 use core::{
     cell::{Cell, RefCell},
     fmt,
 };
+use std::io::{Result as IoResult, Write};
 
 type Field = <console::MainnetV0 as console::Environment>::Field;
 
@@ -114,6 +119,16 @@ impl Environment for Circuit {
     //     })
     // }
 
+    /// Records the positions of a public input in the public input layout.
+    fn label_public_input(input: PublicInput) {
+        CIRCUIT.with(|circuit| circuit.borrow_mut().label_public_input(input))
+    }
+
+    /// Returns the public inputs in the order of the verifier's input vector, starting with the `One` variable.
+    fn public_input_layout() -> Vec<PublicInput> {
+        CIRCUIT.with(|circuit| circuit.borrow().public_input_layout())
+    }
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -201,6 +216,93 @@ impl Environment for Circuit {
         })
     }
 
+    /// Adds a lookup table with the given rows of `(a, b, c)` triples, and returns its index.
+    fn add_lookup_table(rows: Vec<(Self::BaseField, Self::BaseField, Self::BaseField)>) -> TableIndex {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| circuit.borrow_mut().add_lookup_table(rows))
+            } else {
+                Self::halt("Tried to add a new lookup table in witness mode")
+            }
+        })
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the given table.
+    fn enforce_lookup<A, B, C>(a: A, b: B, c: C, table: TableIndex)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| {
+                    // Ensure the table exists.
+                    if table.index() as u64 >= circuit.borrow().num_lookup_tables() {
+                        Self::halt(format!("Tried to look up a row in a missing {table}"))
+                    }
+
+                    let (a, b, c) = (a.into(), b.into(), c.into());
+
+                    // Ensure the lookup constraint is not comprised of constants.
+                    match a.is_constant() && b.is_constant() && c.is_constant() {
+                        true => {
+                            // Evaluate the constant lookup constraint.
+                            let row = (a.value(), b.value(), c.value());
+                            assert!(
+                                circuit.borrow().to_lookup_tables()[table.index()].contains(&row),
+                                "Constant lookup constraint failed: ({a}, {b}, {c}) is not in {table}"
+                            );
+                        }
+                        false => {
+                            // Construct the lookup constraint object.
+                            let constraint = LookupConstraint(circuit.borrow().scope(), a, b, c, table);
+                            // Append the lookup constraint.
+                            circuit.borrow_mut().enforce_lookup(constraint)
+                        }
+                    }
+                });
+            } else {
+                Self::halt("Tried to add a new lookup constraint in witness mode")
+            }
+        })
+    }
+
+    /// Returns a checkpoint of the current size of the environment.
+    fn checkpoint() -> Checkpoint {
+        CIRCUIT.with(|circuit| circuit.borrow().checkpoint())
+    }
+
+    /// Removes the variables and constraints added after the given checkpoint.
+    fn rollback(checkpoint: Checkpoint) {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| {
+                    if let Err(error) = circuit.borrow_mut().rollback(checkpoint) {
+                        Self::halt(error)
+                    }
+                })
+            } else {
+                Self::halt("Tried to roll back to a checkpoint in witness mode")
+            }
+        })
+    }
+
+    /// Removes every constraint that is identical to an earlier one, and returns the number of removed constraints.
+    fn deduplicate_constraints() -> u64 {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                CIRCUIT.with(|circuit| circuit.borrow_mut().deduplicate_constraints())
+            } else {
+                Self::halt("Tried to deduplicate constraints in witness mode")
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
@@ -211,6 +313,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
     }
 
+    /// Returns every constraint in the environment that is not satisfied, with the scope it was added in.
+    fn unsatisfied_constraints() -> Vec<Unsatisfied<Self::BaseField>> {
+        CIRCUIT.with(|circuit| circuit.borrow().unsatisfied_constraints())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         CIRCUIT.with(|circuit| circuit.borrow().num_constants())
@@ -241,6 +348,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| circuit.borrow().num_nonzeros())
     }
 
+    /// Returns a hash of the structure of the constraint system, which does not depend on the values of the variables.
+    fn fingerprint() -> [u8; 32] {
+        CIRCUIT.with(|circuit| circuit.borrow().fingerprint())
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64 {
         CIRCUIT.with(|circuit| circuit.borrow().num_constants_in_scope())
@@ -286,6 +398,16 @@ impl Environment for Circuit {
         CONSTRAINT_LIMIT.with(|current_limit| current_limit.replace(limit));
     }
 
+    /// Returns the circuit size of each scope, if profiling is enabled.
+    fn get_profile() -> Option<Profile> {
+        CIRCUIT.with(|circuit| circuit.borrow().profile().cloned())
+    }
+
+    /// Enables or disables profiling of the circuit size of each scope.
+    fn set_profiling(enabled: bool) {
+        CIRCUIT.with(|circuit| circuit.borrow_mut().set_profiling(enabled))
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -356,6 +478,15 @@ impl Environment for Circuit {
         })
     }
 
+    /// Writes the constraint system and its assignment in the circom `.r1cs` and `.wtns` binary formats.
+    fn export_circom<R: Write, W: Write>(r1cs_writer: R, wtns_writer: W) -> IoResult<()> {
+        CIRCUIT.with(|circuit| {
+            let circuit = circuit.borrow();
+            circuit.write_circom_r1cs(r1cs_writer)?;
+            circuit.write_circom_wtns(wtns_writer)
+        })
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         CIRCUIT.with(|circuit| {
@@ -431,4 +562,177 @@ mod tests {
             assert_eq!(0, Circuit::num_constraints_in_scope());
         })
     }
+
+    #[test]
+    fn test_enforce_lookup() {
+        type F = <Circuit as Environment>::BaseField;
+
+        // Construct a table of `(x, y, x * y)` for small `x` and `y`.
+        let rows = (0..4u64).flat_map(|x| (0..4u64).map(move |y| (F::from(x), F::from(y), F::from(x * y)))).collect();
+        let table = Circuit::add_lookup_table(rows);
+
+        let a = Circuit::new_variable(Mode::Private, F::from(2u64));
+        let b = Circuit::new_variable(Mode::Private, F::from(3u64));
+        let c = Circuit::new_variable(Mode::Private, F::from(6u64));
+        Circuit::enforce_lookup(a.clone(), b.clone(), c, table);
+        assert!(Circuit::is_satisfied());
+
+        let d = Circuit::new_variable(Mode::Private, F::from(5u64));
+        Circuit::enforce_lookup(a, b, d, table);
+        assert!(!Circuit::is_satisfied());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_profile() {
+        assert!(Circuit::get_profile().is_none());
+        Circuit::set_profiling(true);
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        Circuit::scope("outer", || {
+            let b = Field::<Circuit>::new(Mode::Private, one);
+            Circuit::scope("inner", || {
+                let _c = &a * &b;
+            });
+        });
+
+        let profile = Circuit::get_profile().unwrap();
+        assert_eq!(1, profile.scopes()[""].private);
+        assert_eq!(1, profile.scopes()["outer"].private);
+        assert_eq!(1, profile.scopes()["outer.inner"].private);
+        assert_eq!(1, profile.scopes()["outer.inner"].constraints);
+        assert_eq!("circuit;outer;inner 1\n", profile.to_collapsed_stacks(ProfileMetric::Constraints));
+
+        // Ensure resetting the circuit stops profiling.
+        Circuit::reset();
+        assert!(Circuit::get_profile().is_none());
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let expected = Circuit::count();
+
+        let checkpoint = Circuit::checkpoint();
+        Circuit::scope("speculative", || {
+            let b = Field::<Circuit>::new(Mode::Public, one + one);
+            let _c = &a * &b;
+        });
+        // Add an unsatisfied constraint.
+        Circuit::assert_eq(&a, Field::<Circuit>::new(Mode::Private, one + one));
+        assert!(!Circuit::is_satisfied());
+
+        Circuit::rollback(checkpoint);
+        assert_eq!(expected, Circuit::count());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure new variables can be added after the rollback.
+        let b = Field::<Circuit>::new(Mode::Private, one);
+        Circuit::assert_eq(&a, b);
+        assert!(Circuit::is_satisfied());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_deduplicate_constraints() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let b = Field::<Circuit>::new(Mode::Private, one + one);
+        let c = &a * &b;
+
+        let d = Field::<Circuit>::new(Mode::Private, one + one + one);
+
+        // Enforce the same product with its factors swapped, and the same equality twice.
+        Circuit::enforce(|| (&b, &a, &c));
+        Circuit::assert_eq(&a, Field::<Circuit>::one());
+        Circuit::assert_eq(&a, Field::<Circuit>::one());
+        // Enforce the same sum with its terms in a different order.
+        Circuit::assert_eq(&a + &b, &d);
+        Circuit::assert_eq(&b + &a, &d);
+        let (constraints, nonzeros) = (Circuit::num_constraints(), Circuit::num_nonzeros());
+
+        assert_eq!(3, Circuit::deduplicate_constraints());
+        assert_eq!(constraints - 3, Circuit::num_constraints());
+        assert!(Circuit::num_nonzeros().0 < nonzeros.0);
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the pass is idempotent.
+        assert_eq!(0, Circuit::deduplicate_constraints());
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_unsatisfied_constraints() {
+        type F = <Circuit as Environment>::BaseField;
+
+        // Enforce that each input is a boolean.
+        let logic = |inputs: &[u64]| {
+            for (i, input) in inputs.iter().enumerate() {
+                Circuit::scope(format!("input {i}"), || {
+                    let variable = LinearCombination::from(Circuit::new_variable(Mode::Private, F::from(*input)));
+                    Circuit::enforce(|| (variable.clone(), &variable - &Circuit::one(), Circuit::zero()));
+                })
+            }
+        };
+
+        logic(&[0, 5, 1, 7]);
+        let unsatisfied = Circuit::unsatisfied_constraints();
+        assert_eq!(2, unsatisfied.len());
+        assert_eq!("input 1", unsatisfied[0].scope());
+        assert_eq!("input 3", unsatisfied[1].scope());
+
+        // Ensure a single non-boolean input is kept, as it suffices for the failure.
+        let minimized = Circuit::minimize_unsatisfied(vec![0, 5, 1, 7], |_| 0, logic);
+        assert_eq!(vec![0, 0, 0, 7], minimized);
+        assert_eq!(0, Circuit::num_constraints());
+    }
+
+    #[test]
+    fn test_public_input_layout() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let _a: Field<Circuit> = Circuit::new_public_input("a", one);
+        let _b = Field::<Circuit>::new(Mode::Public, one);
+        let _c: Boolean<Circuit> = Circuit::new_public_input("c", true);
+
+        let layout = Circuit::public_input_layout();
+        assert_eq!(
+            vec![
+                PublicInput::new("one", "field", 0..1),
+                PublicInput::new("a", "field", 1..2),
+                PublicInput::new("unlabeled", "field", 2..3),
+                PublicInput::new("c", "boolean", 3..4),
+            ],
+            layout
+        );
+        Circuit::reset();
+    }
+
+    /// Adds `n` constraints in a nested scope.
+    fn add_constraints(n: usize) {
+        Circuit::scope("nested", || {
+            let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+            let a = Field::<Circuit>::new(Mode::Private, one);
+            for _ in 0..n {
+                let _b = a.square();
+            }
+        })
+    }
+
+    #[test]
+    fn test_scope_with_budget() {
+        Circuit::scope_with_budget("within_budget", 3, || add_constraints(3));
+        assert_eq!(3, Circuit::num_constraints());
+        Circuit::reset();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scope_with_budget_fails() {
+        Circuit::scope_with_budget("over_budget", 3, || add_constraints(4));
+    }
 }
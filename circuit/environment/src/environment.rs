@@ -13,11 +13,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Assignment, Inject, LinearCombination, Mode, R1CS, Variable, witness_mode};
+use crate::{
+    Assignment,
+    Checkpoint,
+    Inject,
+    LinearCombination,
+    Mode,
+    Profile,
+    PublicInput,
+    R1CS,
+    TableIndex,
+    Unsatisfied,
+    Variable,
+    minimize_failing_inputs,
+    witness_mode,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
 use core::{fmt, hash};
+use std::io::{Result as IoResult, Write};
 
 /// Attention: Do not use `Send + Sync` on this trait, as it is not thread-safe.
 pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq + hash::Hash {
@@ -56,11 +71,48 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, value: Fn) -> Output;
 
+    /// Injects a public input with the given name, and records its positions in the public input layout.
+    fn new_public_input<S: Into<String>, T: Inject + console::TypeName>(name: S, value: T::Primitive) -> T {
+        let start = Self::num_public();
+        let input = T::new(Mode::Public, value);
+        Self::label_public_input(PublicInput::new(name, T::type_name(), start..Self::num_public()));
+        input
+    }
+
+    /// Records the positions of a public input in the public input layout.
+    fn label_public_input(input: PublicInput);
+
+    /// Returns the public inputs in the order of the verifier's input vector, starting with the `One` variable.
+    fn public_input_layout() -> Vec<PublicInput>;
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
         Fn: FnOnce() -> Output;
 
+    /// Enters a new scope for the environment, and halts if the logic adds more than `max_constraints` constraints,
+    /// including those added in nested scopes.
+    fn scope_with_budget<S: Into<String>, Fn, Output>(name: S, max_constraints: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        let name = name.into();
+        let num_constraints_before = Self::num_constraints();
+        Self::scope(name.clone(), || {
+            let output = logic();
+
+            // Ensure the logic did not exceed the constraint budget.
+            let num_constraints = Self::num_constraints() - num_constraints_before;
+            if num_constraints > max_constraints {
+                Self::halt(format!(
+                    "Scope '{name}' added {num_constraints} constraints, exceeding its budget of {max_constraints}"
+                ))
+            }
+
+            output
+        })
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -105,12 +157,61 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         Self::enforce(|| (a_minus_b, multiplier, Self::one()));
     }
 
+    /// Adds a lookup table with the given rows of `(a, b, c)` triples, and returns its index.
+    fn add_lookup_table(rows: Vec<(Self::BaseField, Self::BaseField, Self::BaseField)>) -> TableIndex;
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the given table.
+    ///
+    /// Lookup constraints are checked by `is_satisfied`, but are not yet supported by the SNARK.
+    fn enforce_lookup<A, B, C>(a: A, b: B, c: C, table: TableIndex)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>;
+
+    /// Returns a checkpoint of the current size of the environment.
+    fn checkpoint() -> Checkpoint;
+
+    /// Removes the variables and constraints added after the given checkpoint.
+    ///
+    /// The checkpoint must be rolled back in the scope it was taken in, and no circuits that use
+    /// the removed variables may be used afterwards.
+    fn rollback(checkpoint: Checkpoint);
+
+    /// Removes every constraint that is identical to an earlier one, and returns the number of removed constraints.
+    ///
+    /// This is an optional pass to run once synthesis is complete. As it changes the constraint system,
+    /// it must be run both when deriving the proving and verifying keys and when proving.
+    fn deduplicate_constraints() -> u64;
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
+    /// Returns every constraint in the environment that is not satisfied, with the scope it was added in.
+    fn unsatisfied_constraints() -> Vec<Unsatisfied<Self::BaseField>>;
+
+    /// Returns a simplification of the `inputs` for which `logic` still synthesizes an unsatisfied circuit.
+    ///
+    /// Each input is either kept or replaced by `simplify(input)`, as in `minimize_failing_inputs`.
+    /// Attention: The environment is reset after each attempt, so it is empty when this returns.
+    fn minimize_unsatisfied<T, S, L>(inputs: Vec<T>, simplify: S, logic: L) -> Vec<T>
+    where
+        T: Clone + PartialEq,
+        S: Fn(&T) -> T,
+        L: Fn(&[T]),
+    {
+        Self::reset();
+        minimize_failing_inputs(inputs, simplify, |candidate| {
+            logic(candidate);
+            let is_unsatisfied = !Self::is_satisfied();
+            Self::reset();
+            is_unsatisfied
+        })
+    }
+
     /// Returns the number of constants in the entire environment.
     fn num_constants() -> u64;
 
@@ -129,6 +230,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns the number of nonzeros in the entire circuit.
     fn num_nonzeros() -> (u64, u64, u64);
 
+    /// Returns a hash of the structure of the constraint system, which does not depend on the values of the variables.
+    fn fingerprint() -> [u8; 32];
+
     /// Returns a tuple containing the number of constants, public variables, private variables, constraints, and nonzeros in the entire environment.
     fn count() -> (u64, u64, u64, u64, (u64, u64, u64)) {
         (Self::num_constants(), Self::num_public(), Self::num_private(), Self::num_constraints(), Self::num_nonzeros())
@@ -172,6 +276,12 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Sets the constraint limit for the circuit.
     fn set_constraint_limit(limit: Option<u64>);
 
+    /// Returns the circuit size of each scope, if profiling is enabled.
+    fn get_profile() -> Option<Profile>;
+
+    /// Enables or disables profiling of the circuit size of each scope.
+    fn set_profiling(enabled: bool);
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         <Self::Network as console::Environment>::halt(message)
@@ -186,6 +296,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns the R1CS assignment of the circuit, resetting the circuit.
     fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field>;
 
+    /// Writes the constraint system and its assignment in the circom `.r1cs` and `.wtns` binary formats.
+    fn export_circom<R: Write, W: Write>(r1cs_writer: R, wtns_writer: W) -> IoResult<()>;
+
     /// Clears and initializes an empty environment.
     fn reset();
 }
@@ -0,0 +1,124 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports the constraint system as a customizable constraint system (CCS) in JSON.
+//!
+//! A CCS over `z = (1, x, w)` is satisfied if `sum_i c_i * hadamard_{j in S_i} (M_j * z) = 0`.
+//! An R1CS is the CCS with `M = (A, B, C)`, `S = ({0, 1}, {2})`, and `c = (1, -1)`.
+//!
+//! The document has the following fields, where field elements are decimal strings:
+//!   - `format`: always `"ccs"`, and `version`: always `1`.
+//!   - `field_modulus`: the modulus of the field.
+//!   - `num_rows`: the number of constraints.
+//!   - `num_columns`: the length of `z`, which is the number of public and private variables.
+//!   - `num_public`: the length of `x`, which excludes the leading `1`.
+//!   - `matrices`: the sparse matrices `M_j`, as lists of `[row, column, value]` entries.
+//!   - `multisets`: the multisets `S_i`, as lists of matrix indices.
+//!   - `constants`: the constants `c_i`.
+//!   - `assignment`: the values of `z`.
+
+use crate::{LinearCombination, R1CS, Variable};
+use snarkvm_fields::PrimeField;
+
+use serde_json::{Value, json};
+
+impl<F: PrimeField> R1CS<F> {
+    /// Returns the constraint system and its assignment as a CCS JSON document.
+    pub fn to_ccs_json(&self) -> Result<Value, String> {
+        // Ensure the constraint system has no lookup constraints, as a CCS cannot express them.
+        if self.num_lookup_constraints() > 0 {
+            return Err("Lookup constraints cannot be exported to a CCS".to_string());
+        }
+
+        let num_public = self.num_public();
+
+        // Construct the sparse matrices `A`, `B`, and `C`.
+        let mut matrices = [Vec::new(), Vec::new(), Vec::new()];
+        for (row, constraint) in self.to_constraints().iter().enumerate() {
+            let (a, b, c) = constraint.to_terms();
+            for (matrix, lc) in matrices.iter_mut().zip([a, b, c]) {
+                for (column, value) in to_entries(lc, num_public)? {
+                    matrix.push(json!([row, column, value.to_string()]));
+                }
+            }
+        }
+
+        // Construct the assignment, which begins with the `One` variable.
+        let assignment = self
+            .to_public_variables()
+            .iter()
+            .chain(self.to_private_variables())
+            .map(|variable| variable.value().to_string())
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "format": "ccs",
+            "version": 1,
+            "field_modulus": F::modulus().to_string(),
+            "num_rows": self.num_constraints(),
+            "num_columns": num_public + self.num_private(),
+            "num_public": num_public - 1,
+            "matrices": matrices,
+            "multisets": [[0, 1], [2]],
+            "constants": [F::one().to_string(), (-F::one()).to_string()],
+            "assignment": assignment,
+        }))
+    }
+}
+
+/// Returns the nonzero entries of the linear combination as `(column, value)` pairs,
+/// where the constant is the value of the column of the `One` variable.
+fn to_entries<F: PrimeField>(lc: &LinearCombination<F>, num_public: u64) -> Result<Vec<(u64, F)>, String> {
+    let constant = lc.to_constant();
+
+    let mut entries = Vec::with_capacity(lc.to_terms().len() + 1);
+    if !constant.is_zero() {
+        entries.push((0, constant));
+    }
+    for (variable, coefficient) in lc.to_terms() {
+        let column = match variable {
+            Variable::Constant(_) => return Err("Linear combinations must not contain constant terms".to_string()),
+            Variable::Public(index_value) => index_value.0,
+            Variable::Private(index_value) => num_public + index_value.0,
+        };
+        entries.push((column, *coefficient));
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_to_ccs_json() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Public, one);
+        let b = Field::<Circuit>::new(Mode::Private, one + one);
+        let _c = a * b;
+        assert!(Circuit::is_satisfied());
+
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        let ccs = r1cs.to_ccs_json().unwrap();
+
+        assert_eq!("ccs", ccs["format"]);
+        assert_eq!(r1cs.num_constraints(), ccs["num_rows"]);
+        assert_eq!(r1cs.num_public() + r1cs.num_private(), ccs["num_columns"]);
+        assert_eq!(r1cs.num_public() - 1, ccs["num_public"]);
+        assert_eq!(3, ccs["matrices"].as_array().unwrap().len());
+        assert_eq!("1", ccs["assignment"][0]);
+        assert_eq!("2", ccs["assignment"][r1cs.num_public() as usize]);
+    }
+}
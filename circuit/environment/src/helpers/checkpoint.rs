@@ -0,0 +1,44 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Profile, Scope};
+
+/// A snapshot of the size of the environment, which the environment can be rolled back to.
+///
+/// A checkpoint must be rolled back in the scope it was taken in, and before the environment is reset.
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    /// The scope the checkpoint was taken in.
+    pub(crate) scope: Scope,
+    /// The number of constants, public variables, private variables, and constraints.
+    pub(crate) sizes: (usize, usize, usize, usize),
+    /// The number of lookup tables and lookup constraints.
+    pub(crate) lookup_sizes: (usize, usize),
+    /// The number of constant, public, and private variables.
+    pub(crate) num_variables: u64,
+    /// The number of nonzeros.
+    pub(crate) nonzeros: (u64, u64, u64),
+    /// The number of constants, public variables, private variables, constraints, and nonzeros in the scope.
+    pub(crate) scope_sizes: (u64, u64, u64, u64, (u64, u64, u64)),
+    /// The profile, if profiling is enabled.
+    pub(crate) profile: Option<Profile>,
+}
+
+impl Checkpoint {
+    /// Returns the scope the checkpoint was taken in.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+}
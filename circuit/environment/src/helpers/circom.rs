@@ -0,0 +1,211 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports the constraint system and its assignment in the circom `.r1cs` and `.wtns` binary formats.
+//!
+//! Both formats store field elements as little-endian integers in canonical form, padded to a multiple of 8 bytes.
+//! The wires are laid out as circom expects: the `One` wire first, then the public inputs, then the private inputs.
+//! As Aleo circuits do not distinguish public outputs from public inputs, every public variable is a public input.
+
+use crate::{LinearCombination, R1CS, Variable};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, ToBytes};
+
+use std::io::{Error, ErrorKind, Result as IoResult, Write};
+
+/// The section type of the `.r1cs` header.
+const R1CS_HEADER: u32 = 1;
+/// The section type of the `.r1cs` constraints.
+const R1CS_CONSTRAINTS: u32 = 2;
+/// The section type of the `.r1cs` wire-to-label map.
+const R1CS_WIRE_TO_LABEL: u32 = 3;
+
+/// The section type of the `.wtns` header.
+const WTNS_HEADER: u32 = 1;
+/// The section type of the `.wtns` witness values.
+const WTNS_VALUES: u32 = 2;
+
+impl<F: PrimeField> R1CS<F> {
+    /// Writes the constraint system in the circom `.r1cs` binary format.
+    pub fn write_circom_r1cs<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ensure_no_lookups()?;
+
+        let num_public = self.num_public();
+        let num_wires = num_public + self.num_private();
+
+        // Write the header section.
+        let mut header = Vec::new();
+        write_prime::<F, _>(&mut header)?;
+        write_u32(&mut header, num_wires)?;
+        // Write the number of public outputs.
+        0u32.write_le(&mut header)?;
+        // Write the number of public inputs, excluding the `One` wire.
+        write_u32(&mut header, num_public - 1)?;
+        write_u32(&mut header, self.num_private())?;
+        num_wires.write_le(&mut header)?;
+        write_u32(&mut header, self.num_constraints())?;
+
+        // Write the constraints section.
+        let mut constraints = Vec::new();
+        for constraint in self.to_constraints() {
+            let (a, b, c) = constraint.to_terms();
+            for lc in [a, b, c] {
+                write_linear_combination(&mut constraints, lc, num_public)?;
+            }
+        }
+
+        // Write the wire-to-label section, where each wire is its own label.
+        let mut labels = Vec::new();
+        for wire in 0..num_wires {
+            wire.write_le(&mut labels)?;
+        }
+
+        write_file(&mut writer, b"r1cs", 1, &[
+            (R1CS_HEADER, header),
+            (R1CS_CONSTRAINTS, constraints),
+            (R1CS_WIRE_TO_LABEL, labels),
+        ])
+    }
+
+    /// Writes the assignment of the constraint system in the circom `.wtns` binary format.
+    pub fn write_circom_wtns<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.ensure_no_lookups()?;
+
+        // Write the header section.
+        let mut header = Vec::new();
+        write_prime::<F, _>(&mut header)?;
+        write_u32(&mut header, self.num_public() + self.num_private())?;
+
+        // Write the values section, which begins with the `One` wire.
+        let mut values = Vec::new();
+        for variable in self.to_public_variables().iter().chain(self.to_private_variables()) {
+            variable.value().to_bigint().write_le(&mut values)?;
+        }
+
+        write_file(&mut writer, b"wtns", 2, &[(WTNS_HEADER, header), (WTNS_VALUES, values)])
+    }
+
+    /// Returns an error if the constraint system has lookup constraints, which circom cannot express.
+    fn ensure_no_lookups(&self) -> IoResult<()> {
+        match self.num_lookup_constraints() {
+            0 => Ok(()),
+            _ => Err(Error::new(ErrorKind::InvalidInput, "Lookup constraints cannot be exported to circom")),
+        }
+    }
+}
+
+/// Writes a file with the given magic bytes, version, and sections.
+fn write_file<W: Write>(writer: &mut W, magic: &[u8; 4], version: u32, sections: &[(u32, Vec<u8>)]) -> IoResult<()> {
+    writer.write_all(magic)?;
+    version.write_le(&mut *writer)?;
+    (sections.len() as u32).write_le(&mut *writer)?;
+    for (section_type, section) in sections {
+        section_type.write_le(&mut *writer)?;
+        (section.len() as u64).write_le(&mut *writer)?;
+        writer.write_all(section)?;
+    }
+    Ok(())
+}
+
+/// Writes the number of bytes of a field element, followed by the field modulus.
+fn write_prime<F: PrimeField, W: Write>(writer: &mut W) -> IoResult<()> {
+    ((<F::BigInteger as BigInteger>::NUM_LIMBS * 8) as u32).write_le(&mut *writer)?;
+    F::modulus().write_le(writer)
+}
+
+/// Writes the given count as a `u32`, or returns an error if it does not fit.
+fn write_u32<W: Write>(writer: &mut W, count: u64) -> IoResult<()> {
+    match u32::try_from(count) {
+        Ok(count) => count.write_le(writer),
+        Err(_) => Err(Error::new(ErrorKind::InvalidInput, format!("{count} exceeds the circom limit of 2^32 - 1"))),
+    }
+}
+
+/// Writes the linear combination as a list of `(wire, coefficient)` pairs, where the constant is the coefficient of the `One` wire.
+fn write_linear_combination<F: PrimeField, W: Write>(
+    writer: &mut W,
+    lc: &LinearCombination<F>,
+    num_public: u64,
+) -> IoResult<()> {
+    let constant = lc.to_constant();
+    let terms = lc.to_terms();
+
+    write_u32(writer, terms.len() as u64 + !constant.is_zero() as u64)?;
+    if !constant.is_zero() {
+        0u32.write_le(&mut *writer)?;
+        constant.to_bigint().write_le(&mut *writer)?;
+    }
+    for (variable, coefficient) in terms {
+        let wire = match variable {
+            Variable::Constant(_) => {
+                return Err(Error::new(ErrorKind::InvalidData, "Linear combinations must not contain constant terms"));
+            }
+            Variable::Public(index_value) => index_value.0,
+            Variable::Private(index_value) => num_public + index_value.0,
+        };
+        write_u32(writer, wire)?;
+        coefficient.to_bigint().write_le(&mut *writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    /// Returns the little-endian `u32` at the given offset.
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_export_circom() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Public, one);
+        let b = Field::<Circuit>::new(Mode::Private, one + one);
+        let _c = a * b;
+        assert!(Circuit::is_satisfied());
+
+        let (num_public, num_private, num_constraints) =
+            (Circuit::num_public(), Circuit::num_private(), Circuit::num_constraints());
+        let num_wires = (num_public + num_private) as u32;
+
+        let (mut r1cs, mut wtns) = (Vec::new(), Vec::new());
+        Circuit::export_circom(&mut r1cs, &mut wtns).unwrap();
+        Circuit::reset();
+
+        // Check the `.r1cs` preamble and header section.
+        assert_eq!(b"r1cs", &r1cs[0..4]);
+        assert_eq!(1, read_u32(&r1cs, 4));
+        assert_eq!(3, read_u32(&r1cs, 8));
+        assert_eq!(1, read_u32(&r1cs, 12));
+        let header = &r1cs[24..];
+        assert_eq!(48, read_u32(header, 0));
+        assert_eq!(num_wires, read_u32(header, 52));
+        assert_eq!(0, read_u32(header, 56));
+        assert_eq!(num_public as u32 - 1, read_u32(header, 60));
+        assert_eq!(num_private as u32, read_u32(header, 64));
+        assert_eq!(num_constraints as u32, read_u32(header, 76));
+
+        // Check the `.wtns` preamble, and that the first witness value is the `One` wire.
+        assert_eq!(b"wtns", &wtns[0..4]);
+        assert_eq!(2, read_u32(&wtns, 4));
+        assert_eq!(num_wires, read_u32(&wtns, 24 + 52));
+        let values = &wtns[24 + 56 + 12..];
+        assert_eq!(num_wires as usize * 48, values.len());
+        assert_eq!(1, values[0]);
+        assert!(values[1..48].iter().all(|byte| *byte == 0));
+    }
+}
@@ -63,6 +63,9 @@ impl<F: PrimeField> R1CS<F> {
     ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
         let mut converter = Converter { public: Default::default(), private: Default::default() };
 
+        // Ensure the constraint system has no lookup constraints, as the SNARK does not support them yet.
+        assert_eq!(0, self.num_lookup_constraints(), "Lookup constraints are not yet supported by the SNARK");
+
         // Ensure the given `cs` is starting off clean.
         assert_eq!(1, cs.num_public_variables());
         assert_eq!(0, cs.num_private_variables());
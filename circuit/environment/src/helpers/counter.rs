@@ -16,7 +16,7 @@
 use crate::*;
 use snarkvm_fields::PrimeField;
 
-use std::{mem, rc::Rc};
+use std::{collections::HashSet, mem, rc::Rc};
 
 #[derive(Debug, Default)]
 pub(crate) struct Counter<F: PrimeField> {
@@ -92,6 +92,34 @@ impl<F: PrimeField> Counter<F> {
         Ok(())
     }
 
+    /// Reverts the current scope to the given number of constants, public variables, private variables,
+    /// constraints, and nonzeros.
+    pub(crate) fn truncate(&mut self, sizes: (u64, u64, u64, u64, (u64, u64, u64))) {
+        let (constants, public, private, constraints, nonzeros) = sizes;
+        self.constants = constants;
+        self.public = public;
+        self.private = private;
+        self.constraints.truncate(constraints as usize);
+        self.nonzeros = nonzeros;
+    }
+
+    /// Removes the given constraints from the current scope and its parent scopes.
+    pub(crate) fn remove_constraints(&mut self, removed: &HashSet<*const Constraint<F>>) {
+        let scopes = self.parents.iter_mut().map(|(_, constraints, _, _, _, nonzeros)| (constraints, nonzeros));
+        for (constraints, nonzeros) in scopes.chain([(&mut self.constraints, &mut self.nonzeros)]) {
+            constraints.retain(|constraint| {
+                let is_removed = removed.contains(&Rc::as_ptr(constraint));
+                if is_removed {
+                    let (a_nonzeros, b_nonzeros, c_nonzeros) = constraint.num_nonzeros();
+                    nonzeros.0 -= a_nonzeros;
+                    nonzeros.1 -= b_nonzeros;
+                    nonzeros.2 -= c_nonzeros;
+                }
+                !is_removed
+            });
+        }
+    }
+
     /// Increments the number of constraints by 1.
     pub(crate) fn add_constraint(&mut self, constraint: Rc<Constraint<F>>) {
         let (a_nonzeros, b_nonzeros, c_nonzeros) = constraint.num_nonzeros();
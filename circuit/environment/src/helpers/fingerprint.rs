@@ -0,0 +1,130 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{LinearCombination, R1CS, Variable};
+use snarkvm_fields::PrimeField;
+
+use sha2::{Digest, Sha256};
+
+/// The domain separator of the fingerprint, which changes whenever the encoding below changes.
+const FINGERPRINT_DOMAIN: &[u8] = b"snarkvm.circuit.fingerprint.v1";
+
+impl<F: PrimeField> R1CS<F> {
+    /// Returns a SHA-256 hash of the structure of the constraint system.
+    ///
+    /// The hash covers the number of public and private variables, the constraints, and the lookups,
+    /// but not the values of the variables, so every assignment of the same circuit has the same fingerprint.
+    /// The number of constants and the scope names are not part of the structure.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(FINGERPRINT_DOMAIN);
+
+        // Hash the field and the number of variables.
+        update_field(&mut hasher, -F::one());
+        hasher.update(self.num_public().to_le_bytes());
+        hasher.update(self.num_private().to_le_bytes());
+
+        // Hash the constraints.
+        hasher.update(self.num_constraints().to_le_bytes());
+        for constraint in self.to_constraints() {
+            let (a, b, c) = constraint.to_terms();
+            for lc in [a, b, c] {
+                update_linear_combination(&mut hasher, lc);
+            }
+        }
+
+        // Hash the lookup tables.
+        hasher.update(self.num_lookup_tables().to_le_bytes());
+        for table in self.to_lookup_tables() {
+            hasher.update((table.rows().len() as u64).to_le_bytes());
+            for (a, b, c) in table.rows() {
+                for value in [a, b, c] {
+                    update_field(&mut hasher, *value);
+                }
+            }
+        }
+
+        // Hash the lookup constraints.
+        hasher.update(self.num_lookup_constraints().to_le_bytes());
+        for constraint in self.to_lookup_constraints() {
+            hasher.update((constraint.table().index() as u64).to_le_bytes());
+            let (a, b, c) = constraint.to_terms();
+            for lc in [a, b, c] {
+                update_linear_combination(&mut hasher, lc);
+            }
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// Hashes the field element as a little-endian integer in canonical form.
+fn update_field<F: PrimeField>(hasher: &mut Sha256, value: F) {
+    for limb in value.to_bigint().as_ref() {
+        hasher.update(limb.to_le_bytes());
+    }
+}
+
+/// Hashes the constant, followed by each term as its kind, index, and coefficient.
+fn update_linear_combination<F: PrimeField>(hasher: &mut Sha256, lc: &LinearCombination<F>) {
+    update_field(hasher, lc.to_constant());
+    hasher.update((lc.to_terms().len() as u64).to_le_bytes());
+    for (variable, coefficient) in lc.to_terms() {
+        match variable {
+            Variable::Constant(value) => {
+                hasher.update([0u8]);
+                update_field(hasher, **value);
+            }
+            Variable::Public(index_value) => {
+                hasher.update([1u8]);
+                hasher.update(index_value.0.to_le_bytes());
+            }
+            Variable::Private(index_value) => {
+                hasher.update([2u8]);
+                hasher.update(index_value.0.to_le_bytes());
+            }
+        }
+        update_field(hasher, *coefficient);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    /// Returns the fingerprint of `a * b` for the given values of `a` and `b`.
+    fn fingerprint_of_product(a: u64, b: u64) -> [u8; 32] {
+        let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(a));
+        let b = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(b));
+        let _c = a * b;
+        let fingerprint = Circuit::fingerprint();
+        Circuit::reset();
+        fingerprint
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        // Ensure the fingerprint does not depend on the witness.
+        assert_eq!(fingerprint_of_product(2, 3), fingerprint_of_product(5, 7));
+
+        // Ensure the fingerprint depends on the structure.
+        let expected = fingerprint_of_product(2, 3);
+        let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(2));
+        let _b = a.square();
+        let _c = a.square();
+        assert_ne!(expected, Circuit::fingerprint());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{prelude::*, *};
+use snarkvm_fields::PrimeField;
+
+use std::collections::HashSet;
+
+/// The index of a lookup table in the environment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableIndex(pub(crate) usize);
+
+impl TableIndex {
+    /// Returns the position of the table in the environment.
+    pub const fn index(&self) -> usize {
+        self.0
+    }
+}
+
+impl Display for TableIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "table {}", self.0)
+    }
+}
+
+/// A lookup table, whose rows are triples of field elements.
+#[derive(Clone, Debug)]
+pub struct LookupTable<F: PrimeField> {
+    /// The rows of the table, in the order they were given.
+    rows: Vec<(F, F, F)>,
+    /// The set of rows, for membership checks.
+    members: HashSet<(F, F, F)>,
+}
+
+impl<F: PrimeField> LookupTable<F> {
+    /// Returns a new lookup table with the given rows.
+    pub(crate) fn new(rows: Vec<(F, F, F)>) -> Self {
+        let members = rows.iter().copied().collect();
+        Self { rows, members }
+    }
+
+    /// Returns `true` if the given triple is a row of the table.
+    pub fn contains(&self, row: &(F, F, F)) -> bool {
+        self.members.contains(row)
+    }
+
+    /// Returns the rows of the table.
+    pub fn rows(&self) -> &[(F, F, F)] {
+        &self.rows
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LookupConstraint<F: PrimeField>(
+    pub(crate) Scope,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+    pub(crate) LinearCombination<F>,
+    pub(crate) TableIndex,
+);
+
+impl<F: PrimeField> LookupConstraint<F> {
+    /// Returns `true` if `(a, b, c)` is a row of the referenced table.
+    pub(crate) fn is_satisfied(&self, tables: &[LookupTable<F>]) -> bool {
+        let (scope, a, b, c, table) = (&self.0, &self.1, &self.2, &self.3, &self.4);
+        let (a, b, c) = (a.value(), b.value(), c.value());
+
+        match tables.get(table.0) {
+            Some(lookup_table) if lookup_table.contains(&(a, b, c)) => true,
+            Some(lookup_table) => {
+                eprintln!(
+                    "Failed lookup constraint at {scope}:\n\t({a}, {b}, {c}) is not in {table} ({} rows)",
+                    lookup_table.rows().len()
+                );
+                false
+            }
+            None => {
+                eprintln!("Failed lookup constraint at {scope}:\n\t{table} does not exist");
+                false
+            }
+        }
+    }
+
+    /// Returns a reference to the terms `(a, b, c)`.
+    pub fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
+        (&self.1, &self.2, &self.3)
+    }
+
+    /// Returns the index of the referenced table.
+    pub fn table(&self) -> TableIndex {
+        self.4
+    }
+}
+
+impl<F: PrimeField> Display for LookupConstraint<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (scope, a, b, c, table) = (&self.0, &self.1, &self.2, &self.3, &self.4);
+        write!(f, "Lookup constraint {scope}:\n\t({}, {}, {}) in {table}\n", a.value(), b.value(), c.value())
+    }
+}
@@ -16,14 +16,23 @@
 mod assignment;
 pub use assignment::*;
 
+mod ccs;
+
+pub mod checkpoint;
+pub use checkpoint::*;
+
 pub mod circuit_type;
 pub use circuit_type::*;
 
+mod circom;
+
 pub mod constraint;
 pub use constraint::*;
 
 pub(super) mod converter;
 
+mod fingerprint;
+
 pub mod count;
 pub use count::*;
 
@@ -33,14 +42,31 @@ pub(super) use counter::*;
 pub mod linear_combination;
 pub use linear_combination::*;
 
+pub mod lookup;
+pub use lookup::*;
+
 mod mode;
 pub use mode::*;
 
+pub mod profile;
+pub use profile::*;
+
+pub mod public_input;
+pub use public_input::*;
+
 pub mod variable;
 pub use variable::*;
 
 pub mod r1cs;
 pub use r1cs::*;
 
+mod smtlib;
+
+pub mod unsatisfied;
+pub use unsatisfied::*;
+
 pub mod updatable_count;
 pub use updatable_count::*;
+
+pub mod witness;
+pub use witness::*;
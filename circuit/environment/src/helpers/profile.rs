@@ -0,0 +1,89 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Scope;
+
+use indexmap::IndexMap;
+
+/// The metric used to weigh each scope in a flamegraph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProfileMetric {
+    /// The number of constraints.
+    Constraints,
+    /// The number of constants, public variables, and private variables.
+    Variables,
+    /// The number of nonzeros in the `A`, `B`, and `C` matrices.
+    Nonzeros,
+}
+
+/// The number of constants, public variables, private variables, constraints, and nonzeros added in a scope,
+/// excluding those added in its nested scopes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeProfile {
+    pub constants: u64,
+    pub public: u64,
+    pub private: u64,
+    pub constraints: u64,
+    pub nonzeros: (u64, u64, u64),
+}
+
+impl ScopeProfile {
+    /// Returns the value of the given metric.
+    pub const fn metric(&self, metric: ProfileMetric) -> u64 {
+        match metric {
+            ProfileMetric::Constraints => self.constraints,
+            ProfileMetric::Variables => self.constants + self.public + self.private,
+            ProfileMetric::Nonzeros => self.nonzeros.0 + self.nonzeros.1 + self.nonzeros.2,
+        }
+    }
+}
+
+/// A record of the circuit size contributed by each scope, in the order the scopes were first entered.
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    scopes: IndexMap<Scope, ScopeProfile>,
+}
+
+impl Profile {
+    /// Returns the profile of each scope.
+    pub const fn scopes(&self) -> &IndexMap<Scope, ScopeProfile> {
+        &self.scopes
+    }
+
+    /// Returns the profile of the given scope, to be updated.
+    pub(crate) fn scope_mut(&mut self, scope: Scope) -> &mut ScopeProfile {
+        self.scopes.entry(scope).or_default()
+    }
+
+    /// Returns the profile in the collapsed stack format read by `flamegraph.pl` and `inferno`.
+    ///
+    /// Each line is a `;`-separated path of scopes, followed by the given metric for that scope.
+    /// Scopes with a zero metric are omitted, and anything outside of a scope is attributed to `circuit`.
+    pub fn to_collapsed_stacks(&self, metric: ProfileMetric) -> String {
+        let mut output = String::new();
+        for (scope, profile) in &self.scopes {
+            let value = profile.metric(metric);
+            if value == 0 {
+                continue;
+            }
+            let stack = match scope.is_empty() {
+                true => "circuit".to_string(),
+                false => format!("circuit;{}", scope.replace('.', ";")),
+            };
+            output += &format!("{stack} {value}\n");
+        }
+        output
+    }
+}
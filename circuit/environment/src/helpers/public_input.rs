@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{fmt, ops::Range};
+
+/// The name of the public variable that is always `1`.
+pub const ONE_INPUT_NAME: &str = "one";
+/// The name of public variables that were not injected with `Environment::new_public_input`.
+pub const UNLABELED_INPUT_NAME: &str = "unlabeled";
+
+/// A named public input, and the positions of its public variables in the verifier's input vector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicInput {
+    /// The name of the input.
+    name: String,
+    /// The type of the input, or `field` for unlabeled public variables.
+    type_name: String,
+    /// The positions of the public variables of the input.
+    positions: Range<u64>,
+}
+
+impl PublicInput {
+    /// Returns a new public input with the given name, type, and positions.
+    pub fn new<S: Into<String>, T: Into<String>>(name: S, type_name: T, positions: Range<u64>) -> Self {
+        Self { name: name.into(), type_name: type_name.into(), positions }
+    }
+
+    /// Returns the name of the input.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the input.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the positions of the public variables of the input.
+    pub fn positions(&self) -> Range<u64> {
+        self.positions.clone()
+    }
+}
+
+impl fmt::Display for PublicInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} at {:?}", self.name, self.type_name, self.positions)
+    }
+}
+
+/// Returns the labeled inputs in order of position, with the `One` variable first and any gaps filled by unlabeled inputs.
+pub(crate) fn to_public_input_layout(labels: &[PublicInput], num_public: u64) -> Vec<PublicInput> {
+    let mut labels = labels.to_vec();
+    labels.sort_by_key(|label| label.positions.start);
+
+    let mut layout = vec![PublicInput::new(ONE_INPUT_NAME, "field", 0..1)];
+    let mut next = 1;
+    for label in labels {
+        if label.positions.start > next {
+            layout.push(PublicInput::new(UNLABELED_INPUT_NAME, "field", next..label.positions.start));
+        }
+        next = next.max(label.positions.end);
+        layout.push(label);
+    }
+    if num_public > next {
+        layout.push(PublicInput::new(UNLABELED_INPUT_NAME, "field", next..num_public));
+    }
+    layout
+}
@@ -14,24 +14,41 @@
 // limitations under the License.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{
+        Checkpoint,
+        Constraint,
+        Counter,
+        LookupConstraint,
+        LookupTable,
+        Profile,
+        PublicInput,
+        TableIndex,
+        to_public_input_layout,
+    },
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
 
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 
 pub type Scope = String;
 
+/// The constant and terms of a linear combination, which identify it regardless of its value.
+type LinearCombinationKey<F> = (F, Vec<(Variable<F>, F)>);
+
 #[derive(Debug)]
 pub struct R1CS<F: PrimeField> {
     constants: Vec<Variable<F>>,
     public: Vec<Variable<F>>,
     private: Vec<Variable<F>>,
     constraints: Vec<Rc<Constraint<F>>>,
+    lookup_tables: Vec<LookupTable<F>>,
+    lookup_constraints: Vec<Rc<LookupConstraint<F>>>,
     counter: Counter<F>,
     num_variables: u64,
     nonzeros: (u64, u64, u64),
+    profile: Option<Profile>,
+    public_inputs: Vec<PublicInput>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,9 +59,13 @@ impl<F: PrimeField> R1CS<F> {
             public: vec![Variable::Public(Rc::new((0u64, F::one())))],
             private: Default::default(),
             constraints: Default::default(),
+            lookup_tables: Default::default(),
+            lookup_constraints: Default::default(),
             counter: Default::default(),
             num_variables: 1u64,
             nonzeros: (0, 0, 0),
+            profile: None,
+            public_inputs: Default::default(),
         }
     }
 
@@ -63,6 +84,9 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Constant(Rc::new(value));
         self.constants.push(variable.clone());
         self.counter.increment_constant();
+        if let Some(profile) = &mut self.profile {
+            profile.scope_mut(self.counter.scope()).constants += 1;
+        }
         self.num_variables += 1;
         variable
     }
@@ -72,6 +96,9 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Public(Rc::new((self.public.len() as u64, value)));
         self.public.push(variable.clone());
         self.counter.increment_public();
+        if let Some(profile) = &mut self.profile {
+            profile.scope_mut(self.counter.scope()).public += 1;
+        }
         self.num_variables += 1;
         variable
     }
@@ -81,6 +108,9 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Private(Rc::new((self.private.len() as u64, value)));
         self.private.push(variable.clone());
         self.counter.increment_private();
+        if let Some(profile) = &mut self.profile {
+            profile.scope_mut(self.counter.scope()).private += 1;
+        }
         self.num_variables += 1;
         variable
     }
@@ -92,11 +122,170 @@ impl<F: PrimeField> R1CS<F> {
         self.nonzeros.1 += b_nonzeros;
         self.nonzeros.2 += c_nonzeros;
 
+        if let Some(profile) = &mut self.profile {
+            let scope = profile.scope_mut(self.counter.scope());
+            scope.constraints += 1;
+            scope.nonzeros.0 += a_nonzeros;
+            scope.nonzeros.1 += b_nonzeros;
+            scope.nonzeros.2 += c_nonzeros;
+        }
+
         let constraint = Rc::new(constraint);
         self.constraints.push(Rc::clone(&constraint));
         self.counter.add_constraint(constraint);
     }
 
+    /// Adds a lookup table with the given rows, and returns its index.
+    pub(crate) fn add_lookup_table(&mut self, rows: Vec<(F, F, F)>) -> TableIndex {
+        self.lookup_tables.push(LookupTable::new(rows));
+        TableIndex(self.lookup_tables.len() - 1)
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the referenced table.
+    pub(crate) fn enforce_lookup(&mut self, constraint: LookupConstraint<F>) {
+        self.lookup_constraints.push(Rc::new(constraint));
+    }
+
+    /// Removes every constraint that is identical to an earlier one, and returns the number of removed constraints.
+    ///
+    /// Two constraints are identical if they have the same `C` and the same `A` and `B`, in either order,
+    /// where linear combinations are compared irrespective of the order of their terms.
+    /// As this changes the constraint system, it must be applied both when deriving the keys and when proving.
+    pub(crate) fn deduplicate_constraints(&mut self) -> u64 {
+        let to_key = |lc: &LinearCombination<F>| -> LinearCombinationKey<F> {
+            // Sort the terms by variable, as the terms are kept in insertion order.
+            let mut terms = lc.to_terms().to_vec();
+            terms.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            (lc.to_constant(), terms)
+        };
+
+        let mut seen = HashSet::with_capacity(self.constraints.len());
+        let mut removed = HashSet::new();
+        for constraint in &self.constraints {
+            let (a, b, c) = constraint.to_terms();
+            let (a, b) = (to_key(a), to_key(b));
+            // Order `A` and `B`, as multiplication is commutative.
+            let (a, b) = match a <= b {
+                true => (a, b),
+                false => (b, a),
+            };
+            if !seen.insert((a, b, to_key(c))) {
+                removed.insert(Rc::as_ptr(constraint));
+            }
+        }
+        if removed.is_empty() {
+            return 0;
+        }
+
+        // Remove the duplicates, and their nonzeros.
+        let profile = &mut self.profile;
+        let nonzeros = &mut self.nonzeros;
+        self.constraints.retain(|constraint| {
+            if !removed.contains(&Rc::as_ptr(constraint)) {
+                return true;
+            }
+            let (a_nonzeros, b_nonzeros, c_nonzeros) = constraint.num_nonzeros();
+            nonzeros.0 -= a_nonzeros;
+            nonzeros.1 -= b_nonzeros;
+            nonzeros.2 -= c_nonzeros;
+            if let Some(profile) = profile {
+                let scope = profile.scope_mut(constraint.0.clone());
+                scope.constraints -= 1;
+                scope.nonzeros.0 -= a_nonzeros;
+                scope.nonzeros.1 -= b_nonzeros;
+                scope.nonzeros.2 -= c_nonzeros;
+            }
+            false
+        });
+        self.counter.remove_constraints(&removed);
+
+        removed.len() as u64
+    }
+
+    /// Returns a checkpoint of the current size of the constraint system.
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            scope: self.scope(),
+            sizes: (self.constants.len(), self.public.len(), self.private.len(), self.constraints.len()),
+            lookup_sizes: (self.lookup_tables.len(), self.lookup_constraints.len()),
+            num_variables: self.num_variables,
+            nonzeros: self.nonzeros,
+            scope_sizes: (
+                self.num_constants_in_scope(),
+                self.num_public_in_scope(),
+                self.num_private_in_scope(),
+                self.num_constraints_in_scope(),
+                self.num_nonzeros_in_scope(),
+            ),
+            profile: self.profile.clone(),
+        }
+    }
+
+    /// Removes the variables, constraints, and lookups added after the given checkpoint.
+    pub(crate) fn rollback(&mut self, checkpoint: Checkpoint) -> Result<(), String> {
+        // Ensure the checkpoint was taken in the current scope.
+        if checkpoint.scope != self.scope() {
+            return Err(format!(
+                "Tried to roll back to a checkpoint from scope '{}' in another scope",
+                checkpoint.scope
+            ));
+        }
+
+        // Ensure the constraint system has not shrunk since the checkpoint.
+        let (num_constants, num_public, num_private, num_constraints) = checkpoint.sizes;
+        let (num_lookup_tables, num_lookup_constraints) = checkpoint.lookup_sizes;
+        if num_constants > self.constants.len()
+            || num_public > self.public.len()
+            || num_private > self.private.len()
+            || num_constraints > self.constraints.len()
+            || num_lookup_tables > self.lookup_tables.len()
+            || num_lookup_constraints > self.lookup_constraints.len()
+        {
+            return Err("Tried to roll back to a checkpoint that is newer than the constraint system".to_string());
+        }
+
+        self.constants.truncate(num_constants);
+        self.public.truncate(num_public);
+        self.private.truncate(num_private);
+        self.constraints.truncate(num_constraints);
+        self.lookup_tables.truncate(num_lookup_tables);
+        self.lookup_constraints.truncate(num_lookup_constraints);
+        self.num_variables = checkpoint.num_variables;
+        self.nonzeros = checkpoint.nonzeros;
+        self.counter.truncate(checkpoint.scope_sizes);
+        self.public_inputs.retain(|input| input.positions().end <= num_public as u64);
+        // Revert the profile, unless profiling has been disabled since the checkpoint.
+        if self.profile.is_some() {
+            self.profile = Some(checkpoint.profile.unwrap_or_default());
+        }
+        Ok(())
+    }
+
+    /// Starts recording the circuit size of each scope if `enabled`, and otherwise stops and discards the record.
+    pub(crate) fn set_profiling(&mut self, enabled: bool) {
+        match enabled {
+            true => self.profile = Some(self.profile.take().unwrap_or_default()),
+            false => self.profile = None,
+        }
+    }
+
+    /// Returns the circuit size of each scope, if profiling is enabled.
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_ref()
+    }
+
+    /// Records the positions of a public input in the public input layout.
+    pub(crate) fn label_public_input(&mut self, input: PublicInput) {
+        self.public_inputs.push(input);
+    }
+
+    /// Returns the public inputs in the order of the verifier's input vector, starting with the `One` variable.
+    ///
+    /// Public variables that were not labeled are reported as unlabeled field inputs.
+    pub fn public_input_layout(&self) -> Vec<PublicInput> {
+        to_public_input_layout(&self.public_inputs, self.num_public())
+    }
+
     /// Returns `true` if all of the constraints are satisfied.
     ///
     /// In addition, when in debug mode, this function also checks that
@@ -108,26 +297,35 @@ impl<F: PrimeField> R1CS<F> {
             return false;
         }
 
+        // Ensure all lookup constraints are satisfied.
+        let lookups_satisfied = self.lookup_constraints.iter().all(|lookup| lookup.is_satisfied(&self.lookup_tables));
+        if !lookups_satisfied {
+            return false;
+        }
+
         // In debug mode, ensure all constraints use variables corresponding to the declared variables.
         #[cfg(not(debug_assertions))]
         return true;
         #[cfg(debug_assertions)]
-        self.constraints.iter().all(|constraint| {
-            let (a, b, c) = constraint.to_terms();
-            [a, b, c].into_iter().all(|lc| {
-                lc.to_terms().iter().all(|(variable, _)| match variable {
-                    Variable::Constant(_value) => false, // terms should not contain Constants
-                    Variable::Private(private) => {
-                        let (index, value) = private.as_ref();
-                        self.private.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
-                    }
-                    Variable::Public(public) => {
-                        let (index, value) = public.as_ref();
-                        self.public.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
-                    }
+        self.constraints
+            .iter()
+            .map(|constraint| constraint.to_terms())
+            .chain(self.lookup_constraints.iter().map(|lookup| lookup.to_terms()))
+            .all(|(a, b, c)| {
+                [a, b, c].into_iter().all(|lc| {
+                    lc.to_terms().iter().all(|(variable, _)| match variable {
+                        Variable::Constant(_value) => false, // terms should not contain Constants
+                        Variable::Private(private) => {
+                            let (index, value) = private.as_ref();
+                            self.private.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
+                        }
+                        Variable::Public(public) => {
+                            let (index, value) = public.as_ref();
+                            self.public.get(*index as usize).map_or_else(|| false, |v| v.value() == *value)
+                        }
+                    })
                 })
             })
-        })
     }
 
     /// Returns `true` if all constraints in the current scope are satisfied.
@@ -170,6 +368,16 @@ impl<F: PrimeField> R1CS<F> {
         self.nonzeros
     }
 
+    /// Returns the number of lookup tables in the constraint system.
+    pub fn num_lookup_tables(&self) -> u64 {
+        self.lookup_tables.len() as u64
+    }
+
+    /// Returns the number of lookup constraints in the constraint system.
+    pub fn num_lookup_constraints(&self) -> u64 {
+        self.lookup_constraints.len() as u64
+    }
+
     /// Returns the number of constants for the current scope.
     pub(crate) fn num_constants_in_scope(&self) -> u64 {
         self.counter.num_constants_in_scope()
@@ -209,6 +417,16 @@ impl<F: PrimeField> R1CS<F> {
     pub fn to_constraints(&self) -> &Vec<Rc<Constraint<F>>> {
         &self.constraints
     }
+
+    /// Returns the lookup tables in the constraint system.
+    pub fn to_lookup_tables(&self) -> &Vec<LookupTable<F>> {
+        &self.lookup_tables
+    }
+
+    /// Returns the lookup constraints in the constraint system.
+    pub fn to_lookup_constraints(&self) -> &Vec<Rc<LookupConstraint<F>>> {
+        &self.lookup_constraints
+    }
 }
 
 impl<F: PrimeField> Display for R1CS<F> {
@@ -217,6 +435,9 @@ impl<F: PrimeField> Display for R1CS<F> {
         for constraint in self.to_constraints() {
             output += &constraint.to_string();
         }
+        for lookup in self.to_lookup_constraints() {
+            output += &lookup.to_string();
+        }
         output += "\n";
 
         write!(f, "{output}")
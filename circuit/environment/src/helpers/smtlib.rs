@@ -0,0 +1,133 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports the constraint system as SMT-LIB assertions in the theory of finite fields (`QF_FF`), as supported by cvc5.
+//!
+//! The public variables are declared as `public_{index}`, where `public_0` is fixed to `1`, and the private
+//! variables are declared as `private_{index}`. Each constraint is a named assertion `constraint_{i}`, and each
+//! lookup constraint is a named assertion `lookup_{i}`, preceded by a comment with its scope.
+//! The script only declares the constraints, so auditors can append their own assertions before `(check-sat)`.
+
+use crate::{LinearCombination, R1CS, Variable};
+use snarkvm_fields::PrimeField;
+
+use core::fmt::Write;
+
+impl<F: PrimeField> R1CS<F> {
+    /// Returns the constraint system and its lookup constraints as an SMT-LIB script.
+    pub fn to_smtlib(&self) -> String {
+        let mut output = String::new();
+        self.write_smtlib(&mut output).expect("Writing to a string cannot fail");
+        output
+    }
+
+    /// Writes the constraint system and its lookup constraints as an SMT-LIB script.
+    fn write_smtlib(&self, output: &mut String) -> core::fmt::Result {
+        writeln!(output, "(set-logic QF_FF)")?;
+        writeln!(output, "(define-sort F () (_ FiniteField {}))", F::modulus())?;
+
+        // Declare the variables.
+        for index in 0..self.num_public() {
+            writeln!(output, "(declare-fun public_{index} () F)")?;
+        }
+        for index in 0..self.num_private() {
+            writeln!(output, "(declare-fun private_{index} () F)")?;
+        }
+        writeln!(output, "(assert (= public_0 (as ff1 F)))")?;
+
+        // Assert the constraints.
+        for (i, constraint) in self.to_constraints().iter().enumerate() {
+            let (a, b, c) = constraint.to_terms();
+            writeln!(output, "; {}", constraint.0)?;
+            writeln!(
+                output,
+                "(assert (! (= (ff.mul {} {}) {}) :named constraint_{i}))",
+                to_term(a),
+                to_term(b),
+                to_term(c)
+            )?;
+        }
+
+        // Assert the lookup constraints, as a disjunction over the rows of their table.
+        for (i, lookup) in self.to_lookup_constraints().iter().enumerate() {
+            let (a, b, c) = lookup.to_terms();
+            let (a, b, c) = (to_term(a), to_term(b), to_term(c));
+            let rows = self.to_lookup_tables()[lookup.table().index()]
+                .rows()
+                .iter()
+                .map(|(x, y, z)| format!("(and (= {a} (as ff{x} F)) (= {b} (as ff{y} F)) (= {c} (as ff{z} F)))"))
+                .collect::<Vec<_>>();
+            let assertion = match rows.len() {
+                0 => "false".to_string(),
+                1 => rows[0].clone(),
+                _ => format!("(or {})", rows.join(" ")),
+            };
+            writeln!(output, "; {}", lookup.0)?;
+            writeln!(output, "(assert (! {assertion} :named lookup_{i}))")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the linear combination as an SMT-LIB term.
+fn to_term<F: PrimeField>(lc: &LinearCombination<F>) -> String {
+    let constant = lc.to_constant();
+
+    let mut terms = Vec::with_capacity(lc.to_terms().len() + 1);
+    if !constant.is_zero() {
+        terms.push(format!("(as ff{constant} F)"));
+    }
+    for (variable, coefficient) in lc.to_terms() {
+        let variable = match variable {
+            Variable::Constant(value) => format!("(as ff{value} F)"),
+            Variable::Public(index_value) => format!("public_{}", index_value.0),
+            Variable::Private(index_value) => format!("private_{}", index_value.0),
+        };
+        match coefficient.is_one() {
+            true => terms.push(variable),
+            false => terms.push(format!("(ff.mul (as ff{coefficient} F) {variable})")),
+        }
+    }
+
+    match terms.len() {
+        0 => "(as ff0 F)".to_string(),
+        1 => terms.remove(0),
+        _ => format!("(ff.add {})", terms.join(" ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_to_smtlib() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Public, one);
+        let b = Field::<Circuit>::new(Mode::Private, one + one);
+        let _c = Circuit::scope("product", || a * b);
+
+        let r1cs = Circuit::eject_r1cs_and_reset();
+        let smtlib = r1cs.to_smtlib();
+
+        assert!(smtlib.starts_with("(set-logic QF_FF)\n"));
+        assert!(smtlib.contains("(declare-fun public_1 () F)"));
+        assert!(smtlib.contains("(declare-fun private_1 () F)"));
+        assert!(
+            smtlib.contains("; product\n(assert (! (= (ff.mul public_1 private_0) private_1) :named constraint_0))")
+        );
+        assert_eq!(r1cs.num_constraints() as usize, smtlib.matches(":named constraint_").count());
+    }
+}
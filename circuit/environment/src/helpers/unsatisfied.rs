@@ -0,0 +1,129 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{R1CS, Scope, TableIndex};
+use snarkvm_fields::PrimeField;
+
+use core::fmt;
+
+/// A constraint that is not satisfied by the current assignment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Unsatisfied<F: PrimeField> {
+    /// The `i`-th constraint, whose `(a, b, c)` values do not satisfy `a * b == c`.
+    Constraint { index: usize, scope: Scope, values: (F, F, F) },
+    /// The `i`-th lookup constraint, whose `(a, b, c)` values are not a row of its table.
+    Lookup { index: usize, scope: Scope, values: (F, F, F), table: TableIndex },
+}
+
+impl<F: PrimeField> Unsatisfied<F> {
+    /// Returns the scope the constraint was added in.
+    pub fn scope(&self) -> &Scope {
+        match self {
+            Self::Constraint { scope, .. } | Self::Lookup { scope, .. } => scope,
+        }
+    }
+}
+
+impl<F: PrimeField> fmt::Display for Unsatisfied<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Constraint { index, scope, values: (a, b, c) } => {
+                write!(f, "Constraint {index} at {scope}: ({a} * {b}) != {c}")
+            }
+            Self::Lookup { index, scope, values: (a, b, c), table } => {
+                write!(f, "Lookup constraint {index} at {scope}: ({a}, {b}, {c}) is not in {table}")
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> R1CS<F> {
+    /// Returns every constraint and lookup constraint that is not satisfied, in the order they were added.
+    ///
+    /// Unlike `is_satisfied`, this does not stop at the first failure.
+    pub fn unsatisfied_constraints(&self) -> Vec<Unsatisfied<F>> {
+        let constraints = self.to_constraints().iter().enumerate().filter_map(|(index, constraint)| {
+            let (a, b, c) = constraint.to_terms();
+            let values = (a.value(), b.value(), c.value());
+            match values.0 * values.1 == values.2 {
+                true => None,
+                false => Some(Unsatisfied::Constraint { index, scope: constraint.0.clone(), values }),
+            }
+        });
+        let lookups = self.to_lookup_constraints().iter().enumerate().filter_map(|(index, lookup)| {
+            let (a, b, c) = lookup.to_terms();
+            let values = (a.value(), b.value(), c.value());
+            let table = lookup.table();
+            match self.to_lookup_tables().get(table.index()) {
+                Some(lookup_table) if lookup_table.contains(&values) => None,
+                _ => Some(Unsatisfied::Lookup { index, scope: lookup.0.clone(), values, table }),
+            }
+        });
+        constraints.chain(lookups).collect()
+    }
+}
+
+/// Returns a simplification of the failing `inputs` that still fails.
+///
+/// Each input is either kept, or replaced by `simplify(input)`. Runs of inputs are simplified together
+/// as in delta debugging, halving the run length down to single inputs, and a simplification is kept
+/// whenever `fails` still returns `true`. The result fails, and no single remaining input can be simplified
+/// without the failure disappearing.
+pub fn minimize_failing_inputs<T, S, Fails>(inputs: Vec<T>, simplify: S, mut fails: Fails) -> Vec<T>
+where
+    T: Clone + PartialEq,
+    S: Fn(&T) -> T,
+    Fails: FnMut(&[T]) -> bool,
+{
+    let mut inputs = inputs;
+    let mut run_length = inputs.len();
+    while run_length > 0 {
+        let mut start = 0;
+        while start < inputs.len() {
+            let end = (start + run_length).min(inputs.len());
+
+            // Simplify the run, skipping it if it is already as simple as it gets.
+            let mut candidate = inputs.clone();
+            for input in &mut candidate[start..end] {
+                *input = simplify(input);
+            }
+            if candidate[start..end] != inputs[start..end] && fails(&candidate) {
+                inputs = candidate;
+            }
+            start = end;
+        }
+        run_length /= 2;
+    }
+    inputs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize_failing_inputs() {
+        // The failure only needs the third input to be large.
+        let fails = |inputs: &[u64]| inputs[2] > 10;
+        let minimized = minimize_failing_inputs(vec![5, 20, 30, 40], |_| 0, fails);
+        assert_eq!(vec![0, 0, 30, 0], minimized);
+
+        // The failure needs the sum to be large, so inputs are only simplified while it stays large.
+        let fails = |inputs: &[u64]| inputs.iter().sum::<u64>() > 50;
+        let minimized = minimize_failing_inputs(vec![5, 20, 30, 40], |_| 0, fails);
+        assert!(fails(&minimized));
+        assert_eq!(vec![0, 0, 30, 40], minimized);
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Assignment, AssignmentLC, AssignmentVariable};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBytes, error};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// The values of the public and private variables of a circuit, without its constraints.
+///
+/// A witness can be written to bytes, handed to another machine, and checked there against
+/// the constraints of the same circuit with `Assignment::is_satisfied_by`, without resynthesizing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Witness<F: PrimeField> {
+    /// The values of the public variables, starting with the `One` variable.
+    public: Vec<F>,
+    /// The values of the private variables.
+    private: Vec<F>,
+}
+
+impl<F: PrimeField> Witness<F> {
+    /// Returns the values of the public variables, starting with the `One` variable.
+    pub fn public(&self) -> &[F] {
+        &self.public
+    }
+
+    /// Returns the values of the private variables.
+    pub fn private(&self) -> &[F] {
+        &self.private
+    }
+
+    /// Returns the value of the given variable, or `None` if the witness does not have it.
+    fn value(&self, variable: &AssignmentVariable<F>) -> Option<F> {
+        match variable {
+            AssignmentVariable::Constant(value) => Some(*value),
+            AssignmentVariable::Public(index) => self.public.get(*index as usize).copied(),
+            AssignmentVariable::Private(index) => self.private.get(*index as usize).copied(),
+        }
+    }
+
+    /// Returns the value of the given linear combination, or `None` if the witness does not have one of its variables.
+    fn evaluate(&self, lc: &AssignmentLC<F>) -> Option<F> {
+        lc.terms()
+            .iter()
+            .try_fold(lc.constant(), |sum, (variable, coefficient)| Some(sum + self.value(variable)? * coefficient))
+    }
+}
+
+impl<F: PrimeField> From<&Assignment<F>> for Witness<F> {
+    /// Returns the values of the variables of the assignment.
+    fn from(assignment: &Assignment<F>) -> Self {
+        Self {
+            public: assignment.public_inputs().iter().map(|(_, value)| *value).collect(),
+            private: assignment.private_inputs().iter().map(|(_, value)| *value).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the values of the variables of the assignment.
+    pub fn to_witness(&self) -> Witness<F> {
+        Witness::from(self)
+    }
+
+    /// Returns `true` if the given witness satisfies the constraints of the assignment.
+    pub fn is_satisfied_by(&self, witness: &Witness<F>) -> bool {
+        // Ensure the witness has a value for every variable, and that the `One` variable is one.
+        if witness.public.len() as u64 != self.num_public()
+            || witness.private.len() as u64 != self.num_private()
+            || witness.public.first() != Some(&F::one())
+        {
+            return false;
+        }
+
+        self.constraints().iter().all(|(a, b, c)| {
+            match (witness.evaluate(a), witness.evaluate(b), witness.evaluate(c)) {
+                (Some(a), Some(b), Some(c)) => a * b == c,
+                _ => false,
+            }
+        })
+    }
+}
+
+impl<F: PrimeField> ToBytes for Witness<F> {
+    /// Writes the witness to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of public and private variables.
+        (self.public.len() as u64).write_le(&mut writer)?;
+        (self.private.len() as u64).write_le(&mut writer)?;
+        // Write the values.
+        for value in self.public.iter().chain(&self.private) {
+            value.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for Witness<F> {
+    /// Reads the witness from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid witness version"));
+        }
+
+        // Read the number of public and private variables.
+        let num_public = u64::read_le(&mut reader)?;
+        let num_private = u64::read_le(&mut reader)?;
+        // Read the values.
+        let public = (0..num_public).map(|_| F::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let private = (0..num_private).map(|_| F::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Ok(Self { public, private })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Environment, Mode};
+    use snarkvm_fields::One;
+
+    type F = <Circuit as Environment>::BaseField;
+
+    #[test]
+    fn test_witness_bytes() {
+        // Enforce `a * b == c`, with `a` public, and `b` and `c` private.
+        let a = Circuit::new_variable(Mode::Public, F::one());
+        let b = Circuit::new_variable(Mode::Private, F::one() + F::one());
+        let c = Circuit::new_variable(Mode::Private, F::one() + F::one());
+        Circuit::enforce(|| (a, b, c));
+
+        let assignment = Circuit::eject_assignment_and_reset();
+        let witness = assignment.to_witness();
+        assert!(assignment.is_satisfied_by(&witness));
+
+        // Ensure the witness round-trips through bytes.
+        let bytes = witness.to_bytes_le().unwrap();
+        let candidate = Witness::<F>::read_le(&bytes[..]).unwrap();
+        assert_eq!(witness, candidate);
+        assert!(assignment.is_satisfied_by(&candidate));
+
+        // Ensure a witness with a different private value does not satisfy the constraints.
+        let mut private = witness.private().to_vec();
+        private[0] += F::one();
+        let tampered = Witness { public: witness.public().to_vec(), private };
+        assert!(!assignment.is_satisfied_by(&tampered));
+
+        // Ensure a witness with a missing variable does not satisfy the constraints.
+        let truncated = Witness { public: witness.public().to_vec(), private: vec![] };
+        assert!(!assignment.is_satisfied_by(&truncated));
+    }
+}
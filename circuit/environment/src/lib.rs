@@ -44,12 +44,18 @@ pub use traits::*;
 
 pub mod prelude {
     pub use crate::{
+        Checkpoint,
         CircuitType,
         Count,
         Environment,
         LinearCombination,
         Mode,
         OutputMode,
+        Profile,
+        ProfileMetric,
+        PublicInput,
+        TableIndex,
+        Unsatisfied,
         Variable,
         count,
         count_is,
@@ -13,12 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Mode, helpers::Constraint, *};
+use crate::{
+    Mode,
+    helpers::{Constraint, LookupConstraint},
+    *,
+};
 
 use core::{
     cell::{Cell, RefCell},
     fmt,
 };
+use std::io::{Result as IoResult, Write};
 
 type Field = <console::TestnetV0 as console::Environment>::Field;
 
@@ -90,6 +95,16 @@ impl Environment for TestnetCircuit {
         })
     }
 
+    /// Records the positions of a public input in the public input layout.
+    fn label_public_input(input: PublicInput) {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().label_public_input(input))
+    }
+
+    /// Returns the public inputs in the order of the verifier's input vector, starting with the `One` variable.
+    fn public_input_layout() -> Vec<PublicInput> {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().public_input_layout())
+    }
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -177,6 +192,93 @@ impl Environment for TestnetCircuit {
         })
     }
 
+    /// Adds a lookup table with the given rows of `(a, b, c)` triples, and returns its index.
+    fn add_lookup_table(rows: Vec<(Self::BaseField, Self::BaseField, Self::BaseField)>) -> TableIndex {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().add_lookup_table(rows))
+            } else {
+                Self::halt("Tried to add a new lookup table in witness mode")
+            }
+        })
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the given table.
+    fn enforce_lookup<A, B, C>(a: A, b: B, c: C, table: TableIndex)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                TESTNET_CIRCUIT.with(|circuit| {
+                    // Ensure the table exists.
+                    if table.index() as u64 >= circuit.borrow().num_lookup_tables() {
+                        Self::halt(format!("Tried to look up a row in a missing {table}"))
+                    }
+
+                    let (a, b, c) = (a.into(), b.into(), c.into());
+
+                    // Ensure the lookup constraint is not comprised of constants.
+                    match a.is_constant() && b.is_constant() && c.is_constant() {
+                        true => {
+                            // Evaluate the constant lookup constraint.
+                            let row = (a.value(), b.value(), c.value());
+                            assert!(
+                                circuit.borrow().to_lookup_tables()[table.index()].contains(&row),
+                                "Constant lookup constraint failed: ({a}, {b}, {c}) is not in {table}"
+                            );
+                        }
+                        false => {
+                            // Construct the lookup constraint object.
+                            let constraint = LookupConstraint(circuit.borrow().scope(), a, b, c, table);
+                            // Append the lookup constraint.
+                            circuit.borrow_mut().enforce_lookup(constraint)
+                        }
+                    }
+                });
+            } else {
+                Self::halt("Tried to add a new lookup constraint in witness mode")
+            }
+        })
+    }
+
+    /// Returns a checkpoint of the current size of the environment.
+    fn checkpoint() -> Checkpoint {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().checkpoint())
+    }
+
+    /// Removes the variables and constraints added after the given checkpoint.
+    fn rollback(checkpoint: Checkpoint) {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                TESTNET_CIRCUIT.with(|circuit| {
+                    if let Err(error) = circuit.borrow_mut().rollback(checkpoint) {
+                        Self::halt(error)
+                    }
+                })
+            } else {
+                Self::halt("Tried to roll back to a checkpoint in witness mode")
+            }
+        })
+    }
+
+    /// Removes every constraint that is identical to an earlier one, and returns the number of removed constraints.
+    fn deduplicate_constraints() -> u64 {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !in_witness.get() {
+                TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().deduplicate_constraints())
+            } else {
+                Self::halt("Tried to deduplicate constraints in witness mode")
+            }
+        })
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().is_satisfied())
@@ -187,6 +289,11 @@ impl Environment for TestnetCircuit {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().is_satisfied_in_scope())
     }
 
+    /// Returns every constraint in the environment that is not satisfied, with the scope it was added in.
+    fn unsatisfied_constraints() -> Vec<Unsatisfied<Self::BaseField>> {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().unsatisfied_constraints())
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().num_constants())
@@ -217,6 +324,11 @@ impl Environment for TestnetCircuit {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().num_nonzeros())
     }
 
+    /// Returns a hash of the structure of the constraint system, which does not depend on the values of the variables.
+    fn fingerprint() -> [u8; 32] {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().fingerprint())
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64 {
         TESTNET_CIRCUIT.with(|circuit| circuit.borrow().num_constants_in_scope())
@@ -262,6 +374,16 @@ impl Environment for TestnetCircuit {
         CONSTRAINT_LIMIT.with(|current_limit| current_limit.replace(limit));
     }
 
+    /// Returns the circuit size of each scope, if profiling is enabled.
+    fn get_profile() -> Option<Profile> {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow().profile().cloned())
+    }
+
+    /// Enables or disables profiling of the circuit size of each scope.
+    fn set_profiling(enabled: bool) {
+        TESTNET_CIRCUIT.with(|circuit| circuit.borrow_mut().set_profiling(enabled))
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         let error = message.into();
@@ -332,6 +454,15 @@ impl Environment for TestnetCircuit {
         })
     }
 
+    /// Writes the constraint system and its assignment in the circom `.r1cs` and `.wtns` binary formats.
+    fn export_circom<R: Write, W: Write>(r1cs_writer: R, wtns_writer: W) -> IoResult<()> {
+        TESTNET_CIRCUIT.with(|circuit| {
+            let circuit = circuit.borrow();
+            circuit.write_circom_r1cs(r1cs_writer)?;
+            circuit.write_circom_wtns(wtns_writer)
+        })
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         TESTNET_CIRCUIT.with(|circuit| {
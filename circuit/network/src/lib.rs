@@ -120,6 +120,9 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
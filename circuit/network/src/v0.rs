@@ -37,6 +37,7 @@ use snarkvm_circuit_algorithms::{
     Sha3_256,
     Sha3_384,
     Sha3_512,
+    Sha256,
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
@@ -48,6 +49,7 @@ use snarkvm_circuit_types::{
 };
 
 use core::fmt;
+use std::io::{Result as IoResult, Write};
 
 type E = Circuit;
 
@@ -90,6 +92,9 @@ thread_local! {
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
 
+    /// The SHA-256 hash function.
+    static SHA_256: Sha256<AleoV0> = Sha256::<AleoV0>::new();
+
     /// The SHA-3 hash function, which outputs 256 bits.
     static SHA3_256: Sha3_256<AleoV0> = Sha3_256::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 384 bits.
@@ -120,6 +125,7 @@ impl Aleo for AleoV0 {
         POSEIDON_2.with(|_| ());
         POSEIDON_4.with(|_| ());
         POSEIDON_8.with(|_| ());
+        SHA_256.with(|_| ());
         SHA3_256.with(|_| ());
         SHA3_384.with(|_| ());
         SHA3_512.with(|_| ());
@@ -271,6 +277,11 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA_256.with(|sha256| sha256.hash(input))
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         SHA3_256.with(|sha3| sha3.hash(input))
@@ -406,6 +417,16 @@ impl Environment for AleoV0 {
         E::new_witness(mode, logic)
     }
 
+    /// Records the positions of a public input in the public input layout.
+    fn label_public_input(input: PublicInput) {
+        E::label_public_input(input)
+    }
+
+    /// Returns the public inputs in the order of the verifier's input vector, starting with the `One` variable.
+    fn public_input_layout() -> Vec<PublicInput> {
+        E::public_input_layout()
+    }
+
     /// Enters a new scope for the environment.
     fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
     where
@@ -425,6 +446,36 @@ impl Environment for AleoV0 {
         E::enforce(constraint)
     }
 
+    /// Adds a lookup table with the given rows of `(a, b, c)` triples, and returns its index.
+    fn add_lookup_table(rows: Vec<(Self::BaseField, Self::BaseField, Self::BaseField)>) -> TableIndex {
+        E::add_lookup_table(rows)
+    }
+
+    /// Adds one lookup constraint enforcing that `(A, B, C)` is a row of the given table.
+    fn enforce_lookup<A, B, C>(a: A, b: B, c: C, table: TableIndex)
+    where
+        A: Into<LinearCombination<Self::BaseField>>,
+        B: Into<LinearCombination<Self::BaseField>>,
+        C: Into<LinearCombination<Self::BaseField>>,
+    {
+        E::enforce_lookup(a, b, c, table)
+    }
+
+    /// Returns a checkpoint of the current size of the environment.
+    fn checkpoint() -> Checkpoint {
+        E::checkpoint()
+    }
+
+    /// Removes the variables and constraints added after the given checkpoint.
+    fn rollback(checkpoint: Checkpoint) {
+        E::rollback(checkpoint)
+    }
+
+    /// Removes every constraint that is identical to an earlier one, and returns the number of removed constraints.
+    fn deduplicate_constraints() -> u64 {
+        E::deduplicate_constraints()
+    }
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool {
         E::is_satisfied()
@@ -435,6 +486,11 @@ impl Environment for AleoV0 {
         E::is_satisfied_in_scope()
     }
 
+    /// Returns every constraint in the environment that is not satisfied, with the scope it was added in.
+    fn unsatisfied_constraints() -> Vec<Unsatisfied<Self::BaseField>> {
+        E::unsatisfied_constraints()
+    }
+
     /// Returns the number of constants in the entire circuit.
     fn num_constants() -> u64 {
         E::num_constants()
@@ -465,6 +521,11 @@ impl Environment for AleoV0 {
         E::num_nonzeros()
     }
 
+    /// Returns a hash of the structure of the constraint system, which does not depend on the values of the variables.
+    fn fingerprint() -> [u8; 32] {
+        E::fingerprint()
+    }
+
     /// Returns the number of constants for the current scope.
     fn num_constants_in_scope() -> u64 {
         E::num_constants_in_scope()
@@ -510,6 +571,16 @@ impl Environment for AleoV0 {
         E::set_constraint_limit(limit)
     }
 
+    /// Returns the circuit size of each scope, if profiling is enabled.
+    fn get_profile() -> Option<Profile> {
+        E::get_profile()
+    }
+
+    /// Enables or disables profiling of the circuit size of each scope.
+    fn set_profiling(enabled: bool) {
+        E::set_profiling(enabled)
+    }
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
         E::halt(message)
@@ -530,6 +601,11 @@ impl Environment for AleoV0 {
         E::eject_assignment_and_reset()
     }
 
+    /// Writes the constraint system and its assignment in the circom `.r1cs` and `.wtns` binary formats.
+    fn export_circom<R: Write, W: Write>(r1cs_writer: R, wtns_writer: W) -> IoResult<()> {
+        E::export_circom(r1cs_writer, wtns_writer)
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         E::reset()
@@ -0,0 +1,237 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in a significand extended with guard, round, and sticky bits.
+const EXTENDED_BITS: usize = FRACTION_BITS + 4;
+
+impl<E: Environment> Add<Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the sum of `self` and `other`, rounded toward zero.
+    fn add(self, other: Float32<E>) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<E: Environment> Add<&Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the sum of `self` and `other`, rounded toward zero.
+    fn add(self, other: &Float32<E>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment> Add<&Float32<E>> for &Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the sum of `self` and `other`, rounded toward zero.
+    /// The circuit is unsatisfiable if the sum overflows.
+    fn add(self, other: &Float32<E>) -> Self::Output {
+        // Order the operands by magnitude, so that `|x| >= |y|`.
+        let swap = self.magnitude().is_less_than(&other.magnitude());
+        let x = Float32::ternary(&swap, other, self);
+        let y = Float32::ternary(&swap, self, other);
+
+        // Extend the significands with guard, round, and sticky bits.
+        let x_extended = [vec![Boolean::constant(false); 3], x.significand_bits_le()].concat();
+        let mut y_aligned = [vec![Boolean::constant(false); 3], y.significand_bits_le()].concat();
+
+        // Align `y` to the exponent of `x`, folding the bits that are shifted out into the sticky bit.
+        // Note: The shift is non-negative, as `|x| >= |y|` implies that the exponent of `x` is at least that of `y`.
+        let shift_bits_le = (x.exponent() - y.exponent()).to_lower_bits_le(8);
+        let mut sticky = Boolean::constant(false);
+        for (i, bit) in shift_bits_le.iter().take(5).enumerate() {
+            let distance = 1 << i;
+            // Accumulate the bits that are shifted out by this stage.
+            let shifted_out = y_aligned[..distance].iter().fold(Boolean::constant(false), |acc, b| acc | b);
+            sticky |= bit & shifted_out;
+            // Shift right by `distance` if the bit is set.
+            y_aligned = (0..EXTENDED_BITS)
+                .map(|j| match y_aligned.get(j + distance) {
+                    Some(shifted) => Boolean::ternary(bit, shifted, &y_aligned[j]),
+                    None => !bit & &y_aligned[j],
+                })
+                .collect();
+        }
+        // If the shift is at least 32, then every bit of `y` is shifted out.
+        let is_shifted_out = shift_bits_le[5..].iter().fold(Boolean::constant(false), |acc, b| acc | b);
+        let sticky = Boolean::ternary(&is_shifted_out, &!y.is_zero(), &sticky);
+        let mut y_aligned = y_aligned.iter().map(|bit| bit & !&is_shifted_out).collect::<Vec<_>>();
+        y_aligned[0] = &y_aligned[0] | &sticky;
+
+        // Add or subtract the aligned significands, which is non-negative as `|x| >= |y|`.
+        let x_extended = Field::from_bits_le(&x_extended);
+        let y_aligned = Field::from_bits_le(&y_aligned);
+        let is_subtraction = x.is_negative() ^ y.is_negative();
+        let sum = x_extended + Field::ternary(&is_subtraction, &-&y_aligned, &y_aligned);
+        let sum_bits_le = sum.to_lower_bits_le(EXTENDED_BITS + 1);
+
+        // Count the leading zeros of the 28-bit sum, which is 28 if the sum is zero.
+        let mut is_leading_zero = Boolean::constant(true);
+        let mut leading_zeros = Field::zero();
+        for bit in sum_bits_le.iter().rev() {
+            is_leading_zero &= !bit;
+            leading_zeros += Field::from_boolean(&is_leading_zero);
+        }
+        let is_zero = is_leading_zero;
+
+        // Normalize the sum so that its leading one is at bit 27, and truncate it to 24 bits.
+        let mut normalized = sum_bits_le;
+        for (i, bit) in leading_zeros.to_lower_bits_le(5).iter().enumerate() {
+            let distance = 1 << i;
+            // Shift left by `distance` if the bit is set.
+            normalized = (0..=EXTENDED_BITS)
+                .map(|j| match j.checked_sub(distance) {
+                    Some(k) => Boolean::ternary(bit, &normalized[k], &normalized[j]),
+                    None => !bit & &normalized[j],
+                })
+                .collect();
+        }
+        let fraction_bits_le = &normalized[4..4 + FRACTION_BITS];
+
+        // The biased exponent is `x_exponent + 1 - leading_zeros`, which is offset by 27 to be non-negative.
+        let shifted_exponent = x.exponent() + Field::constant(console::Field::from_u16(28)) - leading_zeros;
+        Float32::compose(&x.is_negative(), &shifted_exponent, 27, fraction_bits_le, &is_zero)
+    }
+}
+
+impl<E: Environment> Sub<Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the difference of `self` and `other`, rounded toward zero.
+    fn sub(self, other: Float32<E>) -> Self::Output {
+        &self - &other
+    }
+}
+
+impl<E: Environment> Sub<&Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the difference of `self` and `other`, rounded toward zero.
+    fn sub(self, other: &Float32<E>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment> Sub<&Float32<E>> for &Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the difference of `self` and `other`, rounded toward zero.
+    /// The circuit is unsatisfiable if the difference overflows.
+    fn sub(self, other: &Float32<E>) -> Self::Output {
+        self + &(-other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Samples a float whose biased exponent is within the given range.
+    fn sample(
+        rng: &mut TestRng,
+        exponents: core::ops::RangeInclusive<u32>,
+    ) -> console::Float32<<Circuit as Environment>::Network> {
+        let sign = u32::from(rng.gen::<bool>()) << 31;
+        let exponent = rng.gen_range(exponents) << 23;
+        let fraction = rng.gen::<u32>() & ((1 << FRACTION_BITS) - 1);
+        console::Float32::new(f32::from_bits(sign | exponent | fraction))
+    }
+
+    fn check_add(
+        first: console::Float32<<Circuit as Environment>::Network>,
+        second: console::Float32<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Float32::<Circuit>::new(mode_a, first);
+        let b = Float32::<Circuit>::new(mode_b, second);
+
+        match first.checked_add(&second) {
+            Some(expected) => Circuit::scope(format!("Add: {mode_a} + {mode_b}"), || {
+                let candidate = &a + &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            }),
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => assert!(std::panic::catch_unwind(|| &a + &b).is_err()),
+                false => Circuit::scope(format!("Add: {mode_a} + {mode_b}"), || {
+                    let _candidate = &a + &b;
+                    assert!(!Circuit::is_satisfied_in_scope());
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample exponents that are close, so that the significands overlap.
+            let first = sample(&mut rng, 115..=140);
+            let second = sample(&mut rng, 115..=140);
+            check_add(first, second, mode_a, mode_b);
+            check_add(first, -second, mode_a, mode_b);
+
+            // Sample any two floats, which are mostly far apart.
+            let first = console::Float32::rand(&mut rng);
+            let second = console::Float32::rand(&mut rng);
+            check_add(first, second, mode_a, mode_b);
+        }
+
+        let float = console::Float32::new;
+        // Ensure adding zero is the identity, and exact cancellation results in zero.
+        check_add(float(1.5), float(0.0), mode_a, mode_b);
+        check_add(float(0.0), float(-1.5), mode_a, mode_b);
+        check_add(float(0.0), float(0.0), mode_a, mode_b);
+        check_add(float(1.5), float(-1.5), mode_a, mode_b);
+        // Ensure a negligible subtrahend rounds toward zero.
+        check_add(float(1.0), float(-(2f32.powi(-40))), mode_a, mode_b);
+        check_add(float(-1.0), float(2f32.powi(-100)), mode_a, mode_b);
+        // Ensure results that are too small are flushed to zero, and results that are too large overflow.
+        check_add(float(f32::MIN_POSITIVE * 1.5), float(-f32::MIN_POSITIVE), mode_a, mode_b);
+        check_add(float(f32::MAX), float(f32::MAX), mode_a, mode_b);
+        check_add(float(f32::MAX), float(1.0), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        let float = console::Float32::<<Circuit as Environment>::Network>::new;
+
+        let a = Float32::<Circuit>::new(Mode::Private, float(2.0));
+        let b = Float32::<Circuit>::new(Mode::Public, float(1.5));
+        assert_eq!(float(0.5), (&a - &b).eject_value());
+        assert_eq!(float(-0.5), (b - a).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Compare<Self> for Float32<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        // If the signs differ, then `self < other` if `self` is negative, as there is no negative zero.
+        // If the signs are the same, then `self < other` if `|self| < |other|` for positive floats,
+        // and if `|self| > |other|` for negative floats.
+        let self_is_negative = self.is_negative();
+        let same_sign = self_is_negative.is_equal(&other.is_negative());
+        let magnitude_is_less = self.magnitude().is_less_than(&other.magnitude());
+        let magnitude_is_greater = self.magnitude().is_greater_than(&other.magnitude());
+        let same_sign_is_less = Boolean::ternary(&self_is_negative, &magnitude_is_greater, &magnitude_is_less);
+        Boolean::ternary(&same_sign, &same_sign_is_less, &self_is_negative)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_compare(
+        first: console::Float32<<Circuit as Environment>::Network>,
+        second: console::Float32<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Float32::<Circuit>::new(mode_a, first);
+        let b = Float32::<Circuit>::new(mode_b, second);
+
+        Circuit::scope(format!("Compare: {mode_a} {mode_b}"), || {
+            assert_eq!(first < second, a.is_less_than(&b).eject_value());
+            assert_eq!(first > second, a.is_greater_than(&b).eject_value());
+            assert_eq!(first <= second, a.is_less_than_or_equal(&b).eject_value());
+            assert_eq!(first >= second, a.is_greater_than_or_equal(&b).eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random floats.
+            let first = console::Float32::rand(&mut rng);
+            let second = console::Float32::rand(&mut rng);
+
+            check_compare(first, second, mode_a, mode_b);
+            check_compare(first, first, mode_a, mode_b);
+            check_compare(first, -first, mode_a, mode_b);
+        }
+
+        // Ensure zero is between the negative and positive floats.
+        let float = console::Float32::new;
+        check_compare(float(0.0), float(-1.0), mode_a, mode_b);
+        check_compare(float(0.0), float(1.0), mode_a, mode_b);
+        check_compare(float(-0.0), float(0.0), mode_a, mode_b);
+        check_compare(float(-1.5), float(-1.25), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_compare() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Equal<Self> for Float32<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Note: As the encodings are canonical, the floats are equal if and only if their encodings are equal.
+        Field::from_bits_le(&self.bits_le).is_equal(&Field::from_bits_le(&other.bits_le))
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_is_equal(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two random floats.
+            let first = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let second = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let a = Float32::<Circuit>::new(mode_a, first);
+            let b = Float32::<Circuit>::new(mode_b, second);
+            let a_copy = Float32::<Circuit>::new(mode_b, first);
+
+            Circuit::scope(format!("{mode_a} {mode_b} {i}"), || {
+                assert_eq!(first == second, a.is_equal(&b).eject_value());
+                assert_eq!(first != second, a.is_not_equal(&b).eject_value());
+                assert!(a.is_equal(&a_copy).eject_value());
+                assert!(!a.is_not_equal(&a_copy).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_is_equal() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_is_equal(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBits for Float32<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new float from the 32 little-endian bits of its IEEE-754 encoding.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits matches the encoding.
+        if bits_le.len() != SIZE_IN_BITS {
+            E::halt(format!("Attempted to initialize a float from {} bits", bits_le.len()))
+        }
+        let float = Self { bits_le: bits_le.to_vec() };
+        // Ensure the encoding is canonical.
+        float.enforce_canonical();
+        float
+    }
+
+    /// Initializes a new float from the 32 big-endian bits of its IEEE-754 encoding.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random float.
+            let expected = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let given_bits = Float32::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Float32::<Circuit>::from_bits_le(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                let candidate = Float32::<Circuit>::from_bits_be(&given_bits.iter().rev().cloned().collect::<Vec<_>>());
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits() {
+        check_from_bits(Mode::Constant);
+        check_from_bits(Mode::Public);
+        check_from_bits(Mode::Private);
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ToBits for Float32<E> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the 32 little-endian bits of the IEEE-754 encoding.
+    fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
+        (&self).write_bits_le(vec);
+    }
+
+    /// Outputs the 32 big-endian bits of the IEEE-754 encoding.
+    fn write_bits_be(&self, vec: &mut Vec<Self::Boolean>) {
+        (&self).write_bits_be(vec);
+    }
+}
+
+impl<E: Environment> ToBits for &Float32<E> {
+    type Boolean = Boolean<E>;
+
+    /// Outputs the 32 little-endian bits of the IEEE-754 encoding.
+    fn write_bits_le(&self, vec: &mut Vec<Self::Boolean>) {
+        vec.extend_from_slice(&self.bits_le);
+    }
+
+    /// Outputs the 32 big-endian bits of the IEEE-754 encoding.
+    fn write_bits_be(&self, vec: &mut Vec<Self::Boolean>) {
+        vec.extend(self.bits_le.iter().rev().cloned());
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_bits(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random float.
+            let expected = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let candidate = Float32::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                assert_eq!(expected.to_bits_le(), candidate.to_bits_le().eject_value());
+                assert_eq!(expected.to_bits_be(), candidate.to_bits_be().eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bits() {
+        check_to_bits(Mode::Constant);
+        check_to_bits(Mode::Public);
+        check_to_bits(Mode::Private);
+    }
+}
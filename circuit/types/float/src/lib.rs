@@ -0,0 +1,242 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+//! A subset of the IEEE-754 binary32 floating-point format, with the same semantics as the console `Float32`.
+//!
+//! Only finite values are supported, subnormal values are flushed to zero, and arithmetic rounds toward zero.
+//! An operation whose result overflows the largest finite exponent is unsatisfiable.
+
+mod helpers;
+
+pub mod add;
+pub mod compare;
+pub mod equal;
+pub mod mul;
+pub mod neg;
+pub mod ternary;
+
+#[cfg(test)]
+use console::{TestRng, Uniform};
+#[cfg(test)]
+use snarkvm_circuit_environment::assert_scope;
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_field::Field;
+use snarkvm_circuit_types_integers::{U16, U32};
+
+/// The number of bits in the encoding.
+const SIZE_IN_BITS: usize = 32;
+/// The number of fraction bits.
+const FRACTION_BITS: usize = 23;
+/// The exponent bias.
+const EXPONENT_BIAS: u16 = 127;
+/// The largest biased exponent of a finite value.
+const MAX_EXPONENT: u16 = 254;
+
+#[derive(Clone)]
+pub struct Float32<E: Environment> {
+    /// The little-endian bits of the IEEE-754 encoding.
+    bits_le: Vec<Boolean<E>>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Float32<E> {
+    type Primitive = console::Float32<E::Network>;
+
+    /// Initializes a new float from its IEEE-754 encoding.
+    fn new(mode: Mode, value: Self::Primitive) -> Self {
+        let float = Self { bits_le: value.to_bits_le().into_iter().map(|bit| Boolean::new(mode, bit)).collect() };
+        // Ensure the encoding is canonical.
+        float.enforce_canonical();
+        float
+    }
+}
+
+impl<E: Environment> Float32<E> {
+    /// Initializes the zero float.
+    pub fn zero() -> Self {
+        Self { bits_le: vec![Boolean::constant(false); SIZE_IN_BITS] }
+    }
+
+    /// Returns `true` if the float is zero.
+    pub fn is_zero(&self) -> Boolean<E> {
+        // Note: As the encoding is canonical, the float is zero if and only if its exponent is zero.
+        !self.exponent_bits_le().iter().fold(Boolean::constant(false), |is_nonzero, bit| is_nonzero | bit)
+    }
+
+    /// Returns `true` if the float is negative.
+    pub fn is_negative(&self) -> Boolean<E> {
+        self.bits_le[SIZE_IN_BITS - 1].clone()
+    }
+
+    /// Enforces that the encoding is finite, and that subnormal values and negative zero are flushed to zero.
+    fn enforce_canonical(&self) {
+        // Ensure the exponent is not all ones, which would be an infinity or NaN.
+        E::assert(!self.exponent_bits_le().iter().fold(Boolean::constant(true), |is_max, bit| is_max & bit));
+        // Ensure the fraction and sign bits are zero if the exponent is zero.
+        let fraction_and_sign = Field::from_bits_le(&[self.fraction_bits_le(), &[self.is_negative()][..]].concat());
+        E::assert_eq(Field::from_boolean(&self.is_zero()) * fraction_and_sign, Field::<E>::zero());
+    }
+
+    /// Returns the little-endian fraction bits.
+    fn fraction_bits_le(&self) -> &[Boolean<E>] {
+        &self.bits_le[..FRACTION_BITS]
+    }
+
+    /// Returns the little-endian bits of the biased exponent.
+    fn exponent_bits_le(&self) -> &[Boolean<E>] {
+        &self.bits_le[FRACTION_BITS..SIZE_IN_BITS - 1]
+    }
+
+    /// Returns the biased exponent.
+    fn exponent(&self) -> Field<E> {
+        Field::from_bits_le(self.exponent_bits_le())
+    }
+
+    /// Returns the 24 little-endian bits of the significand, including its implicit leading bit.
+    /// The significand of zero is zero.
+    fn significand_bits_le(&self) -> Vec<Boolean<E>> {
+        let mut bits_le = self.fraction_bits_le().to_vec();
+        bits_le.push(!self.is_zero());
+        bits_le
+    }
+
+    /// Returns the magnitude of the float, as the integer of its encoding without the sign bit.
+    /// As the encoding is canonical, the magnitudes of two floats are ordered as their absolute values.
+    fn magnitude(&self) -> U32<E> {
+        U32::from_bits_le(&self.bits_le[..SIZE_IN_BITS - 1])
+    }
+
+    /// Returns the float with the given sign, exponent, and fraction, where the biased exponent is
+    /// `shifted_exponent - offset`, and the result is zero if `is_zero` is set.
+    ///
+    /// The result is zero if the exponent underflows, and unsatisfiable if the exponent overflows.
+    /// `shifted_exponent` is enforced to be a 16-bit integer.
+    fn compose(
+        sign: &Boolean<E>,
+        shifted_exponent: &Field<E>,
+        offset: u16,
+        fraction_bits_le: &[Boolean<E>],
+        is_zero: &Boolean<E>,
+    ) -> Self {
+        debug_assert_eq!(fraction_bits_le.len(), FRACTION_BITS);
+
+        // Ensure the exponent does not overflow.
+        let shifted = U16::from_field(shifted_exponent.clone());
+        E::assert(shifted.is_less_than_or_equal(&U16::constant(console::Integer::new(offset + MAX_EXPONENT))));
+        // Flush the result to zero if the exponent underflows.
+        let is_zero = is_zero | shifted.is_less_than_or_equal(&U16::constant(console::Integer::new(offset)));
+
+        // Compute the biased exponent, which is zero for zero.
+        let exponent = Field::ternary(
+            &is_zero,
+            &Field::zero(),
+            &(shifted_exponent - Field::constant(console::Field::from_u16(offset))),
+        );
+
+        // Compose the bits, clearing the fraction and sign of zero.
+        let is_nonzero = !is_zero;
+        let mut bits_le = fraction_bits_le.iter().map(|bit| bit & &is_nonzero).collect::<Vec<_>>();
+        bits_le.extend(exponent.to_lower_bits_le(SIZE_IN_BITS - FRACTION_BITS - 1));
+        bits_le.push(sign & &is_nonzero);
+        Self { bits_le }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for Float32<E> {
+    type Primitive = console::Float32<E::Network>;
+
+    /// Ejects the mode of the float.
+    fn eject_mode(&self) -> Mode {
+        self.bits_le.eject_mode()
+    }
+
+    /// Ejects the float.
+    fn eject_value(&self) -> Self::Primitive {
+        console::Float32::from_bits_le(&self.bits_le.eject_value())
+            .unwrap_or_else(|error| E::halt(format!("Failed to eject a float value: {error}")))
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Debug for Float32<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Display for Float32<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_new(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random float.
+            let expected = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+
+            Circuit::scope(format!("New {mode}"), || {
+                let candidate = Float32::<Circuit>::new(mode, expected);
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        check_new(Mode::Constant);
+        check_new(Mode::Public);
+        check_new(Mode::Private);
+    }
+
+    #[test]
+    fn test_zero() {
+        let zero = Float32::<Circuit>::zero();
+        assert!(zero.eject_value().is_zero());
+        assert!(zero.is_zero().eject_value());
+        assert!(!zero.is_negative().eject_value());
+    }
+
+    #[test]
+    fn test_non_canonical_is_unsatisfied() {
+        // Ensure an infinity, a subnormal value, and negative zero are rejected.
+        for bits in [f32::INFINITY.to_bits(), 1, 1 << 31] {
+            let bits_le =
+                (0..SIZE_IN_BITS).map(|i| Boolean::<Circuit>::new(Mode::Private, (bits >> i) & 1 == 1)).collect();
+            Float32 { bits_le }.enforce_canonical();
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
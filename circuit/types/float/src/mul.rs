@@ -0,0 +1,132 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Mul<Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the product of `self` and `other`, rounded toward zero.
+    fn mul(self, other: Float32<E>) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl<E: Environment> Mul<&Float32<E>> for Float32<E> {
+    type Output = Self;
+
+    /// Returns the product of `self` and `other`, rounded toward zero.
+    fn mul(self, other: &Float32<E>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment> Mul<&Float32<E>> for &Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the product of `self` and `other`, rounded toward zero.
+    /// The circuit is unsatisfiable if the product overflows.
+    fn mul(self, other: &Float32<E>) -> Self::Output {
+        // Multiply the significands, which for non-zero operands is within `[2^46, 2^48)`.
+        let product =
+            Field::from_bits_le(&self.significand_bits_le()) * Field::from_bits_le(&other.significand_bits_le());
+        let product_bits_le = product.to_lower_bits_le(48);
+
+        // Truncate the product to 24 bits, shifting by one more bit if it carried into bit 47.
+        let carry = &product_bits_le[47];
+        let fraction_bits_le = (0..FRACTION_BITS)
+            .map(|i| Boolean::ternary(carry, &product_bits_le[24 + i], &product_bits_le[23 + i]))
+            .collect::<Vec<_>>();
+
+        // The biased exponent is `a_exponent + b_exponent - 127 + carry`, which is offset by the bias to be non-negative.
+        let shifted_exponent = self.exponent() + other.exponent() + Field::from_boolean(carry);
+        let is_zero = self.is_zero() | other.is_zero();
+
+        Float32::compose(
+            &(self.is_negative() ^ other.is_negative()),
+            &shifted_exponent,
+            EXPONENT_BIAS,
+            &fraction_bits_le,
+            &is_zero,
+        )
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul(
+        first: console::Float32<<Circuit as Environment>::Network>,
+        second: console::Float32<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Float32::<Circuit>::new(mode_a, first);
+        let b = Float32::<Circuit>::new(mode_b, second);
+
+        match first.checked_mul(&second) {
+            Some(expected) => Circuit::scope(format!("Mul: {mode_a} * {mode_b}"), || {
+                let candidate = &a * &b;
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            }),
+            None => match mode_a.is_constant() && mode_b.is_constant() {
+                true => assert!(std::panic::catch_unwind(|| &a * &b).is_err()),
+                false => Circuit::scope(format!("Mul: {mode_a} * {mode_b}"), || {
+                    let _candidate = &a * &b;
+                    assert!(!Circuit::is_satisfied_in_scope());
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random floats, and scale them down to also cover products that do not overflow.
+            let first = console::Float32::rand(&mut rng);
+            let second = console::Float32::rand(&mut rng);
+            let scale = console::Float32::new(2f32.powi(-64));
+
+            check_mul(first, second, mode_a, mode_b);
+            check_mul(first * scale, second * scale, mode_a, mode_b);
+        }
+
+        let float = console::Float32::new;
+        // Ensure the product with zero is zero.
+        check_mul(float(-1.5), float(0.0), mode_a, mode_b);
+        // Ensure products that are too small are flushed to zero, and products that are too large overflow.
+        check_mul(float(f32::MIN_POSITIVE), float(0.5), mode_a, mode_b);
+        check_mul(float(f32::MAX), float(2.0), mode_a, mode_b);
+        // Ensure the product is truncated, with and without a carry.
+        check_mul(float(1.0 + f32::EPSILON), float(1.0 - f32::EPSILON / 2.0), mode_a, mode_b);
+        check_mul(float(-1.75), float(1.75), mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_mul() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+}
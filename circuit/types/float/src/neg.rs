@@ -0,0 +1,74 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Neg for Float32<E> {
+    type Output = Self;
+
+    /// Returns the negation of `self`.
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl<E: Environment> Neg for &Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the negation of `self`.
+    fn neg(self) -> Self::Output {
+        // Flip the sign bit, unless `self` is zero, which is always positive.
+        let mut bits_le = self.bits_le.clone();
+        bits_le[SIZE_IN_BITS - 1] = !self.is_negative() & !self.is_zero();
+        Float32 { bits_le }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_neg(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random float.
+            let given = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let candidate = Float32::<Circuit>::new(mode, given);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                assert_eq!(-given, (-&candidate).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+
+        // Ensure the negation of zero is zero.
+        let zero = Float32::<Circuit>::new(mode, console::Float32::zero());
+        assert_eq!(console::Float32::zero(), (-zero).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_neg() {
+        check_neg(Mode::Constant);
+        check_neg(Mode::Public);
+        check_neg(Mode::Private);
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Ternary for Float32<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        Self {
+            bits_le: first
+                .bits_le
+                .iter()
+                .zip_eq(&second.bits_le)
+                .map(|(first, second)| Boolean::ternary(condition, first, second))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_ternary(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two random floats.
+            let first = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let second = console::Float32::<<Circuit as Environment>::Network>::rand(&mut rng);
+            let a = Float32::<Circuit>::new(mode_a, first);
+            let b = Float32::<Circuit>::new(mode_b, second);
+
+            for flag in [true, false] {
+                let condition = Boolean::<Circuit>::new(mode_condition, flag);
+                Circuit::scope(format!("{mode_condition} {mode_a} {mode_b} {flag} {i}"), || {
+                    let candidate = Float32::ternary(&condition, &a, &b);
+                    assert_eq!(if flag { first } else { second }, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    check_ternary(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DivSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn div_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly return the value of the division.
+            (true, true) => witness!(|self, other| self.div_saturating(&other)),
+            // Handle the remaining cases.
+            // Note that `other` is either a constant and non-zero, or not a constant.
+            _ => {
+                if I::is_signed() {
+                    // Signed integer division wraps when the dividend is Integer::MIN and the divisor is -1.
+                    // In this case, the quotient is bounded to Integer::MAX.
+                    let min = Integer::constant(console::Integer::MIN);
+                    let neg_one = Integer::constant(-console::Integer::one());
+                    let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+
+                    // Note that this call to `div_wrapped` checks that `other` is not zero.
+                    Self::ternary(&overflows, &Integer::constant(console::Integer::MAX), &self.div_wrapped(other))
+                } else {
+                    // Unsigned integer division never overflows.
+                    // Note that this call to `div_wrapped` checks that `other` is not zero.
+                    self.div_wrapped(other)
+                }
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn DivSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Unsigned integer division is the same as `div_wrapped`.
+        if !I::is_signed() {
+            return <Self as Metrics<dyn DivWrapped<Integer<E, I>, Output = Integer<E, I>>>>::count(case);
+        }
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+                true => Count::less_than(8 * I::BITS + 1, 0, (10 * I::BITS) + 11, (10 * I::BITS) + 17),
+                false => Count::less_than(9 * I::BITS + 1, 0, 1614, 1624),
+            },
+            (_, _) => match 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+                true => Count::is(7 * I::BITS, 0, (10 * I::BITS) + 11, (10 * I::BITS) + 17),
+                false => Count::is(7 * I::BITS, 0, 1614, 1624),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn DivSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_div<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::div_saturating),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_saturating(&b);
+                    assert_count_fails!(DivSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                }),
+            }
+        } else {
+            let expected = first.div_saturating(&second);
+            Circuit::scope(name, || {
+                let candidate = a.div_saturating(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(DivSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(DivSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            })
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Div: {first} / {second}");
+            check_div::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Div by One: {first} / 1");
+            check_div::<I>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("Div by Self: {first} / {first}");
+            check_div::<I>(&name, first, first, mode_a, mode_b);
+
+            let name = format!("Div by Zero: {first} / 0");
+            check_div::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check corner cases.
+        check_div::<I>("MAX / 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div::<I>("MIN / 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_div::<I>("1 / 1", console::Integer::one(), console::Integer::one(), mode_a, mode_b);
+        check_div::<I>("0 / 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_div::<I>("MAX / 0", console::Integer::MAX, console::Integer::zero(), mode_a, mode_b);
+        check_div::<I>("MIN / 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integers.
+        if I::is_signed() {
+            check_div::<I>("MAX / -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_div::<I>("MIN / -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+            check_div::<I>("1 / -1", console::Integer::one(), -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let first = console::Integer::<_, I>::new(first);
+                let second = console::Integer::<_, I>::new(second);
+
+                let name = format!("Div: ({first} / {second})");
+                check_div::<I>(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, div_saturating);
+    test_integer_binary!(run_test, i16, div_saturating);
+    test_integer_binary!(run_test, i32, div_saturating);
+    test_integer_binary!(run_test, i64, div_saturating);
+    test_integer_binary!(run_test, i128, div_saturating);
+
+    test_integer_binary!(run_test, u8, div_saturating);
+    test_integer_binary!(run_test, u16, div_saturating);
+    test_integer_binary!(run_test, u32, div_saturating);
+    test_integer_binary!(run_test, u64, div_saturating);
+    test_integer_binary!(run_test, u128, div_saturating);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, div_saturating, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, div_saturating, exhaustive);
+}
@@ -26,6 +26,7 @@ pub mod add_wrapped;
 pub mod and;
 pub mod compare;
 pub mod div_checked;
+pub mod div_saturating;
 pub mod div_wrapped;
 pub mod equal;
 pub mod modulo;
@@ -37,6 +38,7 @@ pub mod or;
 pub mod pow_checked;
 pub mod pow_wrapped;
 pub mod rem_checked;
+pub mod rem_saturating;
 pub mod rem_wrapped;
 pub mod shl_checked;
 pub mod shl_wrapped;
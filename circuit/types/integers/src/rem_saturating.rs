@@ -0,0 +1,126 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RemSaturating<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the remainder of `self` divided by `other`.
+    ///
+    /// The remainder of `MIN % -1` is `0`, which is in range, so this is the same as `rem_wrapped`.
+    #[inline]
+    fn rem_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        self.rem_wrapped(other)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RemSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn RemWrapped<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RemSaturating<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn RemWrapped<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::rem_saturating),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.rem_saturating(&b);
+                    assert_count_fails!(RemSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                }),
+            }
+        } else {
+            let expected = first.rem_saturating(&second);
+            Circuit::scope(name, || {
+                let candidate = a.rem_saturating(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(RemSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(RemSaturating(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            })
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Rem: {first} % {second}");
+            check_rem::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Rem by Zero: {first} % 0");
+            check_rem::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check corner cases.
+        check_rem::<I>("MAX % 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_rem::<I>("MIN % 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_rem::<I>("MIN % 0", console::Integer::MIN, console::Integer::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integers.
+        if I::is_signed() {
+            check_rem::<I>("MIN % -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, rem_saturating);
+    test_integer_binary!(run_test, i16, rem_saturating);
+    test_integer_binary!(run_test, i32, rem_saturating);
+    test_integer_binary!(run_test, i64, rem_saturating);
+    test_integer_binary!(run_test, i128, rem_saturating);
+
+    test_integer_binary!(run_test, u8, rem_saturating);
+    test_integer_binary!(run_test, u16, rem_saturating);
+    test_integer_binary!(run_test, u32, rem_saturating);
+    test_integer_binary!(run_test, u64, rem_saturating);
+    test_integer_binary!(run_test, u128, rem_saturating);
+}
@@ -29,6 +29,9 @@ pub mod modules {
     pub use snarkvm_circuit_types_field as field;
     pub use snarkvm_circuit_types_field::Field;
 
+    pub use snarkvm_circuit_types_float as float;
+    pub use snarkvm_circuit_types_float::Float32;
+
     pub use snarkvm_circuit_types_group as group;
     pub use snarkvm_circuit_types_group::Group;
 
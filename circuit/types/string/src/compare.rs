@@ -0,0 +1,88 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Compare<Self> for StringType<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is lexicographically less than `other`.
+    ///
+    /// The strings are compared byte by byte, which for UTF-8 matches the order of their characters.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        // Initialize a tracker for whether a byte of `self` was less than the byte of `other`, at the first difference.
+        let mut is_less_than = Boolean::constant(false);
+        // Initialize a tracker for whether the bytes compared so far are equal.
+        let mut is_prefix = Boolean::constant(true);
+
+        for (a, b) in self.bytes.iter().zip(&other.bytes) {
+            is_less_than |= &is_prefix & a.is_less_than(b);
+            is_prefix &= a.is_equal(b);
+        }
+
+        // If one string is a prefix of the other, then the shorter string is less.
+        is_less_than | (is_prefix & Boolean::constant(self.bytes.len() < other.bytes.len()))
+    }
+
+    /// Returns `true` if `self` is lexicographically greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        other.is_less_than(self)
+    }
+
+    /// Returns `true` if `self` is lexicographically less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        other.is_greater_than_or_equal(self)
+    }
+
+    /// Returns `true` if `self` is lexicographically greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        !self.is_less_than(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_compare(mode_a: Mode, mode_b: Mode) {
+        let given = ["", "a", "ab", "abc", "abd", "b", "aleo", "Aleo", "é", "🦀", "🦀a"];
+
+        for given_a in given {
+            for given_b in given {
+                let a = StringType::<Circuit>::new(mode_a, console::StringType::new(given_a));
+                let b = StringType::<Circuit>::new(mode_b, console::StringType::new(given_b));
+
+                Circuit::scope(format!("Compare {mode_a} {mode_b}"), || {
+                    assert_eq!(given_a < given_b, a.is_less_than(&b).eject_value());
+                    assert_eq!(given_a > given_b, a.is_greater_than(&b).eject_value());
+                    assert_eq!(given_a <= given_b, a.is_less_than_or_equal(&b).eject_value());
+                    assert_eq!(given_a >= given_b, a.is_greater_than_or_equal(&b).eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_compare(mode_a, mode_b);
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    ///
+    /// The sizes of the strings are known when the circuit is synthesized,
+    /// so this halts if the result exceeds `E::MAX_STRING_BYTES`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the result is within the allowed capacity.
+        let num_bytes = self.bytes.len() + other.bytes.len();
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate strings into {num_bytes} bytes, which exceeds the capacity"))
+        }
+        // Return the string of the concatenated bytes.
+        Self::from_bits_le(&[self.to_bits_le(), other.to_bits_le()].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample two strings that fit within the capacity together.
+            let given_a = rng.next_string(Circuit::MAX_STRING_BYTES / 8, false);
+            let given_b = rng.next_string(Circuit::MAX_STRING_BYTES / 8, false);
+            let a = StringType::<Circuit>::new(mode_a, console::StringType::new(&given_a));
+            let b = StringType::<Circuit>::new(mode_b, console::StringType::new(&given_b));
+
+            Circuit::scope(format!("Concat {mode_a} {mode_b} {i}"), || {
+                let candidate = a.concat(&b);
+                assert_eq!(console::StringType::new(&format!("{given_a}{given_b}")), candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_concat() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_concat(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_empty() {
+        let empty = StringType::<Circuit>::new(Mode::Private, console::StringType::new(""));
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("aleo"));
+
+        assert_eq!(console::StringType::new("aleo"), empty.concat(&string).eject_value());
+        assert_eq!(console::StringType::new("aleo"), string.concat(&empty).eject_value());
+        assert_eq!(console::StringType::new(""), empty.concat(&empty).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
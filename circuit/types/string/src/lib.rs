@@ -16,8 +16,11 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+mod compare;
+mod concat;
 mod equal;
 mod helpers;
+mod substring;
 
 #[cfg(test)]
 use console::TestRng;
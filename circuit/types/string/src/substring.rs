@@ -0,0 +1,92 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `length` bytes, starting at byte `start`.
+    ///
+    /// The bounds are known when the circuit is synthesized, so this halts
+    /// if they are out of range, or if they do not lie on character boundaries.
+    pub fn substring(&self, start: usize, length: usize) -> Self {
+        // Ensure the bounds are within the string.
+        let num_bytes = self.bytes.len();
+        let end = match start.checked_add(length) {
+            Some(end) if end <= num_bytes => end,
+            _ => E::halt(format!("Attempted to take {length} bytes from byte {start} of a {num_bytes}-byte string")),
+        };
+        // Ensure the bounds lie on character boundaries, by checking that neither is a UTF-8 continuation byte.
+        for index in [start, end] {
+            if let Some(byte) = self.bytes.get(index) {
+                if (0x80..0xC0).contains(&*byte.eject_value()) {
+                    E::halt("Attempted to take a substring that does not start or end at a character boundary")
+                }
+            }
+        }
+        // Return the string of the selected bytes.
+        Self::from_bits_le(&self.bytes[start..end].iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_substring(mode: Mode) {
+        let given = "snarkVM 🦀 aleo";
+        let string = StringType::<Circuit>::new(mode, console::StringType::new(given));
+
+        // Check every substring that starts and ends at a character boundary.
+        for start in (0..=given.len()).filter(|index| given.is_char_boundary(*index)) {
+            for end in (start..=given.len()).filter(|index| given.is_char_boundary(*index)) {
+                Circuit::scope(format!("Substring {mode} {start}..{end}"), || {
+                    let candidate = string.substring(start, end - start);
+                    assert_eq!(console::StringType::new(&given[start..end]), candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_substring_constant() {
+        check_substring(Mode::Constant);
+    }
+
+    #[test]
+    fn test_substring_public() {
+        check_substring(Mode::Public);
+    }
+
+    #[test]
+    fn test_substring_private() {
+        check_substring(Mode::Private);
+    }
+
+    #[test]
+    fn test_substring_fails() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("🦀"));
+
+        // Ensure the bounds must be within the string.
+        assert!(std::panic::catch_unwind(|| string.substring(0, 5)).is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(5, 0)).is_err());
+        // Ensure the bounds must lie on a character boundary.
+        assert!(std::panic::catch_unwind(|| string.substring(1, 3)).is_err());
+        assert!(std::panic::catch_unwind(|| string.substring(0, 2)).is_err());
+        Circuit::reset();
+    }
+}
@@ -20,6 +20,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod stream;
+
+pub use stream::BHPStream;
 
 use snarkvm_console_types::prelude::*;
 
@@ -0,0 +1,176 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns a streaming BHP hasher for an input of `input_len` bits.
+    ///
+    /// The length is committed to in the first iteration of the hash, and must be declared upfront.
+    pub fn stream(&self, input_len: u64) -> BHPStream<'_, E, NUM_WINDOWS, WINDOW_SIZE> {
+        BHPStream::new(self, input_len)
+    }
+}
+
+/// A streaming BHP hasher, which absorbs the input incrementally and produces
+/// the same digest as hashing the concatenated input with `BHP::hash`.
+///
+/// Only the preimage of the current iteration is held in memory.
+pub struct BHPStream<'a, E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
+    /// The BHP hash function.
+    bhp: &'a BHP<E, NUM_WINDOWS, WINDOW_SIZE>,
+    /// The declared number of input bits.
+    input_len: u64,
+    /// The number of input bits absorbed so far.
+    num_absorbed: u64,
+    /// The preimage of the current iteration.
+    preimage: Vec<bool>,
+}
+
+impl<'a, E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPStream<'a, E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// The number of hasher bits in one iteration.
+    const NUM_HASHER_BITS: usize = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
+
+    /// Initializes a new streaming hasher for an input of `input_len` bits.
+    fn new(bhp: &'a BHP<E, NUM_WINDOWS, WINDOW_SIZE>, input_len: u64) -> Self {
+        // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
+        let mut preimage = Vec::with_capacity(Self::NUM_HASHER_BITS);
+        preimage.extend(&bhp.domain);
+        input_len.write_bits_le(&mut preimage);
+
+        Self { bhp, input_len, num_absorbed: 0, preimage }
+    }
+
+    /// Absorbs the given input bits.
+    pub fn update(&mut self, mut input: &[bool]) -> Result<()> {
+        // Ensure the input does not exceed the declared length.
+        let num_absorbed = self.num_absorbed.saturating_add(input.len() as u64);
+        ensure!(
+            num_absorbed <= self.input_len,
+            "Input to BHP exceeds the declared length of {} bits, found {num_absorbed} bits",
+            self.input_len
+        );
+        self.num_absorbed = num_absorbed;
+
+        while !input.is_empty() {
+            // If the preimage is full, hash it and start the next iteration.
+            // The last iteration is only hashed in `finalize_uncompressed`.
+            if self.preimage.len() == Self::NUM_HASHER_BITS {
+                self.next_iteration()?;
+            }
+            let num_bits = std::cmp::min(Self::NUM_HASHER_BITS - self.preimage.len(), input.len());
+            self.preimage.extend_from_slice(&input[..num_bits]);
+            input = &input[num_bits..];
+        }
+        Ok(())
+    }
+
+    /// Returns the BHP hash of the absorbed input as a field element.
+    pub fn finalize(self) -> Result<Field<E>> {
+        Ok(self.finalize_uncompressed()?.to_x_coordinate())
+    }
+
+    /// Returns the BHP hash of the absorbed input as an affine group element.
+    pub fn finalize_uncompressed(self) -> Result<Group<E>> {
+        // Ensure the input matches the declared length.
+        ensure!(
+            self.num_absorbed == self.input_len,
+            "Input to BHP is shorter than the declared length of {} bits, found {} bits",
+            self.input_len,
+            self.num_absorbed
+        );
+
+        match self.input_len {
+            0 => Ok(Group::<E>::zero()),
+            _ => self.bhp.hasher.hash_uncompressed(&self.preimage),
+        }
+    }
+
+    /// Hashes the current preimage, and starts the next iteration as:
+    /// [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
+    fn next_iteration(&mut self) -> Result<()> {
+        let digest = self.bhp.hasher.hash_uncompressed(&self.preimage)?;
+        self.preimage.clear();
+        digest.to_x_coordinate().write_bits_le(&mut self.preimage);
+        self.preimage.truncate(Field::<E>::size_in_data_bits());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_stream<const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>() -> Result<()> {
+        let bhp = BHP::<CurrentEnvironment, NUM_WINDOWS, WINDOW_SIZE>::setup("BHPTest")?;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let input = (0..rng.gen_range(0..4096)).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Absorb the input in randomly-sized pieces.
+            let mut stream = bhp.stream(input.len() as u64);
+            let mut remaining = &input[..];
+            while !remaining.is_empty() {
+                let (piece, rest) = remaining.split_at(rng.gen_range(0..=remaining.len().min(1024)));
+                stream.update(piece)?;
+                remaining = rest;
+            }
+            assert_eq!(bhp.hash_uncompressed(&input)?, stream.finalize_uncompressed()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp256_stream() -> Result<()> {
+        check_stream::<3, 57>()
+    }
+
+    #[test]
+    fn test_bhp512_stream() -> Result<()> {
+        check_stream::<6, 43>()
+    }
+
+    #[test]
+    fn test_bhp768_stream() -> Result<()> {
+        check_stream::<15, 23>()
+    }
+
+    #[test]
+    fn test_bhp1024_stream() -> Result<()> {
+        check_stream::<8, 54>()
+    }
+
+    #[test]
+    fn test_stream_length_mismatch() -> Result<()> {
+        let bhp = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+
+        // Ensure the input cannot exceed the declared length.
+        let mut stream = bhp.stream(8);
+        assert!(stream.update(&[true; 9]).is_err());
+
+        // Ensure the input cannot be shorter than the declared length.
+        let mut stream = bhp.stream(8);
+        stream.update(&[true; 7])?;
+        assert!(stream.finalize().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use snarkvm_curves::ed25519::{Ed25519Affine, Ed25519Signature};
+
+use sha2::Digest;
+
+/// The Ed25519 signature scheme, as specified in RFC 8032.
+///
+/// Ed25519 is a Schnorr-style signature scheme over the edwards25519 curve, which derives its
+/// challenge as `SHA-512(R || A || M)`, where `R` is the nonce commitment, `A` is the public key,
+/// and `M` is the message. It is used by Solana and Cosmos, among others.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Ed25519;
@@ -0,0 +1,97 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Ed25519 {
+    /// Returns `true` if the given 64-byte signature is valid for the given message,
+    /// under the given 32-byte public key, with all values in their RFC 8032 encoding.
+    pub fn verify(&self, public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        // Decode the public key and signature, rejecting non-canonical encodings.
+        let (Ok(key), Ok(sig)) =
+            (Ed25519Affine::from_rfc8032_bytes(public_key), Ed25519Signature::from_bytes(signature))
+        else {
+            return false;
+        };
+        // Compute the challenge `SHA-512(R || A || M)`.
+        let challenge: [u8; 64] = sha2::Sha512::new()
+            .chain_update(&signature[..32])
+            .chain_update(public_key)
+            .chain_update(message)
+            .finalize()
+            .into();
+        // Verify the signature.
+        sig.verify(&key, &challenge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The RFC 8032 test vectors of Section 7.1, as `(public key, message, signature)`.
+    const TEST_VECTORS: [(&str, &str, &str); 3] = [
+        (
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+        (
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ),
+    ];
+
+    fn decode<const N: usize>(hex: &str) -> [u8; N] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_verify_rfc8032() {
+        for (public_key, message, signature) in TEST_VECTORS {
+            let (public_key, signature) = (decode(public_key), decode(signature));
+            let message = hex::decode(message).unwrap();
+            assert!(Ed25519.verify(&public_key, &message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_signatures() {
+        let (public_key, message, signature) = TEST_VECTORS[2];
+        let (public_key, signature) = (decode::<32>(public_key), decode::<64>(signature));
+        let message = hex::decode(message).unwrap();
+
+        // Ensure a different message is rejected.
+        assert!(!Ed25519.verify(&public_key, &[0xaf, 0x83], &signature));
+        // Ensure a different public key is rejected.
+        assert!(!Ed25519.verify(&decode(TEST_VECTORS[0].0), &message, &signature));
+        // Ensure a tampered nonce commitment or proof is rejected.
+        for index in [0, 32] {
+            let mut tampered = signature;
+            tampered[index] ^= 1;
+            assert!(!Ed25519.verify(&public_key, &message, &tampered));
+        }
+        // Ensure a proof that is not reduced modulo the group order is rejected.
+        let mut malleated = signature;
+        malleated[63] |= 0x80;
+        assert!(!Ed25519.verify(&public_key, &message, &malleated));
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+impl HashToCurve {
+    /// The output size of SHA-256, in bytes.
+    const B_IN_BYTES: usize = 32;
+    /// The input block size of SHA-256, in bytes.
+    const S_IN_BYTES: usize = 64;
+
+    /// Returns `length` uniformly random bytes derived from `message` and the domain separation tag `dst`.
+    ///
+    /// This is `expand_message_xmd` of RFC 9380, Section 5.3.1, instantiated with SHA-256.
+    pub fn expand_message_xmd(message: &[u8], dst: &[u8], length: usize) -> Result<Vec<u8>> {
+        let (Ok(ell), Ok(length_in_bytes)) = (u8::try_from(length.div_ceil(Self::B_IN_BYTES)), u16::try_from(length))
+        else {
+            bail!("Cannot expand a message to {length} bytes")
+        };
+
+        // Hash oversized domain separation tags, as specified in RFC 9380, Section 5.3.3.
+        let dst = match dst.len() > 255 {
+            true => Sha256::new().chain_update(b"H2C-OVERSIZE-DST-").chain_update(dst).finalize().to_vec(),
+            false => dst.to_vec(),
+        };
+        let dst_prime = [&dst[..], &[u8::try_from(dst.len())?]].concat();
+
+        // Compute `b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)`.
+        let b_0 = Sha256::new()
+            .chain_update([0u8; Self::S_IN_BYTES])
+            .chain_update(message)
+            .chain_update(length_in_bytes.to_be_bytes())
+            .chain_update([0u8])
+            .chain_update(&dst_prime)
+            .finalize();
+
+        // Compute `b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)`, where `b_0` is xored into an all-zero `b_(i - 1)` for `i = 1`.
+        let mut output = Vec::with_capacity(usize::from(ell) * Self::B_IN_BYTES);
+        let mut b_i = [0u8; Self::B_IN_BYTES];
+        for i in 1..=ell {
+            let input = b_0.iter().zip_eq(&b_i).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+            b_i = Sha256::new().chain_update(input).chain_update([i]).chain_update(&dst_prime).finalize().into();
+            output.extend_from_slice(&b_i);
+        }
+        output.truncate(length);
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // Reference values from RFC 9380, Appendix K.1.
+        let expected = [
+            ("", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            ("abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            ("abcdef0123456789", 0x20, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+            (
+                "",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+        for (message, length, output) in expected {
+            let candidate = HashToCurve::expand_message_xmd(message.as_bytes(), DST, length).unwrap();
+            assert_eq!(hex::encode(candidate), output);
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_length() {
+        assert!(HashToCurve::expand_message_xmd(b"", DST, 255 * 32).is_ok());
+        assert!(HashToCurve::expand_message_xmd(b"", DST, 255 * 32 + 1).is_err());
+    }
+}
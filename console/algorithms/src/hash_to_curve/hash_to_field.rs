@@ -0,0 +1,61 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_fields::{Field, PrimeField};
+
+impl HashToCurve {
+    /// Returns `COUNT` field elements derived from `message` and the domain separation tag `dst`.
+    ///
+    /// This is `hash_to_field` of RFC 9380, Section 5.2, where each coefficient over the base prime field
+    /// is reduced from `L = ceil((ceil(log2(p)) + k) / 8)` bytes, so its bias is at most `2^-k`.
+    pub fn hash_to_field<F: Field, const COUNT: usize>(message: &[u8], dst: &[u8]) -> Result<[F; COUNT]> {
+        let degree = usize::try_from(F::extension_degree())?;
+        let modulus_bits = <F::BasePrimeField as PrimeField>::size_in_bits();
+        let length = (modulus_bits + Self::SECURITY_LEVEL).div_ceil(8);
+
+        let bytes = Self::expand_message_xmd(message, dst, COUNT * degree * length)?;
+        let elements = bytes
+            .chunks(degree * length)
+            .map(|chunk| {
+                let coefficients =
+                    chunk.chunks(length).map(F::BasePrimeField::from_bytes_be_mod_order).collect::<Vec<_>>();
+                F::from_base_prime_field_elems(&coefficients)
+                    .ok_or_else(|| anyhow!("Failed to construct a field element from its coefficients"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        elements.try_into().map_err(|_| anyhow!("Failed to hash to {COUNT} field elements"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fq, Fq2};
+
+    #[test]
+    fn test_hash_to_field_length() {
+        let dst = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SSWU_RO_";
+
+        // Ensure each base prime field coefficient is derived from its own 64 bytes.
+        let [a, b] = HashToCurve::hash_to_field::<Fq, 2>(b"abc", dst).unwrap();
+        let bytes = HashToCurve::expand_message_xmd(b"abc", dst, 128).unwrap();
+        assert_eq!(a, Fq::from_bytes_be_mod_order(&bytes[..64]));
+        assert_eq!(b, Fq::from_bytes_be_mod_order(&bytes[64..]));
+
+        let [c] = HashToCurve::hash_to_field::<Fq2, 1>(b"abc", dst).unwrap();
+        assert_eq!(c, Fq2::new(a, b));
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod expand_message;
+mod hash_to_field;
+
+use snarkvm_console_types::environment::prelude::*;
+use snarkvm_curves::templates::short_weierstrass_jacobian::{Affine, MapToCurve};
+
+/// The hash-to-curve suites of RFC 9380, instantiated with `expand_message_xmd` over SHA-256.
+///
+/// Unlike the try-and-increment approach of [`Blake2Xs::hash_to_curve`](crate::Blake2Xs::hash_to_curve),
+/// these suites run in a constant number of field operations, and interoperate with other
+/// implementations of the same suite.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HashToCurve;
+
+impl HashToCurve {
+    /// The security level of the suites, in bits.
+    const SECURITY_LEVEL: usize = 128;
+
+    /// Returns the suite identifier of the random oracle suite for `P`, e.g. `BLS12377G1_XMD:SHA-256_SSWU_RO_`.
+    pub fn suite_id<P: MapToCurve>() -> String {
+        format!("{}_XMD:SHA-256_{}_RO_", P::CURVE_ID, P::MAP_ID)
+    }
+
+    /// Returns the hash of `message` to a point in the prime-order subgroup of `P`, under the domain separation tag `dst`.
+    ///
+    /// This is the `hash_to_curve` random oracle construction of RFC 9380, Section 3.
+    pub fn hash_to_curve<P: MapToCurve>(message: &[u8], dst: &[u8]) -> Result<Affine<P>> {
+        let [u0, u1] = Self::hash_to_field::<P::BaseField, 2>(message, dst)?;
+        let point = P::map_to_curve(u0).to_projective() + P::map_to_curve(u1).to_projective();
+        Ok(P::clear_cofactor(point.to_affine()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{
+        Fq,
+        Fq2,
+        G1Affine,
+        G2Affine,
+        g1::Bls12_377G1Parameters,
+        g2::Bls12_377G2Parameters,
+    };
+
+    use core::str::FromStr;
+
+    const G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SSWU_RO_";
+    const G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_RO_";
+
+    fn fq(value: &str) -> Fq {
+        Fq::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_suite_id() {
+        assert_eq!(HashToCurve::suite_id::<Bls12_377G1Parameters>(), "BLS12377G1_XMD:SHA-256_SSWU_RO_");
+        assert_eq!(HashToCurve::suite_id::<Bls12_377G2Parameters>(), "BLS12377G2_XMD:SHA-256_SVDW_RO_");
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        // Reference values computed with an independent implementation of RFC 9380.
+        let expected = [
+            (
+                "",
+                "135644788506464358258987460083253733315216701454015302820622365091034384215029504600911121808261431698664775361980",
+                "188473728890658939293824817979369960495611961853448436263412246502098437457195081666368713413350802862064422404383",
+            ),
+            (
+                "abc",
+                "241885307474838499880951702466750515379875967583952188950591702002665417004604589521407642419637901958341640668385",
+                "44617608618314787533253084393984475277310713214426442276585262024643853000485071919587340622269493705809740753879",
+            ),
+            (
+                "abcdef0123456789",
+                "212782592878570318679625001521729294065968552375627051730442534119139613465587083787714835755134703009030274513924",
+                "127976337522369521207468266036500708036609489662825894083077082907610019855685796685979056114838661176781432076505",
+            ),
+        ];
+        for (message, x, y) in expected {
+            let point = HashToCurve::hash_to_curve::<Bls12_377G1Parameters>(message.as_bytes(), G1_DST).unwrap();
+            assert_eq!(point, G1Affine::new(fq(x), fq(y), false));
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g2() {
+        // Reference values computed with an independent implementation of RFC 9380.
+        let expected = [
+            ("", [
+                "190955461573567000865269267528362167211069076262550666830439441963431279995894239041794345238287370456620217963664",
+                "176721373618845104953446694246988926098098769462125644930384122926695070632633151558317501812584280339290968379839",
+                "10652953648419141375111615337285826093311466888889365367837299804821789548128941112525016910526734927214707378276",
+                "31378453920514466995367094203754819224583796500577019884580397324221003964740134384488826667546854096487763869026",
+            ]),
+            ("abc", [
+                "194235013039719318993657341487413947812008860824852111652722134286394161585073373762991265847128062135108870834128",
+                "61603075753659268160557008281781100093820274261416698465442276555188122682078863150316979820672201861432020993252",
+                "196155438781014552453111622351308327449622819475485379918622851240750325996065260168288028540461389042667906338797",
+                "32230311516416426908346891119394622561233187163602153007972226838896365413525876146866759402302394193552780746323",
+            ]),
+            ("abcdef0123456789", [
+                "36996873393455831979119622149800869012364190140667887523890871088182156213513062587944212117178900031098702128394",
+                "115603398922925363617416711801168677346969809515011652088614069569693278809243111457786338787869318444810251311823",
+                "87556243539433308819964095957067036883736045268532660499467833219194783995791260009655099589859898343001586252232",
+                "2807597704603335298736159891991276311225590717859492241960490604782514675187916996902403573273243604983263587533",
+            ]),
+        ];
+        for (message, [x_c0, x_c1, y_c0, y_c1]) in expected {
+            let point = HashToCurve::hash_to_curve::<Bls12_377G2Parameters>(message.as_bytes(), G2_DST).unwrap();
+            let x = Fq2::new(fq(x_c0), fq(x_c1));
+            let y = Fq2::new(fq(y_c0), fq(y_c1));
+            assert_eq!(point, G2Affine::new(x, y, false));
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve_domain_separation() {
+        let a = HashToCurve::hash_to_curve::<Bls12_377G1Parameters>(b"message", b"DST-A").unwrap();
+        let b = HashToCurve::hash_to_curve::<Bls12_377G1Parameters>(b"message", b"DST-B").unwrap();
+        assert_ne!(a, b);
+    }
+}
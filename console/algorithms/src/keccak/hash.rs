@@ -23,15 +23,25 @@ impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
     /// Returns the Keccak hash of the given input as bits.
     #[inline]
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&self.hash_bytes(&bytes_from_bits_le(input))?).collect())
+    }
+}
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input as bytes.
+    ///
+    /// For `Keccak256`, this matches the `keccak256` function used by Ethereum.
+    #[inline]
+    pub fn hash_bytes(&self, input: &[u8]) -> Result<Vec<u8>> {
         let result = match (TYPE, VARIANT) {
-            (0, 224) => bits_from_bytes_le(&keccak_224_native(&bytes_from_bits_le(input))).collect(),
-            (0, 256) => bits_from_bytes_le(&keccak_256_native(&bytes_from_bits_le(input))).collect(),
-            (0, 384) => bits_from_bytes_le(&keccak_384_native(&bytes_from_bits_le(input))).collect(),
-            (0, 512) => bits_from_bytes_le(&keccak_512_native(&bytes_from_bits_le(input))).collect(),
-            (1, 224) => bits_from_bytes_le(&sha3_224_native(&bytes_from_bits_le(input))).collect(),
-            (1, 256) => bits_from_bytes_le(&sha3_256_native(&bytes_from_bits_le(input))).collect(),
-            (1, 384) => bits_from_bytes_le(&sha3_384_native(&bytes_from_bits_le(input))).collect(),
-            (1, 512) => bits_from_bytes_le(&sha3_512_native(&bytes_from_bits_le(input))).collect(),
+            (0, 224) => keccak_224_native(input).to_vec(),
+            (0, 256) => keccak_256_native(input).to_vec(),
+            (0, 384) => keccak_384_native(input).to_vec(),
+            (0, 512) => keccak_512_native(input).to_vec(),
+            (1, 224) => sha3_224_native(input).to_vec(),
+            (1, 256) => sha3_256_native(input).to_vec(),
+            (1, 384) => sha3_384_native(input).to_vec(),
+            (1, 512) => sha3_512_native(input).to_vec(),
             _ => unreachable!("Invalid Keccak type and variant"),
         };
         Ok(result)
@@ -148,6 +158,20 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_keccak_256_hash_bytes() {
+        // Reference values for the Ethereum `keccak256` function.
+        let keccak = Keccak256::default();
+        assert_eq!(
+            hex::encode(keccak.hash_bytes(b"").unwrap()),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak.hash_bytes(b"hello world").unwrap()),
+            "47173285a8d7341e5e972fc677286384f802f8ef42a5ec5f03bbfa254cb01fad"
+        );
+    }
+
     #[test]
     fn test_keccak_224_equivalence() {
         check_equivalence!(Keccak224::default(), keccak_224_native);
@@ -21,19 +21,28 @@
 pub use snarkvm_console_types::prelude::*;
 
 pub mod bhp;
-pub use bhp::{BHP, BHP256, BHP512, BHP768, BHP1024};
+pub use bhp::{BHP, BHP256, BHP512, BHP768, BHP1024, BHPStream};
 
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ed25519;
+pub use ed25519::Ed25519;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod hash_to_curve;
+pub use hash_to_curve::HashToCurve;
+
 mod keccak;
 pub use keccak::*;
 
 mod pedersen;
-pub use pedersen::{Pedersen, Pedersen64, Pedersen128};
+pub use pedersen::{Pedersen, Pedersen64, Pedersen128, PedersenStream};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod sha256;
+pub use sha256::Sha256;
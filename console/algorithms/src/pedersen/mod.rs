@@ -17,6 +17,9 @@ mod commit;
 mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
+mod stream;
+
+pub use stream::PedersenStream;
 
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
+    /// Returns a streaming Pedersen hasher.
+    pub fn stream(&self) -> PedersenStream<'_, E, NUM_BITS> {
+        PedersenStream { pedersen: self, num_absorbed: 0, digest: Group::<E>::zero() }
+    }
+}
+
+/// A streaming Pedersen hasher, which absorbs up to `NUM_BITS` bits incrementally and produces
+/// the same digest as hashing the concatenated input with `Pedersen::hash`.
+pub struct PedersenStream<'a, E: Environment, const NUM_BITS: u8> {
+    /// The Pedersen hash function.
+    pedersen: &'a Pedersen<E, NUM_BITS>,
+    /// The number of input bits absorbed so far.
+    num_absorbed: usize,
+    /// The sum of the bases for the input bits absorbed so far.
+    digest: Group<E>,
+}
+
+impl<E: Environment, const NUM_BITS: u8> PedersenStream<'_, E, NUM_BITS> {
+    /// Absorbs the given input bits.
+    pub fn update(&mut self, input: &[bool]) -> Result<()> {
+        // Ensure the input size is within the parameter size.
+        let num_absorbed = self.num_absorbed.saturating_add(input.len());
+        ensure!(
+            num_absorbed <= NUM_BITS as usize,
+            "Invalid input size for Pedersen: expected <= {NUM_BITS}, found {num_absorbed}"
+        );
+
+        // Compute sum of h_i^{m_i} for the absorbed bits.
+        for (bit, base) in input.iter().zip_eq(&self.pedersen.base_window[self.num_absorbed..num_absorbed]) {
+            if *bit {
+                self.digest += base;
+            }
+        }
+        self.num_absorbed = num_absorbed;
+        Ok(())
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as a field element.
+    pub fn finalize(self) -> Field<E> {
+        self.digest.to_x_coordinate()
+    }
+
+    /// Returns the Pedersen hash of the absorbed input as a group element.
+    pub fn finalize_uncompressed(self) -> Group<E> {
+        self.digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_stream<const NUM_BITS: u8>() -> Result<()> {
+        let pedersen = Pedersen::<CurrentEnvironment, NUM_BITS>::setup("PedersenTest");
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let input = (0..rng.gen_range(0..=NUM_BITS)).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Absorb the input in randomly-sized pieces.
+            let mut stream = pedersen.stream();
+            let mut remaining = &input[..];
+            while !remaining.is_empty() {
+                let (piece, rest) = remaining.split_at(rng.gen_range(0..=remaining.len()));
+                stream.update(piece)?;
+                remaining = rest;
+            }
+            assert_eq!(pedersen.hash(&input)?, stream.finalize());
+        }
+
+        // Ensure the input cannot exceed the parameter size.
+        let mut stream = pedersen.stream();
+        stream.update(&vec![true; NUM_BITS as usize])?;
+        assert!(stream.update(&[true]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pedersen64_stream() -> Result<()> {
+        check_stream::<64>()
+    }
+
+    #[test]
+    fn test_pedersen128_stream() -> Result<()> {
+        check_stream::<128>()
+    }
+}
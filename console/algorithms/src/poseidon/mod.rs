@@ -27,8 +27,6 @@ use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
 
 use std::sync::Arc;
 
-const CAPACITY: usize = 1;
-
 /// Poseidon2 is a cryptographic hash function of input rate 2.
 pub type Poseidon2<E> = Poseidon<E, 2>;
 /// Poseidon4 is a cryptographic hash function of input rate 4.
@@ -36,8 +34,12 @@ pub type Poseidon4<E> = Poseidon<E, 4>;
 /// Poseidon8 is a cryptographic hash function of input rate 8.
 pub type Poseidon8<E> = Poseidon<E, 8>;
 
+/// Poseidon is a cryptographic hash function of input rate `RATE` and capacity `CAPACITY`.
+///
+/// Instances with a capacity of 1 use the default parameters for the field, and
+/// instances with other widths can be constructed with `Poseidon::setup_with_parameters`.
 #[derive(Clone, Debug, PartialEq)]
-pub struct Poseidon<E: Environment, const RATE: usize> {
+pub struct Poseidon<E: Environment, const RATE: usize, const CAPACITY: usize = 1> {
     /// The domain separator for the Poseidon hash function.
     domain: Field<E>,
     /// The Poseidon parameters for hashing.
@@ -47,15 +49,34 @@ pub struct Poseidon<E: Environment, const RATE: usize> {
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Initializes a new instance of Poseidon.
     pub fn setup(domain: &str) -> Result<Self> {
+        Ok(Self {
+            domain: Self::domain_separator(domain)?,
+            parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
+        })
+    }
+}
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> Poseidon<E, RATE, CAPACITY> {
+    /// Initializes a new instance of Poseidon with the given S-box exponent and number of rounds.
+    /// The round constants and MDS matrix are derived from the Poseidon Grain LFSR.
+    pub fn setup_with_parameters(domain: &str, alpha: u64, full_rounds: usize, partial_rounds: usize) -> Result<Self> {
+        // Ensure the rate fits the domain separator and input length.
+        ensure!(RATE >= 2, "Poseidon requires a rate of at least 2, found {RATE}");
+
+        Ok(Self {
+            domain: Self::domain_separator(domain)?,
+            parameters: Arc::new(PoseidonParameters::new(alpha, full_rounds, partial_rounds)?),
+        })
+    }
+
+    /// Returns the domain separator for the given domain.
+    fn domain_separator(domain: &str) -> Result<Field<E>> {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits();
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
-        Ok(Self {
-            domain: Field::<E>::new_domain_separator(domain),
-            parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
-        })
+        Ok(Field::<E>::new_domain_separator(domain))
     }
 
     /// Returns the domain separator for the hash function.
@@ -138,7 +159,7 @@ mod tests {
             for squeeze in 0..10 {
                 let iteration = format!("absorb_{absorb}_squeeze_{squeeze}");
 
-                let mut sponge = PoseidonSponge::<CurrentEnvironment, RATE, CAPACITY>::new(&parameters);
+                let mut sponge = PoseidonSponge::<CurrentEnvironment, RATE, 1>::new(&parameters);
                 sponge.absorb(&vec![Field::<CurrentEnvironment>::from_u64(1237812u64); absorb]);
 
                 let next_absorb_index = if absorb % RATE != 0 || absorb == 0 { absorb % RATE } else { RATE };
@@ -420,4 +441,39 @@ mod tests {
             .unwrap(),
         ]);
     }
+
+    #[test]
+    fn test_setup_with_parameters() -> Result<()> {
+        // The generated parameters for a capacity of 1 match the defaults.
+        let poseidon = Poseidon::<CurrentEnvironment, 2>::setup_with_parameters("Poseidon2", 17, 8, 31)?;
+        assert_eq!(poseidon, Poseidon2::<CurrentEnvironment>::setup("Poseidon2")?);
+
+        // Instantiate a wider sponge with a capacity of 2.
+        let poseidon = Poseidon::<CurrentEnvironment, 3, 2>::setup_with_parameters("Poseidon3x2", 17, 8, 31)?;
+        assert_eq!(poseidon.parameters().ark.len(), 8 + 31);
+        assert!(poseidon.parameters().ark.iter().all(|round| round.len() == 5));
+        assert_eq!(poseidon.parameters().mds.len(), 5);
+        assert!(poseidon.parameters().mds.iter().all(|row| row.len() == 5));
+
+        let mut rng = TestRng::default();
+        let input = (0..7).map(|_| Uniform::rand(&mut rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+        let poseidon3 = Poseidon::<CurrentEnvironment, 3>::setup_with_parameters("Poseidon3x2", 17, 8, 31)?;
+        assert_eq!(poseidon.hash(&input)?, poseidon.hash(&input)?);
+        assert_ne!(poseidon.hash(&input)?, poseidon3.hash(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup_with_invalid_parameters() {
+        // The rate must fit the domain separator and input length.
+        assert!(Poseidon::<CurrentEnvironment, 1, 2>::setup_with_parameters("Poseidon", 17, 8, 31).is_err());
+        // The capacity must be nonzero.
+        assert!(Poseidon::<CurrentEnvironment, 2, 0>::setup_with_parameters("Poseidon", 17, 8, 31).is_err());
+        // The number of full rounds must be even and nonzero.
+        assert!(Poseidon::<CurrentEnvironment, 2, 2>::setup_with_parameters("Poseidon", 17, 0, 31).is_err());
+        assert!(Poseidon::<CurrentEnvironment, 2, 2>::setup_with_parameters("Poseidon", 17, 7, 31).is_err());
+        // The S-box must be a non-linear permutation.
+        assert!(Poseidon::<CurrentEnvironment, 2, 2>::setup_with_parameters("Poseidon", 1, 8, 31).is_err());
+        assert!(Poseidon::<CurrentEnvironment, 2, 2>::setup_with_parameters("Poseidon", 3, 8, 31).is_err());
+    }
 }
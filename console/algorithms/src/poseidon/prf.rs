@@ -15,7 +15,7 @@
 
 use super::*;
 
-impl<E: Environment, const RATE: usize> PRF for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> PRF for Poseidon<E, RATE, CAPACITY> {
     type Input = Field<E>;
     type Output = Field<E>;
     type Seed = Field<E>;
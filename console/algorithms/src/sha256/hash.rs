@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&self.hash_bytes(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+impl Sha256 {
+    /// Returns the SHA-256 hash of the given input as bytes.
+    #[inline]
+    pub fn hash_bytes(&self, input: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn test_sha256_hash_bytes() {
+        // Reference values from FIPS 180-4.
+        let sha256 = Sha256;
+        assert_eq!(
+            hex::encode(sha256.hash_bytes(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(sha256.hash_bytes(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex::encode(sha256.hash_bytes(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_sha256_equivalence() {
+        let rng = &mut TestRng::default();
+
+        let mut input_sizes = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 512, 1024];
+        input_sizes.extend((0..100).map(|_| rng.gen_range(1..1024)));
+
+        for num_inputs in input_sizes {
+            // Prepare the preimage.
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+
+            // Compute the native hash.
+            let expected = sha2::Sha256::digest(bytes_from_bits_le(&input));
+            let expected = bits_from_bytes_le(&expected).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let candidate = Sha256.hash(&input).unwrap();
+            assert_eq!(expected, candidate);
+        }
+    }
+}
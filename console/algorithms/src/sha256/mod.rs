@@ -0,0 +1,32 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(test)]
+use snarkvm_utilities::Uniform;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+use sha2::Digest;
+
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// SHA-256 is a Merkle-Damgård construction over a 256-bit state, which processes the input
+/// in 512-bit blocks after padding it with `0x80 || 0x00…0x00 || LENGTH(M)`,
+/// where `LENGTH(M)` is the 64-bit big-endian length of the input in bits.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sha256;
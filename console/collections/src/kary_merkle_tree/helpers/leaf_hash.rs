@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_algorithms::{BHP, Keccak, Poseidon};
+use snarkvm_console_algorithms::{BHP, Keccak, Poseidon, Sha256};
 use snarkvm_console_types::prelude::*;
 
 use crate::kary_merkle_tree::BooleanHash;
@@ -86,3 +86,22 @@ impl<const TYPE: u8, const VARIANT: usize> LeafHash for Keccak<TYPE, VARIANT> {
         Ok(result)
     }
 }
+
+impl LeafHash for Sha256 {
+    type Hash = BooleanHash<256>;
+    type Leaf = Vec<bool>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash> {
+        let mut input = Vec::with_capacity(1 + leaf.len());
+        // Prepend the leaf with a `false` bit.
+        input.push(false);
+        input.extend(leaf);
+        // Hash the input.
+        let output = Hash::hash(self, &input)?;
+        // Read the 256 bits.
+        let mut result = BooleanHash::new();
+        result.0.copy_from_slice(&output);
+        Ok(result)
+    }
+}
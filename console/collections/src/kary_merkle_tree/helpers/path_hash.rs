@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use super::BooleanHash;
-use snarkvm_console_algorithms::{BHP, Keccak, Poseidon};
+use snarkvm_console_algorithms::{BHP, Keccak, Poseidon, Sha256};
 use snarkvm_console_types::prelude::*;
 
 #[cfg(not(feature = "serial"))]
@@ -94,3 +94,23 @@ impl<const TYPE: u8, const VARIANT: usize> PathHash for Keccak<TYPE, VARIANT> {
         Ok(result)
     }
 }
+
+impl PathHash for Sha256 {
+    type Hash = BooleanHash<256>;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, children: &[Self::Hash]) -> Result<Self::Hash> {
+        let mut input = Vec::new();
+        // Prepend the nodes with a `true` bit.
+        input.push(true);
+        for child in children {
+            input.extend_from_slice(child.as_slice());
+        }
+        // Hash the input.
+        let output = Hash::hash(self, &input)?;
+        // Read the 256 bits.
+        let mut result = BooleanHash::new();
+        result.0.copy_from_slice(&output);
+        Ok(result)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::kary_merkle_tree::BooleanHash;
+use snarkvm_console_algorithms::{BHP, Keccak, Poseidon, Sha256};
+use snarkvm_console_types::prelude::*;
+
+/// A trait for a 2-to-1 compression function, which hashes two digests into one.
+///
+/// The nodes of a Merkle tree are hashed with a compression function. To separate the nodes
+/// from the leaves of a Merkle tree, the inputs are prepended with a `true` bit (or a `1field` element).
+pub trait CompressionFunction: Clone + Send + Sync {
+    type Digest: Copy + Clone + Debug + Default + PartialEq + Eq + FromBytes + ToBytes + Send + Sync;
+
+    /// Returns the compression of the given digests.
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Result<Self::Digest>;
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> CompressionFunction
+    for BHP<E, NUM_WINDOWS, WINDOW_SIZE>
+{
+    type Digest = Field<E>;
+
+    /// Returns the compression of the given digests.
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Result<Self::Digest> {
+        let mut input = Vec::with_capacity(1 + <Self::Digest as SizeInBits>::size_in_bits() * 2);
+        // Prepend the digests with a `true` bit.
+        input.push(true);
+        left.write_bits_le(&mut input);
+        right.write_bits_le(&mut input);
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> CompressionFunction for Poseidon<E, RATE, CAPACITY> {
+    type Digest = Field<E>;
+
+    /// Returns the compression of the given digests.
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Result<Self::Digest> {
+        // Prepend the digests with a `1field` element.
+        let input = &[Self::Digest::one(), *left, *right];
+        // Hash the input.
+        Hash::hash(self, input)
+    }
+}
+
+impl<const TYPE: u8, const VARIANT: usize> CompressionFunction for Keccak<TYPE, VARIANT> {
+    type Digest = BooleanHash<VARIANT>;
+
+    /// Returns the compression of the given digests.
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Result<Self::Digest> {
+        // Hash the input, and read the first VARIANT bits.
+        compress_bits(|input| Hash::hash(self, input), left, right)
+    }
+}
+
+impl CompressionFunction for Sha256 {
+    type Digest = BooleanHash<256>;
+
+    /// Returns the compression of the given digests.
+    fn compress(&self, left: &Self::Digest, right: &Self::Digest) -> Result<Self::Digest> {
+        // Hash the input, and read the first 256 bits.
+        compress_bits(|input| Hash::hash(self, input), left, right)
+    }
+}
+
+/// Returns the first `VARIANT` bits of the hash of the given digests, prepended with a `true` bit.
+fn compress_bits<const VARIANT: usize>(
+    hash: impl Fn(&[bool]) -> Result<Vec<bool>>,
+    left: &BooleanHash<VARIANT>,
+    right: &BooleanHash<VARIANT>,
+) -> Result<BooleanHash<VARIANT>> {
+    let mut input = Vec::with_capacity(1 + VARIANT * 2);
+    // Prepend the digests with a `true` bit.
+    input.push(true);
+    input.extend_from_slice(left.as_slice());
+    input.extend_from_slice(right.as_slice());
+    // Hash the input.
+    let output = hash(&input)?;
+    ensure!(output.len() >= VARIANT, "The hash output must contain at least {VARIANT} bits");
+    // Read the first VARIANT bits.
+    let mut result = BooleanHash::new();
+    result.0.copy_from_slice(&output[..VARIANT]);
+    Ok(result)
+}
@@ -13,7 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_algorithms::{BHP, Poseidon};
+use crate::kary_merkle_tree::BooleanHash;
+use snarkvm_console_algorithms::{BHP, Keccak, Poseidon, Sha256};
 use snarkvm_console_types::prelude::*;
 
 #[cfg(not(feature = "serial"))]
@@ -21,7 +22,7 @@ use rayon::prelude::*;
 
 /// A trait for a Merkle leaf hash function.
 pub trait LeafHash: Clone + Send + Sync {
-    type Hash: FieldTrait;
+    type Hash: Copy + Clone + Debug + Default + PartialEq + Eq + FromBytes + ToBytes + Send + Sync;
     type Leaf: Clone + Send + Sync;
 
     /// Returns the hash of the given leaf node.
@@ -52,7 +53,7 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> LeafHash for
     }
 }
 
-impl<E: Environment, const RATE: usize> LeafHash for Poseidon<E, RATE> {
+impl<E: Environment, const RATE: usize, const CAPACITY: usize> LeafHash for Poseidon<E, RATE, CAPACITY> {
     type Hash = Field<E>;
     type Leaf = Vec<Self::Hash>;
 
@@ -66,3 +67,41 @@ impl<E: Environment, const RATE: usize> LeafHash for Poseidon<E, RATE> {
         Hash::hash(self, &input)
     }
 }
+
+impl<const TYPE: u8, const VARIANT: usize> LeafHash for Keccak<TYPE, VARIANT> {
+    type Hash = BooleanHash<VARIANT>;
+    type Leaf = Vec<bool>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash> {
+        hash_leaf_bits(|input| Hash::hash(self, input), leaf)
+    }
+}
+
+impl LeafHash for Sha256 {
+    type Hash = BooleanHash<256>;
+    type Leaf = Vec<bool>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash> {
+        hash_leaf_bits(|input| Hash::hash(self, input), leaf)
+    }
+}
+
+/// Returns the first `VARIANT` bits of the hash of the given leaf, prepended with a `false` bit.
+fn hash_leaf_bits<const VARIANT: usize>(
+    hash: impl Fn(&[bool]) -> Result<Vec<bool>>,
+    leaf: &[bool],
+) -> Result<BooleanHash<VARIANT>> {
+    let mut input = Vec::with_capacity(1 + leaf.len());
+    // Prepend the leaf with a `false` bit.
+    input.push(false);
+    input.extend_from_slice(leaf);
+    // Hash the input.
+    let output = hash(&input)?;
+    ensure!(output.len() >= VARIANT, "The hash output must contain at least {VARIANT} bits");
+    // Read the first VARIANT bits.
+    let mut result = BooleanHash::new();
+    result.0.copy_from_slice(&output[..VARIANT]);
+    Ok(result)
+}
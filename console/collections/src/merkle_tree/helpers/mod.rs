@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod compression_function;
+pub use compression_function::*;
+
 mod leaf_hash;
 pub use leaf_hash::*;
 
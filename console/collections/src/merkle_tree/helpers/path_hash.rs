@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_algorithms::{BHP, Poseidon};
+use super::CompressionFunction;
 use snarkvm_console_types::prelude::*;
 
 #[cfg(not(feature = "serial"))]
@@ -21,11 +21,11 @@ use rayon::prelude::*;
 
 /// A trait for a Merkle path hash function.
 pub trait PathHash: Clone + Send + Sync {
-    type Hash: FieldTrait;
+    type Hash: Copy + Clone + Debug + Default + PartialEq + Eq + FromBytes + ToBytes + Send + Sync;
 
     /// Returns the empty hash.
     fn hash_empty(&self) -> Result<Self::Hash> {
-        self.hash_children(&Self::Hash::zero(), &Self::Hash::zero())
+        self.hash_children(&Self::Hash::default(), &Self::Hash::default())
     }
 
     /// Returns the hash of the given child nodes.
@@ -41,29 +41,11 @@ pub trait PathHash: Clone + Send + Sync {
     }
 }
 
-impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash for BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
-    type Hash = Field<E>;
+impl<C: CompressionFunction> PathHash for C {
+    type Hash = C::Digest;
 
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
-        let mut input = Vec::with_capacity(1 + <Self::Hash as SizeInBits>::size_in_bits() * 2);
-        // Prepend the nodes with a `true` bit.
-        input.push(true);
-        left.write_bits_le(&mut input);
-        right.write_bits_le(&mut input);
-        // Hash the input.
-        Hash::hash(self, &input)
-    }
-}
-
-impl<E: Environment, const RATE: usize> PathHash for Poseidon<E, RATE> {
-    type Hash = Field<E>;
-
-    /// Returns the hash of the given child nodes.
-    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
-        // Prepend the nodes with a `1field` byte.
-        let input = &[Self::Hash::one(), *left, *right];
-        // Hash the input.
-        Hash::hash(self, input)
+        self.compress(left, right)
     }
 }
@@ -16,18 +16,18 @@
 use super::*;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct MerklePath<E: Environment, const DEPTH: u8> {
+pub struct MerklePath<E: Environment, const DEPTH: u8, H = Field<E>> {
     /// The leaf index for the path.
     leaf_index: U64<E>,
     /// The `siblings` contains a list of sibling hashes from the leaf to the root.
-    siblings: Vec<Field<E>>,
+    siblings: Vec<H>,
 }
 
-impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, Vec<Field<E>>)> for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, H> TryFrom<(U64<E>, Vec<H>)> for MerklePath<E, DEPTH, H> {
     type Error = Error;
 
     /// Returns a new instance of a Merkle path.
-    fn try_from((leaf_index, siblings): (U64<E>, Vec<Field<E>>)) -> Result<Self> {
+    fn try_from((leaf_index, siblings): (U64<E>, Vec<H>)) -> Result<Self> {
         // Ensure the Merkle tree depth is greater than 0.
         ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
         // Ensure the Merkle tree depth is less than or equal to 64.
@@ -41,25 +41,52 @@ impl<E: Environment, const DEPTH: u8> TryFrom<(U64<E>, Vec<Field<E>>)> for Merkl
     }
 }
 
-impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, H: Copy + PartialEq> MerklePath<E, DEPTH, H> {
     /// Returns the leaf index for the path.
     pub fn leaf_index(&self) -> U64<E> {
         self.leaf_index
     }
 
     /// Returns the siblings for the path.
-    pub fn siblings(&self) -> &[Field<E>] {
+    pub fn siblings(&self) -> &[H] {
         &self.siblings
     }
 
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
-    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = H>>(
         &self,
         leaf_hasher: &LH,
         path_hasher: &PH,
         root: &PH::Hash,
         leaf: &LH::Leaf,
     ) -> bool {
+        // Compute the leaf hash to start.
+        match leaf_hasher.hash_leaf(leaf) {
+            Ok(leaf_hash) => self.verify_leaf_hash(path_hasher, root, leaf_hash),
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and the empty leaf.
+    ///
+    /// In a sparse Merkle tree, where every unset leaf holds the empty hash, this proves
+    /// that no leaf is set at the leaf index of the path.
+    pub fn verify_non_membership<PH: PathHash<Hash = H>>(&self, path_hasher: &PH, root: &PH::Hash) -> bool {
+        // Compute the empty hash to start.
+        match path_hasher.hash_empty() {
+            Ok(empty_hash) => self.verify_leaf_hash(path_hasher, root, empty_hash),
+            Err(error) => {
+                eprintln!("Failed to compute the empty hash during verification: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf hash.
+    fn verify_leaf_hash<PH: PathHash<Hash = H>>(&self, path_hasher: &PH, root: &PH::Hash, leaf_hash: H) -> bool {
         // Ensure the leaf index is within the tree depth.
         if (*self.leaf_index as u128) >= (1u128 << DEPTH) {
             eprintln!("Found an out of bounds Merkle leaf index");
@@ -71,14 +98,8 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
             return false;
         }
 
-        // Initialize a tracker for the current hash, by computing the leaf hash to start.
-        let mut current_hash = match leaf_hasher.hash_leaf(leaf) {
-            Ok(candidate_leaf_hash) => candidate_leaf_hash,
-            Err(error) => {
-                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
-                return false;
-            }
-        };
+        // Initialize a tracker for the current hash, starting from the leaf hash.
+        let mut current_hash = leaf_hash;
 
         // Compute the ordering of the current hash and sibling hash on each level.
         // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
@@ -107,21 +128,20 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
     }
 }
 
-impl<E: Environment, const DEPTH: u8> FromBytes for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, H: FromBytes> FromBytes for MerklePath<E, DEPTH, H> {
     /// Reads in a Merkle path from a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the leaf index.
         let leaf_index = u64::read_le(&mut reader)?;
         // Read the Merkle path siblings.
-        let siblings =
-            (0..DEPTH).map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        let siblings = (0..DEPTH).map(|_| H::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
         // Return the Merkle path.
         Self::try_from((U64::new(leaf_index), siblings)).map_err(error)
     }
 }
 
-impl<E: Environment, const DEPTH: u8> ToBytes for MerklePath<E, DEPTH> {
+impl<E: Environment, const DEPTH: u8, H: ToBytes> ToBytes for MerklePath<E, DEPTH, H> {
     /// Writes the Merkle path to a buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -14,7 +14,8 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_console_algorithms::{BHP512, BHP1024, Poseidon};
+use crate::kary_merkle_tree::BooleanHash;
+use snarkvm_console_algorithms::{BHP512, BHP1024, Keccak256, Poseidon, Sha3_256, Sha256};
 use snarkvm_console_types::prelude::Console;
 
 type CurrentEnvironment = Console;
@@ -72,7 +73,7 @@ fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash
 /// Runs the following test:
 /// 1. Construct a depth-2 Merkle tree with 4 leaves.
 /// 2. Checks that every node hash and the Merkle root is correct.
-fn check_merkle_tree_depth_2<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+fn check_merkle_tree_depth_2<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash>(
     leaf_hasher: &LH,
     path_hasher: &PH,
     leaves: &[LH::Leaf],
@@ -501,6 +502,69 @@ fn test_merkle_tree_depth_2_poseidon() -> Result<()> {
     )
 }
 
+/// Runs the following test:
+/// 1. Construct a depth-2 Merkle tree with 4 leaves, for a compression function over bits.
+/// 2. Check that the Merkle proof for every leaf is valid.
+fn check_merkle_tree_depth_2_bits<LH: LeafHash<Hash = PH::Hash, Leaf = Vec<bool>>, PH: PathHash>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    rng: &mut TestRng,
+) -> Result<()> {
+    let leaves = (0..4).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<Vec<bool>>>();
+
+    // Check the depth-2 Merkle tree.
+    check_merkle_tree_depth_2::<CurrentEnvironment, LH, PH>(leaf_hasher, path_hasher, &leaves)?;
+
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<CurrentEnvironment, LH, PH, 2>::new(leaf_hasher, path_hasher, &leaves)?;
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        // Verify the Merkle proof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+        // Verify the Merkle proof **fails** on an invalid root.
+        assert!(!proof.verify(leaf_hasher, path_hasher, &PH::Hash::default(), leaf));
+        // Verify the Merkle proof is preserved by serialization.
+        assert_eq!(proof, MerklePath::from_bytes_le(&proof.to_bytes_le()?)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_depth_2_keccak() -> Result<()> {
+    let mut rng = TestRng::default();
+    check_merkle_tree_depth_2_bits(&Keccak256::default(), &Keccak256::default(), &mut rng)?;
+    check_merkle_tree_depth_2_bits(&Sha3_256::default(), &Sha3_256::default(), &mut rng)
+}
+
+#[test]
+fn test_merkle_tree_depth_2_sha256() -> Result<()> {
+    let mut rng = TestRng::default();
+    check_merkle_tree_depth_2_bits(&Sha256, &Sha256, &mut rng)
+}
+
+#[test]
+fn test_compression_function() -> Result<()> {
+    let mut rng = TestRng::default();
+
+    // Ensure the compression functions prepend the digests with a `true` bit (or a `1field` element).
+    let (left, right) = (Field::<CurrentEnvironment>::rand(&mut rng), Field::<CurrentEnvironment>::rand(&mut rng));
+
+    let bhp = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+    let input = [vec![true], left.to_bits_le(), right.to_bits_le()].concat();
+    assert_eq!(Hash::hash(&bhp, &input)?, bhp.compress(&left, &right)?);
+
+    let poseidon = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest1")?;
+    let input = [Field::one(), left, right];
+    assert_eq!(Hash::hash(&poseidon, &input)?, poseidon.compress(&left, &right)?);
+
+    let (left, right) = (rng.gen::<BooleanHash<256>>(), rng.gen::<BooleanHash<256>>());
+    let input = [&[true][..], left.as_slice(), right.as_slice()].concat();
+    assert_eq!(Hash::hash(&Sha256, &input)?, Sha256.compress(&left, &right)?.to_vec());
+    assert_eq!(Hash::hash(&Keccak256::default(), &input)?, Keccak256::default().compress(&left, &right)?.to_vec());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_3_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
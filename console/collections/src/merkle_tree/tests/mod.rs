@@ -16,6 +16,7 @@
 use super::*;
 
 mod append;
+mod non_membership;
 mod remove;
 mod update;
 mod update_many;
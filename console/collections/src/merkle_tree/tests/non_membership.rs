@@ -0,0 +1,87 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Poseidon;
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+/// Returns the levels of a sparse Merkle tree, from the leaf hashes to the root,
+/// where every unset leaf holds the empty hash.
+fn sparse_merkle_tree<LH: LeafHash<Hash = PH::Hash>, PH: PathHash>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[Option<LH::Leaf>],
+) -> Result<Vec<Vec<PH::Hash>>> {
+    let empty_hash = path_hasher.hash_empty()?;
+    let mut level = leaves
+        .iter()
+        .map(|leaf| match leaf {
+            Some(leaf) => leaf_hasher.hash_leaf(leaf),
+            None => Ok(empty_hash),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut levels = vec![level.clone()];
+    while level.len() > 1 {
+        level =
+            level.chunks(2).map(|pair| path_hasher.hash_children(&pair[0], &pair[1])).collect::<Result<Vec<_>>>()?;
+        levels.push(level.clone());
+    }
+    Ok(levels)
+}
+
+#[test]
+fn test_merkle_path_verify_non_membership() -> Result<()> {
+    const DEPTH: u8 = 4;
+
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Set every third leaf, and leave the rest empty.
+    let leaves = (0..1usize << DEPTH)
+        .map(|index| match index % 3 == 0 {
+            true => Some((0..4).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>()),
+            false => None,
+        })
+        .collect::<Vec<_>>();
+    let levels = sparse_merkle_tree(&leaf_hasher, &path_hasher, &leaves)?;
+    let root = levels[DEPTH as usize][0];
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        // Compute the Merkle path from the sibling on each level.
+        let siblings = (0..DEPTH as usize).map(|depth| levels[depth][(index >> depth) ^ 1]).collect();
+        let path = MerklePath::<CurrentEnvironment, DEPTH>::try_from((U64::new(index as u64), siblings))?;
+
+        match leaf {
+            // Ensure a set leaf is a member, and is not proven to be absent.
+            Some(leaf) => {
+                assert!(path.verify(&leaf_hasher, &path_hasher, &root, leaf));
+                assert!(!path.verify_non_membership(&path_hasher, &root));
+            }
+            // Ensure an unset leaf is proven to be absent.
+            None => assert!(path.verify_non_membership(&path_hasher, &root)),
+        }
+        // Ensure the proof fails on an invalid root.
+        assert!(!path.verify_non_membership(&path_hasher, &Field::rand(&mut rng)));
+    }
+    Ok(())
+}
@@ -84,6 +84,8 @@ pub mod prelude {
         has_duplicates,
         io::{Read, Result as IoResult, Write},
     };
+    #[cfg(feature = "constant-time")]
+    pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
     pub use core::{
         cmp::Ordering,
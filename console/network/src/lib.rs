@@ -333,6 +333,9 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
 
@@ -31,6 +31,7 @@ use snarkvm_console_algorithms::{
     Sha3_256,
     Sha3_384,
     Sha3_512,
+    Sha256,
 };
 
 lazy_static! {
@@ -215,6 +216,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the scalar multiplication on the generator `G`.
+    #[cfg(not(feature = "constant-time"))]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
         GENERATOR_G
             .iter()
@@ -226,6 +228,14 @@ impl Network for MainnetV0 {
             .sum()
     }
 
+    /// Returns the scalar multiplication on the generator `G`, in time independent of the scalar.
+    #[cfg(feature = "constant-time")]
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        GENERATOR_G.iter().zip_eq(&scalar.to_bits_le()).fold(Group::zero(), |output, (base, bit)| {
+            Group::conditional_select(&output, &(output + base), Choice::from(*bit as u8))
+        })
+    }
+
     /// Returns the Varuna universal prover.
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
@@ -388,6 +398,11 @@ impl Network for MainnetV0 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
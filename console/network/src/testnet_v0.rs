@@ -32,6 +32,7 @@ use snarkvm_console_algorithms::{
     Sha3_256,
     Sha3_384,
     Sha3_512,
+    Sha256,
 };
 
 lazy_static! {
@@ -204,6 +205,7 @@ impl Network for TestnetV0 {
     }
 
     /// Returns the scalar multiplication on the generator `G`.
+    #[cfg(not(feature = "constant-time"))]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
         GENERATOR_G
             .iter()
@@ -215,6 +217,14 @@ impl Network for TestnetV0 {
             .sum()
     }
 
+    /// Returns the scalar multiplication on the generator `G`, in time independent of the scalar.
+    #[cfg(feature = "constant-time")]
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        GENERATOR_G.iter().zip_eq(&scalar.to_bits_le()).fold(Group::zero(), |output, (base, bit)| {
+            Group::conditional_select(&output, &(output + base), Choice::from(*bit as u8))
+        })
+    }
+
     /// Returns the Varuna universal prover.
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         MainnetV0::varuna_universal_prover()
@@ -365,6 +375,11 @@ impl Network for TestnetV0 {
         TESTNET_POSEIDON_8.hash(input)
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
@@ -0,0 +1,255 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Float32<E> {
+    /// Returns the sum of `self` and `other`, rounded toward zero, or `None` if it overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        // Order the operands by magnitude, so that `|x| >= |y|`.
+        let (x, y) = match (self.bits & !SIGN_MASK) >= (other.bits & !SIGN_MASK) {
+            true => (self, other),
+            false => (other, self),
+        };
+        let (x_sign, x_exponent, x_significand) = x.decompose();
+        let (y_sign, y_exponent, y_significand) = y.decompose();
+
+        // Extend the significands with guard, round, and sticky bits.
+        let x_extended = x_significand << 3;
+        let y_extended = y_significand << 3;
+        // Align `y` to the exponent of `x`, folding the bits that are shifted out into the sticky bit.
+        let shift = x_exponent - y_exponent;
+        let y_aligned = match shift < 32 {
+            true => (y_extended >> shift) | u64::from(y_extended & ((1 << shift) - 1) != 0),
+            false => u64::from(y_extended != 0),
+        };
+
+        // Add or subtract the aligned significands, which is non-negative as `|x| >= |y|`.
+        let sum = match x_sign == y_sign {
+            true => x_extended + y_aligned,
+            false => x_extended - y_aligned,
+        };
+        // Normalize the 28-bit sum so that its leading one is at bit 27, and truncate it to 24 bits.
+        let leading_zeros = sum.leading_zeros() - 36;
+        let significand = (sum << leading_zeros) >> 4;
+
+        // The biased exponent is `x_exponent + 1 - leading_zeros`, which is offset by 27 to be non-negative.
+        Self::compose(x_sign, x_exponent + 28 - leading_zeros, 27, significand)
+    }
+
+    /// Returns the product of `self` and `other`, rounded toward zero, or `None` if it overflows.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let (a_sign, a_exponent, a_significand) = self.decompose();
+        let (b_sign, b_exponent, b_significand) = other.decompose();
+
+        // Multiply the significands, which for non-zero operands is within `[2^46, 2^48)`.
+        let product = a_significand * b_significand;
+        // Truncate the product to 24 bits, shifting by one more bit if it carried into bit 47.
+        let carry = u32::from(product >> 47 == 1);
+        let significand = product >> (23 + carry);
+
+        // The biased exponent is `a_exponent + b_exponent - 127 + carry`, which is offset by the bias to be non-negative.
+        Self::compose(a_sign ^ b_sign, a_exponent + b_exponent + carry, EXPONENT_BIAS, significand)
+    }
+}
+
+impl<E: Environment> Neg for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the negation of `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.is_zero() {
+            true => self,
+            false => Self::from_canonical_bits(self.bits ^ SIGN_MASK),
+        }
+    }
+}
+
+impl<E: Environment> Add<Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the sum of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn add(self, other: Float32<E>) -> Self::Output {
+        match self.checked_add(&other) {
+            Some(sum) => sum,
+            None => E::halt(format!("Float32 overflow on addition of {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Add<&Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the sum of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn add(self, other: &Float32<E>) -> Self::Output {
+        self + *other
+    }
+}
+
+impl<E: Environment> Sub<Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the difference of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn sub(self, other: Float32<E>) -> Self::Output {
+        self + -other
+    }
+}
+
+impl<E: Environment> Sub<&Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the difference of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn sub(self, other: &Float32<E>) -> Self::Output {
+        self - *other
+    }
+}
+
+impl<E: Environment> Mul<Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the product of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn mul(self, other: Float32<E>) -> Self::Output {
+        match self.checked_mul(&other) {
+            Some(product) => product,
+            None => E::halt(format!("Float32 overflow on multiplication of {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Mul<&Float32<E>> for Float32<E> {
+    type Output = Float32<E>;
+
+    /// Returns the product of `self` and `other`, rounded toward zero.
+    #[inline]
+    fn mul(self, other: &Float32<E>) -> Self::Output {
+        self * *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100_000;
+
+    /// Returns the given exact value rounded toward zero, or `None` if it overflows.
+    fn round_toward_zero(exact: f64) -> Option<Float32<CurrentEnvironment>> {
+        if exact.abs() >= 2f64.powi(128) {
+            return None;
+        }
+        // Round to nearest, and step back toward zero if that rounded away from zero.
+        // The cast intentionally drops precision, as the result is corrected below.
+        #[allow(clippy::cast_possible_truncation)]
+        let rounded = exact as f32;
+        let truncated = match f64::from(rounded).abs() > exact.abs() {
+            true => f32::from_bits(rounded.to_bits() - 1),
+            false => rounded,
+        };
+        Some(Float32::new(truncated))
+    }
+
+    /// Samples a float whose biased exponent is within the given range.
+    fn sample(rng: &mut TestRng, exponents: core::ops::RangeInclusive<u32>) -> Float32<CurrentEnvironment> {
+        let sign = u32::from(rng.gen::<bool>()) << 31;
+        let exponent = rng.gen_range(exponents) << 23;
+        let fraction = rng.gen::<u32>() & FRACTION_MASK;
+        Float32::new(f32::from_bits(sign | exponent | fraction))
+    }
+
+    #[test]
+    fn test_add() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample exponents that are close enough for the sum to be exact as an `f64`.
+            let a = sample(&mut rng, 115..=140);
+            let b = sample(&mut rng, 115..=140);
+
+            let expected = round_toward_zero(f64::from(a.to_f32()) + f64::from(b.to_f32()));
+            assert_eq!(expected, a.checked_add(&b), "{a} + {b}");
+            assert_eq!(expected, b.checked_add(&a), "{b} + {a}");
+        }
+    }
+
+    #[test]
+    fn test_add_edge_cases() {
+        let float = Float32::<CurrentEnvironment>::new;
+
+        // Ensure adding zero is the identity.
+        assert_eq!(Some(float(1.5)), float(1.5).checked_add(&float(0.0)));
+        assert_eq!(Some(float(-1.5)), float(0.0).checked_add(&float(-1.5)));
+        // Ensure exact cancellation results in zero.
+        assert_eq!(Some(float(0.0)), float(1.5).checked_add(&float(-1.5)));
+        // Ensure a negligible addend is truncated away, and a negligible subtrahend rounds toward zero.
+        assert_eq!(Some(float(1.0)), float(1.0).checked_add(&float(2f32.powi(-40))));
+        assert_eq!(Some(float(1.0 - f32::EPSILON / 2.0)), float(1.0).checked_add(&float(-(2f32.powi(-40)))));
+        assert_eq!(Some(float(-1.0)), float(-1.0).checked_add(&float(-(2f32.powi(-100)))));
+        // Ensure results that are too small are flushed to zero.
+        assert_eq!(Some(float(0.0)), float(f32::MIN_POSITIVE * 1.5).checked_add(&float(-f32::MIN_POSITIVE)));
+        // Ensure results that are too large overflow.
+        assert_eq!(None, float(f32::MAX).checked_add(&float(f32::MAX)));
+        assert_eq!(Some(float(f32::MAX)), float(f32::MAX).checked_add(&float(1.0)));
+        // Ensure subtraction is addition of the negation.
+        assert_eq!(float(0.5), float(2.0) - float(1.5));
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample any two floats, as their product is always exact as an `f64`.
+            let a: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            let expected = round_toward_zero(f64::from(a.to_f32()) * f64::from(b.to_f32()));
+            assert_eq!(expected, a.checked_mul(&b), "{a} * {b}");
+            assert_eq!(expected, b.checked_mul(&a), "{b} * {a}");
+        }
+    }
+
+    #[test]
+    fn test_mul_edge_cases() {
+        let float = Float32::<CurrentEnvironment>::new;
+
+        // Ensure multiplying by zero results in (positive) zero.
+        assert_eq!(Some(float(0.0)), float(-1.5).checked_mul(&float(0.0)));
+        // Ensure the product rounds toward zero.
+        assert_eq!(Some(float(0.099999994)), float(0.1).checked_mul(&float(1.0 - f32::EPSILON / 2.0)));
+        // Ensure results that are too small are flushed to zero.
+        assert_eq!(Some(float(0.0)), float(f32::MIN_POSITIVE).checked_mul(&float(0.5)));
+        // Ensure results that are too large overflow.
+        assert_eq!(None, float(f32::MAX).checked_mul(&float(2.0)));
+        assert!(std::panic::catch_unwind(|| float(f32::MAX) * float(-2.0)).is_err());
+    }
+
+    #[test]
+    fn test_neg() {
+        let float = Float32::<CurrentEnvironment>::new;
+
+        assert_eq!(float(-1.5), -float(1.5));
+        assert_eq!(float(1.5), -float(-1.5));
+        assert_eq!(float(0.0), -float(0.0));
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBytes for Float32<E> {
+    /// Reads the float from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let bits = u32::read_le(&mut reader)?;
+        Self::from_bits_le(&bits.to_bits_le()).map_err(error)
+    }
+}
+
+impl<E: Environment> ToBytes for Float32<E> {
+    /// Writes the float to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.bits.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected.to_f32().to_le_bytes()[..], expected_bytes[..]);
+            assert_eq!(expected, Float32::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> PartialOrd for Float32<E> {
+    /// Returns the numeric ordering of `self` and `other`.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: Environment> Ord for Float32<E> {
+    /// Returns the numeric ordering of `self` and `other`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Note: The floats are finite, so `total_cmp` is the numeric ordering, and there is a single zero.
+        self.to_f32().total_cmp(&other.to_f32())
+    }
+}
+
+impl<E: Environment> Equal for Float32<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self == other)
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self != other)
+    }
+}
+
+impl<E: Environment> Compare<Self> for Float32<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` is less than `other`.
+    fn is_less_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self < other)
+    }
+
+    /// Returns `true` if `self` is greater than `other`.
+    fn is_greater_than(&self, other: &Self) -> Self::Output {
+        Boolean::new(self > other)
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`.
+    fn is_less_than_or_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self <= other)
+    }
+
+    /// Returns `true` if `self` is greater than or equal to `other`.
+    fn is_greater_than_or_equal(&self, other: &Self) -> Self::Output {
+        Boolean::new(self >= other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_compare() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Ensure the ordering matches the ordering of `f32`.
+            assert_eq!(a.to_f32() < b.to_f32(), *a.is_less_than(&b));
+            assert_eq!(a.to_f32() > b.to_f32(), *a.is_greater_than(&b));
+            assert_eq!(a.to_f32() <= b.to_f32(), *a.is_less_than_or_equal(&b));
+            assert_eq!(a.to_f32() >= b.to_f32(), *a.is_greater_than_or_equal(&b));
+            assert_eq!(a.to_f32() == b.to_f32(), *a.is_equal(&b));
+            assert!(*a.is_equal(&a));
+        }
+    }
+}
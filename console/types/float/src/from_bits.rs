@@ -0,0 +1,61 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBits for Float32<E> {
+    /// Initializes a new float from the little-endian bits of its canonical IEEE-754 encoding.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        let bits = u32::from_bits_le(bits_le)?;
+        // Ensure the encoding is finite, and is not a subnormal value or negative zero.
+        ensure!((bits >> 23) & 0xFF != 0xFF, "Float32 does not support infinity or NaN");
+        ensure!(Self::canonicalize(bits) == bits, "Float32 does not support subnormal values or negative zero");
+        Ok(Self::from_canonical_bits(bits))
+    }
+
+    /// Initializes a new float from the big-endian bits of its canonical IEEE-754 encoding.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        let mut bits_le = bits_be.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: Float32<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(expected, Float32::from_bits_le(&expected.to_bits_le())?);
+            assert_eq!(expected, Float32::from_bits_be(&expected.to_bits_be())?);
+        }
+
+        // Ensure non-canonical encodings are rejected.
+        for value in [f32::INFINITY, f32::NAN, -0.0, f32::from_bits(1)] {
+            assert!(Float32::<CurrentEnvironment>::from_bits_le(&value.to_bits().to_bits_le()).is_err());
+        }
+        Ok(())
+    }
+}
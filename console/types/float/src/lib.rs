@@ -0,0 +1,171 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+#![warn(clippy::cast_possible_truncation)]
+
+//! A subset of the IEEE-754 binary32 floating-point format.
+//!
+//! A `Float32` has the same encoding as an `f32`, and supports the following subset of its values:
+//!   - Only the finite values are supported. There is no infinity or NaN.
+//!   - Subnormal values are flushed to zero, and there is a single (positive) zero.
+//!
+//! Arithmetic rounds toward zero, by truncating the exact result to 24 significant bits.
+//! Results whose exponent is too small to be normal are flushed to zero, and results whose
+//! exponent is too large to be finite halt. The circuit `Float32` follows the same rules,
+//! so the results of the two agree bit for bit.
+
+mod arithmetic;
+mod bytes;
+mod compare;
+mod from_bits;
+mod random;
+mod to_bits;
+
+pub use snarkvm_console_network_environment::prelude::*;
+pub use snarkvm_console_types_boolean::Boolean;
+pub use snarkvm_console_types_field::Field;
+pub use snarkvm_console_types_integers::Integer;
+
+use core::marker::PhantomData;
+
+/// The mask of the sign bit.
+const SIGN_MASK: u32 = 1 << 31;
+/// The mask of the fraction bits.
+const FRACTION_MASK: u32 = (1 << 23) - 1;
+/// The exponent bias.
+const EXPONENT_BIAS: u32 = 127;
+/// The largest biased exponent of a finite value.
+const MAX_EXPONENT: u32 = 254;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Float32<E: Environment> {
+    /// The underlying IEEE-754 encoding.
+    bits: u32,
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> Float32<E> {
+    /// Initializes a new float, flushing subnormal values and negative zero to zero.
+    pub fn new(value: f32) -> Self {
+        match value.is_finite() {
+            true => Self::from_canonical_bits(Self::canonicalize(value.to_bits())),
+            false => E::halt(format!("Attempted to initialize a non-finite float: {value}")),
+        }
+    }
+
+    /// Initializes the zero float.
+    pub const fn zero() -> Self {
+        Self::from_canonical_bits(0)
+    }
+
+    /// Returns the float as an `f32`.
+    pub fn to_f32(&self) -> f32 {
+        f32::from_bits(self.bits)
+    }
+
+    /// Returns `true` if the float is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Initializes a float from an encoding that is known to be canonical.
+    const fn from_canonical_bits(bits: u32) -> Self {
+        Self { bits, _phantom: PhantomData }
+    }
+
+    /// Returns the canonical encoding of the given finite encoding, flushing subnormal values and negative zero to zero.
+    const fn canonicalize(bits: u32) -> u32 {
+        match (bits >> 23) & 0xFF {
+            0 => 0,
+            _ => bits,
+        }
+    }
+
+    /// Returns the sign, the biased exponent, and the significand with its implicit leading bit.
+    /// The significand of zero is zero.
+    fn decompose(&self) -> (bool, u32, u64) {
+        let sign = self.bits & SIGN_MASK != 0;
+        let exponent = (self.bits >> 23) & 0xFF;
+        let significand = match exponent {
+            0 => 0,
+            _ => u64::from(self.bits & FRACTION_MASK) | (1 << 23),
+        };
+        (sign, exponent, significand)
+    }
+
+    /// Returns the float with the given sign, exponent, and significand, where the biased exponent is
+    /// `shifted_exponent - offset`, and the significand has its leading bit at bit 23, or is zero.
+    ///
+    /// Returns zero if the significand is zero or the exponent underflows, and `None` if the exponent overflows.
+    #[allow(clippy::cast_possible_truncation)]
+    fn compose(sign: bool, shifted_exponent: u32, offset: u32, significand: u64) -> Option<Self> {
+        if shifted_exponent > offset + MAX_EXPONENT {
+            return None;
+        }
+        match significand == 0 || shifted_exponent <= offset {
+            true => Some(Self::zero()),
+            false => {
+                let fraction = (significand & u64::from(FRACTION_MASK)) as u32;
+                Some(Self::from_canonical_bits(((sign as u32) << 31) | ((shifted_exponent - offset) << 23) | fraction))
+            }
+        }
+    }
+}
+
+impl<E: Environment> Debug for Float32<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment> Display for Float32<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}f32", self.to_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_new() {
+        // Ensure normal values are unchanged.
+        for value in [1.0f32, -1.5, f32::MAX, f32::MIN, f32::MIN_POSITIVE, -f32::MIN_POSITIVE, 1234.5678] {
+            assert_eq!(value.to_bits(), Float32::<CurrentEnvironment>::new(value).to_f32().to_bits());
+        }
+        // Ensure subnormal values and negative zero are flushed to zero.
+        for value in [0.0f32, -0.0, f32::MIN_POSITIVE / 2.0, -f32::MIN_POSITIVE / 2.0, f32::from_bits(1)] {
+            assert!(Float32::<CurrentEnvironment>::new(value).is_zero());
+            assert_eq!(0, Float32::<CurrentEnvironment>::new(value).to_f32().to_bits());
+        }
+        // Ensure non-finite values are rejected.
+        for value in [f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+            assert!(std::panic::catch_unwind(|| Float32::<CurrentEnvironment>::new(value)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("1.5f32", Float32::<CurrentEnvironment>::new(1.5).to_string());
+        assert_eq!("-0.1f32", Float32::<CurrentEnvironment>::new(-0.1).to_string());
+        assert_eq!("0f32", Float32::<CurrentEnvironment>::zero().to_string());
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Distribution<Float32<E>> for Standard {
+    /// Samples a random float, where subnormal encodings are sampled as zero.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float32<E> {
+        loop {
+            let bits: u32 = rng.gen();
+            // Resample infinity and NaN.
+            if (bits >> 23) & 0xFF != 0xFF {
+                break Float32::from_canonical_bits(Float32::<E>::canonicalize(bits));
+            }
+        }
+    }
+}
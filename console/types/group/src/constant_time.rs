@@ -0,0 +1,57 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ConditionallySelectable for Group<E> {
+    /// Returns `b` if `choice` is set, and `a` otherwise, in constant time.
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from_projective(E::Projective::conditional_select(&a.group, &b.group, choice))
+    }
+}
+
+impl<E: Environment> ConstantTimeEq for Group<E> {
+    /// Returns `true` if `self` and `other` are equal, in constant time.
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.group.ct_eq(&other.group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_conditional_select() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            let b: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+            assert_eq!(Group::conditional_select(&a, &b, Choice::from(0)), a);
+            assert_eq!(Group::conditional_select(&a, &b, Choice::from(1)), b);
+            assert!(bool::from(a.ct_eq(&a)));
+            assert!(bool::from((a + b).ct_eq(&(b + a))));
+            assert!(!bool::from(a.ct_eq(&b)));
+        }
+    }
+}
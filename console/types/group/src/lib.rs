@@ -19,6 +19,8 @@
 mod arithmetic;
 mod bitwise;
 mod bytes;
+#[cfg(feature = "constant-time")]
+mod constant_time;
 mod from_bits;
 mod from_field;
 mod from_fields;
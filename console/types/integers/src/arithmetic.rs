@@ -260,6 +260,36 @@ impl<E: Environment, I: IntegerType> Div<&Integer<E, I>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> DivChecked<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `quotient` of `self` and `other`, halting on division by zero or overflow.
+    #[inline]
+    fn div_checked(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_div(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> DivSaturating<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `quotient` of `self` and `other`, bounded to `MAX` on overflow.
+    #[inline]
+    fn div_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        match other.is_zero() {
+            true => E::halt(format!("Integer division by zero: {self} / {other}")),
+            // Note that the only overflow is `MIN / -1`, whose quotient is `MAX + 1`.
+            false => match self.integer.checked_div(&other.integer) {
+                Some(integer) => Integer::new(integer),
+                None => Integer::MAX,
+            },
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> DivWrapped<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
@@ -337,6 +367,31 @@ impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> RemChecked<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `remainder` of `self` divided by `other`, halting on division by zero or overflow.
+    #[inline]
+    fn rem_checked(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_rem(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> RemSaturating<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `remainder` of `self` divided by `other`.
+    ///
+    /// The remainder of `MIN % -1` is `0`, which is in range, so this is the same as `rem_wrapped`.
+    #[inline]
+    fn rem_saturating(&self, other: &Integer<E, I>) -> Self::Output {
+        self.rem_wrapped(other)
+    }
+}
+
 impl<E: Environment, I: IntegerType> RemWrapped<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
@@ -42,6 +42,11 @@ pub mod modules {
     #[cfg(feature = "field")]
     pub use snarkvm_console_types_field::Field;
 
+    #[cfg(feature = "float")]
+    pub use snarkvm_console_types_float as float;
+    #[cfg(feature = "float")]
+    pub use snarkvm_console_types_float::Float32;
+
     #[cfg(feature = "group")]
     pub use snarkvm_console_types_group as group;
     #[cfg(feature = "group")]
@@ -23,12 +23,23 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
 
+#[cfg(feature = "std")]
+use snarkvm_fields::SqrtTables;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
 pub type Fq = Fp384<FqParameters>;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct FqParameters;
 
-impl Fp384Parameters for FqParameters {}
+impl Fp384Parameters for FqParameters {
+    #[cfg(feature = "std")]
+    fn sqrt_tables() -> Option<&'static SqrtTables<Fq>> {
+        static SQRT_TABLES: OnceLock<SqrtTables<Fq>> = OnceLock::new();
+        Some(SQRT_TABLES.get_or_init(SqrtTables::new))
+    }
+}
 
 impl FftParameters for FqParameters {
     type BigInteger = BigInteger;
@@ -23,6 +23,11 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 
+#[cfg(feature = "std")]
+use snarkvm_fields::SqrtTables;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
 /// BLS12-377 scalar field.
 ///
 /// Roots of unity computed from modulus and R using this sage code:
@@ -51,7 +56,13 @@ pub type Fr = Fp256<FrParameters>;
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct FrParameters;
 
-impl Fp256Parameters for FrParameters {}
+impl Fp256Parameters for FrParameters {
+    #[cfg(feature = "std")]
+    fn sqrt_tables() -> Option<&'static SqrtTables<Fr>> {
+        static SQRT_TABLES: OnceLock<SqrtTables<Fr>> = OnceLock::new();
+        Some(SQRT_TABLES.get_or_init(SqrtTables::new))
+    }
+}
 
 impl FftParameters for FrParameters {
     type BigInteger = BigInteger;
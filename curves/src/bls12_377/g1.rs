@@ -15,7 +15,6 @@
 
 use snarkvm_fields::{Field, One, PrimeField, Zero, field};
 use snarkvm_utilities::{
-    BigInteger,
     BitIteratorBE,
     biginteger::{BigInteger256, BigInteger384},
 };
@@ -24,12 +23,13 @@ use crate::{
     AffineCurve,
     ProjectiveCurve,
     bls12_377::{Fq, Fr},
-    templates::bls12::Bls12Parameters,
+    templates::{
+        bls12::Bls12Parameters,
+        short_weierstrass_jacobian::{MapToCurve, SWUParameters, map_to_curve_simple_swu},
+    },
     traits::{ModelParameters, ShortWeierstrassParameters},
 };
 
-use std::ops::Neg;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bls12_377G1Parameters;
 
@@ -116,103 +116,71 @@ impl ShortWeierstrassParameters for Bls12_377G1Parameters {
         p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
         by: Self::ScalarField,
     ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
-        type ScalarBigInt = <Fr as PrimeField>::BigInteger;
-
-        /// The scalar multiplication window size.
-        const GLV_WINDOW_SIZE: usize = 4;
-
-        /// The table size, used for w-ary NAF recoding.
-        const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
-        const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
-        const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
-        /// The GLV table length.
-        const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
-
-        let decomposition = by.decompose(&Self::Q1, &Self::Q2, Self::B1, Self::B2, Self::R128, &Self::HALF_R);
-
-        // Prepare tables.
-        let mut t_1 = Vec::with_capacity(L);
-        let double = crate::templates::short_weierstrass_jacobian::Affine::<Self>::from(p.double());
-        t_1.push(p);
-        for i in 1..L {
-            t_1.push(t_1[i - 1].add_mixed(&double));
-        }
-        let t_1 =
-            crate::templates::short_weierstrass_jacobian::Projective::<Self>::batch_normalization_into_affine(t_1);
-
-        let t_2 = t_1.iter().copied().map(Self::glv_endomorphism).collect::<Vec<_>>();
-
-        let mod_signed = |d| {
-            let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
-            if d_mod_window_size >= HALF_TABLE_SIZE { d_mod_window_size - TABLE_SIZE } else { d_mod_window_size }
-        };
-        let to_wnaf = |e: Self::ScalarField| -> Vec<i32> {
-            let mut naf = vec![];
-            let mut e = e.to_bigint();
-            while !e.is_zero() {
-                let next = if e.is_odd() {
-                    let naf_sign = mod_signed(e.as_ref()[0]);
-                    if naf_sign < 0 {
-                        e.add_nocarry(&ScalarBigInt::from(-naf_sign as u64));
-                    } else {
-                        e.sub_noborrow(&ScalarBigInt::from(naf_sign as u64));
-                    }
-                    naf_sign.try_into().unwrap()
-                } else {
-                    0
-                };
-                naf.push(next);
-                e.div2();
-            }
-
-            naf
-        };
-
-        let wnaf = |k1: Self::ScalarField, k2: Self::ScalarField, s1: bool, s2: bool| -> (Vec<i32>, Vec<i32>) {
-            let mut wnaf_1 = to_wnaf(k1);
-            let mut wnaf_2 = to_wnaf(k2);
-
-            if s1 {
-                wnaf_1.iter_mut().for_each(|e| *e = -*e);
-            }
-            if !s2 {
-                wnaf_2.iter_mut().for_each(|e| *e = -*e);
-            }
-
-            (wnaf_1, wnaf_2)
-        };
-
-        let naf_add = |table: &[crate::templates::short_weierstrass_jacobian::Affine<Self>],
-                       naf: i32,
-                       acc: &mut crate::templates::short_weierstrass_jacobian::Projective<Self>| {
-            if naf != 0 {
-                let mut p_1 = table[(naf.abs() >> 1) as usize];
-                if naf < 0 {
-                    p_1 = p_1.neg();
-                }
-                acc.add_assign_mixed(&p_1);
-            }
-        };
+        crate::templates::short_weierstrass_jacobian::glv_mul_projective(p, by)
+    }
+}
 
-        // Recode scalars.
-        let (naf_1, naf_2) = wnaf(decomposition.0, decomposition.1, decomposition.2, decomposition.3);
-        let max_len = naf_1.len().max(naf_2.len());
-        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
-        for i in (0..max_len).rev() {
-            if i < naf_1.len() {
-                naf_add(&t_1, naf_1[i], &mut acc)
-            }
+impl MapToCurve for Bls12_377G1Parameters {
+    const CURVE_ID: &'static str = "BLS12377G1";
+    const MAP_ID: &'static str = "SSWU";
 
-            if i < naf_2.len() {
-                naf_add(&t_2, naf_2[i], &mut acc)
-            }
+    fn map_to_curve(u: Fq) -> super::G1Affine {
+        map_to_curve_simple_swu::<Self>(u)
+    }
+}
 
-            if i != 0 {
-                acc.double_in_place();
-            }
-        }
+/// The Simplified SWU map to G1 goes through the curve `y^2 = x^3 - 15 * x + 22`,
+/// which is 2-isogenous to G1 through the isogeny with kernel `(2, 0)`.
+impl SWUParameters for Bls12_377G1Parameters {
+    /// ISOGENOUS_CURVE_A = -15
+    const ISOGENOUS_CURVE_A: Fq = field!(
+        Fq,
+        BigInteger384([
+            8005640918860302573,
+            12713514003070977187,
+            4783777178294134989,
+            5265655741464679543,
+            6688503545619719304,
+            8483428697112459,
+        ])
+    );
+    /// ISOGENOUS_CURVE_B = 22
+    const ISOGENOUS_CURVE_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            11130294635325289193,
+            6502679372128844082,
+            15863297759487624914,
+            16270683149854112145,
+            3560014356538878812,
+            27923742146399959,
+        ])
+    );
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field!(
+        Fq,
+        BigInteger384([
+            17674658212592223884,
+            16025666105175442278,
+            11630212292767080598,
+            2029684077883034878,
+            14585373342160350959,
+            46587285280047369,
+        ])
+    );
 
-        acc
+    /// Returns `((x - 3) * (x + 1) / (4 * (x - 2)), y * ((x - 2)^2 + 3) / (8 * (x - 2)^2))`.
+    fn isogeny_map(x: Fq, y: Fq) -> Option<(Fq, Fq)> {
+        let x_minus_two = x - Fq::from(2u8);
+        let x_minus_two_inverse = x_minus_two.inverse()?;
+        let x_minus_two_inverse_squared = x_minus_two_inverse.square();
+
+        let x_numerator = (x - Fq::from(3u8)) * (x + Fq::one());
+        let y_numerator = y * (x_minus_two.square() + Fq::from(3u8));
+        Some((
+            x_numerator * x_minus_two_inverse * Fq::from(4u8).inverse()?,
+            y_numerator * x_minus_two_inverse_squared * Fq::from(8u8).inverse()?,
+        ))
     }
 }
 
@@ -13,22 +13,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_fields::{Field, PrimeField, Zero, field};
+use snarkvm_fields::{Field, Zero, field};
 use snarkvm_utilities::{
-    BigInteger,
     BitIteratorBE,
     biginteger::{BigInteger256, BigInteger384},
 };
 
 use crate::{
     AffineCurve,
-    ProjectiveCurve,
     bls12_377::{Fq, Fq2, Fr, g1::Bls12_377G1Parameters},
+    templates::short_weierstrass_jacobian::{MapToCurve, SVDWParameters, map_to_curve_svdw},
     traits::{ModelParameters, ShortWeierstrassParameters},
 };
 
-use std::ops::Neg;
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bls12_377G2Parameters;
 
@@ -134,106 +131,40 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
         p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
         by: Self::ScalarField,
     ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
-        type ScalarBigInt = <Fr as PrimeField>::BigInteger;
-
-        /// The scalar multiplication window size.
-        const GLV_WINDOW_SIZE: usize = 4;
-
-        /// The table size, used for w-ary NAF recoding.
-        const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
-        const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
-        const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
-        /// The GLV table length.
-        const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
-
-        let decomposition = by.decompose(&Self::Q1, &Self::Q2, Self::B1, Self::B2, Self::R128, &Self::HALF_R);
-
-        // Prepare tables.
-        let mut t_1 = Vec::with_capacity(L);
-        let double = crate::templates::short_weierstrass_jacobian::Affine::<Self>::from(p.double());
-        t_1.push(p);
-        for i in 1..L {
-            t_1.push(t_1[i - 1].add_mixed(&double));
-        }
-        let t_1 =
-            crate::templates::short_weierstrass_jacobian::Projective::<Self>::batch_normalization_into_affine(t_1);
-
-        let t_2 = t_1.iter().copied().map(Self::glv_endomorphism).collect::<Vec<_>>();
-
-        let mod_signed = |d| {
-            let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
-            if d_mod_window_size >= HALF_TABLE_SIZE { d_mod_window_size - TABLE_SIZE } else { d_mod_window_size }
-        };
-        let to_wnaf = |e: Self::ScalarField| -> Vec<i32> {
-            let mut naf = vec![];
-            let mut e = e.to_bigint();
-            while !e.is_zero() {
-                let next = if e.is_odd() {
-                    let naf_sign = mod_signed(e.as_ref()[0]);
-                    if naf_sign < 0 {
-                        e.add_nocarry(&ScalarBigInt::from(-naf_sign as u64));
-                    } else {
-                        e.sub_noborrow(&ScalarBigInt::from(naf_sign as u64));
-                    }
-                    naf_sign.try_into().unwrap()
-                } else {
-                    0
-                };
-                naf.push(next);
-                e.div2();
-            }
-
-            naf
-        };
-
-        let wnaf = |k1: Self::ScalarField, k2: Self::ScalarField, s1: bool, s2: bool| -> (Vec<i32>, Vec<i32>) {
-            let mut wnaf_1 = to_wnaf(k1);
-            let mut wnaf_2 = to_wnaf(k2);
-
-            if s1 {
-                wnaf_1.iter_mut().for_each(|e| *e = -*e);
-            }
-            if !s2 {
-                wnaf_2.iter_mut().for_each(|e| *e = -*e);
-            }
-
-            (wnaf_1, wnaf_2)
-        };
-
-        let naf_add = |table: &[crate::templates::short_weierstrass_jacobian::Affine<Self>],
-                       naf: i32,
-                       acc: &mut crate::templates::short_weierstrass_jacobian::Projective<Self>| {
-            if naf != 0 {
-                let mut p_1 = table[(naf.abs() >> 1) as usize];
-                if naf < 0 {
-                    p_1 = p_1.neg();
-                }
-                acc.add_assign_mixed(&p_1);
-            }
-        };
-
-        // Recode scalars.
-        let (naf_1, naf_2) = wnaf(decomposition.0, decomposition.1, decomposition.2, decomposition.3);
-        let max_len = naf_1.len().max(naf_2.len());
-        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
-        for i in (0..max_len).rev() {
-            if i < naf_1.len() {
-                naf_add(&t_1, naf_1[i], &mut acc)
-            }
-
-            if i < naf_2.len() {
-                naf_add(&t_2, naf_2[i], &mut acc)
-            }
+        crate::templates::short_weierstrass_jacobian::glv_mul_projective(p, by)
+    }
+}
 
-            if i != 0 {
-                acc.double_in_place();
-            }
-        }
+/// G2 has no low-degree isogeny to a curve with nonzero coefficients `A` and `B`,
+/// so the Simplified SWU map does not apply, and the Shallue-van de Woestijne map is used instead.
+impl MapToCurve for Bls12_377G2Parameters {
+    const CURVE_ID: &'static str = "BLS12377G2";
+    const MAP_ID: &'static str = "SVDW";
 
-        acc
+    fn map_to_curve(u: Fq2) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        map_to_curve_svdw::<Self>(u)
     }
 }
 
+impl SVDWParameters for Bls12_377G2Parameters {
+    /// SVDW_Z = 2
+    const SVDW_Z: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                404198066556501712,
+                11709709805437321058,
+                4538334656037814244,
+                17770411857874044427,
+                11090443381845330384,
+                79601084644714804,
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0]))
+    );
+}
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
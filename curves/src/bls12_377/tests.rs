@@ -33,7 +33,14 @@ use crate::{
         g1::Bls12_377G1Parameters,
         g2::Bls12_377G2Parameters,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        short_weierstrass_jacobian::{
+            MapToCurve,
+            SWUParameters,
+            tests::{sw_tests, sw_zcash_serialization_test},
+        },
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
         AffineCurve,
         PairingEngine,
@@ -53,6 +60,7 @@ use crate::{
     },
 };
 use snarkvm_fields::{
+    CompressedFp12,
     FftField,
     FftParameters,
     Field,
@@ -63,10 +71,16 @@ use snarkvm_fields::{
     PrimeField,
     SquareRootField,
     Zero,
+    batch_from_bigint,
+    batch_to_bigint,
     fp6_3over2::Fp6Parameters,
 };
 use snarkvm_utilities::{
     BitIteratorBE,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    FromBytes,
+    ToBytes,
     biginteger::{BigInteger, BigInteger256, BigInteger384},
     rand::{TestRng, Uniform},
 };
@@ -299,6 +313,52 @@ fn test_fq_mul_assign() {
     }
 }
 
+#[test]
+fn test_fq_batch_mul_assign() {
+    let mut rng = TestRng::default();
+
+    // Cover every remainder modulo the number of lanes of each backend.
+    for size in 0..=17 {
+        let a = (0..size).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+        let b = (0..size).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+
+        let mut candidate = a.clone();
+        Fq::batch_mul_assign(&mut candidate, &b);
+
+        let expected = a.iter().zip(&b).map(|(a, b)| *a * b).collect::<Vec<_>>();
+        assert_eq!(expected, candidate);
+    }
+
+    // Ensure the edge cases of the reduction are handled.
+    let edges = [Fq::zero(), Fq::one(), -Fq::one(), -Fq::from(2u8)];
+    let a = edges.iter().flat_map(|a| [*a; 4]).collect::<Vec<_>>();
+    let b = edges.repeat(4);
+
+    let mut candidate = a.clone();
+    Fq::batch_mul_assign(&mut candidate, &b);
+
+    let expected = a.iter().zip(&b).map(|(a, b)| *a * b).collect::<Vec<_>>();
+    assert_eq!(expected, candidate);
+}
+
+#[test]
+fn test_fq_batch_bigint_conversion() {
+    let mut rng = TestRng::default();
+
+    for size in [0, 1, 2, 7, 1000] {
+        let elements = (0..size).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Ensure the batch conversions match the conversions of each element.
+        let integers = batch_to_bigint(&elements);
+        assert_eq!(elements.iter().map(|element| element.to_bigint()).collect::<Vec<_>>(), integers);
+        assert_eq!(Some(elements), batch_from_bigint::<Fq>(&integers));
+    }
+
+    // Ensure an integer that is not less than the modulus is rejected.
+    let integers = [Fq::one().to_bigint(), FqParameters::MODULUS];
+    assert_eq!(None, batch_from_bigint::<Fq>(&integers));
+}
+
 #[test]
 fn test_fq_squaring() {
     let mut rng = TestRng::default();
@@ -618,6 +678,42 @@ fn test_fq12_mul_by_034() {
     }
 }
 
+#[test]
+fn test_fq12_cyclotomic_compression() {
+    let mut rng = TestRng::default();
+
+    // The identity is compressed to zero, and decompressed back to the identity.
+    assert_eq!(Fq12::one().cyclotomic_compress().decompress(), Fq12::one());
+
+    for _ in 0..ITERATIONS {
+        // Map a random element into the cyclotomic subgroup, by raising it to the power of (q^6 - 1)(q^2 + 1).
+        let a = Fq12::rand(&mut rng);
+        let mut b = a;
+        b.conjugate();
+        let b = b * a.inverse().unwrap();
+        let mut a = b;
+        a.frobenius_map(2);
+        a *= b;
+
+        let compressed = a.cyclotomic_compress();
+        assert_eq!(compressed.decompress(), a);
+        assert_eq!(compressed.square().decompress(), a.cyclotomic_square());
+        assert_eq!(a.cyclotomic_square_n(20), a.pow([1 << 20]));
+
+        let exponent = Fr::rand(&mut rng).to_bigint();
+        assert_eq!(a.cyclotomic_exp(exponent), a.pow(exponent));
+
+        // Ensure the compressed form serializes to two thirds of the size, and round-trips.
+        let bytes = compressed.to_bytes_le().unwrap();
+        assert_eq!(bytes.len() * 3, a.to_bytes_le().unwrap().len() * 2);
+        assert_eq!(CompressedFp12::read_le(&bytes[..]).unwrap(), compressed);
+        let mut bytes = Vec::new();
+        compressed.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), compressed.compressed_size());
+        assert_eq!(CompressedFp12::deserialize_compressed(&bytes[..]).unwrap(), compressed);
+    }
+}
+
 #[test]
 fn test_g1_projective_glv() {
     let mut rng = TestRng::default();
@@ -629,12 +725,32 @@ fn test_g1_projective_glv() {
     assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
 }
 
+#[test]
+fn test_glv_decompose() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let scalar = Fr::rand(&mut rng);
+
+        let g1 = G1Affine::rand(&mut rng);
+        let [(p_1, k_1), (p_2, k_2)] = g1.glv_decompose(scalar).unwrap();
+        assert!(k_1.to_bigint().num_bits() <= 128 && k_2.to_bigint().num_bits() <= 128);
+        assert_eq!(p_1.mul(k_1) + p_2.mul(k_2), g1.mul(scalar));
+
+        let g2 = G2Affine::rand(&mut rng);
+        let [(p_1, k_1), (p_2, k_2)] = g2.glv_decompose(scalar).unwrap();
+        assert!(k_1.to_bigint().num_bits() <= 128 && k_2.to_bigint().num_bits() <= 128);
+        assert_eq!(p_1.mul(k_1) + p_2.mul(k_2), g2.mul(scalar));
+    }
+}
+
 #[test]
 fn test_g1_projective_curve() {
     let mut rng = TestRng::default();
 
     curve_tests::<G1Projective>(&mut rng);
     sw_tests::<Bls12_377G1Parameters>(&mut rng);
+    sw_zcash_serialization_test::<Bls12_377G1Parameters>(&mut rng);
 }
 
 #[test]
@@ -659,6 +775,7 @@ fn test_g2_projective_curve() {
 
     curve_tests::<G2Projective>(&mut rng);
     sw_tests::<Bls12_377G2Parameters>(&mut rng);
+    sw_zcash_serialization_test::<Bls12_377G2Parameters>(&mut rng);
 }
 
 #[test]
@@ -703,3 +820,77 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_multi_pairing() {
+    let mut rng = TestRng::default();
+
+    for num_pairs in 0..4 {
+        let g1s = (0..num_pairs).map(|_| rng.gen()).collect::<Vec<G1Projective>>();
+        let g2s = (0..num_pairs).map(|_| rng.gen()).collect::<Vec<G2Projective>>();
+
+        let expected = g1s.iter().zip(&g2s).map(|(a, b)| Bls12_377::pairing(*a, *b)).product::<Fq12>();
+        assert_eq!(Bls12_377::multi_pairing(g1s, g2s), expected);
+    }
+
+    // Ensure the pairings cancel out as expected.
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+    assert!(Bls12_377::multi_pairing([a * s, -a], [b, b * s]).is_one());
+}
+
+#[test]
+#[should_panic]
+fn test_multi_pairing_length_mismatch() {
+    let mut rng = TestRng::default();
+
+    let g1s = [rng.gen::<G1Projective>(), rng.gen()];
+    let g2s = [rng.gen::<G2Projective>()];
+    let _ = Bls12_377::multi_pairing(g1s, g2s);
+}
+
+#[test]
+fn test_g1_isogeny_map() {
+    let mut rng = TestRng::default();
+
+    // Sample a point on the isogenous curve `y^2 = x^3 - 15 * x + 22`.
+    let sample = |rng: &mut TestRng| loop {
+        let x = Fq::rand(rng);
+        let rhs =
+            x.square() * x + Bls12_377G1Parameters::ISOGENOUS_CURVE_A * x + Bls12_377G1Parameters::ISOGENOUS_CURVE_B;
+        if let Some(y) = rhs.sqrt() {
+            break (x, y);
+        }
+    };
+
+    for _ in 0..ITERATIONS {
+        let (x, y) = sample(&mut rng);
+        let (x, y) = Bls12_377G1Parameters::isogeny_map(x, y).unwrap();
+        assert!(G1Affine::new(x, y, false).is_on_curve());
+    }
+
+    // Ensure the point in the kernel of the isogeny maps to the point at infinity.
+    assert!(Bls12_377G1Parameters::isogeny_map(Fq::from(2u8), Fq::zero()).is_none());
+}
+
+#[test]
+fn test_map_to_curve() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g1 = Bls12_377G1Parameters::map_to_curve(Fq::rand(&mut rng));
+        assert!(g1.is_on_curve());
+        let g1 = Bls12_377G1Parameters::clear_cofactor(g1);
+        assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+
+        let g2 = Bls12_377G2Parameters::map_to_curve(Fq2::rand(&mut rng));
+        assert!(g2.is_on_curve());
+        let g2 = Bls12_377G2Parameters::clear_cofactor(g2);
+        assert!(g2.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    // Ensure the exceptional inputs are handled.
+    assert!(Bls12_377G1Parameters::map_to_curve(Fq::zero()).is_on_curve());
+    assert!(Bls12_377G2Parameters::map_to_curve(Fq2::zero()).is_on_curve());
+}
@@ -0,0 +1,179 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{Field, Zero, field};
+use snarkvm_utilities::{
+    BitIteratorBE,
+    biginteger::{BigInteger256, BigInteger384},
+};
+
+use crate::{
+    AffineCurve,
+    bls12_381::{Fq, Fq2, Fr, g1::Bls12_381G1Parameters},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bls12_381G2Parameters;
+
+impl ModelParameters for Bls12_381G2Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bls12_381G2Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+    /// B1 = x^2 - 1
+    const B1: Fr = Bls12_381G1Parameters::B1;
+    /// B2 = x^2
+    const B2: Fr = Bls12_381G1Parameters::B2;
+    /// COFACTOR =
+    /// 305502333931268344200999753193121504214466019254188142667664032982267604182971884026507427359259977847832272839041616661285803823378372096355777062779109
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c38e31c7238e5,
+        0x1616ec6e786f0c70,
+        0x21537e293a6691ae,
+        0xa628f1cb4d9e82ef,
+        0xa68a205b2e5a7ddf,
+        0xcd91de4547085aba,
+        0x091d50792876a202,
+        0x05d543a95414e7f1,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 26652489039290660355457965112010883481355318854675681319708643586776743290055
+    const COFACTOR_INV: Fr =
+        field!(Fr, BigInteger256([6746407649509787816, 1304054119431494378, 2461312685643913071, 5956596749362435284]));
+    /// PHI = [0, 793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350]
+    const PHI: Fq2 = field!(
+        Fq2,
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+        field!(
+            Fq,
+            BigInteger384([
+                0x30f1361b798a64e8,
+                0xf3b8ddab7ece5a2a,
+                0x16a8ca3ac61577f7,
+                0xc26a2ff874fd029b,
+                0x3636b76660701c6e,
+                0x051ba4ab241b6160,
+            ])
+        ),
+    );
+    /// Q1 = x^2 * R / q
+    const Q1: [u64; 4] = Bls12_381G1Parameters::Q1;
+    /// Q2 = R / q = 2
+    const Q2: [u64; 4] = Bls12_381G1Parameters::Q2;
+    /// R128 = 2^128 - 1
+    const R128: Fr = Bls12_381G1Parameters::R128;
+    /// WEIERSTRASS_A = [0, 0]
+    const WEIERSTRASS_A: Fq2 = field!(Fq2, Bls12_381G1Parameters::WEIERSTRASS_A, Bls12_381G1Parameters::WEIERSTRASS_A,);
+    // This curve is an M-type sextic twist, so b' = b * (u + 1), where b is the COEFF_B of G1
+    // and u + 1 is the non-residue used to extend from Fp2 to Fp6.
+    /// WEIERSTRASS_B = [4, 4]
+    const WEIERSTRASS_B: Fq2 = field!(Fq2, Bls12_381G1Parameters::WEIERSTRASS_B, Bls12_381G1Parameters::WEIERSTRASS_B,);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x.mul_by_fp(&Self::PHI.c1);
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        crate::templates::short_weierstrass_jacobian::glv_mul_projective(p, by)
+    }
+}
+
+pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
+pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
+
+///
+/// G2_GENERATOR_X_C0 =
+/// 352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160
+///
+pub const G2_GENERATOR_X_C0: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        17722385409647053328,
+        12967546844987299354,
+        11648722842835150208,
+        10994581490347323113,
+        8027586497049998955,
+        396758299565931735
+    ])
+);
+
+///
+/// G2_GENERATOR_X_C1 =
+/// 3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758
+///
+pub const G2_GENERATOR_X_C1: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        11937283898719073798,
+        12295044263989567683,
+        4301357764460312582,
+        1953074377943790439,
+        14030662337566180679,
+        1266120665323335155
+    ])
+);
+
+///
+/// G2_GENERATOR_Y_C0 =
+/// 1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905
+///
+pub const G2_GENERATOR_Y_C0: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        5508758831087832138,
+        6448303779119275098,
+        16710190169160573786,
+        13542242618704742751,
+        563980702369916322,
+        37152010398653157
+    ])
+);
+
+///
+/// G2_GENERATOR_Y_C1 =
+/// 927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582
+///
+pub const G2_GENERATOR_Y_C1: Fq = field!(
+    Fq,
+    BigInteger384::new([
+        12520284671833321565,
+        1777275927576994268,
+        9704602344324656032,
+        8739618045342622522,
+        16651875250601773805,
+        804950956836789234
+    ])
+);
@@ -0,0 +1,83 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Point encodings compatible with the zcash BLS12-381 serialization format.
+//!
+//! See [`zcash`](crate::templates::short_weierstrass_jacobian::zcash) for a description of the encoding.
+
+use snarkvm_utilities::serialize::Validate;
+
+use crate::{
+    GroupError,
+    bls12_381::{G1Affine, G2Affine},
+    templates::short_weierstrass_jacobian::zcash::{
+        read_compressed,
+        read_uncompressed,
+        write_compressed,
+        write_uncompressed,
+    },
+};
+
+impl G1Affine {
+    /// Returns the 48-byte compressed zcash encoding of this point.
+    pub fn to_compressed(&self) -> [u8; 48] {
+        let mut bytes = [0u8; 48];
+        write_compressed(self, &mut bytes);
+        bytes
+    }
+
+    /// Returns the 96-byte uncompressed zcash encoding of this point.
+    pub fn to_uncompressed(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        write_uncompressed(self, &mut bytes);
+        bytes
+    }
+
+    /// Reads a point from its 48-byte compressed zcash encoding.
+    pub fn from_compressed(bytes: &[u8; 48]) -> Result<Self, GroupError> {
+        read_compressed(bytes, Validate::Yes)
+    }
+
+    /// Reads a point from its 96-byte uncompressed zcash encoding.
+    pub fn from_uncompressed(bytes: &[u8; 96]) -> Result<Self, GroupError> {
+        read_uncompressed(bytes, Validate::Yes)
+    }
+}
+
+impl G2Affine {
+    /// Returns the 96-byte compressed zcash encoding of this point.
+    pub fn to_compressed(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        write_compressed(self, &mut bytes);
+        bytes
+    }
+
+    /// Returns the 192-byte uncompressed zcash encoding of this point.
+    pub fn to_uncompressed(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        write_uncompressed(self, &mut bytes);
+        bytes
+    }
+
+    /// Reads a point from its 96-byte compressed zcash encoding.
+    pub fn from_compressed(bytes: &[u8; 96]) -> Result<Self, GroupError> {
+        read_compressed(bytes, Validate::Yes)
+    }
+
+    /// Reads a point from its 192-byte uncompressed zcash encoding.
+    pub fn from_uncompressed(bytes: &[u8; 192]) -> Result<Self, GroupError> {
+        read_uncompressed(bytes, Validate::Yes)
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(unused_imports)]
+use crate::{
+    bls12_381::{
+        Bls12_381,
+        Fq,
+        Fq2,
+        Fq2Parameters,
+        Fq6,
+        Fq6Parameters,
+        Fq12,
+        FqParameters,
+        Fr,
+        FrParameters,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+        g1::Bls12_381G1Parameters,
+        g2::Bls12_381G2Parameters,
+    },
+    templates::short_weierstrass_jacobian::{
+        PointEncoding,
+        tests::{sw_tests, sw_zcash_serialization_test},
+    },
+    traits::{
+        AffineCurve,
+        PairingEngine,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+    },
+};
+use snarkvm_fields::{
+    FftField,
+    Field,
+    FieldParameters,
+    Fp2Parameters,
+    LegendreSymbol::*,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+    fp6_3over2::Fp6Parameters,
+};
+use snarkvm_utilities::{
+    BitIteratorBE,
+    Compress,
+    biginteger::{BigInteger, BigInteger384},
+    rand::{TestRng, Uniform},
+};
+
+use rand::Rng;
+use std::ops::{Mul, MulAssign};
+
+pub(crate) const ITERATIONS: usize = 10;
+
+/// The compressed zcash encoding of the G1 generator.
+const G1_GENERATOR_COMPRESSED: &str =
+    "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+/// The compressed zcash encoding of the G2 generator.
+const G2_GENERATOR_COMPRESSED: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (byte, chunk) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap();
+    }
+    bytes
+}
+
+#[test]
+fn test_bls12_381_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bls12_381_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bls12_381_fq2() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b, &mut rng);
+        sqrt_field_test(a, &mut rng);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq2>(&mut rng);
+}
+
+#[test]
+fn test_bls12_381_fq6() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rng.gen();
+        let h: Fq6 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq6>(&mut rng);
+}
+
+#[test]
+fn test_bls12_381_fq12() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = rng.gen();
+        let h: Fq12 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq12>(&mut rng);
+}
+
+#[test]
+fn test_fq_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 381);
+    assert_eq!(FqParameters::CAPACITY, 380);
+}
+
+#[test]
+fn test_fr_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 255);
+    assert_eq!(FrParameters::CAPACITY, 254);
+}
+
+#[test]
+fn test_fq_legendre() {
+    assert_eq!(QuadraticResidue, Fq::one().legendre());
+    assert_eq!(Zero, Fq::zero().legendre());
+    assert_eq!(QuadraticResidue, Fq::from_bigint(BigInteger384::from(4)).unwrap().legendre());
+    assert_eq!(QuadraticNonResidue, Fq::from_bigint(BigInteger384::from(2)).unwrap().legendre());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq2_legendre() {
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // u^2 = -1
+    let mut m1 = -Fq2::one();
+    assert_eq!(QuadraticResidue, m1.legendre());
+    m1 = Fq6Parameters::mul_fp2_by_nonresidue(&m1);
+    assert_eq!(QuadraticNonResidue, m1.legendre());
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = TestRng::default();
+
+    let nqr = Fq2::new(Fq::one(), Fq::one());
+
+    for _ in 0..1000 {
+        let a = Fq2::rand(&mut rng);
+        let mut b = a;
+        b.mul_assign(&nqr);
+
+        assert_eq!(Fq6Parameters::mul_fp2_by_nonresidue(&a), b);
+    }
+}
+
+#[test]
+fn test_g1_projective_glv() {
+    let mut rng = TestRng::default();
+
+    let point = G1Projective::rand(&mut rng);
+    let scalar = Fr::rand(&mut rng);
+    let affine = point.to_affine();
+    assert_eq!(point.mul(scalar), affine.mul(scalar));
+    assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+}
+
+#[test]
+fn test_g2_projective_glv() {
+    let mut rng = TestRng::default();
+
+    let point = G2Projective::rand(&mut rng);
+    let scalar = Fr::rand(&mut rng);
+    let affine = point.to_affine();
+    assert_eq!(point.mul(scalar), affine.mul(scalar));
+    assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+}
+
+#[test]
+fn test_glv_decompose() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let scalar = Fr::rand(&mut rng);
+
+        let g1 = G1Affine::rand(&mut rng);
+        let [(p_1, k_1), (p_2, k_2)] = g1.glv_decompose(scalar).unwrap();
+        assert!(k_1.to_bigint().num_bits() <= 128 && k_2.to_bigint().num_bits() <= 128);
+        assert_eq!(p_1.mul(k_1) + p_2.mul(k_2), g1.mul(scalar));
+
+        let g2 = G2Affine::rand(&mut rng);
+        let [(p_1, k_1), (p_2, k_2)] = g2.glv_decompose(scalar).unwrap();
+        assert!(k_1.to_bigint().num_bits() <= 128 && k_2.to_bigint().num_bits() <= 128);
+        assert_eq!(p_1.mul(k_1) + p_2.mul(k_2), g2.mul(scalar));
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G1Projective>(&mut rng);
+    sw_tests::<Bls12_381G1Parameters>(&mut rng);
+    sw_zcash_serialization_test::<Bls12_381G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G2Projective>(&mut rng);
+    sw_tests::<Bls12_381G2Parameters>(&mut rng);
+    sw_zcash_serialization_test::<Bls12_381G2Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bls12_381::pairing(sa, b);
+    let ans2 = Bls12_381::pairing(a, sb);
+    let ans3 = Bls12_381::pairing(a, b).pow(s.to_bigint());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_ne!(ans2, Fq12::one());
+    assert_ne!(ans3, Fq12::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
+}
+
+#[test]
+fn test_zcash_generator_encodings() {
+    let g1 = G1Affine::prime_subgroup_generator();
+    let expected = from_hex::<48>(G1_GENERATOR_COMPRESSED);
+    assert_eq!(g1.to_compressed(), expected);
+    assert_eq!(G1Affine::from_compressed(&expected).unwrap(), g1);
+
+    let g2 = G2Affine::prime_subgroup_generator();
+    let expected = from_hex::<96>(G2_GENERATOR_COMPRESSED);
+    assert_eq!(g2.to_compressed(), expected);
+    assert_eq!(G2Affine::from_compressed(&expected).unwrap(), g2);
+}
+
+#[test]
+fn test_zcash_serialization() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g1 = G1Projective::rand(&mut rng).to_affine();
+        assert_eq!(G1Affine::from_compressed(&g1.to_compressed()).unwrap(), g1);
+        assert_eq!(G1Affine::from_uncompressed(&g1.to_uncompressed()).unwrap(), g1);
+        assert_eq!(G1Affine::from_compressed(&(-g1).to_compressed()).unwrap(), -g1);
+        let mut bytes = Vec::new();
+        g1.serialize_with_encoding(&mut bytes, Compress::Yes, PointEncoding::Zcash).unwrap();
+        assert_eq!(bytes, g1.to_compressed());
+
+        let g2 = G2Projective::rand(&mut rng).to_affine();
+        assert_eq!(G2Affine::from_compressed(&g2.to_compressed()).unwrap(), g2);
+        assert_eq!(G2Affine::from_uncompressed(&g2.to_uncompressed()).unwrap(), g2);
+        assert_eq!(G2Affine::from_compressed(&(-g2).to_compressed()).unwrap(), -g2);
+        let mut bytes = Vec::new();
+        g2.serialize_with_encoding(&mut bytes, Compress::No, PointEncoding::Zcash).unwrap();
+        assert_eq!(bytes, g2.to_uncompressed());
+    }
+
+    // Check the point at infinity.
+    let mut expected = [0u8; 48];
+    expected[0] = 0xc0;
+    assert_eq!(G1Affine::zero().to_compressed(), expected);
+    assert!(G1Affine::from_compressed(&expected).unwrap().is_zero());
+    let mut expected = [0u8; 192];
+    expected[0] = 0x40;
+    assert_eq!(G2Affine::zero().to_uncompressed(), expected);
+    assert!(G2Affine::from_uncompressed(&expected).unwrap().is_zero());
+
+    // Check that mismatched encodings are rejected.
+    let g1 = G1Affine::prime_subgroup_generator();
+    let mut bytes = g1.to_compressed();
+    assert!(G1Affine::from_uncompressed(&g1.to_uncompressed().map(|b| b | 0x80)).is_err());
+    bytes[0] &= 0x7f;
+    assert!(G1Affine::from_compressed(&bytes).is_err());
+    // Check that non-canonical field elements are rejected.
+    assert!(G1Affine::from_compressed(&[0xff; 48]).is_err());
+}
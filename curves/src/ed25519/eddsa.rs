@@ -0,0 +1,89 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    AffineCurve,
+    GroupError,
+    ProjectiveCurve,
+    ed25519::{
+        Ed25519Affine,
+        Fr,
+        serialization::{read_le, write_le},
+    },
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::ToString;
+
+/// An Ed25519 signature, as specified in RFC 8032.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ed25519Signature {
+    /// The nonce commitment `R`.
+    r: Ed25519Affine,
+    /// The signature proof `S`.
+    s: Fr,
+}
+
+impl Ed25519Signature {
+    /// Returns a signature from the given nonce commitment and proof.
+    pub const fn new(r: Ed25519Affine, s: Fr) -> Self {
+        Self { r, s }
+    }
+
+    /// Returns a signature from its 64-byte `R || S` encoding.
+    /// Encodings of `S` that are not reduced modulo the group order are rejected, to prevent malleability.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, GroupError> {
+        let (r, s) = bytes.split_at(32);
+        let r = Ed25519Affine::from_rfc8032_bytes(r.try_into().map_err(|_| GroupError::InvalidGroupElement)?)?;
+        let s = s.try_into().map_err(|_| GroupError::InvalidGroupElement)?;
+        let s =
+            Fr::from_bigint(read_le(s)).ok_or(GroupError::Message("Invalid Ed25519 signature scalar".to_string()))?;
+        Ok(Self::new(r, s))
+    }
+
+    /// Returns the 64-byte `R || S` encoding of this signature.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_rfc8032_bytes());
+        bytes[32..].copy_from_slice(&write_le(&self.s.to_bigint()));
+        bytes
+    }
+
+    /// Returns the nonce commitment `R` of this signature.
+    pub const fn r(&self) -> Ed25519Affine {
+        self.r
+    }
+
+    /// Returns the proof `S` of this signature.
+    pub const fn s(&self) -> Fr {
+        self.s
+    }
+
+    /// Returns `true` if this is a valid signature under the given public key,
+    /// where `challenge` is the 64-byte `SHA-512(R || A || M)` digest of the message `M` under the public key `A`.
+    ///
+    /// This checks the cofactored equation `[8][S]B = [8]R + [8][k]A`, where `k` is the challenge
+    /// reduced modulo the group order, which RFC 8032 recommends over the cofactorless equation.
+    pub fn verify(&self, public_key: &Ed25519Affine, challenge: &[u8; 64]) -> bool {
+        // Ensure the public key and nonce commitment are valid points.
+        if !public_key.is_on_curve() || !self.r.is_on_curve() {
+            return false;
+        }
+        // Compute the challenge scalar `k`, reducing the digest modulo the group order.
+        let k = Fr::from_bytes_le_mod_order(challenge);
+        // Compute `[S]B - [k]A - R`, and ensure it is a point of small order.
+        let difference = Ed25519Affine::prime_subgroup_generator() * self.s - *public_key * k - self.r.to_projective();
+        difference.to_affine().mul_by_cofactor_to_projective().is_zero()
+    }
+}
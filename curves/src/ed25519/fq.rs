@@ -0,0 +1,166 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Ed25519 base field.
+pub type Fq = Fp256<FqParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([4276176457567034116, 285293570747525613, 7885265008028943057, 8464351723258321832]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 2;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 19681161376707505956807079304988542015446066515923890162744021073123829784752
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        4276176457567034116u64,
+        285293570747525613u64,
+        7885265008028943057u64,
+        8464351723258321832u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 2
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        76u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 9708812670373448219u64;
+    /// MODULUS = 57896044618658097711785492504343953926634992332820282019728792003956564819949
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xffffffffffffffed,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (MODULUS - 1) / 2 =
+    /// 28948022309329048855892746252171976963317496166410141009864396001978282409974
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffffffffffffff6,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        38u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        1444u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) / 2^S =
+    /// 14474011154664524427946373126085988481658748083205070504932198000989141204987
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xfffffffffffffffb,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x1fffffffffffffff,
+    ]);
+    /// (T - 1) / 2 =
+    /// 7237005577332262213973186563042994240829374041602535252466099000494570602493
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xfffffffffffffff,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fq::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fq::two_adic_root_of_unity();
+        let powers = (0..FqParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fq::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+        assert_eq!(powers[0], Fq::two_adic_root_of_unity());
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FqParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FqParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fq::multiplicative_generator().pow(FqParameters::T);
+        assert_eq!(expected, Fq::two_adic_root_of_unity());
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Ed25519 scalar field.
+pub type Fr = Fp256<FrParameters>;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([8969215743819189885, 5516037659391044808, 15508184678381615533, 385507852950656554]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 2;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 4202356475871964119699734399548423449193549369991576068503119564443318355924
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        8969215743819189885u64,
+        5516037659391044808u64,
+        15508184678381615533u64,
+        385507852950656554u64,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 2
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        6180627327055779405u64,
+        8718896354912633355u64,
+        18446744073709551613u64,
+        1152921504606846975u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 15183074304973897243u64;
+    /// MODULUS = 7237005577332262213973186563042994240857116359379907606001950938285454250989
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x5812631a5cf5d3ed,
+        0x14def9dea2f79cd6,
+        0x0,
+        0x1000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 253;
+    /// (MODULUS - 1) / 2 =
+    /// 3618502788666131106986593281521497120428558179689953803000975469142727125494
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x2c09318d2e7ae9f6,
+        0xa6f7cef517bce6b,
+        0x0,
+        0x800000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        15486807595281847581u64,
+        14334777244411350896u64,
+        18446744073709551614u64,
+        1152921504606846975u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        11819153939886771969u64,
+        14991950615390032711u64,
+        14910419812499177061u64,
+        259310039853996605u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 3;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) / 2^S =
+    /// 1809251394333065553493296640760748560214279089844976901500487734571363562747
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x960498c6973d74fb,
+        0x537be77a8bde735,
+        0x0,
+        0x400000000000000,
+    ]);
+    /// (T - 1) / 2 =
+    /// 904625697166532776746648320380374280107139544922488450750243867285681781373
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcb024c634b9eba7d,
+        0x29bdf3bd45ef39a,
+        0x0,
+        0x200000000000000,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FrParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fr::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fr::two_adic_root_of_unity();
+        let powers = (0..FrParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fr::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+        assert_eq!(powers[0], Fr::two_adic_root_of_unity());
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FrParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FrParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fr::multiplicative_generator().pow(FrParameters::T);
+        assert_eq!(expected, Fr::two_adic_root_of_unity());
+    }
+}
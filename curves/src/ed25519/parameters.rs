@@ -0,0 +1,88 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ed25519::{Fq, Fr},
+    templates::twisted_edwards_extended::{Affine, Projective},
+    traits::{ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+};
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger256;
+
+pub type Ed25519Affine = Affine<Ed25519Parameters>;
+pub type Ed25519Projective = Projective<Ed25519Parameters>;
+
+/// The twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2` of RFC 8032, known as edwards25519.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ed25519Parameters;
+
+impl ModelParameters for Ed25519Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TwistedEdwardsParameters for Ed25519Parameters {
+    type MontgomeryParameters = Ed25519Parameters;
+
+    /// The base point `B` of RFC 8032, whose y-coordinate is 4/5.
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+    /// COFACTOR_INV = 2713877091499598330239944961141122840321418634767465352250731601857045344121
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([12100500283911187475, 16942830013509034793, 18446744073709551615, 1152921504606846975])
+    );
+    /// EDWARDS_A = -1
+    const EDWARDS_A: Fq = field!(
+        Fq,
+        BigInteger256([18446744073709551559, 18446744073709551615, 18446744073709551615, 9223372036854775807])
+    );
+    /// EDWARDS_D = -121665 / 121666
+    ///           = 37095705934669439343138083508754565189542113879843219016388785533085940283555
+    const EDWARDS_D: Fq = field!(
+        Fq,
+        BigInteger256([9290235533119187450, 1198387923977120115, 16542726418180114064, 3207173552111338790])
+    );
+
+    /// Multiplication by `a` is just negation.
+    #[inline(always)]
+    fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
+        -*elem
+    }
+}
+
+impl MontgomeryParameters for Ed25519Parameters {
+    type TwistedEdwardsParameters = Ed25519Parameters;
+
+    /// MONTGOMERY_A = 486662
+    const MONTGOMERY_A: Fq = field!(Fq, BigInteger256([18493156, 0, 0, 0]));
+    /// MONTGOMERY_B = -486664
+    ///              = 57896044618658097711785492504343953926634992332820282019728792003956564333285
+    const MONTGOMERY_B: Fq = field!(
+        Fq,
+        BigInteger256([18446744073691058365, 18446744073709551615, 18446744073709551615, 9223372036854775807])
+    );
+}
+
+/// GENERATOR_X =
+/// 15112221349535400772501151409588531511454012693041857206046113283949847762202
+const GENERATOR_X: Fq =
+    field!(Fq, BigInteger256([16342081272192803463, 11287595536805717129, 10986974856635266487, 8475250514821412816]));
+
+/// GENERATOR_Y =
+/// 46316835694926478169428394003475163141307993866256225615783033603165251855960
+const GENERATOR_Y: Fq =
+    field!(Fq, BigInteger256([3689348814741910346, 3689348814741910323, 3689348814741910323, 3689348814741910323]));
@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Point encodings following RFC 8032, as used for Ed25519 public keys and signatures.
+//!
+//! A point is encoded as the 32-byte little-endian encoding of its y-coordinate,
+//! with the most significant bit set to the least significant bit of its x-coordinate.
+
+use crate::{
+    AffineCurve,
+    GroupError,
+    ed25519::{Ed25519Affine, Fq},
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{BigInteger, biginteger::BigInteger256};
+
+/// Returns the little-endian encoding of the given integer.
+pub(super) fn write_le(value: &BigInteger256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(value.0.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+/// Returns the integer with the given little-endian encoding.
+pub(super) fn read_le(bytes: &[u8; 32]) -> BigInteger256 {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7]]);
+    }
+    BigInteger256(limbs)
+}
+
+impl Ed25519Affine {
+    /// Returns the 32-byte RFC 8032 encoding of this point.
+    pub fn to_rfc8032_bytes(&self) -> [u8; 32] {
+        let mut bytes = write_le(&self.y.to_bigint());
+        bytes[31] |= (self.x.to_bigint().is_odd() as u8) << 7;
+        bytes
+    }
+
+    /// Returns the point with the given RFC 8032 encoding.
+    /// Non-canonical encodings, whose y-coordinate is not reduced, are rejected.
+    /// The point is not guaranteed to be in the prime order subgroup.
+    pub fn from_rfc8032_bytes(bytes: &[u8; 32]) -> Result<Self, GroupError> {
+        // Split off the parity of the x-coordinate.
+        let is_odd = bytes[31] >> 7 == 1;
+        let mut bytes = *bytes;
+        bytes[31] &= 0x7f;
+
+        let y = Fq::from_bigint(read_le(&bytes)).ok_or(GroupError::InvalidGroupElement)?;
+        let point = Self::from_y_coordinate(y, false).ok_or(GroupError::InvalidGroupElement)?;
+        // The x-coordinate zero has no odd counterpart.
+        if point.x.is_zero() && is_odd {
+            return Err(GroupError::InvalidGroupElement);
+        }
+        // Select the x-coordinate with the encoded parity.
+        match point.x.to_bigint().is_odd() == is_odd {
+            true => Ok(point),
+            false => Ok(-point),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ed25519::{Ed25519Affine, Ed25519Parameters, Ed25519Projective, Ed25519Signature, Fq, Fr},
+    templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
+    traits::{
+        AffineCurve,
+        ProjectiveCurve,
+        tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+    },
+};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::rand::{TestRng, Uniform};
+
+use rand::Rng;
+
+const ITERATIONS: usize = 10;
+
+/// The RFC 8032 encoding of the base point.
+const GENERATOR_ENCODING: &str = "5866666666666666666666666666666666666666666666666666666666666666";
+/// The public key and signature of RFC 8032, Section 7.1, Test 1, on the empty message.
+const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+/// The digest `SHA-512(R || A || M)` for the signature above.
+const CHALLENGE: &str = "2771062b6b536fe7ffbdda0320c3827b035df10d284df3f08222f04dbca7a4c20ef15bdc988a22c7207411377c33f2ac09b1e86a046234283768ee7ba03c0e9f";
+
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let bytes = hex
+        .as_bytes()
+        .chunks(2)
+        .map(|chunk| u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap())
+        .collect::<Vec<_>>();
+    bytes.try_into().unwrap()
+}
+
+#[test]
+fn test_ed25519_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+}
+
+#[test]
+fn test_ed25519_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<Ed25519Projective>(&mut rng);
+    edwards_test::<Ed25519Parameters>(&mut rng);
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a = rng.gen();
+        let b = rng.gen();
+        projective_test::<Ed25519Projective>(a, b, &mut rng);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Ed25519Affine = rng.gen();
+        affine_test::<Ed25519Affine>(a);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = Ed25519Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<Ed25519Parameters>();
+}
+
+#[test]
+fn test_rfc8032_encoding() {
+    let mut rng = TestRng::default();
+
+    // Check the encoding of the base point.
+    let generator = Ed25519Affine::prime_subgroup_generator();
+    assert_eq!(generator.to_rfc8032_bytes(), from_hex::<32>(GENERATOR_ENCODING));
+    assert_eq!(Ed25519Affine::from_rfc8032_bytes(&from_hex(GENERATOR_ENCODING)).unwrap(), generator);
+    assert_eq!(
+        Ed25519Affine::from_rfc8032_bytes(&Ed25519Affine::zero().to_rfc8032_bytes()).unwrap(),
+        Ed25519Affine::zero()
+    );
+
+    for _ in 0..ITERATIONS {
+        let point = Ed25519Projective::rand(&mut rng).to_affine();
+        assert_eq!(Ed25519Affine::from_rfc8032_bytes(&point.to_rfc8032_bytes()).unwrap(), point);
+        assert_eq!(Ed25519Affine::from_rfc8032_bytes(&(-point).to_rfc8032_bytes()).unwrap(), -point);
+    }
+
+    // Reject a y-coordinate that is not reduced, namely `p + 1`.
+    let mut bytes = [0xffu8; 32];
+    bytes[0] = 0xee;
+    bytes[31] = 0x7f;
+    assert!(Ed25519Affine::from_rfc8032_bytes(&bytes).is_err());
+    // Reject a negative zero x-coordinate, for the point `(0, 1)`.
+    let mut bytes = Ed25519Affine::zero().to_rfc8032_bytes();
+    bytes[31] |= 0x80;
+    assert!(Ed25519Affine::from_rfc8032_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_signature_verification() {
+    let public_key = Ed25519Affine::from_rfc8032_bytes(&from_hex(PUBLIC_KEY)).unwrap();
+    let signature = Ed25519Signature::from_bytes(&from_hex(SIGNATURE)).unwrap();
+    let challenge = from_hex::<64>(CHALLENGE);
+    assert_eq!(signature.to_bytes(), from_hex::<64>(SIGNATURE));
+    assert!(signature.verify(&public_key, &challenge));
+
+    // Ensure a different challenge, proof, or public key is rejected.
+    let mut tampered = challenge;
+    tampered[0] ^= 1;
+    assert!(!signature.verify(&public_key, &tampered));
+    let forged = Ed25519Signature::new(signature.r(), signature.s() + Fr::one());
+    assert!(!forged.verify(&public_key, &challenge));
+    assert!(!signature.verify(&Ed25519Affine::prime_subgroup_generator(), &challenge));
+
+    // Ensure a proof that is not reduced modulo the group order is rejected.
+    let mut bytes = from_hex::<64>(SIGNATURE);
+    bytes[63] |= 0x80;
+    assert!(Ed25519Signature::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_sign_and_verify() {
+    let mut rng = TestRng::default();
+    let generator = Ed25519Affine::prime_subgroup_generator();
+
+    for _ in 0..ITERATIONS {
+        let secret_key = Fr::rand(&mut rng);
+        let public_key = (generator * secret_key).to_affine();
+        let nonce = Fr::rand(&mut rng);
+        let challenge: [u8; 64] = std::array::from_fn(|_| rng.gen());
+        let k = Fr::from_bytes_le_mod_order(&challenge);
+        let signature = Ed25519Signature::new((generator * nonce).to_affine(), nonce + k * secret_key);
+        assert!(signature.verify(&public_key, &challenge));
+        assert!(!signature.verify(&(-public_key), &challenge));
+    }
+}
@@ -23,17 +23,28 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
 
+#[cfg(feature = "std")]
+use snarkvm_fields::SqrtTables;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
 pub type Fr = Fp256<FrParameters>;
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct FrParameters;
 
-impl Fp256Parameters for FrParameters {}
+impl Fp256Parameters for FrParameters {
+    #[cfg(feature = "std")]
+    fn sqrt_tables() -> Option<&'static SqrtTables<Fr>> {
+        static SQRT_TABLES: OnceLock<SqrtTables<Fr>> = OnceLock::new();
+        Some(SQRT_TABLES.get_or_init(SqrtTables::new))
+    }
+}
 
 impl FftParameters for FrParameters {
     type BigInteger = BigInteger;
 
-    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = unimplemented!();
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[];
     const TWO_ADICITY: u32 = 1;
     #[rustfmt::skip]
     const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
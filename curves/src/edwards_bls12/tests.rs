@@ -21,7 +21,7 @@ use crate::{
         MontgomeryParameters,
         ProjectiveCurve,
         TwistedEdwardsParameters,
-        tests_field::{field_serialization_test, field_test, primefield_test},
+        tests_field::{field_serialization_test, field_test, primefield_test, random_sqrt_tonelli_tests},
         tests_group::*,
         tests_projective::curve_tests,
     },
@@ -57,6 +57,13 @@ fn test_edwards_bls12_fq() {
     field_serialization_test::<Fq>(&mut rng);
 }
 
+#[test]
+fn test_edwards_bls12_fr_sqrt_tonelli() {
+    let mut rng = TestRng::default();
+
+    random_sqrt_tonelli_tests::<Fr>(&mut rng);
+}
+
 #[test]
 fn test_projective_curve() {
     let mut rng = TestRng::default();
@@ -103,6 +110,32 @@ fn test_conversion() {
     assert_eq!(b.to_projective().to_affine(), b);
 }
 
+#[cfg(feature = "constant-time")]
+#[test]
+fn test_constant_time_mul() {
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::BitIteratorBE;
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    let mut rng = TestRng::default();
+
+    for _ in 0..10 {
+        let a: EdwardsAffine = rng.gen();
+        let b: EdwardsProjective = rng.gen();
+        let scalar: Fr = rng.gen();
+
+        // Ensure the constant-time scalar multiplication matches double-and-add.
+        let expected = a.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint()));
+        assert_eq!(a.to_projective() * scalar, expected);
+
+        // Ensure the constant-time selection and equality are consistent.
+        assert_eq!(EdwardsProjective::conditional_select(&expected, &b, Choice::from(0)), expected);
+        assert_eq!(EdwardsProjective::conditional_select(&expected, &b, Choice::from(1)), b);
+        assert!(bool::from(expected.ct_eq(&expected.to_affine().to_projective())));
+        assert!(!bool::from(expected.ct_eq(&b)));
+    }
+}
+
 #[test]
 fn test_montgomery_conversion() {
     montgomery_conversion_test::<EdwardsParameters>();
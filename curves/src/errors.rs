@@ -13,44 +13,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Error)]
+use snarkvm_utilities::{String, format};
+
+use core::fmt;
+
+#[derive(Debug)]
 pub enum GroupError {
-    #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
-    #[error("{}", _0)]
     FieldError(snarkvm_fields::FieldError),
 
-    #[error("Invalid group element")]
     InvalidGroupElement,
 
-    #[error("Attempting to parse an invalid string into a group element")]
     InvalidString,
 
-    #[error("{}", _0)]
     Message(String),
 
-    #[error("Attempting to parse an empty string into a group element")]
     ParsingEmptyString,
 
-    #[error("Attempting to parse a non-digit character into a group element")]
     ParsingNonDigitCharacter,
 }
 
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Crate(name, message) => write!(f, "{name}: {message}"),
+            Self::FieldError(error) => write!(f, "{error}"),
+            Self::InvalidGroupElement => write!(f, "Invalid group element"),
+            Self::InvalidString => write!(f, "Attempting to parse an invalid string into a group element"),
+            Self::Message(message) => write!(f, "{message}"),
+            Self::ParsingEmptyString => write!(f, "Attempting to parse an empty string into a group element"),
+            Self::ParsingNonDigitCharacter => {
+                write!(f, "Attempting to parse a non-digit character into a group element")
+            }
+        }
+    }
+}
+
+impl snarkvm_utilities::error::Error for GroupError {}
+
 impl From<snarkvm_fields::FieldError> for GroupError {
     fn from(error: snarkvm_fields::FieldError) -> Self {
         GroupError::FieldError(error)
     }
 }
 
-impl From<std::io::Error> for GroupError {
-    fn from(error: std::io::Error) -> Self {
+impl From<snarkvm_utilities::io::Error> for GroupError {
+    fn from(error: snarkvm_utilities::io::Error) -> Self {
         GroupError::Crate("std::io", format!("{error:?}"))
     }
 }
 
-impl From<GroupError> for std::io::Error {
+impl From<GroupError> for snarkvm_utilities::io::Error {
     fn from(error: GroupError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{error}"))
+        snarkvm_utilities::error(format!("{error}"))
     }
 }
@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    AffineCurve,
+    GroupError,
+    ProjectiveCurve,
+    secp256k1::{
+        Fr,
+        FrParameters,
+        Secp256k1Affine,
+        serialization::{read_be, write_be},
+    },
+};
+use snarkvm_fields::{Field, FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::{BigInteger, ToString};
+
+/// An ECDSA signature over secp256k1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ECDSASignature {
+    /// The x-coordinate of the nonce commitment, reduced modulo the group order.
+    r: Fr,
+    /// The signature proof.
+    s: Fr,
+}
+
+impl ECDSASignature {
+    /// Returns a signature from the given `(r, s)` pair, failing if either is zero.
+    pub fn new(r: Fr, s: Fr) -> Result<Self, GroupError> {
+        match r.is_zero() || s.is_zero() {
+            true => Err(GroupError::Message("The ECDSA signature scalars must be nonzero".to_string())),
+            false => Ok(Self { r, s }),
+        }
+    }
+
+    /// Returns a signature from its 64-byte `r || s` encoding, with both scalars in big-endian order.
+    pub fn from_bytes_be(bytes: &[u8; 64]) -> Result<Self, GroupError> {
+        let read_scalar = |bytes: &[u8]| {
+            let bytes: &[u8; 32] = bytes.try_into().map_err(|_| GroupError::InvalidGroupElement)?;
+            Fr::from_bigint(read_be(bytes)).ok_or(GroupError::Message("Invalid ECDSA signature scalar".to_string()))
+        };
+        Self::new(read_scalar(&bytes[..32])?, read_scalar(&bytes[32..])?)
+    }
+
+    /// Returns the 64-byte `r || s` encoding of this signature, with both scalars in big-endian order.
+    pub fn to_bytes_be(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&write_be(&self.r.to_bigint()));
+        bytes[32..].copy_from_slice(&write_be(&self.s.to_bigint()));
+        bytes
+    }
+
+    /// Returns the `r` scalar of this signature.
+    pub const fn r(&self) -> Fr {
+        self.r
+    }
+
+    /// Returns the `s` scalar of this signature.
+    pub const fn s(&self) -> Fr {
+        self.s
+    }
+
+    /// Returns `true` if `s` is at most half the group order.
+    /// Bitcoin and Ethereum only accept such signatures, to prevent malleability.
+    pub fn is_low_s(&self) -> bool {
+        self.s.to_bigint() <= FrParameters::MODULUS_MINUS_ONE_DIV_TWO
+    }
+
+    /// Returns `true` if this is a valid signature on the given 32-byte message hash under the given public key.
+    pub fn verify(&self, public_key: &Secp256k1Affine, message_hash: &[u8; 32]) -> bool {
+        // Ensure the public key is a valid point.
+        if public_key.is_zero() || !public_key.is_on_curve() {
+            return false;
+        }
+        // Compute the message scalar `z`, reducing the hash modulo the group order.
+        let z = Fr::from_bytes_be_mod_order(message_hash);
+        // Compute `R = (z / s) * G + (r / s) * public_key`.
+        let s_inv = match self.s.inverse() {
+            Some(s_inv) => s_inv,
+            None => return false,
+        };
+        let point =
+            (Secp256k1Affine::prime_subgroup_generator() * (z * s_inv) + *public_key * (self.r * s_inv)).to_affine();
+        if point.is_zero() {
+            return false;
+        }
+        // Ensure the x-coordinate of `R`, reduced modulo the group order, matches `r`.
+        let mut x = point.x.to_bigint();
+        if x >= FrParameters::MODULUS {
+            x.sub_noborrow(&FrParameters::MODULUS);
+        }
+        Fr::from_bigint(x) == Some(self.r)
+    }
+}
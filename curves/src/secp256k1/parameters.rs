@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    secp256k1::{Fq, Fr},
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ModelParameters, ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{PrimeField, Zero, field};
+use snarkvm_utilities::{BitIteratorBE, biginteger::BigInteger256};
+
+pub type Secp256k1Affine = Affine<Secp256k1Parameters>;
+pub type Secp256k1Projective = Projective<Secp256k1Parameters>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Secp256k1Parameters;
+
+impl ModelParameters for Secp256k1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Secp256k1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// B1 = 0, as the decomposition is not used for this curve.
+    const B1: Fr = field!(Fr, BigInteger256([0, 0, 0, 0]));
+    /// B2 = 0, as the decomposition is not used for this curve.
+    const B2: Fr = field!(Fr, BigInteger256([0, 0, 0, 0]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field!(Fr, BigInteger256([4624529908474429119, 4994812053365940164, 1, 0]));
+    /// PHI = 55594575648329892869085402983802832744385952214688224221778511981742606582254
+    ///
+    /// This is the cube root of unity `beta` for which `(beta * x, y) = lambda * (x, y)`.
+    const PHI: Fq =
+        field!(Fq, BigInteger256([0x58a4361c8e81894e, 0x03fde1631c4b80af, 0xf8e98978d02e3905, 0x7a4a36aebcbb3d53,]));
+    /// Q1 = 0, so that the decomposition of a scalar `k` is the trivial `(k, 0)`.
+    const Q1: [u64; 4] = [0, 0, 0, 0];
+    /// Q2 = 0, so that the decomposition of a scalar `k` is the trivial `(k, 0)`.
+    const Q2: [u64; 4] = [0, 0, 0, 0];
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(Fr, BigInteger256([0, 0, 4624529908474429119, 4994812053365940164]));
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 7
+    const WEIERSTRASS_B: Fq =
+        field!(Fq, BigInteger256([0x0000000700001ab7, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    /// The curve has prime order, so every point on the curve is in the subgroup.
+    fn is_in_correct_subgroup_assuming_on_curve(_: &Affine<Self>) -> bool {
+        true
+    }
+
+    fn glv_endomorphism(mut p: Affine<Self>) -> Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(p: Projective<Self>, by: Self::ScalarField) -> Projective<Self> {
+        let mut output = Projective::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            output.double_in_place();
+            if bit {
+                output += p;
+            }
+        }
+        output
+    }
+}
+
+///
+/// GENERATOR_X =
+/// 55066263022277343669578718895168534326250603453777594175500187360389116729240
+///
+pub const GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256::new([15507633332195041431, 2530505477788034779, 10925531211367256732, 11061375339145502536])
+);
+
+///
+/// GENERATOR_Y =
+/// 32670510020758816978083085130507043184471273380659243275938904335757337482424
+///
+pub const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256::new([12780836216951778274, 10231155108014310989, 8121878653926228278, 14933801261141951190])
+);
@@ -0,0 +1,90 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Point encodings following SEC 1, as used for Bitcoin and Ethereum public keys.
+//!
+//! Compressed points are `0x02 || x` or `0x03 || x`, where the prefix encodes the parity of `y`,
+//! and uncompressed points are `0x04 || x || y`, with coordinates written in big-endian order.
+
+use crate::{
+    AffineCurve,
+    GroupError,
+    secp256k1::{Fq, Secp256k1Affine},
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{BigInteger, ToString, Vec, biginteger::BigInteger256};
+
+/// Returns the big-endian encoding of the given integer.
+pub(super) fn write_be(value: &BigInteger256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(value.0.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// Returns the integer with the given big-endian encoding.
+pub(super) fn read_be(bytes: &[u8; 32]) -> BigInteger256 {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7]]);
+    }
+    BigInteger256(limbs)
+}
+
+/// Returns the base field element with the given big-endian encoding, failing if it is not canonical.
+fn read_fq(bytes: &[u8]) -> Result<Fq, GroupError> {
+    let bytes: &[u8; 32] = bytes.try_into().map_err(|_| GroupError::InvalidGroupElement)?;
+    Fq::from_bigint(read_be(bytes)).ok_or(GroupError::InvalidGroupElement)
+}
+
+impl Secp256k1Affine {
+    /// Returns the SEC 1 encoding of this point, which is 33 bytes if `compressed` is set, and 65 bytes otherwise.
+    /// The point at infinity has no SEC 1 encoding, and is rejected.
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Result<Vec<u8>, GroupError> {
+        if self.is_zero() {
+            return Err(GroupError::Message("The point at infinity has no SEC 1 encoding".to_string()));
+        }
+        let x = write_be(&self.x.to_bigint());
+        let y = self.y.to_bigint();
+        match compressed {
+            true => Ok([&[0x02 | y.is_odd() as u8], &x[..]].concat()),
+            false => Ok([&[0x04], &x[..], &write_be(&y)[..]].concat()),
+        }
+    }
+
+    /// Returns the point with the given compressed or uncompressed SEC 1 encoding.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, GroupError> {
+        match (bytes.first(), bytes.len()) {
+            (Some(prefix @ (0x02 | 0x03)), 33) => {
+                let x = read_fq(&bytes[1..])?;
+                let point = Self::from_x_coordinate(x, false).ok_or(GroupError::InvalidGroupElement)?;
+                // Select the y-coordinate with the encoded parity.
+                match point.y.to_bigint().is_odd() == (*prefix == 0x03) {
+                    true => Ok(point),
+                    false => Ok(-point),
+                }
+            }
+            (Some(0x04), 65) => {
+                let point = Self::new(read_fq(&bytes[1..33])?, read_fq(&bytes[33..])?, false);
+                match point.is_on_curve() {
+                    true => Ok(point),
+                    false => Err(GroupError::InvalidGroupElement),
+                }
+            }
+            _ => Err(GroupError::Message("Invalid SEC 1 point encoding".to_string())),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    secp256k1::{ECDSASignature, Fq, Fr, Secp256k1Affine, Secp256k1Parameters, Secp256k1Projective},
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        AffineCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+        tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+        tests_group::*,
+        tests_projective::curve_tests,
+    },
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    BigInteger,
+    rand::{TestRng, Uniform},
+};
+
+use rand::Rng;
+use std::str::FromStr;
+
+pub(crate) const ITERATIONS: usize = 10;
+
+/// The compressed SEC 1 encoding of the generator.
+const GENERATOR_COMPRESSED: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// A public key, and a signature under it on `SHA-256("hello world")`.
+const PUBLIC_KEY_COMPRESSED: &str = "024db05b7ac5f93a9e1385545865676098b3ce16a2cf666883ba6139c7a732bf3d";
+const PUBLIC_KEY_UNCOMPRESSED: &str = "044db05b7ac5f93a9e1385545865676098b3ce16a2cf666883ba6139c7a732bf3d7a83fab518388c40754206c18aa211d722bd564ed453e655500c86285b182cc8";
+const MESSAGE_HASH: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+const SIGNATURE: &str = "17f53289eac961e5adc858d3ca50dab056ddca7a1a906c0815a0369312d1aa497226fb568cc12d312e0afca8e9ad9df2b2f3312d2da11322d6eb92b3906319eb";
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    hex.as_bytes().chunks(2).map(|chunk| u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap()).collect()
+}
+
+#[test]
+fn test_secp256k1_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+}
+
+#[test]
+fn test_secp256k1_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_fq_full_width_arithmetic() {
+    // The modulus uses every bit of the representation, so sums and products of large elements carry.
+    let minus_one = -Fq::one();
+    assert_eq!(minus_one + minus_one, -Fq::from(2u8));
+    assert_eq!(minus_one.double(), -Fq::from(2u8));
+    assert_eq!(minus_one * minus_one, Fq::one());
+    assert_eq!(minus_one.square(), Fq::one());
+    assert_eq!(minus_one.inverse().unwrap(), minus_one);
+    assert_eq!(Fq::from(2u8).inverse().unwrap().double(), Fq::one());
+}
+
+#[test]
+fn test_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<Secp256k1Projective>(&mut rng);
+    sw_tests::<Secp256k1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: Secp256k1Projective = rng.gen();
+    let b: Secp256k1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_generator() {
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!((generator * -Fr::one()).add_mixed(&generator).is_zero());
+}
+
+#[test]
+fn test_endomorphism() {
+    let mut rng = TestRng::default();
+
+    // The endomorphism acts as multiplication by a cube root of unity `lambda` in the scalar field.
+    let lambda = Fr::from_str("37718080363155996902926221483475020450927657555482586988616620542887997980018").unwrap();
+    assert!(lambda.pow([3]).is_one());
+    for _ in 0..ITERATIONS {
+        let point = Secp256k1Projective::rand(&mut rng).to_affine();
+        assert_eq!(Secp256k1Parameters::glv_endomorphism(point), (point * lambda).to_affine());
+    }
+}
+
+#[test]
+fn test_sec1_serialization() {
+    let mut rng = TestRng::default();
+
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert_eq!(generator.to_sec1_bytes(true).unwrap(), from_hex(GENERATOR_COMPRESSED));
+    assert_eq!(Secp256k1Affine::from_sec1_bytes(&from_hex(GENERATOR_COMPRESSED)).unwrap(), generator);
+
+    let public_key = Secp256k1Affine::from_sec1_bytes(&from_hex(PUBLIC_KEY_COMPRESSED)).unwrap();
+    assert_eq!(Secp256k1Affine::from_sec1_bytes(&from_hex(PUBLIC_KEY_UNCOMPRESSED)).unwrap(), public_key);
+    assert_eq!(public_key.to_sec1_bytes(false).unwrap(), from_hex(PUBLIC_KEY_UNCOMPRESSED));
+
+    for _ in 0..ITERATIONS {
+        let point = Secp256k1Projective::rand(&mut rng).to_affine();
+        for compressed in [true, false] {
+            let bytes = point.to_sec1_bytes(compressed).unwrap();
+            assert_eq!(Secp256k1Affine::from_sec1_bytes(&bytes).unwrap(), point);
+            let bytes = (-point).to_sec1_bytes(compressed).unwrap();
+            assert_eq!(Secp256k1Affine::from_sec1_bytes(&bytes).unwrap(), -point);
+        }
+    }
+
+    // Check that invalid encodings are rejected.
+    assert!(Secp256k1Affine::zero().to_sec1_bytes(true).is_err());
+    assert!(Secp256k1Affine::from_sec1_bytes(&[]).is_err());
+    assert!(Secp256k1Affine::from_sec1_bytes(&from_hex(PUBLIC_KEY_COMPRESSED)[..32]).is_err());
+    assert!(Secp256k1Affine::from_sec1_bytes(&[&[0x02], &[0xff; 32][..]].concat()).is_err());
+    let mut bytes = from_hex(PUBLIC_KEY_UNCOMPRESSED);
+    bytes[64] ^= 1;
+    assert!(Secp256k1Affine::from_sec1_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_ecdsa_verify() {
+    let public_key = Secp256k1Affine::from_sec1_bytes(&from_hex(PUBLIC_KEY_COMPRESSED)).unwrap();
+    let message_hash: [u8; 32] = from_hex(MESSAGE_HASH).try_into().unwrap();
+    let signature_bytes: [u8; 64] = from_hex(SIGNATURE).try_into().unwrap();
+    let signature = ECDSASignature::from_bytes_be(&signature_bytes).unwrap();
+    assert_eq!(signature.to_bytes_be(), signature_bytes);
+    assert!(signature.is_low_s());
+    assert!(signature.verify(&public_key, &message_hash));
+
+    // The high-s form of the signature is also valid, but is not canonical.
+    let high_s = ECDSASignature::new(signature.r(), -signature.s()).unwrap();
+    assert!(!high_s.is_low_s());
+    assert!(high_s.verify(&public_key, &message_hash));
+
+    // Check that the signature is rejected for a different message or public key.
+    let mut other_hash = message_hash;
+    other_hash[0] ^= 1;
+    assert!(!signature.verify(&public_key, &other_hash));
+    assert!(!signature.verify(&Secp256k1Affine::prime_subgroup_generator(), &message_hash));
+    assert!(!signature.verify(&Secp256k1Affine::zero(), &message_hash));
+    let other = ECDSASignature::new(signature.r() + Fr::one(), signature.s()).unwrap();
+    assert!(!other.verify(&public_key, &message_hash));
+
+    // Check that invalid signature encodings are rejected.
+    assert!(ECDSASignature::from_bytes_be(&[0u8; 64]).is_err());
+    assert!(ECDSASignature::from_bytes_be(&[0xff; 64]).is_err());
+}
+
+#[test]
+fn test_ecdsa_sign_and_verify() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        // Sign a random message hash under a random key.
+        let private_key = Fr::rand(&mut rng);
+        let public_key = (Secp256k1Affine::prime_subgroup_generator() * private_key).to_affine();
+        let message_hash: [u8; 32] = rng.gen();
+        let nonce = Fr::rand(&mut rng);
+        let commitment = (Secp256k1Affine::prime_subgroup_generator() * nonce).to_affine();
+        let r = Fr::from_bytes_le_mod_order(&commitment.x.to_bigint().to_biguint().to_bytes_le());
+        let s = nonce.inverse().unwrap() * (Fr::from_bytes_be_mod_order(&message_hash) + r * private_key);
+        let signature = ECDSASignature::new(r, s).unwrap();
+        assert!(signature.verify(&public_key, &message_hash));
+    }
+}
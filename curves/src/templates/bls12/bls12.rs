@@ -36,7 +36,7 @@ use snarkvm_fields::{
     Zero,
     fp6_3over2::Fp6Parameters,
 };
-use snarkvm_utilities::bititerator::BitIteratorBE;
+use snarkvm_utilities::{bititerator::BitIteratorBE, vec};
 
 use core::{fmt::Debug, hash::Hash, marker::PhantomData};
 use serde::{Deserialize, Serialize};
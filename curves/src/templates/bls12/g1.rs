@@ -23,7 +23,7 @@ use crate::{
 use snarkvm_fields::Zero;
 use snarkvm_utilities::{FromBytes, ToBytes, serialize::*};
 
-use std::io::{Read, Result as IoResult, Write};
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
 
 pub type G1Affine<P> = Affine<<P as Bls12Parameters>::G1Parameters>;
 pub type G1Projective<P> = Projective<<P as Bls12Parameters>::G1Parameters>;
@@ -21,9 +21,9 @@ use crate::{
     traits::{AffineCurve, ShortWeierstrassParameters},
 };
 use snarkvm_fields::{Field, Fp2, One, Zero};
-use snarkvm_utilities::{ToBytes, bititerator::BitIteratorBE, serialize::*};
+use snarkvm_utilities::{ToBytes, bititerator::BitIteratorBE, serialize::*, vec};
 
-use std::io::{Result as IoResult, Write};
+use snarkvm_utilities::io::{Result as IoResult, Write};
 
 pub type G2Affine<P> = Affine<<P as Bls12Parameters>::G2Parameters>;
 pub type G2Projective<P> = Projective<<P as Bls12Parameters>::G2Parameters>;
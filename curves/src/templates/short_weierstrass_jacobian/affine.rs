@@ -18,12 +18,13 @@ use crate::{
     templates::short_weierstrass_jacobian::Projective,
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, SquareRootField, Zero};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     FromBytes,
     ToBytes,
     bititerator::BitIteratorBE,
-    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    error,
+    io::{Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::*,
 };
@@ -89,6 +90,8 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     type Projective = Projective<P>;
     type ScalarField = P::ScalarField;
 
+    const PREFERS_BATCH_AFFINE_ADDITION: bool = true;
+
     /// Initializes a new affine group element from the given coordinates.
     fn from_coordinates(coordinates: Self::Coordinates) -> Option<Self> {
         let (x, y, infinity) = coordinates;
@@ -181,6 +184,15 @@ impl<P: Parameters> AffineCurve for Affine<P> {
         output
     }
 
+    fn glv_decompose(&self, scalar: P::ScalarField) -> Option<[(Self, P::ScalarField); 2]> {
+        let (k_1, k_2, k_1_is_negative, k_2_is_positive) =
+            scalar.decompose(&P::Q1, &P::Q2, P::B1, P::B2, P::R128, &P::HALF_R);
+        // Fold the signs of the decomposition into the bases.
+        let p_1 = if k_1_is_negative { -*self } else { *self };
+        let p_2 = P::glv_endomorphism(if k_2_is_positive { *self } else { -*self });
+        Some([(p_1, k_1), (p_2, k_2)])
+    }
+
     fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
         self.mul_bits(BitIteratorBE::new_without_leading_zeros(P::COFACTOR))
     }
@@ -307,7 +319,7 @@ impl<P: Parameters> FromBytes for Affine<P> {
         let infinity = bool::read_le(&mut reader)?;
 
         if infinity != x.is_zero() && y.is_one() {
-            return Err(Error::new(ErrorKind::InvalidData, "Infinity flag is not valid"));
+            return Err(error("Infinity flag is not valid"));
         }
         Ok(Self::new(x, y, infinity))
     }
@@ -0,0 +1,122 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{BigInteger, Vec, vec};
+
+use core::ops::Neg;
+
+/// Returns `p * by`, using the GLV endomorphism of the curve to halve the number of doublings.
+///
+/// The scalar is decomposed into two half-length scalars with the decomposition parameters of the curve,
+/// which are then recoded in w-ary NAF form and evaluated against tables of `p` and its endomorphism.
+pub fn glv_mul_projective<P: Parameters>(p: Projective<P>, by: P::ScalarField) -> Projective<P> {
+    /// The scalar multiplication window size.
+    const GLV_WINDOW_SIZE: usize = 4;
+
+    /// The table size, used for w-ary NAF recoding.
+    const TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE + 1);
+    const HALF_TABLE_SIZE: i64 = 1 << (GLV_WINDOW_SIZE);
+    const MASK_FOR_MOD_TABLE_SIZE: u64 = (TABLE_SIZE as u64) - 1;
+    /// The GLV table length.
+    const L: usize = 1 << (GLV_WINDOW_SIZE - 1);
+
+    let decomposition = by.decompose(&P::Q1, &P::Q2, P::B1, P::B2, P::R128, &P::HALF_R);
+
+    // Prepare tables.
+    let mut t_1 = Vec::with_capacity(L);
+    let double = Affine::<P>::from(p.double());
+    t_1.push(p);
+    for i in 1..L {
+        t_1.push(t_1[i - 1].add_mixed(&double));
+    }
+    let t_1 = Projective::<P>::batch_normalization_into_affine(t_1);
+
+    let t_2 = t_1.iter().copied().map(P::glv_endomorphism).collect::<Vec<_>>();
+
+    let mod_signed = |d| {
+        let d_mod_window_size = i64::try_from(d & MASK_FOR_MOD_TABLE_SIZE).unwrap();
+        if d_mod_window_size >= HALF_TABLE_SIZE { d_mod_window_size - TABLE_SIZE } else { d_mod_window_size }
+    };
+    let to_wnaf = |e: P::ScalarField| -> Vec<i32> {
+        let mut naf = vec![];
+        let mut e = e.to_bigint();
+        while !e.is_zero() {
+            let next = if e.is_odd() {
+                let naf_sign = mod_signed(e.as_ref()[0]);
+                if naf_sign < 0 {
+                    e.add_nocarry(&<P::ScalarField as PrimeField>::BigInteger::from(-naf_sign as u64));
+                } else {
+                    e.sub_noborrow(&<P::ScalarField as PrimeField>::BigInteger::from(naf_sign as u64));
+                }
+                naf_sign.try_into().unwrap()
+            } else {
+                0
+            };
+            naf.push(next);
+            e.div2();
+        }
+
+        naf
+    };
+
+    let wnaf = |k1: P::ScalarField, k2: P::ScalarField, s1: bool, s2: bool| -> (Vec<i32>, Vec<i32>) {
+        let mut wnaf_1 = to_wnaf(k1);
+        let mut wnaf_2 = to_wnaf(k2);
+
+        if s1 {
+            wnaf_1.iter_mut().for_each(|e| *e = -*e);
+        }
+        if !s2 {
+            wnaf_2.iter_mut().for_each(|e| *e = -*e);
+        }
+
+        (wnaf_1, wnaf_2)
+    };
+
+    let naf_add = |table: &[Affine<P>], naf: i32, acc: &mut Projective<P>| {
+        if naf != 0 {
+            let mut p_1 = table[(naf.abs() >> 1) as usize];
+            if naf < 0 {
+                p_1 = p_1.neg();
+            }
+            acc.add_assign_mixed(&p_1);
+        }
+    };
+
+    // Recode scalars.
+    let (naf_1, naf_2) = wnaf(decomposition.0, decomposition.1, decomposition.2, decomposition.3);
+    let max_len = naf_1.len().max(naf_2.len());
+    let mut acc = Projective::<P>::zero();
+    for i in (0..max_len).rev() {
+        if i < naf_1.len() {
+            naf_add(&t_1, naf_1[i], &mut acc)
+        }
+
+        if i < naf_2.len() {
+            naf_add(&t_2, naf_2[i], &mut acc)
+        }
+
+        if i != 0 {
+            acc.double_in_place();
+        }
+    }
+
+    acc
+}
@@ -0,0 +1,133 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, ShortWeierstrassParameters as Parameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::BigInteger;
+
+/// A deterministic map from field elements to points on a short Weierstrass curve,
+/// as used by the hash-to-curve constructions of RFC 9380.
+pub trait MapToCurve: Parameters {
+    /// The identifier of the curve in RFC 9380 suite identifiers, e.g. `BLS12377G1`.
+    const CURVE_ID: &'static str;
+    /// The identifier of the map in RFC 9380 suite identifiers, e.g. `SSWU`.
+    const MAP_ID: &'static str;
+
+    /// Maps the given field element to a point on the curve, which need not be in the prime-order subgroup.
+    fn map_to_curve(u: Self::BaseField) -> Affine<Self>;
+
+    /// Maps the given point on the curve into the prime-order subgroup.
+    fn clear_cofactor(point: Affine<Self>) -> Affine<Self> {
+        point.mul_by_cofactor()
+    }
+}
+
+/// The parameters of the Simplified Shallue-van de Woestijne-Ulas map of RFC 9380 (section 6.6.3),
+/// which maps to a curve through an isogenous curve `y^2 = x^3 + A' * x + B'` where `A'` and `B'` are nonzero.
+pub trait SWUParameters: Parameters {
+    /// The coefficient `A'` of the isogenous curve.
+    const ISOGENOUS_CURVE_A: Self::BaseField;
+    /// The coefficient `B'` of the isogenous curve.
+    const ISOGENOUS_CURVE_B: Self::BaseField;
+    /// The constant `Z` of the map, chosen as in RFC 9380 (appendix H.2).
+    const SWU_Z: Self::BaseField;
+
+    /// Evaluates the isogeny from the isogenous curve to the curve on the affine point `(x, y)`,
+    /// returning `None` if the point is in the kernel of the isogeny.
+    fn isogeny_map(x: Self::BaseField, y: Self::BaseField) -> Option<(Self::BaseField, Self::BaseField)>;
+}
+
+/// The parameters of the Shallue-van de Woestijne map of RFC 9380 (section 6.6.1),
+/// which maps to any short Weierstrass curve.
+pub trait SVDWParameters: Parameters {
+    /// The constant `Z` of the map, chosen as in RFC 9380 (appendix H.1).
+    const SVDW_Z: Self::BaseField;
+}
+
+/// Returns the point on the curve that the Simplified SWU map of RFC 9380 (section 6.6.2)
+/// assigns to `u` on the isogenous curve, mapped to the curve through the isogeny.
+pub fn map_to_curve_simple_swu<P: SWUParameters>(u: P::BaseField) -> Affine<P> {
+    let (a, b, z) = (P::ISOGENOUS_CURVE_A, P::ISOGENOUS_CURVE_B, P::SWU_Z);
+    let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+    // Compute x1 = (-B / A) * (1 + 1 / (Z^2 * u^4 + Z * u^2)), or x1 = B / (Z * A) if the denominator is zero.
+    let z_u2 = z * u.square();
+    let x1 = match (z_u2.square() + z_u2).inverse() {
+        Some(tv1) => (-b / a) * (P::BaseField::one() + tv1),
+        None => b / (z * a),
+    };
+
+    // Use x1 if g(x1) is square, and x2 = Z * u^2 * x1 otherwise, in which case g(x2) is square.
+    let (x, y) = match g(x1).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z_u2 * x1;
+            (x2, g(x2).sqrt().expect("Either g(x1) or g(x2) is square"))
+        }
+    };
+    let y = if sgn0(&u) == sgn0(&y) { y } else { -y };
+
+    match P::isogeny_map(x, y) {
+        Some((x, y)) => Affine::new(x, y, false),
+        None => Affine::zero(),
+    }
+}
+
+/// Returns the point on the curve that the Shallue-van de Woestijne map of RFC 9380 (section 6.6.1) assigns to `u`.
+pub fn map_to_curve_svdw<P: SVDWParameters>(u: P::BaseField) -> Affine<P> {
+    let (a, b, z) = (P::WEIERSTRASS_A, P::WEIERSTRASS_B, P::SVDW_Z);
+    let one = P::BaseField::one();
+    let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+    // Compute the constants of the map.
+    let three_z2_plus_four_a = z.square().double() + z.square() + a.double().double();
+    let c1 = g(z);
+    let c2 = -z * P::BaseField::half();
+    let c3 = (-c1 * three_z2_plus_four_a).sqrt().expect("The constant Z is chosen such that c3 exists");
+    let c3 = if sgn0(&c3) { -c3 } else { c3 };
+    let c4 = -c1.double().double() / three_z2_plus_four_a;
+
+    let tv1 = u.square() * c1;
+    let tv2 = one + tv1;
+    let tv1 = one - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_else(P::BaseField::zero);
+    let tv4 = u * tv1 * tv3 * c3;
+
+    // Select the first of the three candidate x-coordinates for which g(x) is square.
+    let x1 = c2 - tv4;
+    let x2 = c2 + tv4;
+    let x3 = (tv2.square() * tv3).square() * c4 + z;
+    let (x, y) = [x1, x2, x3]
+        .into_iter()
+        .find_map(|x| g(x).sqrt().map(|y| (x, y)))
+        .expect("One of g(x1), g(x2), or g(x3) is square");
+    let y = if sgn0(&u) == sgn0(&y) { y } else { -y };
+
+    Affine::new(x, y, false)
+}
+
+/// Returns the sign of the field element, as defined in RFC 9380 (section 4.1).
+pub fn sgn0<F: Field>(element: &F) -> bool {
+    let mut sign = false;
+    let mut zero = true;
+    for coefficient in element.to_base_prime_field_elements() {
+        sign |= zero && coefficient.to_bigint().is_odd();
+        zero &= coefficient.is_zero();
+    }
+    sign
+}
@@ -16,8 +16,17 @@
 pub mod affine;
 pub use affine::*;
 
+pub mod glv;
+pub use glv::*;
+
+pub mod map_to_curve;
+pub use map_to_curve::*;
+
 pub mod projective;
 pub use projective::*;
 
+pub mod zcash;
+pub use zcash::{PointEncoding, ZcashField};
+
 #[cfg(test)]
 pub mod tests;
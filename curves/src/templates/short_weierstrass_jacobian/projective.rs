@@ -17,8 +17,10 @@ use crate::{
     templates::short_weierstrass_jacobian::Affine,
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, Zero, impl_add_sub_from_field_ref};
-use snarkvm_utilities::{FromBytes, ToBytes, cfg_iter_mut, rand::Uniform, serialize::*};
+use snarkvm_fields::{Field, One, Zero, batch_inverse, impl_add_sub_from_field_ref};
+#[cfg(feature = "std")]
+use snarkvm_utilities::cfg_iter_mut;
+use snarkvm_utilities::{FromBytes, ToBytes, rand::Uniform, serialize::*};
 
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -29,9 +31,10 @@ use rand::{
     Rng,
     distributions::{Distribution, Standard},
 };
-#[cfg(not(feature = "serial"))]
+#[cfg(all(feature = "std", not(feature = "serial")))]
 use rayon::prelude::*;
-use std::io::{Read, Result as IoResult, Write};
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Projective<P: Parameters> {
@@ -170,46 +173,17 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
     /// TODO (howardwu): This method can likely be sped up.
     #[inline]
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-        {
-            tmp.mul_assign(&g.z);
-            prod.push(tmp);
+        // Invert the z-coordinates of the elements that are not normalized.
+        let mut z_inverses = v.iter().filter(|g| !g.is_normalized()).map(|g| g.z).collect::<Vec<_>>();
+        batch_inverse(&mut z_inverses);
+        for (g, z_inverse) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_inverses) {
+            g.z = z_inverse; // 1/z
         }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * g.z;
-            g.z = tmp * s;
-            tmp = newtmp;
-        }
-        cfg_iter_mut!(v).filter(|g| !g.is_normalized()).for_each(|g| {
+        #[cfg(feature = "std")]
+        let v = cfg_iter_mut!(v);
+        #[cfg(not(feature = "std"))]
+        let v = v.iter_mut();
+        v.filter(|g| !g.is_normalized()).for_each(|g| {
             // Perform affine transformations
             let z2 = g.z.square(); // 1/z
             g.x *= &z2; // x/z^2
@@ -485,6 +459,34 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for Projective<P> {
     }
 }
 
+impl<P: Parameters> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // x1/z1^2 == x2/z2^2  <==> x1 * z2^2 == x2 * z1^2, and likewise for y with cubes.
+        // As the point at infinity has Z = 0 and Y != 0, it is only equal to itself.
+        let (z1_squared, z2_squared) = (self.z.square(), other.z.square());
+        (self.x * z2_squared).ct_eq(&(other.x * z1_squared))
+            & (self.y * z2_squared * other.z).ct_eq(&(other.y * z1_squared * self.z))
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     type Output = Self;
 
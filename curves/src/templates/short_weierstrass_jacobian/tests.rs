@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{Affine, Projective};
+use super::{Affine, PointEncoding, Projective, ZcashField};
 use crate::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters};
 use snarkvm_fields::Zero;
 use snarkvm_utilities::{
@@ -160,3 +160,34 @@ pub fn sw_from_x_coordinate<P: ShortWeierstrassParameters>(rng: &mut TestRng) {
         }
     }
 }
+
+pub fn sw_zcash_serialization_test<P: ShortWeierstrassParameters>(rng: &mut TestRng)
+where
+    P::BaseField: ZcashField,
+{
+    let modes = [(Compress::Yes, Validate::Yes), (Compress::No, Validate::Yes), (Compress::Yes, Validate::No)];
+    for (compress, validate) in modes {
+        for encoding in [PointEncoding::Canonical, PointEncoding::Zcash] {
+            let points = (0..ITERATIONS).map(|_| Projective::<P>::rand(rng).to_affine()).chain([Affine::<P>::zero()]);
+            for a in points {
+                let mut bytes = Vec::new();
+                a.serialize_with_encoding(&mut bytes, compress, encoding).unwrap();
+                assert_eq!(bytes.len(), a.serialized_size_with_encoding(compress, encoding));
+
+                let b = Affine::<P>::deserialize_with_encoding(&bytes[..], compress, validate, encoding).unwrap();
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    // Ensure the zcash encoding differs from the canonical encoding, and sets the compression flag.
+    let a = Projective::<P>::rand(rng).to_affine();
+    let (mut canonical, mut zcash) = (Vec::new(), Vec::new());
+    a.serialize_with_encoding(&mut canonical, Compress::Yes, PointEncoding::Canonical).unwrap();
+    a.serialize_with_encoding(&mut zcash, Compress::Yes, PointEncoding::Zcash).unwrap();
+    assert_ne!(canonical, zcash);
+    assert_eq!(zcash[0] & 0x80, 0x80);
+    assert!(
+        Affine::<P>::deserialize_with_encoding(&zcash[..], Compress::No, Validate::Yes, PointEncoding::Zcash).is_err()
+    );
+}
@@ -0,0 +1,242 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Point encodings compatible with the zcash serialization format, as used by zcash, arkworks, and blst.
+//!
+//! Field elements are written in big-endian order, with `Fp2` elements written as `c1 || c0`.
+//! The three most significant bits of the first byte are used as flags:
+//!  - bit 7 is set if the encoding is compressed,
+//!  - bit 6 is set if the point is the point at infinity,
+//!  - bit 5 is set if the point is compressed and `y` is the lexicographically largest of `±y`.
+//!
+//! The encoding requires the three most significant bits of the base field encoding to be unused,
+//! which holds for both BLS12-377 and BLS12-381.
+
+use crate::{
+    AffineCurve,
+    GroupError,
+    templates::short_weierstrass_jacobian::Affine,
+    traits::ShortWeierstrassParameters as Parameters,
+};
+use snarkvm_fields::{Fp2, Fp2Parameters, Fp384, Fp384Parameters, PrimeField, Zero};
+use snarkvm_utilities::{
+    ToString,
+    Vec,
+    biginteger::BigInteger384,
+    io::{Read, Write},
+    serialize::{Compress, SerializationError, Validate},
+    vec,
+};
+
+const COMPRESSION_FLAG: u8 = 0b1000_0000;
+const INFINITY_FLAG: u8 = 0b0100_0000;
+const SORT_FLAG: u8 = 0b0010_0000;
+
+/// The encoding of a point in a short Weierstrass curve.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PointEncoding {
+    /// The native encoding of `CanonicalSerialize`, with flags in the last byte of the little-endian encoding.
+    #[default]
+    Canonical,
+    /// The zcash encoding, with flags in the first byte of the big-endian encoding.
+    Zcash,
+}
+
+/// A base field element with a fixed-size big-endian encoding.
+pub trait ZcashField: Sized {
+    /// The number of bytes in the encoding.
+    const SIZE: usize;
+
+    /// Writes `self` into `bytes` in big-endian order.
+    fn write_be(&self, bytes: &mut [u8]);
+
+    /// Reads a field element from `bytes`, failing if it is not canonical.
+    fn read_be(bytes: &[u8]) -> Result<Self, GroupError>;
+}
+
+impl<P: Fp384Parameters> ZcashField for Fp384<P> {
+    const SIZE: usize = 48;
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.to_bigint().0.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+
+    fn read_be(bytes: &[u8]) -> Result<Self, GroupError> {
+        let mut limbs = [0u64; 6];
+        for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_be_bytes(chunk.try_into().map_err(|_| GroupError::InvalidGroupElement)?);
+        }
+        Self::from_bigint(BigInteger384(limbs)).ok_or(GroupError::InvalidGroupElement)
+    }
+}
+
+impl<P: Fp2Parameters> ZcashField for Fp2<P>
+where
+    P::Fp: ZcashField,
+{
+    const SIZE: usize = 2 * P::Fp::SIZE;
+
+    fn write_be(&self, bytes: &mut [u8]) {
+        let (c1, c0) = bytes.split_at_mut(P::Fp::SIZE);
+        self.c1.write_be(c1);
+        self.c0.write_be(c0);
+    }
+
+    fn read_be(bytes: &[u8]) -> Result<Self, GroupError> {
+        let (c1, c0) = bytes.split_at(P::Fp::SIZE);
+        Ok(Self::new(P::Fp::read_be(c0)?, P::Fp::read_be(c1)?))
+    }
+}
+
+/// Writes the compressed zcash encoding of `point` into `bytes`.
+pub(crate) fn write_compressed<P: Parameters>(point: &Affine<P>, bytes: &mut [u8])
+where
+    P::BaseField: ZcashField,
+{
+    if point.is_zero() {
+        bytes[0] = INFINITY_FLAG;
+    } else {
+        point.x.write_be(bytes);
+        if point.y > -point.y {
+            bytes[0] |= SORT_FLAG;
+        }
+    }
+    bytes[0] |= COMPRESSION_FLAG;
+}
+
+/// Writes the uncompressed zcash encoding of `point` into `bytes`.
+pub(crate) fn write_uncompressed<P: Parameters>(point: &Affine<P>, bytes: &mut [u8])
+where
+    P::BaseField: ZcashField,
+{
+    if point.is_zero() {
+        bytes[0] = INFINITY_FLAG;
+    } else {
+        let (x, y) = bytes.split_at_mut(P::BaseField::SIZE);
+        point.x.write_be(x);
+        point.y.write_be(y);
+    }
+}
+
+/// Reads the flags from the first byte of `bytes`, and returns them with the flags cleared from the encoding.
+fn read_flags(bytes: &[u8]) -> (bool, bool, bool, Vec<u8>) {
+    let mut bytes = bytes.to_vec();
+    let flags = bytes[0];
+    bytes[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+    (flags & COMPRESSION_FLAG != 0, flags & INFINITY_FLAG != 0, flags & SORT_FLAG != 0, bytes)
+}
+
+/// Reads a point from its compressed zcash encoding, checking that it is in the prime-order subgroup if `validate` is set.
+pub(crate) fn read_compressed<P: Parameters>(bytes: &[u8], validate: Validate) -> Result<Affine<P>, GroupError>
+where
+    P::BaseField: ZcashField,
+{
+    let (is_compressed, is_infinity, is_largest, bytes) = read_flags(bytes);
+    if !is_compressed {
+        return Err(GroupError::Message("Expected a compressed point encoding".to_string()));
+    }
+    if is_infinity {
+        return match !is_largest && bytes.iter().all(|byte| *byte == 0) {
+            true => Ok(Affine::zero()),
+            false => Err(GroupError::InvalidGroupElement),
+        };
+    }
+    let x = P::BaseField::read_be(&bytes)?;
+    let point = Affine::<P>::from_x_coordinate(x, is_largest).ok_or(GroupError::InvalidGroupElement)?;
+    match validate == Validate::No || point.is_in_correct_subgroup_assuming_on_curve() {
+        true => Ok(point),
+        false => Err(GroupError::InvalidGroupElement),
+    }
+}
+
+/// Reads a point from its uncompressed zcash encoding, checking that it is on the curve
+/// and in the prime-order subgroup if `validate` is set.
+pub(crate) fn read_uncompressed<P: Parameters>(bytes: &[u8], validate: Validate) -> Result<Affine<P>, GroupError>
+where
+    P::BaseField: ZcashField,
+{
+    let (is_compressed, is_infinity, is_largest, bytes) = read_flags(bytes);
+    if is_compressed {
+        return Err(GroupError::Message("Expected an uncompressed point encoding".to_string()));
+    }
+    if is_largest {
+        return Err(GroupError::InvalidGroupElement);
+    }
+    if is_infinity {
+        return match bytes.iter().all(|byte| *byte == 0) {
+            true => Ok(Affine::zero()),
+            false => Err(GroupError::InvalidGroupElement),
+        };
+    }
+    let (x, y) = bytes.split_at(P::BaseField::SIZE);
+    let point = Affine::<P>::new(P::BaseField::read_be(x)?, P::BaseField::read_be(y)?, false);
+    match validate == Validate::No || (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()) {
+        true => Ok(point),
+        false => Err(GroupError::InvalidGroupElement),
+    }
+}
+
+impl<P: Parameters> Affine<P>
+where
+    P::BaseField: ZcashField,
+{
+    /// Returns the size of the encoding of this point with the given compression and encoding.
+    pub fn serialized_size_with_encoding(&self, compress: Compress, encoding: PointEncoding) -> usize {
+        match (encoding, compress) {
+            (PointEncoding::Canonical, _) => snarkvm_utilities::CanonicalSerialize::serialized_size(self, compress),
+            (PointEncoding::Zcash, Compress::Yes) => P::BaseField::SIZE,
+            (PointEncoding::Zcash, Compress::No) => 2 * P::BaseField::SIZE,
+        }
+    }
+
+    /// Serializes this point into `writer` with the given compression and encoding.
+    pub fn serialize_with_encoding<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+        encoding: PointEncoding,
+    ) -> Result<(), SerializationError> {
+        if encoding == PointEncoding::Canonical {
+            return snarkvm_utilities::CanonicalSerialize::serialize_with_mode(self, writer, compress);
+        }
+        let mut bytes = vec![0u8; self.serialized_size_with_encoding(compress, encoding)];
+        match compress {
+            Compress::Yes => write_compressed(self, &mut bytes),
+            Compress::No => write_uncompressed(self, &mut bytes),
+        }
+        Ok(writer.write_all(&bytes)?)
+    }
+
+    /// Deserializes a point from `reader` with the given compression and encoding.
+    pub fn deserialize_with_encoding<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+        encoding: PointEncoding,
+    ) -> Result<Self, SerializationError> {
+        if encoding == PointEncoding::Canonical {
+            return snarkvm_utilities::CanonicalDeserialize::deserialize_with_mode(reader, compress, validate);
+        }
+        let mut bytes = vec![0u8; Self::zero().serialized_size_with_encoding(compress, encoding)];
+        reader.read_exact(&mut bytes)?;
+        let point = match compress {
+            Compress::Yes => read_compressed(&bytes, validate),
+            Compress::No => read_uncompressed(&bytes, validate),
+        };
+        point.map_err(|_| SerializationError::InvalidData)
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     templates::twisted_edwards_extended::Affine,
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{Field, One, PrimeField, Zero, impl_add_sub_from_field_ref};
+use snarkvm_fields::{Field, One, PrimeField, Zero, batch_inverse, impl_add_sub_from_field_ref};
 use snarkvm_utilities::{FromBytes, ToBytes, bititerator::BitIteratorBE, rand::Uniform, serialize::*};
 
 use core::{
@@ -29,7 +29,8 @@ use rand::{
     Rng,
     distributions::{Distribution, Standard},
 };
-use std::io::{Read, Result as IoResult, Write};
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Projective<P: Parameters> {
@@ -156,44 +157,11 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
     }
 
     fn batch_normalization(v: &mut [Self]) {
-        // Montgomery’s Trick and Fast Implementation of Masked AES
-        // Genelle, Prouff and Quisquater
-        // Section 3.2
-
-        // First pass: compute [a, ab, abc, ...]
-        let mut prod = Vec::with_capacity(v.len());
-        let mut tmp = P::BaseField::one();
-        for g in v
-            .iter_mut()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-        {
-            tmp.mul_assign(&g.z);
-            prod.push(tmp);
-        }
-
-        // Invert `tmp`.
-        tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-        // Second pass: iterate backwards to compute inverses
-        for (g, s) in v
-            .iter_mut()
-            // Backwards
-            .rev()
-            // Ignore normalized elements
-            .filter(|g| !g.is_normalized())
-            // Backwards, skip last element, fill in one for last term.
-            .zip(
-                prod.into_iter()
-                    .rev()
-                    .skip(1)
-                    .chain(Some(P::BaseField::one())),
-            )
-        {
-            // tmp := tmp * g.z; g.z := tmp * s = 1/z
-            let newtmp = tmp * g.z;
-            g.z = tmp * s;
-            tmp = newtmp;
+        // Invert the z-coordinates of the elements that are not normalized.
+        let mut z_inverses = v.iter().filter(|g| !g.is_normalized()).map(|g| g.z).collect::<Vec<_>>();
+        batch_inverse(&mut z_inverses);
+        for (g, z_inverse) in v.iter_mut().filter(|g| !g.is_normalized()).zip(z_inverses) {
+            g.z = z_inverse; // 1/z
         }
 
         // Perform affine transformations
@@ -363,10 +331,37 @@ impl<'a, P: Parameters> SubAssign<&'a Self> for Projective<P> {
     }
 }
 
+impl<P: Parameters> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // x1/z1 == x2/z2  <==> x1 * z2 == x2 * z1, and likewise for y.
+        (self.x * other.z).ct_eq(&(other.x * self.z)) & (self.y * other.z).ct_eq(&(other.y * self.z))
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     type Output = Self;
 
     /// Performs scalar multiplication of this element.
+    #[cfg(not(feature = "constant-time"))]
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
@@ -388,6 +383,25 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
 
         res
     }
+
+    /// Performs scalar multiplication of this element, in time independent of the scalar.
+    ///
+    /// Every bit of the scalar costs a doubling and a (unified) addition,
+    /// and the sum is kept or discarded with a constant-time selection.
+    #[cfg(feature = "constant-time")]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn mul(self, other: P::ScalarField) -> Self {
+        let mut res = Self::zero();
+
+        for i in BitIteratorBE::new(other.to_bigint()) {
+            res.double_in_place();
+            let sum = res + self;
+            res = Self::conditional_select(&res, &sum, Choice::from(i as u8));
+        }
+
+        res
+    }
 }
 
 impl<P: Parameters> MulAssign<P::ScalarField> for Projective<P> {
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::{PairingEngine, templates::short_weierstrass_jacobian};
-use snarkvm_fields::{Field, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{ConstantTime, Field, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{FromBytes, ToBytes, rand::Uniform, serialize::*};
 
 use core::{
@@ -58,6 +58,7 @@ pub trait ProjectiveCurve:
     + ToBytes
     + iter::Sum
     + From<<Self as ProjectiveCurve>::Affine>
+    + ConstantTime
 {
     type Affine: AffineCurve<Projective = Self, ScalarField = Self::ScalarField> + From<Self> + Into<Self>;
     type BaseField: Field;
@@ -144,6 +145,10 @@ pub trait AffineCurve:
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInteger>;
     type Coordinates;
 
+    /// Whether affine additions that share an inversion across a batch (see `batch_add_loop_1`)
+    /// are cheaper than mixed additions into projective points, as on short Weierstrass curves.
+    const PREFERS_BATCH_AFFINE_ADDITION: bool = false;
+
     /// Initializes a new affine group element from the given coordinates.
     fn from_coordinates(coordinates: Self::Coordinates) -> Option<Self>;
 
@@ -199,6 +204,14 @@ pub trait AffineCurve:
     /// an integer.
     fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> Self::Projective;
 
+    /// Returns `[(p_1, k_1), (p_2, k_2)]` such that `scalar * self = k_1 * p_1 + k_2 * p_2`, where `k_1` and `k_2`
+    /// have about half the bits of `scalar`, using the GLV endomorphism of the curve.
+    /// Returns `None` if the curve does not have an efficiently computable endomorphism.
+    fn glv_decompose(&self, scalar: Self::ScalarField) -> Option<[(Self, Self::ScalarField); 2]> {
+        let _ = scalar;
+        None
+    }
+
     /// Multiply this element by the cofactor.
     #[must_use]
     fn mul_by_cofactor(&self) -> Self {
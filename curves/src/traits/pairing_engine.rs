@@ -15,6 +15,7 @@
 
 use crate::traits::{AffineCurve, PairingCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField};
+use snarkvm_utilities::Vec;
 
 use core::{fmt::Debug, hash::Hash, iter};
 
@@ -74,6 +75,28 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Hash
         Self::final_exponentiation(&Self::miller_loop(i)).unwrap()
     }
 
+    /// Computes the product of the pairings of the corresponding elements of `g1s` and `g2s`,
+    /// sharing a single final exponentiation across all of the pairs.
+    ///
+    /// Panics if `g1s` and `g2s` have different lengths.
+    #[must_use]
+    fn multi_pairing<G1, G2>(g1s: impl IntoIterator<Item = G1>, g2s: impl IntoIterator<Item = G2>) -> Self::Fqk
+    where
+        G1: Into<Self::G1Affine>,
+        G2: Into<Self::G2Affine>,
+    {
+        let (mut g1s, mut g2s) = (g1s.into_iter(), g2s.into_iter());
+        let mut prepared = Vec::with_capacity(g1s.size_hint().0);
+        loop {
+            match (g1s.next(), g2s.next()) {
+                (Some(p), Some(q)) => prepared.push((p.into().prepare(), q.into().prepare())),
+                (None, None) => break,
+                _ => panic!("The number of G1 and G2 elements in a multi-pairing must match"),
+            }
+        }
+        Self::product_of_pairings(prepared.iter().map(|(p, q)| (p, q)))
+    }
+
     /// Performs multiple pairing operations
     #[must_use]
     fn pairing<G1, G2>(p: G1, q: G2) -> Self::Fqk
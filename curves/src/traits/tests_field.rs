@@ -19,6 +19,9 @@ use snarkvm_fields::{
     LegendreSymbol,
     PrimeField,
     SquareRootField,
+    Zero,
+    batch_inverse,
+    batch_inverse_to_vec,
     traits::{FftParameters, FieldParameters},
 };
 use snarkvm_utilities::{
@@ -98,6 +101,24 @@ fn random_multiplication_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_base_prime_field_elements_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let elements = a.to_base_prime_field_elements();
+        assert_eq!(elements.len() as u64, F::extension_degree());
+        assert_eq!(F::from_base_prime_field_elems(&elements), Some(a));
+
+        // Ensure the wrong number of coefficients is rejected.
+        assert_eq!(F::from_base_prime_field_elems(&elements[1..]), None);
+        assert_eq!(F::from_base_prime_field_elems(&[elements.clone(), elements].concat()), None);
+    }
+
+    let b = F::rand(rng).to_base_prime_field_elements()[0];
+    let elements = F::from_base_prime_field(b).to_base_prime_field_elements();
+    assert_eq!(elements[0], b);
+    assert!(elements[1..].iter().all(|element| element.is_zero()));
+}
+
 fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     assert!(F::zero().inverse().is_none());
 
@@ -110,6 +131,22 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
+    for length in [0, 1, 2, 3, 10, 100] {
+        // Sample the elements, with a few zeros interspersed.
+        let elements = (0..length).map(|i| if i % 7 == 3 { F::zero() } else { F::rand(rng) }).collect::<Vec<_>>();
+        let expected = elements.iter().map(|f| f.inverse().unwrap_or_else(F::zero)).collect::<Vec<_>>();
+
+        // Check the out-of-place variant.
+        assert_eq!(batch_inverse_to_vec(&elements), expected);
+
+        // Check the in-place variant.
+        let mut candidate = elements;
+        batch_inverse(&mut candidate);
+        assert_eq!(candidate, expected);
+    }
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -503,9 +540,11 @@ pub fn field_test<F: Field>(a: F, b: F, rng: &mut TestRng) {
     random_subtraction_tests::<F, _>(rng);
     random_multiplication_tests::<F, _>(rng);
     random_inversion_tests::<F, _>(rng);
+    random_batch_inversion_tests::<F, _>(rng);
     random_doubling_tests::<F, _>(rng);
     random_squaring_tests::<F, _>(rng);
     random_expansion_tests::<F, _>(rng);
+    random_base_prime_field_elements_tests::<F, _>(rng);
 
     assert!(F::zero().is_zero());
     {
@@ -130,6 +130,14 @@ fn fp384(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("fp384_batch_mul_assign", |b| {
+        b.iter(|| {
+            let mut lhs = values[..N - 1].to_vec();
+            Fp384::batch_mul_assign(&mut lhs, &values[1..]);
+            black_box(lhs)
+        })
+    });
+
     c.bench_function("fp384_inverse", |b| {
         b.iter(|| {
             for v in values.iter() {
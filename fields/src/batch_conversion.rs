@@ -0,0 +1,60 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::PrimeField;
+use snarkvm_utilities::Vec;
+
+/// Converts the given integers into field elements, i.e. into Montgomery form,
+/// and returns `None` if any of the integers is not less than the modulus.
+///
+/// This is the batch form of `PrimeField::from_bigint`, and runs over parallel chunks
+/// if the `std` feature is enabled and the `serial` feature is not.
+pub fn batch_from_bigint<F: PrimeField>(integers: &[F::BigInteger]) -> Option<Vec<F>> {
+    #[cfg(all(feature = "std", not(feature = "serial")))]
+    {
+        use rayon::prelude::*;
+        integers
+            .par_chunks(chunk_size(integers.len()))
+            .flat_map_iter(|chunk| chunk.iter().map(|integer| F::from_bigint(*integer)))
+            .collect()
+    }
+    #[cfg(any(feature = "serial", not(feature = "std")))]
+    {
+        integers.iter().map(|integer| F::from_bigint(*integer)).collect()
+    }
+}
+
+/// Converts the given field elements out of Montgomery form, into integers in the range `0..F::MODULUS`.
+///
+/// This is the batch form of `PrimeField::to_bigint`, and runs over parallel chunks
+/// if the `std` feature is enabled and the `serial` feature is not.
+pub fn batch_to_bigint<F: PrimeField>(elements: &[F]) -> Vec<F::BigInteger> {
+    #[cfg(all(feature = "std", not(feature = "serial")))]
+    {
+        use rayon::prelude::*;
+        elements.par_chunks(chunk_size(elements.len())).flat_map_iter(|chunk| chunk.iter().map(F::to_bigint)).collect()
+    }
+    #[cfg(any(feature = "serial", not(feature = "std")))]
+    {
+        elements.iter().map(F::to_bigint).collect()
+    }
+}
+
+/// Returns the number of elements converted per chunk, to divide `num_elements` evenly between all available cores.
+#[cfg(all(feature = "std", not(feature = "serial")))]
+fn chunk_size(num_elements: usize) -> usize {
+    let num_cpus_available = snarkvm_utilities::parallel::max_available_threads();
+    1.max(num_elements / num_cpus_available)
+}
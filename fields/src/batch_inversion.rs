@@ -0,0 +1,102 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Field;
+use snarkvm_utilities::Vec;
+
+/// Inverts every nonzero element of `v` in place, and leaves every zero element as is.
+///
+/// This uses Montgomery's trick, which costs a single field inversion and three multiplications per element,
+/// and runs in parallel if the `std` feature is enabled and the `serial` feature is not.
+pub fn batch_inverse<F: Field>(v: &mut [F]) {
+    batch_inversion_and_mul(v, &F::one());
+}
+
+/// Returns the inverse of every nonzero element of `v`, and zero for every zero element, leaving `v` as is.
+///
+/// This is the out-of-place variant of [`batch_inverse`].
+pub fn batch_inverse_to_vec<F: Field>(v: &[F]) -> Vec<F> {
+    let mut inverses = v.to_vec();
+    batch_inverse(&mut inverses);
+    inverses
+}
+
+// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
+#[deprecated(note = "Use `batch_inverse` instead")]
+pub fn batch_inversion<F: Field>(v: &mut [F]) {
+    batch_inverse(v);
+}
+
+#[cfg(any(feature = "serial", not(feature = "std")))]
+// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
+pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    serial_batch_inversion_and_mul(v, coeff);
+}
+
+#[cfg(all(feature = "std", not(feature = "serial")))]
+// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
+pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    use rayon::prelude::*;
+    // Divide the vector v evenly between all available cores
+    let min_elements_per_thread = 1;
+    let num_cpus_available = snarkvm_utilities::parallel::max_available_threads();
+    let num_elems = v.len();
+    let num_elem_per_thread = min_elements_per_thread.max(num_elems / num_cpus_available);
+
+    // Batch invert in parallel, without copying the vector
+    v.par_chunks_mut(num_elem_per_thread).for_each(|chunk| {
+        serial_batch_inversion_and_mul(chunk, coeff);
+    });
+}
+
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// This method is explicitly single-threaded.
+pub(crate) fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    // Montgomery’s Trick and Fast Implementation of Masked AES
+    // Genelle, Prouff and Quisquater
+    // Section 3.2
+    // but with an optimization to multiply every element in the returned vector by
+    // coeff
+
+    // First pass: compute [a, ab, abc, ...]
+    let mut prod = Vec::with_capacity(v.len());
+    let mut tmp = F::one();
+    for f in v.iter().filter(|f| !f.is_zero()) {
+        tmp.mul_assign(f);
+        prod.push(tmp);
+    }
+
+    // Invert `tmp`.
+    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+    // Multiply product by coeff, so all inverses will be scaled by coeff
+    tmp *= coeff;
+
+    // Second pass: iterate backwards to compute inverses
+    for (f, s) in v
+        .iter_mut()
+        // Backwards
+        .rev()
+        // Ignore normalized elements
+        .filter(|f| !f.is_zero())
+        // Backwards, skip last element, fill in one for last term.
+        .zip(prod.into_iter().rev().skip(1).chain(Some(F::one())))
+    {
+        // tmp := tmp * f; f := tmp * s = 1/f
+        let new_tmp = tmp * *f;
+        *f = tmp * s;
+        tmp = new_tmp;
+    }
+}
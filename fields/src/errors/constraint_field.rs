@@ -13,20 +13,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Error)]
+use snarkvm_utilities::{String, format};
+
+use core::fmt;
+
+#[derive(Debug)]
 pub enum ConstraintFieldError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+    AnyhowError(anyhow::Error),
 
-    #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
-    #[error("{}", _0)]
     Message(&'static str),
 }
 
-impl From<std::io::Error> for ConstraintFieldError {
-    fn from(error: std::io::Error) -> Self {
+impl fmt::Display for ConstraintFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{error}"),
+            Self::Crate(name, message) => write!(f, "{name}: {message}"),
+            Self::Message(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl snarkvm_utilities::error::Error for ConstraintFieldError {}
+
+impl From<anyhow::Error> for ConstraintFieldError {
+    fn from(error: anyhow::Error) -> Self {
+        ConstraintFieldError::AnyhowError(error)
+    }
+}
+
+impl From<snarkvm_utilities::io::Error> for ConstraintFieldError {
+    fn from(error: snarkvm_utilities::io::Error) -> Self {
         ConstraintFieldError::Crate("std::io", format!("{error:?}"))
     }
 }
@@ -21,11 +21,12 @@ use rand::{
     distributions::{Distribution, Standard},
 };
 use serde::{Deserialize, Serialize};
-use std::{
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+use core::{
     cmp::Ordering,
     fmt::Debug,
     hash::Hash,
-    io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -38,6 +39,9 @@ pub trait Fp12Parameters: 'static + Copy + Clone + Debug + Default + PartialEq +
 
 type Fp2Params<P> = <<P as Fp12Parameters>::Fp6Params as Fp6Parameters>::Fp2Params;
 
+/// The minimum number of consecutive cyclotomic squarings for which compressed squaring is used.
+const COMPRESSED_SQUARING_THRESHOLD: usize = 16;
+
 /// An element of Fp12, represented by c0 + c1 * v
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Fp12<P: Fp12Parameters> {
@@ -169,10 +173,28 @@ impl<P: Fp12Parameters> Fp12<P> {
         result
     }
 
+    /// Returns `self^(2^n)` for an element of the cyclotomic subgroup.
+    /// Long runs of squarings are performed in compressed form, which is cheaper per squaring
+    /// but requires an inversion to decompress the result.
+    pub fn cyclotomic_square_n(&self, n: usize) -> Self {
+        match n >= COMPRESSED_SQUARING_THRESHOLD {
+            true => {
+                let mut compressed = self.cyclotomic_compress();
+                for _ in 0..n {
+                    compressed.square_in_place();
+                }
+                compressed.decompress()
+            }
+            false => (0..n).fold(*self, |res, _| res.cyclotomic_square()),
+        }
+    }
+
     pub fn cyclotomic_exp<S: AsRef<[u64]>>(&self, exp: S) -> Self {
         let mut res = Self::one();
 
         let mut found_one = false;
+        // The number of squarings that have not yet been applied to `res`.
+        let mut num_squarings = 0;
 
         for i in BitIteratorBE::new(exp) {
             if !found_one {
@@ -183,18 +205,26 @@ impl<P: Fp12Parameters> Fp12<P> {
                 }
             }
 
-            res = res.cyclotomic_square();
+            num_squarings += 1;
 
             if i {
+                res = res.cyclotomic_square_n(num_squarings);
                 res *= self;
+                num_squarings = 0;
             }
         }
-        res
+        res.cyclotomic_square_n(num_squarings)
+    }
+
+    /// Returns the compressed form of an element of the cyclotomic subgroup.
+    /// The result is meaningless if `self` is not in the cyclotomic subgroup.
+    pub fn cyclotomic_compress(&self) -> CompressedFp12<P> {
+        CompressedFp12 { c0_c1: self.c0.c1, c0_c2: self.c0.c2, c1_c0: self.c1.c0, c1_c2: self.c1.c2 }
     }
 }
 
-impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp12Parameters> core::fmt::Display for Fp12<P> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         write!(f, "Fp12({} + {} * w)", self.c0, self.c1)
     }
 }
@@ -233,6 +263,23 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
         Self::new(Fp6::from_base_prime_field(other), Fp6::zero())
     }
 
+    fn extension_degree() -> u64 {
+        2 * Fp6::<P::Fp6Params>::extension_degree()
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() as u64 != Self::extension_degree() {
+            return None;
+        }
+        let mut coefficients = elems.chunks(Fp6::<P::Fp6Params>::extension_degree() as usize);
+        let mut next = || coefficients.next().and_then(Fp6::from_base_prime_field_elems);
+        Some(Self::new(next()?, next()?))
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        [self.c0, self.c1].iter().flat_map(Fp6::to_base_prime_field_elements).collect()
+    }
+
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
         Fp6::<P::Fp6Params>::characteristic()
@@ -557,3 +604,151 @@ impl<P: Fp12Parameters> CanonicalDeserialize for Fp12<P> {
         Ok(Fp12::new(c0, c1))
     }
 }
+
+/// An element of the cyclotomic subgroup of `Fp12`, in the compressed form of Karabina
+/// (https://eprint.iacr.org/2010/542.pdf).
+///
+/// The coefficients `c0.c0` and `c1.c1` are omitted, as they are determined by the remaining four.
+/// This form takes two thirds of the space of an `Fp12` element, and supports squaring
+/// at a lower cost than `Fp12::cyclotomic_square`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompressedFp12<P: Fp12Parameters> {
+    c0_c1: Fp2<Fp2Params<P>>,
+    c0_c2: Fp2<Fp2Params<P>>,
+    c1_c0: Fp2<Fp2Params<P>>,
+    c1_c2: Fp2<Fp2Params<P>>,
+}
+
+impl<P: Fp12Parameters> CompressedFp12<P> {
+    /// Returns the square of `self`.
+    pub fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    /// Squares `self` in place.
+    pub fn square_in_place(&mut self) -> &mut Self {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let (g1, g2, g3, g5) = (self.c0_c1, self.c0_c2, self.c1_c0, self.c1_c2);
+        let (g1_squared, g2_squared, g3_squared, g5_squared) = (g1.square(), g2.square(), g3.square(), g5.square());
+
+        // t0 = 2 * g1 * g5
+        let t0 = (g1 + g5).square() - g1_squared - g5_squared;
+        // t1 = 2 * g2 * g3
+        let t1 = (g2 + g3).square() - g2_squared - g3_squared;
+
+        // g1 = 3 * (g3^2 + xi * g2^2) - 2 * g1
+        let tmp = g3_squared + fp2_nr(&g2_squared);
+        self.c0_c1 = (tmp - g1).double() + tmp;
+
+        // g2 = 3 * (g1^2 + xi * g5^2) - 2 * g2
+        let tmp = g1_squared + fp2_nr(&g5_squared);
+        self.c0_c2 = (tmp - g2).double() + tmp;
+
+        // g3 = 3 * xi * t0 + 2 * g3
+        let tmp = fp2_nr(&t0);
+        self.c1_c0 = (tmp + g3).double() + tmp;
+
+        // g5 = 3 * t1 + 2 * g5
+        self.c1_c2 = (t1 + g5).double() + t1;
+
+        self
+    }
+
+    /// Returns the element of `Fp12` that `self` is the compressed form of.
+    pub fn decompress(&self) -> Fp12<P> {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let (g1, g2, g3, g5) = (self.c0_c1, self.c0_c2, self.c1_c0, self.c1_c2);
+
+        // Recover g4, as either (xi * g5^2 + 3 * g1^2 - 2 * g2) / (4 * g3), or 2 * g1 * g5 / g2 if g3 is zero.
+        let (numerator, denominator) = match g3.is_zero() {
+            true => ((g1 * g5).double(), g2),
+            false => {
+                let g1_squared = g1.square();
+                (fp2_nr(&g5.square()) + (g1_squared - g2).double() + g1_squared, g3.double().double())
+            }
+        };
+        let g4 = match denominator.inverse() {
+            Some(denominator_inverse) => numerator * denominator_inverse,
+            // If both g2 and g3 are zero, then the element is the identity.
+            None => return Fp12::one(),
+        };
+
+        // Recover g0 = xi * (2 * g4^2 + g3 * g5 - 3 * g1 * g2) + 1.
+        let g1_g2 = g1 * g2;
+        let g0 = fp2_nr(&((g4.square() - g1_g2).double() - g1_g2 + g3 * g5)) + Fp2::one();
+
+        Fp12::new(Fp6::new(g0, g1, g2), Fp6::new(g3, g4, g5))
+    }
+}
+
+impl<P: Fp12Parameters> ToBytes for CompressedFp12<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0_c1.write_le(&mut writer)?;
+        self.c0_c2.write_le(&mut writer)?;
+        self.c1_c0.write_le(&mut writer)?;
+        self.c1_c2.write_le(writer)
+    }
+}
+
+impl<P: Fp12Parameters> FromBytes for CompressedFp12<P> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0_c1 = Fp2::read_le(&mut reader)?;
+        let c0_c2 = Fp2::read_le(&mut reader)?;
+        let c1_c0 = Fp2::read_le(&mut reader)?;
+        let c1_c2 = Fp2::read_le(reader)?;
+        Ok(Self { c0_c1, c0_c2, c1_c0, c1_c2 })
+    }
+}
+
+impl<P: Fp12Parameters> CanonicalSerialize for CompressedFp12<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.c0_c1.serialize_with_mode(&mut writer, compress)?;
+        self.c0_c2.serialize_with_mode(&mut writer, compress)?;
+        self.c1_c0.serialize_with_mode(&mut writer, compress)?;
+        self.c1_c2.serialize_with_mode(&mut writer, compress)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.c0_c1.serialized_size(compress)
+            + self.c0_c2.serialized_size(compress)
+            + self.c1_c0.serialized_size(compress)
+            + self.c1_c2.serialized_size(compress)
+    }
+}
+
+impl<P: Fp12Parameters> Valid for CompressedFp12<P> {
+    fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
+        Ok(())
+    }
+
+    fn batch_check<'a>(_batch: impl Iterator<Item = &'a Self>) -> Result<(), snarkvm_utilities::SerializationError>
+    where
+        Self: 'a,
+    {
+        Ok(())
+    }
+}
+
+impl<P: Fp12Parameters> CanonicalDeserialize for CompressedFp12<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0_c1 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c0_c2 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1_c0 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1_c2 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { c0_c1, c0_c2, c1_c0, c1_c2 })
+    }
+}
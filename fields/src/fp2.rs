@@ -20,6 +20,7 @@ use snarkvm_utilities::{
     ToBytes,
     rand::Uniform,
     serialize::{SerializationError, *},
+    vec,
 };
 
 use rand::{
@@ -27,11 +28,12 @@ use rand::{
     distributions::{Distribution, Standard},
 };
 use serde::{Deserialize, Serialize};
-use std::{
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::Debug,
     hash::Hash,
-    io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -108,6 +110,21 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
         Self::new(other, P::Fp::zero())
     }
 
+    fn extension_degree() -> u64 {
+        2
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [c0, c1] => Some(Self::new(*c0, *c1)),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![self.c0, self.c1]
+    }
+
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
         P::Fp::characteristic()
@@ -416,8 +433,8 @@ impl<'a, P: Fp2Parameters> DivAssign<&'a Self> for Fp2<P> {
     }
 }
 
-impl<P: Fp2Parameters> std::fmt::Display for Fp2<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp2Parameters> core::fmt::Display for Fp2<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fp2({} + {} * u)", self.c0, self.c1)
     }
 }
@@ -27,11 +27,12 @@ use rand::{
     distributions::{Distribution, Standard},
 };
 use serde::{Deserialize, Serialize};
-use std::{
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+use core::{
     cmp::Ordering,
     fmt::Debug,
     hash::Hash,
-    io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -175,6 +176,23 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
         Self::new(Fp2::from_base_prime_field(other), Fp2::zero(), Fp2::zero())
     }
 
+    fn extension_degree() -> u64 {
+        3 * Fp2::<P::Fp2Params>::extension_degree()
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() as u64 != Self::extension_degree() {
+            return None;
+        }
+        let mut coefficients = elems.chunks(Fp2::<P::Fp2Params>::extension_degree() as usize);
+        let mut next = || coefficients.next().and_then(Fp2::from_base_prime_field_elems);
+        Some(Self::new(next()?, next()?, next()?))
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        [self.c0, self.c1, self.c2].iter().flat_map(Fp2::to_base_prime_field_elements).collect()
+    }
+
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
         Fp2::<P::Fp2Params>::characteristic()
@@ -294,8 +312,8 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
-impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp6Parameters> core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fq6_3over2({} + {} * v, {} * v^2)", self.c0, self.c1, self.c2)
     }
 }
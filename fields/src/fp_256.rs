@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "std")]
+use crate::SqrtTables;
 use crate::{
     FftField,
     Field,
@@ -32,21 +34,32 @@ use snarkvm_utilities::{
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
     biginteger::{BigInteger as _BigInteger, BigInteger256 as BigInteger, arithmetic as fa},
     serialize::CanonicalDeserialize,
+    vec,
 };
 
-use std::{
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+#[cfg(feature = "constant-time")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
-pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
+pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {
+    /// Returns the precomputed tables used to accelerate square roots, if the field provides them.
+    #[cfg(feature = "std")]
+    fn sqrt_tables() -> Option<&'static SqrtTables<Fp256<Self>>> {
+        None
+    }
+}
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
 pub struct Fp256<P: Fp256Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
@@ -57,6 +70,7 @@ impl<P: Fp256Parameters> Fp256<P> {
         self.0 < P::MODULUS
     }
 
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn reduce(&mut self) {
         if !self.is_valid() {
@@ -64,6 +78,33 @@ impl<P: Fp256Parameters> Fp256<P> {
         }
     }
 
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn reduce(&mut self) {
+        self.reduce_with_carry(false);
+    }
+
+    /// Reduces `self`, where `carry` is the bit above the most significant limb.
+    /// This is only set for moduli without a spare bit, in which case the (wrapping)
+    /// subtraction of the modulus yields the reduced value.
+    #[cfg(not(feature = "constant-time"))]
+    #[inline]
+    fn reduce_with_carry(&mut self, carry: bool) {
+        if carry || !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    /// Reduces `self`, where `carry` is the bit above the most significant limb, without branching on `self`.
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn reduce_with_carry(&mut self, carry: bool) {
+        // Subtract the modulus, and keep the difference unless it underflowed.
+        let mut reduced = *self;
+        let borrow = reduced.0.sub_noborrow(&P::MODULUS);
+        *self = Self::conditional_select(self, &reduced, Choice::from((carry | !borrow) as u8));
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -111,12 +152,12 @@ impl<P: Fp256Parameters> Fp256<P> {
         r4 = fa::mac_with_carry(r4, k, P::MODULUS.0[1], &mut carry);
         r5 = fa::mac_with_carry(r5, k, P::MODULUS.0[2], &mut carry);
         r6 = fa::mac_with_carry(r6, k, P::MODULUS.0[3], &mut carry);
-        fa::adc(&mut r7, carry2, carry);
+        let carry = fa::adc(&mut r7, carry2, carry);
         (self.0).0[0] = r4;
         (self.0).0[1] = r5;
         (self.0).0[2] = r6;
         (self.0).0[3] = r7;
-        self.reduce();
+        self.reduce_with_carry(carry != 0);
     }
 }
 
@@ -154,6 +195,21 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         other
     }
 
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+
     /// Returns the constant 2^{-1}.
     fn half() -> Self {
         // Compute 1/2 `(p+1)/2` as `1/2`.
@@ -182,6 +238,12 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         //   single bitshift by the limb size after each iteration. This means we only
         //   need to store a single extra limb overall, instead of keeping around all the
         //   intermediate results and eventually having twice as many limbs.
+        //
+        // The accumulated carries are absorbed by the spare bits of the top limb, so
+        // moduli with fewer than two spare bits fall back to summing the products.
+        if P::REPR_SHAVE_BITS < 2 {
+            return a.zip(b).map(|(a, b)| *a * b).sum();
+        }
 
         // Algorithm 2, line 2
         let (u0, u1, u2, u3) = (0..4).fold((0, 0, 0, 0), |(u0, u1, u2, u3), j| {
@@ -230,10 +292,11 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn double_in_place(&mut self) {
-        // This cannot exceed the backing capacity.
+        // This can only exceed the backing capacity if the modulus has no spare bit.
+        let carry = (self.0).0[3] >> 63 == 1;
         self.0.mul2();
         // However, it may need to be reduced.
-        self.reduce();
+        self.reduce_with_carry(carry);
     }
 
     #[inline]
@@ -290,6 +353,11 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
     fn inverse(&self) -> Option<Self> {
         if self.is_zero() {
             None
+        } else if cfg!(feature = "constant-time") {
+            // Apply Fermat's little theorem, as the exponent `MODULUS - 2` does not depend on `self`.
+            let mut exponent = P::MODULUS;
+            exponent.sub_noborrow(&BigInteger::from(2));
+            Some(self.pow(exponent))
         } else {
             // Guajardo Kumar Paar Pelzl
             // Efficient Software-Implementation of Finite Fields with Applications to
@@ -310,8 +378,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if b.0.is_even() {
                         b.0.div2();
                     } else {
-                        b.0.add_nocarry(&P::MODULUS);
+                        let carry = b.0.add_nocarry(&P::MODULUS);
                         b.0.div2();
+                        // Restore the carry for moduli without a spare bit.
+                        (b.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -321,8 +391,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if c.0.is_even() {
                         c.0.div2();
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
+                        let carry = c.0.add_nocarry(&P::MODULUS);
                         c.0.div2();
+                        // Restore the carry for moduli without a spare bit.
+                        (c.0).0[3] |= (carry as u64) << 63;
                     }
                 }
 
@@ -533,6 +605,10 @@ impl<P: Fp256Parameters> SquareRootField for Fp256<P> {
 
     #[inline]
     fn sqrt(&self) -> Option<Self> {
+        #[cfg(feature = "std")]
+        if let Some(tables) = P::sqrt_tables() {
+            return tables.sqrt(self);
+        }
         sqrt_impl!(Self, P, self)
     }
 
@@ -670,6 +746,7 @@ impl<P: Fp256Parameters> Display for Fp256<P> {
 impl<P: Fp256Parameters> Neg for Fp256<P> {
     type Output = Self;
 
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     #[must_use]
     fn neg(self) -> Self {
@@ -681,6 +758,16 @@ impl<P: Fp256Parameters> Neg for Fp256<P> {
             self
         }
     }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        // Subtract `self` from the modulus, and keep zero as is.
+        let mut negated = Self(P::MODULUS, PhantomData);
+        negated.0.sub_noborrow(&self.0);
+        Self::conditional_select(&negated, &self, self.ct_eq(&Self::zero()))
+    }
 }
 
 impl<'a, P: Fp256Parameters> Add<&'a Fp256<P>> for Fp256<P> {
@@ -730,14 +817,15 @@ impl<'a, P: Fp256Parameters> Div<&'a Fp256<P>> for Fp256<P> {
 impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
-        self.0.add_nocarry(&other.0);
+        // This can only exceed the backing capacity if the modulus has no spare bit.
+        let carry = self.0.add_nocarry(&other.0);
         // However, it may need to be reduced.
-        self.reduce();
+        self.reduce_with_carry(carry);
     }
 }
 
 impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
         // If `other` is larger than `self`, add the modulus to self first.
@@ -747,11 +835,37 @@ impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
 
         self.0.sub_noborrow(&other.0);
     }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // Subtract `other`, and add the modulus back if the subtraction underflowed.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = *self;
+        corrected.0.add_nocarry(&P::MODULUS);
+        *self = Self::conditional_select(self, &corrected, Choice::from(borrow as u8));
+    }
 }
 
 impl<'a, P: Fp256Parameters> MulAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
+        // The interleaved multiplication below relies on a spare bit in the modulus,
+        // so moduli without one use a schoolbook multiplication and Montgomery reduction.
+        if P::REPR_SHAVE_BITS == 0 {
+            let (a, b) = ((self.0).0, (other.0).0);
+            let mut r = [0u64; 8];
+            for i in 0..4 {
+                let mut carry = 0;
+                for j in 0..4 {
+                    r[i + j] = fa::mac_with_carry(r[i + j], a[i], b[j], &mut carry);
+                }
+                r[i + 4] = carry;
+            }
+            self.mont_reduce(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7]);
+            return;
+        }
+
         let mut r = [0u64; 4];
         let mut carry1 = 0u64;
         let mut carry2 = 0u64;
@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "std")]
+use crate::SqrtTables;
 use crate::{
     FftField,
     Field,
@@ -27,26 +29,38 @@ use crate::{
     Zero,
     impl_add_sub_from_field_ref,
     impl_mul_div_from_field_ref,
+    simd::{Backend, MAX_LANES},
 };
 use snarkvm_utilities::{
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
     biginteger::{BigInteger as _BigInteger, BigInteger384 as BigInteger, arithmetic as fa},
     serialize::CanonicalDeserialize,
+    vec,
 };
 
-use std::{
+use snarkvm_utilities::io::{Read, Result as IoResult, Write};
+
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+#[cfg(feature = "constant-time")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
-pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
+pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {
+    /// Returns the precomputed tables used to accelerate square roots, if the field provides them.
+    #[cfg(feature = "std")]
+    fn sqrt_tables() -> Option<&'static SqrtTables<Fp384<Self>>> {
+        None
+    }
+}
 
 #[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroize)]
 pub struct Fp384<P: Fp384Parameters>(pub BigInteger, #[doc(hidden)] pub PhantomData<P>);
@@ -57,6 +71,38 @@ impl<P: Fp384Parameters> Fp384<P> {
         self.0 < P::MODULUS
     }
 
+    /// Multiplies each element of `lhs` by the element of `rhs` at the same index.
+    ///
+    /// The products are computed several at a time with the vector instructions of the host CPU
+    /// (AVX2, AVX-512 with the `avx512` feature, or NEON), if they are available.
+    pub fn batch_mul_assign(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "Mismatched lengths in batch multiplication");
+
+        let backend = Backend::detect();
+        let lanes = backend.lanes();
+        let num_vectorized = if backend == Backend::Scalar { 0 } else { lhs.len() - lhs.len() % lanes };
+
+        let (lhs_vectorized, lhs_remaining) = lhs.split_at_mut(num_vectorized);
+        let (rhs_vectorized, rhs_remaining) = rhs.split_at(num_vectorized);
+        for (lhs, rhs) in lhs_vectorized.chunks_exact_mut(lanes).zip(rhs_vectorized.chunks_exact(lanes)) {
+            let mut a = [[0u64; 6]; MAX_LANES];
+            let mut b = [[0u64; 6]; MAX_LANES];
+            for (lane, (x, y)) in lhs.iter().zip(rhs).enumerate() {
+                a[lane] = (x.0).0;
+                b[lane] = (y.0).0;
+            }
+            let products = backend.montgomery_mul(&a, &b, &P::MODULUS.0, P::INV);
+            for (x, product) in lhs.iter_mut().zip(products) {
+                x.0 = BigInteger(product);
+                x.reduce();
+            }
+        }
+        for (x, y) in lhs_remaining.iter_mut().zip(rhs_remaining) {
+            *x *= y;
+        }
+    }
+
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn reduce(&mut self) {
         if !self.is_valid() {
@@ -64,6 +110,16 @@ impl<P: Fp384Parameters> Fp384<P> {
         }
     }
 
+    /// Reduces `self` without branching on `self`.
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn reduce(&mut self) {
+        // Subtract the modulus, and keep the difference unless it underflowed.
+        let mut reduced = *self;
+        let borrow = reduced.0.sub_noborrow(&P::MODULUS);
+        *self = Self::conditional_select(self, &reduced, Choice::from(!borrow as u8));
+    }
+
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -188,6 +244,21 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
         other
     }
 
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+
     fn half() -> Self {
         // Compute 1/2 `(p+1)/2` as `1/2`.
         // This is cheaper than `Self::one().double().inverse()`
@@ -348,6 +419,11 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
     fn inverse(&self) -> Option<Self> {
         if self.is_zero() {
             None
+        } else if cfg!(feature = "constant-time") {
+            // Apply Fermat's little theorem, as the exponent `MODULUS - 2` does not depend on `self`.
+            let mut exponent = P::MODULUS;
+            exponent.sub_noborrow(&BigInteger::from(2));
+            Some(self.pow(exponent))
         } else {
             // Guajardo Kumar Paar Pelzl
             // Efficient Software-Implementation of Finite Fields with Applications to
@@ -550,6 +626,10 @@ impl<P: Fp384Parameters> SquareRootField for Fp384<P> {
 
     #[inline]
     fn sqrt(&self) -> Option<Self> {
+        #[cfg(feature = "std")]
+        if let Some(tables) = P::sqrt_tables() {
+            return tables.sqrt(self);
+        }
         sqrt_impl!(Self, P, self)
     }
 
@@ -687,6 +767,7 @@ impl<P: Fp384Parameters> Display for Fp384<P> {
 impl<P: Fp384Parameters> Neg for Fp384<P> {
     type Output = Self;
 
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     #[must_use]
     fn neg(self) -> Self {
@@ -698,6 +779,16 @@ impl<P: Fp384Parameters> Neg for Fp384<P> {
             self
         }
     }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        // Subtract `self` from the modulus, and keep zero as is.
+        let mut negated = Self(P::MODULUS, PhantomData);
+        negated.0.sub_noborrow(&self.0);
+        Self::conditional_select(&negated, &self, self.ct_eq(&Self::zero()))
+    }
 }
 
 impl<'a, P: Fp384Parameters> Add<&'a Fp384<P>> for Fp384<P> {
@@ -755,6 +846,7 @@ impl<'a, P: Fp384Parameters> AddAssign<&'a Self> for Fp384<P> {
 }
 
 impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
+    #[cfg(not(feature = "constant-time"))]
     #[inline]
     fn sub_assign(&mut self, other: &Self) {
         // If `other` is larger than `self`, add the modulus to self first.
@@ -764,6 +856,16 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 
         self.0.sub_noborrow(&other.0);
     }
+
+    #[cfg(feature = "constant-time")]
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // Subtract `other`, and add the modulus back if the subtraction underflowed.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = *self;
+        corrected.0.add_nocarry(&P::MODULUS);
+        *self = Self::conditional_select(self, &corrected, Choice::from(borrow as u8));
+    }
 }
 
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
@@ -13,15 +13,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
-
-#[macro_use]
-extern crate thiserror;
+#![deny(unsafe_code)]
+#![cfg_attr(feature = "avx512", feature(avx512_target_feature, stdarch_x86_avx512))]
 
 #[macro_use]
 mod macros;
 
+mod batch_conversion;
+pub use batch_conversion::*;
+
+mod batch_inversion;
+pub use batch_inversion::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -42,6 +47,14 @@ pub use fp12_2over3over2::*;
 mod legendre;
 pub use legendre::*;
 
+#[allow(unsafe_code)]
+mod simd;
+
+#[cfg(feature = "std")]
+mod sqrt_tables;
+#[cfg(feature = "std")]
+pub use sqrt_tables::*;
+
 mod to_field_vec;
 #[allow(unused_imports)]
 pub use to_field_vec::*;
@@ -49,6 +62,8 @@ pub use to_field_vec::*;
 pub mod traits;
 pub use traits::*;
 
+#[cfg(feature = "constant-time")]
+use fp6_3over2::{Fp6, Fp6Parameters};
 use snarkvm_utilities::{
     FromBytes,
     ToBytes,
@@ -62,68 +77,13 @@ impl_field_to_biginteger!(Fp384, BigInteger384, Fp384Parameters);
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
 
-// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
-pub fn batch_inversion<F: Field>(v: &mut [F]) {
-    batch_inversion_and_mul(v, &F::one());
-}
-
-#[cfg(feature = "serial")]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
-pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
-    serial_batch_inversion_and_mul(v, coeff);
-}
-
-#[cfg(not(feature = "serial"))]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
-pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
-    use rayon::prelude::*;
-    // Divide the vector v evenly between all available cores
-    let min_elements_per_thread = 1;
-    let num_cpus_available = snarkvm_utilities::parallel::max_available_threads();
-    let num_elems = v.len();
-    let num_elem_per_thread = min_elements_per_thread.max(num_elems / num_cpus_available);
-
-    // Batch invert in parallel, without copying the vector
-    v.par_chunks_mut(num_elem_per_thread).for_each(|chunk| {
-        serial_batch_inversion_and_mul(chunk, coeff);
-    });
-}
-
-/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
-/// This method is explicitly single-threaded.
-fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
-    // Montgomery’s Trick and Fast Implementation of Masked AES
-    // Genelle, Prouff and Quisquater
-    // Section 3.2
-    // but with an optimization to multiply every element in the returned vector by
-    // coeff
-
-    // First pass: compute [a, ab, abc, ...]
-    let mut prod = Vec::with_capacity(v.len());
-    let mut tmp = F::one();
-    for f in v.iter().filter(|f| !f.is_zero()) {
-        tmp.mul_assign(f);
-        prod.push(tmp);
-    }
-
-    // Invert `tmp`.
-    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-    // Multiply product by coeff, so all inverses will be scaled by coeff
-    tmp *= coeff;
-
-    // Second pass: iterate backwards to compute inverses
-    for (f, s) in v.iter_mut()
-        // Backwards
-        .rev()
-        // Ignore normalized elements
-        .filter(|f| !f.is_zero())
-        // Backwards, skip last element, fill in one for last term.
-        .zip(prod.into_iter().rev().skip(1).chain(Some(F::one())))
-    {
-        // tmp := tmp * f; f := tmp * s = 1/f
-        let new_tmp = tmp * *f;
-        *f = tmp * s;
-        tmp = new_tmp;
-    }
-}
+#[cfg(feature = "constant-time")]
+impl_constant_time!(Fp256, Fp256Parameters);
+#[cfg(feature = "constant-time")]
+impl_constant_time!(Fp384, Fp384Parameters);
+#[cfg(feature = "constant-time")]
+impl_constant_time_extension!(Fp2, Fp2Parameters, c0, c1);
+#[cfg(feature = "constant-time")]
+impl_constant_time_extension!(Fp6, Fp6Parameters, c0, c1, c2);
+#[cfg(feature = "constant-time")]
+impl_constant_time_extension!(Fp12, Fp12Parameters, c0, c1);
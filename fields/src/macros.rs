@@ -16,7 +16,7 @@
 #[macro_export]
 macro_rules! field {
     ($name:ident, $c0:expr) => {
-        $name { 0: $c0, 1: std::marker::PhantomData }
+        $name { 0: $c0, 1: core::marker::PhantomData }
     };
     ($name:ident, $c0:expr, $c1:expr $(,)?) => {
         $name { c0: $c0, c1: $c1 }
@@ -37,6 +37,48 @@ macro_rules! impl_field_to_biginteger {
     };
 }
 
+#[cfg(feature = "constant-time")]
+macro_rules! impl_constant_time {
+    ($field: ident, $parameters: ident) => {
+        impl<P: $parameters> subtle::ConstantTimeEq for $field<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                subtle::ConstantTimeEq::ct_eq(&(self.0).0[..], &(other.0).0[..])
+            }
+        }
+
+        impl<P: $parameters> subtle::ConditionallySelectable for $field<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                let mut output = *a;
+                for (limb, (a, b)) in (output.0).0.iter_mut().zip((a.0).0.iter().zip((b.0).0.iter())) {
+                    *limb = <u64 as subtle::ConditionallySelectable>::conditional_select(a, b, choice);
+                }
+                output
+            }
+        }
+    };
+}
+
+#[cfg(feature = "constant-time")]
+macro_rules! impl_constant_time_extension {
+    ($field: ident, $parameters: ident, $($component: ident),+) => {
+        impl<P: $parameters> subtle::ConstantTimeEq for $field<P> {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> subtle::Choice {
+                $(subtle::ConstantTimeEq::ct_eq(&self.$component, &other.$component))&+
+            }
+        }
+
+        impl<P: $parameters> subtle::ConditionallySelectable for $field<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+                Self::new($(subtle::ConditionallySelectable::conditional_select(&a.$component, &b.$component, choice)),+)
+            }
+        }
+    };
+}
+
 macro_rules! impl_primefield_standard_sample {
     ($field: ident, $params: ident) => {
         impl<P: $params> rand::distributions::Distribution<$field<P>> for rand::distributions::Standard {
@@ -45,7 +87,7 @@ macro_rules! impl_primefield_standard_sample {
                 loop {
                     let mut tmp = $field(rng.sample(rand::distributions::Standard), PhantomData);
                     // Mask away the unused bits at the beginning.
-                    tmp.0.as_mut().last_mut().map(|val| *val &= std::u64::MAX >> P::REPR_SHAVE_BITS);
+                    tmp.0.as_mut().last_mut().map(|val| *val &= core::u64::MAX >> P::REPR_SHAVE_BITS);
 
                     if tmp.is_valid() {
                         return tmp;
@@ -89,6 +131,8 @@ macro_rules! sqrt_impl {
         match $self.legendre() {
             Zero => Some(*$self),
             QuadraticNonResidue => None,
+            // If the modulus is 3 (mod 4), then the square root is `self^((p + 1) / 4) = self^((t - 1) / 2) * self`.
+            QuadraticResidue if $P::TWO_ADICITY == 1 => Some(*$self * $self.pow($P::T_MINUS_ONE_DIV_TWO)),
             QuadraticResidue => {
                 let n = $P::TWO_ADICITY as u64;
                 // `T` is equivalent to `m` in the paper.
@@ -100,14 +144,15 @@ macro_rules! sqrt_impl {
                 // divisible by `k`, because the native arithmetic will not match the field
                 // arithmetic otherwise (native numbers will divide and round down, but field
                 // elements will end up nowhere near the native number).
-                let k_2 = if n % 2 == 0 { k / 2 } else { (n - 1) % k };
+                let k_2 = (n - 1) % k;
                 let k_1 = k - k_2;
                 let l_minus_one_times_k = n - 1 - k_2;
                 let l_minus_one = l_minus_one_times_k / k;
                 let l = l_minus_one + 1;
 
-                let l_s =
-                    || std::iter::repeat(l_minus_one).take(k_1 as usize).chain(std::iter::repeat(l).take(k_2 as usize));
+                let l_s = || {
+                    core::iter::repeat(l_minus_one).take(k_1 as usize).chain(core::iter::repeat(l).take(k_2 as usize))
+                };
 
                 let mut l_sum = 0;
                 let x_s = l_s().take((k as usize) - 1).map(|l| {
@@ -264,7 +309,8 @@ macro_rules! impl_primefield_serializer {
                 // Calculate the number of bytes required to represent a field element
                 // serialized with `flags`. If `F::BIT_SIZE < 8`,
                 // this is at most `$byte_size + 1`
-                let output_byte_size = Self::SERIALIZED_SIZE;
+                let output_byte_size =
+                    snarkvm_utilities::serialize::number_of_bits_and_bytes(P::MODULUS_BITS as usize + F::BIT_SIZE).1;
 
                 let mut masked_bytes = [0; $byte_size + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
@@ -305,7 +351,7 @@ macro_rules! impl_primefield_serializer {
 
         impl<P: $params> serde::Serialize for $field<P> {
             fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                let mut bytes = Vec::with_capacity(Self::SERIALIZED_SIZE);
+                let mut bytes = snarkvm_utilities::Vec::with_capacity(Self::SERIALIZED_SIZE);
                 self.serialize_uncompressed(&mut bytes).map_err(serde::ser::Error::custom)?;
 
                 if serializer.is_human_readable() {
@@ -320,16 +366,16 @@ macro_rules! impl_primefield_serializer {
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 match deserializer.is_human_readable() {
                     true => {
-                        let s: String = serde::Deserialize::deserialize(deserializer)?;
+                        let s: snarkvm_utilities::String = serde::Deserialize::deserialize(deserializer)?;
                         core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom)
                     }
                     false => {
-                        struct SerVisitor<P>(std::marker::PhantomData<P>);
+                        struct SerVisitor<P>(core::marker::PhantomData<P>);
 
                         impl<'de, P: $params> serde::de::Visitor<'de> for SerVisitor<P> {
                             type Value = $field<P>;
 
-                            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
                                 formatter.write_str("a valid field element")
                             }
 
@@ -343,13 +389,13 @@ macro_rules! impl_primefield_serializer {
                                         seq.next_element()?
                                             .ok_or_else(|| serde::de::Error::custom("could not read bytes"))
                                     })
-                                    .collect::<Result<Vec<_>, _>>()?;
+                                    .collect::<Result<snarkvm_utilities::Vec<_>, _>>()?;
 
                                 CanonicalDeserialize::deserialize_compressed(&*bytes).map_err(serde::de::Error::custom)
                             }
                         }
 
-                        let visitor = SerVisitor(std::marker::PhantomData);
+                        let visitor = SerVisitor(core::marker::PhantomData);
                         deserializer.deserialize_tuple(Self::SERIALIZED_SIZE, visitor)
                     }
                 }
@@ -376,7 +422,9 @@ macro_rules! impl_field_from_random_bytes_with_flags {
                     last_bytes_mask[..8].copy_from_slice(&last_limb_mask);
 
                     // Length of the buffer containing the field element and the flag.
-                    let output_byte_size = Self::SERIALIZED_SIZE;
+                    let output_byte_size =
+                        snarkvm_utilities::serialize::number_of_bits_and_bytes(P::MODULUS_BITS as usize + F::BIT_SIZE)
+                            .1;
                     // Location of the flag is the last byte of the serialized
                     // form of the field element.
                     let flag_location = output_byte_size - 1;
@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{LIMB_MASK, LIMBS, Lanes, MAX_LANES};
+
+use core::arch::x86_64::*;
+
+/// Four 64-bit lanes in an AVX2 register.
+#[derive(Copy, Clone)]
+struct Avx2(__m256i);
+
+impl Lanes for Avx2 {
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(values: &[u64; MAX_LANES]) -> Self {
+        Self(_mm256_loadu_si256(values.as_ptr() as *const __m256i))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(self, values: &mut [u64; MAX_LANES]) {
+        _mm256_storeu_si256(values.as_mut_ptr() as *mut __m256i, self.0)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn splat(value: u64) -> Self {
+        Self(_mm256_set1_epi64x(value as i64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul(self, other: Self) -> Self {
+        Self(_mm256_mul_epu32(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add(self, other: Self) -> Self {
+        Self(_mm256_add_epi64(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn lo(self) -> Self {
+        Self(_mm256_and_si256(self.0, _mm256_set1_epi64x(LIMB_MASK as i64)))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn hi(self) -> Self {
+        Self(_mm256_srli_epi64(self.0, 32))
+    }
+}
+
+/// Computes the Montgomery products of four pairs of operands.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn montgomery_mul(
+    a: &[[u64; MAX_LANES]; LIMBS],
+    b: &[[u64; MAX_LANES]; LIMBS],
+    modulus: &[u64; LIMBS],
+    inv: u64,
+) -> [[u64; MAX_LANES]; LIMBS] {
+    super::montgomery_mul::<Avx2>(a, b, modulus, inv)
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{LIMB_MASK, LIMBS, Lanes, MAX_LANES};
+
+use core::arch::x86_64::*;
+
+/// Eight 64-bit lanes in an AVX-512 register.
+#[derive(Copy, Clone)]
+struct Avx512(__m512i);
+
+impl Lanes for Avx512 {
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load(values: &[u64; MAX_LANES]) -> Self {
+        Self(_mm512_loadu_epi64(values.as_ptr() as *const i64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn store(self, values: &mut [u64; MAX_LANES]) {
+        _mm512_storeu_epi64(values.as_mut_ptr() as *mut i64, self.0)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn splat(value: u64) -> Self {
+        Self(_mm512_set1_epi64(value as i64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn mul(self, other: Self) -> Self {
+        Self(_mm512_mul_epu32(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn add(self, other: Self) -> Self {
+        Self(_mm512_add_epi64(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn lo(self) -> Self {
+        Self(_mm512_and_si512(self.0, _mm512_set1_epi64(LIMB_MASK as i64)))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn hi(self) -> Self {
+        Self(_mm512_srli_epi64(self.0, 32))
+    }
+}
+
+/// Computes the Montgomery products of eight pairs of operands.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512.
+#[target_feature(enable = "avx512f")]
+pub(super) unsafe fn montgomery_mul(
+    a: &[[u64; MAX_LANES]; LIMBS],
+    b: &[[u64; MAX_LANES]; LIMBS],
+    modulus: &[u64; LIMBS],
+    inv: u64,
+) -> [[u64; MAX_LANES]; LIMBS] {
+    super::montgomery_mul::<Avx512>(a, b, modulus, inv)
+}
@@ -0,0 +1,251 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vectorized Montgomery multiplication for 384-bit prime fields.
+//!
+//! Each backend multiplies several independent pairs of field elements at once, one pair per 64-bit vector lane.
+//! The operands are split into 32-bit limbs, so that every limb product fits in a lane, and the products are
+//! computed with the CIOS method. As `R = 2^384` for both 64-bit and 32-bit limbs, the results are in the same
+//! Montgomery form as the scalar multiplication.
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+mod avx512;
+#[cfg(target_arch = "aarch64")]
+mod neon;
+
+/// The number of 64-bit words in a 384-bit integer.
+const WORDS: usize = 6;
+
+/// The number of 32-bit limbs in a 384-bit integer.
+const LIMBS: usize = 2 * WORDS;
+
+/// The largest number of lanes of any backend.
+pub(crate) const MAX_LANES: usize = 8;
+
+/// The mask of the low 32 bits of a lane.
+const LIMB_MASK: u64 = 0xffff_ffff;
+
+/// Returns `true` if the host CPU supports the given x86 target feature.
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+macro_rules! is_supported {
+    ($feature:tt) => {
+        std::is_x86_feature_detected!($feature)
+    };
+}
+
+/// Returns `true` if the crate was compiled for the given x86 target feature,
+/// as runtime detection requires `std`.
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+macro_rules! is_supported {
+    ($feature:tt) => {
+        cfg!(target_feature = $feature)
+    };
+}
+
+/// The vectorized multiplication backends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Backend {
+    /// No vector instructions are available.
+    Scalar,
+    /// AVX2, with four lanes.
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    /// AVX-512, with eight lanes.
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    Avx512,
+    /// NEON, with two lanes.
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl Backend {
+    /// Returns the widest backend supported by the host CPU.
+    #[inline]
+    pub(crate) fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            #[cfg(feature = "avx512")]
+            if is_supported!("avx512f") {
+                return Self::Avx512;
+            }
+            if is_supported!("avx2") {
+                return Self::Avx2;
+            }
+            Self::Scalar
+        }
+        // NEON is mandatory on AArch64.
+        #[cfg(target_arch = "aarch64")]
+        {
+            Self::Neon
+        }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            Self::Scalar
+        }
+    }
+
+    /// Returns the number of products computed at once.
+    #[inline]
+    pub(crate) const fn lanes(self) -> usize {
+        match self {
+            Self::Scalar => 1,
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => 4,
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Self::Avx512 => 8,
+            #[cfg(target_arch = "aarch64")]
+            Self::Neon => 2,
+        }
+    }
+
+    /// Returns `a[l] * b[l] * R^-1` for the first `self.lanes()` lanes `l`, where `R = 2^384`.
+    ///
+    /// The operands must be less than `modulus`, which must be less than `2^383`, and `inv` must be
+    /// `-modulus^-1 mod 2^64`. The products are less than `2 * modulus`, and may need a final subtraction.
+    #[inline]
+    pub(crate) fn montgomery_mul(
+        self,
+        a: &[[u64; WORDS]; MAX_LANES],
+        b: &[[u64; WORDS]; MAX_LANES],
+        modulus: &[u64; WORDS],
+        inv: u64,
+    ) -> [[u64; WORDS]; MAX_LANES] {
+        let (a, b) = (split(a), split(b));
+        let mut modulus_limbs = [0u64; LIMBS];
+        for (i, word) in modulus.iter().enumerate() {
+            modulus_limbs[2 * i] = word & LIMB_MASK;
+            modulus_limbs[2 * i + 1] = word >> 32;
+        }
+        // Only the low 32 bits of `inv` are used, which are `-modulus^-1 mod 2^32`.
+        let product = match self {
+            Self::Scalar => unreachable!("The scalar backend does not multiply vectors"),
+            // Safety: `detect` only returns this backend if the CPU supports AVX2.
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => unsafe { avx2::montgomery_mul(&a, &b, &modulus_limbs, inv) },
+            // Safety: `detect` only returns this backend if the CPU supports AVX-512.
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Self::Avx512 => unsafe { avx512::montgomery_mul(&a, &b, &modulus_limbs, inv) },
+            // Safety: NEON is mandatory on AArch64.
+            #[cfg(target_arch = "aarch64")]
+            Self::Neon => unsafe { neon::montgomery_mul(&a, &b, &modulus_limbs, inv) },
+        };
+        join(&product)
+    }
+}
+
+/// The vector operations used by the Montgomery multiplication, on 64-bit lanes.
+trait Lanes: Copy {
+    /// Loads the first lanes from `values`.
+    unsafe fn load(values: &[u64; MAX_LANES]) -> Self;
+
+    /// Stores the lanes into the first entries of `values`.
+    unsafe fn store(self, values: &mut [u64; MAX_LANES]);
+
+    /// Returns a vector with every lane set to `value`.
+    unsafe fn splat(value: u64) -> Self;
+
+    /// Multiplies the low 32 bits of each lane into a 64-bit product.
+    unsafe fn mul(self, other: Self) -> Self;
+
+    /// Adds each lane, wrapping on overflow.
+    unsafe fn add(self, other: Self) -> Self;
+
+    /// Returns the low 32 bits of each lane.
+    unsafe fn lo(self) -> Self;
+
+    /// Returns the high 32 bits of each lane.
+    unsafe fn hi(self) -> Self;
+}
+
+/// Returns the Montgomery products of the lanes of `a` and `b`, where `a[i]` holds the `i`-th
+/// 32-bit limb of every lane.
+///
+/// Every intermediate sum is `t + x * y + carry` for 32-bit `t`, `x`, `y`, and `carry`, which fits in 64 bits.
+#[inline(always)]
+#[allow(clippy::needless_range_loop)]
+unsafe fn montgomery_mul<L: Lanes>(
+    a: &[[u64; MAX_LANES]; LIMBS],
+    b: &[[u64; MAX_LANES]; LIMBS],
+    modulus: &[u64; LIMBS],
+    inv: u64,
+) -> [[u64; MAX_LANES]; LIMBS] {
+    let zero = L::splat(0);
+    let inv = L::splat(inv & LIMB_MASK);
+
+    let a_limbs = a.map(|limb| L::load(&limb));
+    let modulus_limbs = modulus.map(|limb| L::splat(limb));
+
+    let mut t = [zero; LIMBS + 2];
+    for b_limb in b {
+        let b_limb = L::load(b_limb);
+
+        // Add `a * b_i` to `t`.
+        let mut carry = zero;
+        for (t_j, a_j) in t.iter_mut().zip(&a_limbs) {
+            let sum = t_j.add(a_j.mul(b_limb)).add(carry);
+            *t_j = sum.lo();
+            carry = sum.hi();
+        }
+        let sum = t[LIMBS].add(carry);
+        t[LIMBS] = sum.lo();
+        t[LIMBS + 1] = sum.hi();
+
+        // Add `m * modulus` to `t`, which clears its lowest limb, and shift `t` down by one limb.
+        let m = t[0].mul(inv).lo();
+        let mut carry = t[0].add(m.mul(modulus_limbs[0])).hi();
+        for j in 1..LIMBS {
+            let sum = t[j].add(m.mul(modulus_limbs[j])).add(carry);
+            t[j - 1] = sum.lo();
+            carry = sum.hi();
+        }
+        let sum = t[LIMBS].add(carry);
+        t[LIMBS - 1] = sum.lo();
+        t[LIMBS] = t[LIMBS + 1].add(sum.hi());
+    }
+
+    // As the modulus is less than `2^383`, the product is less than `2^384`, and `t[LIMBS]` is zero.
+    let mut product = [[0u64; MAX_LANES]; LIMBS];
+    for (t_i, product_i) in t.iter().zip(&mut product) {
+        t_i.store(product_i);
+    }
+    product
+}
+
+/// Splits each 384-bit lane into 32-bit limbs, stored limb-major.
+#[inline(always)]
+fn split(values: &[[u64; WORDS]; MAX_LANES]) -> [[u64; MAX_LANES]; LIMBS] {
+    let mut limbs = [[0u64; MAX_LANES]; LIMBS];
+    for (lane, value) in values.iter().enumerate() {
+        for (i, word) in value.iter().enumerate() {
+            limbs[2 * i][lane] = word & LIMB_MASK;
+            limbs[2 * i + 1][lane] = word >> 32;
+        }
+    }
+    limbs
+}
+
+/// Joins limb-major 32-bit limbs back into 384-bit lanes.
+#[inline(always)]
+fn join(limbs: &[[u64; MAX_LANES]; LIMBS]) -> [[u64; WORDS]; MAX_LANES] {
+    let mut values = [[0u64; WORDS]; MAX_LANES];
+    for (lane, value) in values.iter_mut().enumerate() {
+        for (i, word) in value.iter_mut().enumerate() {
+            *word = limbs[2 * i][lane] | (limbs[2 * i + 1][lane] << 32);
+        }
+    }
+    values
+}
@@ -0,0 +1,81 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{LIMB_MASK, LIMBS, Lanes, MAX_LANES};
+
+use core::arch::aarch64::*;
+
+/// Two 64-bit lanes in a NEON register.
+#[derive(Copy, Clone)]
+struct Neon(uint64x2_t);
+
+impl Lanes for Neon {
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn load(values: &[u64; MAX_LANES]) -> Self {
+        Self(vld1q_u64(values.as_ptr()))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn store(self, values: &mut [u64; MAX_LANES]) {
+        vst1q_u64(values.as_mut_ptr(), self.0)
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn splat(value: u64) -> Self {
+        Self(vdupq_n_u64(value))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mul(self, other: Self) -> Self {
+        Self(vmull_u32(vmovn_u64(self.0), vmovn_u64(other.0)))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn add(self, other: Self) -> Self {
+        Self(vaddq_u64(self.0, other.0))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn lo(self) -> Self {
+        Self(vandq_u64(self.0, vdupq_n_u64(LIMB_MASK)))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn hi(self) -> Self {
+        Self(vshrq_n_u64::<32>(self.0))
+    }
+}
+
+/// Computes the Montgomery products of two pairs of operands.
+///
+/// # Safety
+///
+/// The CPU must support NEON, which is mandatory on AArch64.
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn montgomery_mul(
+    a: &[[u64; MAX_LANES]; LIMBS],
+    b: &[[u64; MAX_LANES]; LIMBS],
+    modulus: &[u64; LIMBS],
+    inv: u64,
+) -> [[u64; MAX_LANES]; LIMBS] {
+    super::montgomery_mul::<Neon>(a, b, modulus, inv)
+}
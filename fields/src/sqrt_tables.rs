@@ -0,0 +1,151 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FftParameters, FieldParameters, PrimeField};
+use snarkvm_utilities::Vec;
+
+use std::collections::HashMap;
+
+/// The number of bits of the discrete logarithm recovered per table lookup.
+const WINDOW_BITS: u32 = 8;
+
+/// Precomputed tables for a table-based Tonelli-Shanks square root.
+///
+/// Let `MODULUS - 1 = 2^s * t` with `t` odd, and let `g` be the `2^s`-th root of unity.
+/// For a nonzero `u`, the element `x = u^t` lies in the subgroup generated by `g`, so there is an `e`
+/// such that `x * g^e = 1`. The element `u` is a quadratic residue if and only if `e` is even,
+/// in which case `u^((t + 1) / 2) * g^(e / 2)` is a square root of `u`.
+///
+/// The tables recover `e` one window of `WINDOW_BITS` bits at a time, with a single lookup per window,
+/// which avoids both the Legendre symbol computation and the bit-by-bit search of the generic algorithm.
+/// See https://eprint.iacr.org/2020/1407.pdf (section 3) for the underlying idea.
+#[derive(Clone, Debug)]
+pub struct SqrtTables<F: PrimeField> {
+    /// The two-adicity `s` of the field.
+    two_adicity: u32,
+    /// The window size, which is `WINDOW_BITS` unless the two-adicity is smaller.
+    window: u32,
+    /// The powers of the root of unity, where `powers[i][d] = g^(d * 2^(i * window))`.
+    powers: Vec<Vec<F>>,
+    /// The discrete logarithms of the `2^window`-th roots of unity, mapping `zeta^(-d)` to `d`,
+    /// where `zeta = g^(2^(s - window))`.
+    logarithms: HashMap<F, u32>,
+}
+
+impl<F: PrimeField> SqrtTables<F> {
+    /// Precomputes the square root tables for the field `F`.
+    pub fn new() -> Self {
+        let two_adicity = F::FftParameters::TWO_ADICITY;
+        assert!(two_adicity <= 64, "The two-adicity of the field must be at most 64");
+
+        let window = WINDOW_BITS.min(two_adicity);
+        let num_windows = two_adicity.div_ceil(window);
+
+        // Compute `g^(d * 2^(i * window))` for every window `i` and digit `d`.
+        let mut base = F::two_adic_root_of_unity();
+        let mut powers = Vec::with_capacity(num_windows as usize);
+        for _ in 0..num_windows {
+            let mut power = F::one();
+            let mut row = Vec::with_capacity(1 << window);
+            for _ in 0..(1u32 << window) {
+                row.push(power);
+                power *= base;
+            }
+            powers.push(row);
+            for _ in 0..window {
+                base.square_in_place();
+            }
+        }
+
+        // Compute `zeta^(-d)` for every digit `d`.
+        let mut zeta_inverse = F::two_adic_root_of_unity().inverse().expect("The root of unity is nonzero");
+        for _ in 0..two_adicity - window {
+            zeta_inverse.square_in_place();
+        }
+        let mut power = F::one();
+        let mut logarithms = HashMap::with_capacity(1 << window);
+        for digit in 0..(1u32 << window) {
+            logarithms.insert(power, digit);
+            power *= zeta_inverse;
+        }
+
+        Self { two_adicity, window, powers, logarithms }
+    }
+
+    /// Returns the square root of `u`, or `None` if `u` is a quadratic non-residue.
+    pub fn sqrt(&self, u: &F) -> Option<F> {
+        if u.is_zero() {
+            return Some(F::zero());
+        }
+
+        let (two_adicity, window) = (self.two_adicity, self.window);
+        let num_windows = self.powers.len() as u32;
+        // The size of the most significant window.
+        let last_window = two_adicity - (num_windows - 1) * window;
+
+        // Compute `v = u^((t - 1) / 2)`, `uv = u^((t + 1) / 2)`, and `x = u^t`.
+        let v = u.pow(F::Parameters::T_MINUS_ONE_DIV_TWO);
+        let uv = *u * v;
+        let x = uv * v;
+
+        // Compute `x^(2^(s - (i + 1) * window))` for every window `i` but the last, and `x` for the last window.
+        let mut x_powers = Vec::with_capacity(num_windows as usize);
+        let mut x_power = x;
+        x_powers.push(x_power);
+        for i in (0..num_windows - 1).rev() {
+            let squarings = if i == num_windows - 2 { last_window } else { window };
+            for _ in 0..squarings {
+                x_power.square_in_place();
+            }
+            x_powers.push(x_power);
+        }
+        x_powers.reverse();
+
+        // Recover the discrete logarithm `e`, from the least significant window to the most significant one.
+        let mut e = 0u64;
+        for (i, x_power) in (0..num_windows).zip(x_powers) {
+            let is_last = i == num_windows - 1;
+            let shift = if is_last { 0 } else { two_adicity - (i + 1) * window };
+            // Cancel out the windows recovered so far, leaving a `2^window`-th root of unity.
+            let zeta_power = x_power * self.root_of_unity_pow(e << shift);
+            let digit = self.logarithms[&zeta_power];
+            let digit = if is_last { digit >> (window - last_window) } else { digit };
+            e |= (digit as u64) << (i * window);
+        }
+
+        // The element is a quadratic residue if and only if `e` is even.
+        match e & 1 == 0 {
+            true => Some(uv * self.root_of_unity_pow(e >> 1)),
+            false => None,
+        }
+    }
+
+    /// Returns `g^exponent`, for an `exponent` less than `2^s`.
+    fn root_of_unity_pow(&self, exponent: u64) -> F {
+        let mask = (1u64 << self.window) - 1;
+        self.powers.iter().enumerate().fold(F::one(), |result, (i, row)| {
+            match (exponent >> (i as u32 * self.window)) & mask {
+                0 => result,
+                digit => result * row[digit as usize],
+            }
+        })
+    }
+}
+
+impl<F: PrimeField> Default for SqrtTables<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use crate::{ConstraintFieldError, Field, Fp2, Fp2Parameters, PrimeField, ToConstraintField};
-use snarkvm_utilities::FromBits;
+use snarkvm_utilities::{FromBits, Vec, vec};
 
 impl<F: Field> ToConstraintField<F> for () {
     #[inline]
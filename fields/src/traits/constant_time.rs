@@ -0,0 +1,34 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The constant-time operations required of every field element and curve point.
+///
+/// With the `constant-time` feature, this requires constant-time selection and equality from `subtle`,
+/// and arithmetic avoids branching on the values of its operands, where noted. Otherwise, it is empty.
+#[cfg(feature = "constant-time")]
+pub trait ConstantTime: subtle::ConditionallySelectable + subtle::ConstantTimeEq {}
+
+#[cfg(feature = "constant-time")]
+impl<T: subtle::ConditionallySelectable + subtle::ConstantTimeEq> ConstantTime for T {}
+
+/// The constant-time operations required of every field element and curve point.
+///
+/// With the `constant-time` feature, this requires constant-time selection and equality from `subtle`,
+/// and arithmetic avoids branching on the values of its operands, where noted. Otherwise, it is empty.
+#[cfg(not(feature = "constant-time"))]
+pub trait ConstantTime {}
+
+#[cfg(not(feature = "constant-time"))]
+impl<T> ConstantTime for T {}
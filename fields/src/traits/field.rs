@@ -13,11 +13,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{One, PrimeField, Zero};
+use crate::{ConstantTime, One, PrimeField, Zero};
 use snarkvm_utilities::{
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
     bititerator::BitIteratorBE,
     rand::Uniform,
     serialize::{
@@ -30,7 +31,7 @@ use snarkvm_utilities::{
     },
 };
 
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -90,6 +91,7 @@ pub trait Field:
     + CanonicalDeserializeWithFlags
     + Serialize
     + for<'a> Deserialize<'a>
+    + ConstantTime
 {
     type BasePrimeField: PrimeField;
 
@@ -97,6 +99,16 @@ pub trait Field:
     /// prime field.
     fn from_base_prime_field(other: Self::BasePrimeField) -> Self;
 
+    /// Returns the extension degree of `Self` over the base prime field.
+    fn extension_degree() -> u64;
+
+    /// Constructs an element of `Self` from its coefficients over the base prime field,
+    /// or returns `None` if the number of coefficients is not the extension degree.
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self>;
+
+    /// Returns the coefficients of `self` over the base prime field.
+    fn to_base_prime_field_elements(&self) -> Vec<Self::BasePrimeField>;
+
     /// Returns the constant 2^{-1}.
     fn half() -> Self {
         Self::from_base_prime_field(Self::BasePrimeField::half())
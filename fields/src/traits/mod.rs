@@ -15,6 +15,9 @@
 
 pub use num_traits::One;
 
+mod constant_time;
+pub use constant_time::*;
+
 mod fft_field;
 pub use fft_field::*;
 
@@ -33,6 +36,8 @@ pub use poseidon_grain_lfsr::*;
 mod poseidon_default;
 pub use poseidon_default::*;
 
+mod poseidon_security;
+
 mod prime_field;
 pub use prime_field::*;
 
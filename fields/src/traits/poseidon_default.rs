@@ -13,11 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{PoseidonGrainLFSR, PrimeField, serial_batch_inversion_and_mul};
+use super::poseidon_security::{ensure_valid_alpha, is_mds_secure, resists_known_attacks, secure_round_numbers};
+use crate::{FieldParameters, PoseidonGrainLFSR, PrimeField, serial_batch_inversion_and_mul};
 use aleo_std::{end_timer, start_timer};
 use itertools::Itertools;
+use snarkvm_utilities::{Vec, vec};
 
-use anyhow::{Result, bail};
+use anyhow::{Result, bail, ensure};
 
 /// Parameters and RNG used
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -35,6 +37,145 @@ pub struct PoseidonParameters<F: PrimeField, const RATE: usize, const CAPACITY:
     pub mds: Vec<Vec<F>>,
 }
 
+impl<F: PrimeField, const RATE: usize, const CAPACITY: usize> PoseidonParameters<F, RATE, CAPACITY> {
+    /// Returns Poseidon parameters for the given S-box exponent and number of rounds,
+    /// with the round constants and MDS matrix sampled from the Poseidon Grain LFSR.
+    ///
+    /// This allows instantiating Poseidon with a rate and capacity for which
+    /// no default parameters are provided.
+    /// Candidate MDS matrices that admit invariant subspace trails are rejected.
+    pub fn new(alpha: u64, full_rounds: usize, partial_rounds: usize) -> Result<Self> {
+        Self::generate(alpha, full_rounds, partial_rounds, None)
+    }
+
+    /// Returns Poseidon parameters for the given S-box exponent, with the number of rounds
+    /// chosen to resist the known attacks at the given security level in bits.
+    pub fn new_secure(alpha: u64, security_level: u32) -> Result<Self> {
+        let (full_rounds, partial_rounds) = Self::secure_round_numbers(alpha, security_level)?;
+        Self::new(alpha, full_rounds, partial_rounds)
+    }
+
+    /// Returns the number of full and partial rounds for the given S-box exponent
+    /// that resist the known attacks at the given security level in bits, with a security margin.
+    pub fn secure_round_numbers(alpha: u64, security_level: u32) -> Result<(usize, usize)> {
+        secure_round_numbers(F::Parameters::MODULUS_BITS, RATE + CAPACITY, alpha, security_level)
+    }
+
+    /// Ensures these parameters are well-formed, and resist the known attacks at the given security level in bits.
+    pub fn check_security(&self, security_level: u32) -> Result<()> {
+        let state_len = RATE + CAPACITY;
+        // Ensure the parameters match the state.
+        ensure!(
+            self.ark.len() == self.full_rounds + self.partial_rounds
+                && self.ark.iter().all(|round| round.len() == state_len),
+            "The round constants must have {} rounds of {state_len} elements",
+            self.full_rounds + self.partial_rounds
+        );
+        ensure!(
+            self.mds.len() == state_len && self.mds.iter().all(|row| row.len() == state_len),
+            "The MDS matrix must be {state_len} by {state_len}"
+        );
+        ensure!(self.full_rounds % 2 == 0, "The number of full rounds must be even");
+        ensure_valid_alpha::<F>(self.alpha)?;
+        // Ensure the number of rounds resists the known attacks.
+        ensure!(
+            resists_known_attacks(
+                F::Parameters::MODULUS_BITS,
+                state_len,
+                self.full_rounds,
+                self.partial_rounds,
+                self.alpha,
+                security_level
+            ),
+            "{} full rounds and {} partial rounds do not provide {security_level} bits of security",
+            self.full_rounds,
+            self.partial_rounds
+        );
+        // Ensure the MDS matrix admits no invariant subspace trails.
+        ensure!(is_mds_secure(&self.mds), "The MDS matrix admits invariant subspace trails");
+        Ok(())
+    }
+
+    /// Returns Poseidon parameters for the given S-box exponent and number of rounds.
+    ///
+    /// If `skip_matrices` is provided, the first `skip_matrices` candidate MDS matrices from the Grain LFSR
+    /// are skipped and the next one is used. Otherwise, candidates are sampled until one is secure.
+    fn generate(alpha: u64, full_rounds: usize, partial_rounds: usize, skip_matrices: Option<usize>) -> Result<Self> {
+        // Ensure the state has a non-empty rate and capacity.
+        ensure!(RATE > 0, "The Poseidon rate must be greater than zero");
+        ensure!(CAPACITY > 0, "The Poseidon capacity must be greater than zero");
+        // Ensure the full rounds can be split evenly around the partial rounds.
+        ensure!(full_rounds > 0 && full_rounds % 2 == 0, "The number of full rounds must be even and nonzero");
+        // Ensure the S-box `x^alpha` is a non-linear permutation of the field.
+        ensure_valid_alpha::<F>(alpha)?;
+
+        let (ark, mds) =
+            find_poseidon_ark_and_mds::<F>(RATE + CAPACITY, full_rounds as u64, partial_rounds as u64, skip_matrices)?;
+        Ok(Self { full_rounds, partial_rounds, alpha, ark, mds })
+    }
+}
+
+/// Returns the ark and mds for a state of width `state_len`, computed from the Poseidon Grain LFSR.
+///
+/// If `skip_matrices` is `None`, candidate MDS matrices are sampled until one admits no invariant subspace trails.
+#[allow(clippy::type_complexity)]
+fn find_poseidon_ark_and_mds<F: PrimeField>(
+    state_len: usize,
+    full_rounds: u64,
+    partial_rounds: u64,
+    skip_matrices: Option<usize>,
+) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
+    let lfsr_time = start_timer!(|| "LFSR Init");
+    let mut lfsr =
+        PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, state_len as u64, full_rounds, partial_rounds);
+    end_timer!(lfsr_time);
+
+    let ark_time = start_timer!(|| "Constructing ARK");
+    let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
+    for _ in 0..(full_rounds + partial_rounds) {
+        ark.push(lfsr.get_field_elements_rejection_sampling(state_len)?);
+    }
+    end_timer!(ark_time);
+
+    let skip_time = start_timer!(|| "Skipping matrices");
+    for _ in 0..skip_matrices.unwrap_or(0) {
+        let _ = lfsr.get_field_elements_mod_p::<F>(2 * state_len)?;
+    }
+    end_timer!(skip_time);
+
+    // A qualifying matrix must satisfy the following requirements:
+    // - There is no duplication among the elements in x or y.
+    // - There is no i and j such that x[i] + y[j] = p.
+    // - There resultant MDS passes all three tests.
+
+    let mds_time = start_timer!(|| "Construct MDS");
+    let mds = loop {
+        let xs = lfsr.get_field_elements_mod_p::<F>(state_len)?;
+        let ys = lfsr.get_field_elements_mod_p::<F>(state_len)?;
+        // Resample if the candidate would not yield a Cauchy matrix.
+        if !(all_unique(&xs) && all_unique(&ys) && xs.iter().cartesian_product(&ys).all(|(x, y)| !(*x + y).is_zero())) {
+            continue;
+        }
+
+        let mut mds_flattened = vec![F::zero(); state_len * state_len];
+        for (x, mds_row_i) in xs.iter().zip_eq(mds_flattened.chunks_mut(state_len)) {
+            for (y, e) in ys.iter().zip_eq(mds_row_i) {
+                *e = *x + y;
+            }
+        }
+        serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
+        let mds = mds_flattened.chunks(state_len).map(|row| row.to_vec()).collect::<Vec<_>>();
+
+        // Resample if the candidate is not known to be secure.
+        if skip_matrices.is_some() || is_mds_secure(&mds) {
+            break mds;
+        }
+    };
+    end_timer!(mds_time);
+
+    Ok((ark, mds))
+}
+
 /// A field with Poseidon parameters associated
 pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
@@ -43,68 +184,13 @@ pub trait PoseidonDefaultField {
     where
         Self: PrimeField,
     {
-        /// Internal function that computes the ark and mds from the Poseidon Grain LFSR.
-        #[allow(clippy::type_complexity)]
-        fn find_poseidon_ark_and_mds<F: PrimeField, const RATE: usize>(
-            full_rounds: u64,
-            partial_rounds: u64,
-            skip_matrices: u64,
-        ) -> Result<(Vec<Vec<F>>, Vec<Vec<F>>)> {
-            let lfsr_time = start_timer!(|| "LFSR Init");
-            let mut lfsr =
-                PoseidonGrainLFSR::new(false, F::size_in_bits() as u64, (RATE + 1) as u64, full_rounds, partial_rounds);
-            end_timer!(lfsr_time);
-
-            let ark_time = start_timer!(|| "Constructing ARK");
-            let mut ark = Vec::with_capacity((full_rounds + partial_rounds) as usize);
-            for _ in 0..(full_rounds + partial_rounds) {
-                ark.push(lfsr.get_field_elements_rejection_sampling(RATE + 1)?);
-            }
-            end_timer!(ark_time);
-
-            let skip_time = start_timer!(|| "Skipping matrices");
-            for _ in 0..skip_matrices {
-                let _ = lfsr.get_field_elements_mod_p::<F>(2 * (RATE + 1))?;
-            }
-            end_timer!(skip_time);
-
-            // A qualifying matrix must satisfy the following requirements:
-            // - There is no duplication among the elements in x or y.
-            // - There is no i and j such that x[i] + y[j] = p.
-            // - There resultant MDS passes all three tests.
-
-            let xs = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-            let ys = lfsr.get_field_elements_mod_p::<F>(RATE + 1)?;
-
-            let mds_time = start_timer!(|| "Construct MDS");
-            let mut mds_flattened = vec![F::zero(); (RATE + 1) * (RATE + 1)];
-            for (x, mds_row_i) in xs.iter().take(RATE + 1).zip_eq(mds_flattened.chunks_mut(RATE + 1)) {
-                for (y, e) in ys.iter().take(RATE + 1).zip_eq(mds_row_i) {
-                    *e = *x + y;
-                }
-            }
-            serial_batch_inversion_and_mul(&mut mds_flattened, &F::one());
-            let mds = mds_flattened.chunks(RATE + 1).map(|row| row.to_vec()).collect();
-            end_timer!(mds_time);
-
-            Ok((ark, mds))
-        }
-
         match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
-            Some(entry) => {
-                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
-                    entry.full_rounds as u64,
-                    entry.partial_rounds as u64,
-                    entry.skip_matrices as u64,
-                )?;
-                Ok(PoseidonParameters {
-                    full_rounds: entry.full_rounds,
-                    partial_rounds: entry.partial_rounds,
-                    alpha: entry.alpha as u64,
-                    ark,
-                    mds,
-                })
-            }
+            Some(entry) => PoseidonParameters::generate(
+                entry.alpha as u64,
+                entry.full_rounds,
+                entry.partial_rounds,
+                Some(entry.skip_matrices),
+            ),
             None => bail!("No Poseidon parameters were found for this rate"),
         }
     }
@@ -150,3 +236,8 @@ impl PoseidonDefaultParametersEntry {
         Self { rate, alpha, full_rounds, partial_rounds, skip_matrices }
     }
 }
+
+/// Returns `true` if the given elements are pairwise distinct.
+fn all_unique<F: PartialEq>(elements: &[F]) -> bool {
+    elements.iter().enumerate().all(|(i, element)| !elements[..i].contains(element))
+}
@@ -0,0 +1,191 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Security checks for Poseidon parameters, following the reference
+//! [parameter generation scripts](https://extgit.iaik.tugraz.at/krypto/hadeshash).
+
+use crate::{Field, PrimeField};
+use snarkvm_utilities::{BigInteger, Vec, vec};
+
+use anyhow::{Result, bail, ensure};
+
+/// Ensures the S-box `x^alpha` is a non-linear permutation of the field.
+pub(super) fn ensure_valid_alpha<F: PrimeField>(alpha: u64) -> Result<()> {
+    ensure!(alpha >= 3, "The S-box exponent must be at least 3, found {alpha}");
+    ensure!(
+        gcd(alpha, modulus_minus_one_mod::<F>(alpha)) == 1,
+        "The S-box exponent {alpha} must be coprime to the field modulus minus one"
+    );
+    Ok(())
+}
+
+/// Returns the number of full and partial rounds that minimize the number of S-boxes
+/// for a Poseidon permutation with an S-box `x^alpha` over a field of `field_bits` bits
+/// and a state of `state_len` elements, at the given security level in bits.
+///
+/// The rounds are the smallest that resist the statistical, interpolation, and Gröbner basis attacks
+/// from the Poseidon paper and [ePrint 2023/537](https://eprint.iacr.org/2023/537), with a security margin
+/// of 2 additional full rounds and 7.5% additional partial rounds.
+pub(super) fn secure_round_numbers(
+    field_bits: u32,
+    state_len: usize,
+    alpha: u64,
+    security_level: u32,
+) -> Result<(usize, usize)> {
+    ensure!(state_len >= 2, "The Poseidon state must have at least 2 elements");
+    ensure!(alpha >= 3, "The S-box exponent must be at least 3, found {alpha}");
+    ensure!(security_level > 0, "The security level must be nonzero");
+
+    let mut best: Option<(usize, usize, usize)> = None;
+    for partial_rounds in 1..500 {
+        for full_rounds in (4..100).step_by(2) {
+            if !resists_known_attacks(field_bits, state_len, full_rounds, partial_rounds, alpha, security_level) {
+                continue;
+            }
+            // Add the security margin.
+            let full_rounds = full_rounds + 2;
+            let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+            // Minimize the number of S-boxes, and then the number of full rounds.
+            let cost = state_len * full_rounds + partial_rounds;
+            if best.map_or(true, |(best_cost, best_full_rounds, _)| {
+                cost < best_cost || (cost == best_cost && full_rounds < best_full_rounds)
+            }) {
+                best = Some((cost, full_rounds, partial_rounds));
+            }
+        }
+    }
+    match best {
+        Some((_, full_rounds, partial_rounds)) => Ok((full_rounds, partial_rounds)),
+        None => bail!("No secure Poseidon round numbers exist for a state of {state_len} elements"),
+    }
+}
+
+/// Returns `true` if the given number of rounds resists the known attacks at the given security level.
+pub(super) fn resists_known_attacks(
+    field_bits: u32,
+    state_len: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    security_level: u32,
+) -> bool {
+    let (n, t, r_f, r_p, m) =
+        (field_bits as f64, state_len as f64, full_rounds as f64, partial_rounds as f64, security_level as f64);
+    let alpha = alpha as f64;
+    // Returns `log_alpha(x)`.
+    let log_alpha = |x: f64| x.ln() / alpha.ln();
+
+    // The statistical attack.
+    let r_f_1 = if m <= (n - (alpha - 1.0) / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+    // The interpolation attack.
+    let r_f_2 = 1.0 + (log_alpha(2.0) * m.min(n)).ceil() + log_alpha(t).ceil() - r_p;
+    // The Gröbner basis attacks.
+    let r_f_3 = log_alpha(2.0) * m.min(n) - r_p;
+    let r_f_4 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(n / 2.0) - r_p;
+    let r_f_5 = (t - 2.0 + m / (2.0 * alpha.log2()) - r_p) / (t - 1.0);
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5].into_iter().map(f64::ceil).fold(f64::MIN, f64::max);
+
+    // The Gröbner basis attack from ePrint 2023/537.
+    let r = (t / 3.0).floor();
+    let over = (r_f - 1.0) * t + r_p + r + r * (r_f / 2.0) + r_p + alpha;
+    let under = r * (r_f / 2.0) + r_p + alpha;
+    let cost_gb4 = (2.0 * log2_binomial(over as u64, under as u64)).ceil();
+
+    r_f >= r_f_max && cost_gb4 >= m
+}
+
+/// Returns `log2(binomial(n, k))`.
+fn log2_binomial(n: u64, k: u64) -> f64 {
+    let k = k.min(n - k);
+    (1..=k).map(|i| ((n - k + i) as f64 / i as f64).log2()).sum()
+}
+
+/// Returns `true` if the MDS matrix admits no infinitely long subspace trails through the partial rounds.
+///
+/// A partial round applies the S-box to the first state element only, so a trail that never activates
+/// the S-box exists if and only if a nonzero subspace stays orthogonal to `e_0` under repeated application
+/// of the matrix. This is ruled out when `e_0^T, e_0^T M, ..., e_0^T M^(t-1)` are linearly independent.
+/// The check is repeated for the powers `M^l` with `1 <= l <= 2t`, as a conservative margin.
+/// See [Grassi, Rechberger, and Schofnegger](https://eprint.iacr.org/2020/500) for the analysis of subspace trails.
+pub(super) fn is_mds_secure<F: PrimeField>(mds: &[Vec<F>]) -> bool {
+    let t = mds.len();
+    let mut power = mds.to_vec();
+    for l in 1..=2 * t {
+        if l > 1 {
+            power = matrix_mul(&power, mds);
+        }
+        // Construct the rows e_0^T M^(lk) for k = 0, ..., t - 1.
+        let mut rows = Vec::with_capacity(t);
+        let mut row = vec![F::zero(); t];
+        row[0] = F::one();
+        for _ in 0..t {
+            let next = (0..t).map(|j| (0..t).map(|i| row[i] * power[i][j]).sum()).collect();
+            rows.push(core::mem::replace(&mut row, next));
+        }
+        if rank(rows) != t {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns the product of the given square matrices.
+fn matrix_mul<F: Field>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    let t = a.len();
+    (0..t).map(|i| (0..t).map(|j| (0..t).map(|k| a[i][k] * b[k][j]).sum()).collect()).collect()
+}
+
+/// Returns the rank of the given matrix, using Gaussian elimination.
+fn rank<F: Field>(mut rows: Vec<Vec<F>>) -> usize {
+    let num_columns = rows.first().map_or(0, |row| row.len());
+    let mut rank = 0;
+    for column in 0..num_columns {
+        // Find a pivot for this column.
+        let Some(pivot) = (rank..rows.len()).find(|&i| !rows[i][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = rows[rank][column].inverse().expect("The pivot is nonzero");
+        // Eliminate this column from the remaining rows.
+        for i in rank + 1..rows.len() {
+            let factor = rows[i][column] * inverse;
+            if !factor.is_zero() {
+                for j in column..num_columns {
+                    let value = rows[rank][j];
+                    rows[i][j] -= factor * value;
+                }
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Returns `(p - 1) mod m`, where `p` is the modulus of `F`.
+fn modulus_minus_one_mod<F: PrimeField>(m: u64) -> u64 {
+    let modulus = F::modulus();
+    // The modulus is odd, so subtracting one only clears the least significant bit.
+    (0..modulus.num_bits() as usize)
+        .rev()
+        .fold(0u128, |acc, i| ((acc << 1) | u128::from(i != 0 && modulus.get_bit(i))) % m as u128) as u64
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
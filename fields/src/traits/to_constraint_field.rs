@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::{ConstraintFieldError, Field};
+use snarkvm_utilities::Vec;
 
 use core::fmt::Debug;
 
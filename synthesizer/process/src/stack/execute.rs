@@ -226,14 +226,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         use circuit::{Eject, Inject};
 
         // Inject the transition public key `tpk` as `Mode::Public`.
-        let tpk = circuit::Group::<A>::new(circuit::Mode::Public, console_request.to_tpk());
+        let tpk: circuit::Group<A> = A::new_public_input("tpk", console_request.to_tpk());
         // Inject the request as `Mode::Private`.
         let request = circuit::Request::new(circuit::Mode::Private, console_request.clone());
 
         // Inject `is_root` as `Mode::Public`.
-        let is_root = circuit::Boolean::new(circuit::Mode::Public, console_is_root);
+        let is_root: circuit::Boolean<A> = A::new_public_input("is_root", console_is_root);
         // Inject the parent as `Mode::Public`.
-        let parent = circuit::Address::new(circuit::Mode::Public, console_parent);
+        let parent: circuit::Address<A> = A::new_public_input("parent", console_parent);
         // Determine the caller.
         let caller = Ternary::ternary(&is_root, request.signer(), &parent);
 
@@ -0,0 +1,267 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Register;
+
+use std::collections::HashMap;
+
+/// An estimate of the size of the circuit of a function.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CircuitSizeEstimate {
+    /// The number of constraints.
+    pub num_constraints: u64,
+    /// The number of public and private variables.
+    pub num_variables: u64,
+    /// The number of non-zero entries in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl CircuitSizeEstimate {
+    /// Returns the size of the circuit that is counted by the environment of `A`.
+    fn from_environment<A: circuit::Aleo>() -> Self {
+        let (_, num_public, num_private, num_constraints, num_nonzeros) = A::count();
+        Self { num_constraints, num_variables: num_public + num_private, num_nonzeros }
+    }
+
+    /// Returns the size of the circuit that was added to the environment of `A` since `self` was counted.
+    fn since<A: circuit::Aleo>(&self) -> Self {
+        let now = Self::from_environment::<A>();
+        Self {
+            num_constraints: now.num_constraints.saturating_sub(self.num_constraints),
+            num_variables: now.num_variables.saturating_sub(self.num_variables),
+            num_nonzeros: (
+                now.num_nonzeros.0.saturating_sub(self.num_nonzeros.0),
+                now.num_nonzeros.1.saturating_sub(self.num_nonzeros.1),
+                now.num_nonzeros.2.saturating_sub(self.num_nonzeros.2),
+            ),
+        }
+    }
+
+    /// Returns the sum of both estimates.
+    fn add(self, other: Self) -> Self {
+        Self {
+            num_constraints: self.num_constraints.saturating_add(other.num_constraints),
+            num_variables: self.num_variables.saturating_add(other.num_variables),
+            num_nonzeros: (
+                self.num_nonzeros.0.saturating_add(other.num_nonzeros.0),
+                self.num_nonzeros.1.saturating_add(other.num_nonzeros.1),
+                self.num_nonzeros.2.saturating_add(other.num_nonzeros.2),
+            ),
+        }
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns an estimate of the size of the circuit for the given function name, without synthesizing the function.
+    ///
+    /// The request and the response of the function are synthesized from sampled inputs and outputs, and each
+    /// instruction is synthesized once per signature, on private operands that are sampled from its register types.
+    /// The estimate is an upper bound: it overestimates the size of instructions whose operands are constant,
+    /// and does not account for bit decompositions that are shared between instructions in the synthesized circuit.
+    /// Functions that contain `call` instructions are not supported.
+    ///
+    /// This method resets the circuit environment of `A`.
+    pub fn estimate_circuit_size<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<CircuitSizeEstimate> {
+        let function = self.get_function_ref(function_name)?;
+        if function.instructions().iter().any(|instruction| matches!(instruction, Instruction::Call(_))) {
+            bail!("Cannot estimate the circuit size of '{function_name}', as it contains a 'call' instruction");
+        }
+
+        // Ensure the global constants for the Aleo environment are initialized.
+        A::initialize_global_constants();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+
+        // Estimate the size of the request and the response.
+        let estimate = self.estimate_request_and_response_size::<A, R>(function, &burner_private_key, rng)?;
+
+        // Estimate the size of the instructions, synthesizing each signature once.
+        let register_types = self.get_register_types(function_name)?;
+        let mut instruction_estimates = HashMap::new();
+        let mut estimate = estimate;
+        for instruction in function.instructions() {
+            let signature = self.instruction_signature(register_types, instruction)?;
+            let instruction_estimate = match instruction_estimates.get(&signature) {
+                Some(instruction_estimate) => *instruction_estimate,
+                None => {
+                    let instruction_estimate = self.estimate_instruction_size::<A, R>(
+                        register_types,
+                        instruction,
+                        &burner_private_key,
+                        &burner_address,
+                        rng,
+                    )?;
+                    instruction_estimates.insert(signature, instruction_estimate);
+                    instruction_estimate
+                }
+            };
+            estimate = estimate.add(instruction_estimate);
+        }
+
+        // Ensure the circuit environment is clean.
+        A::reset();
+        Ok(estimate)
+    }
+
+    /// Returns the size of the circuit that verifies the request and computes the response of `function`,
+    /// on sampled inputs and outputs.
+    fn estimate_request_and_response_size<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function: &Function<N>,
+        burner_private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<CircuitSizeEstimate> {
+        use circuit::Inject;
+
+        // Ensure the circuit environment is clean.
+        A::reset();
+
+        // Sample the inputs, and compute the request as in `Stack::synthesize_key`.
+        let burner_address = Address::try_from(burner_private_key)?;
+        let input_types = function.input_types();
+        let inputs = input_types
+            .iter()
+            .map(|input_type| self.sample_value(&burner_address, input_type, rng))
+            .collect::<Result<Vec<_>>>()?;
+        let console_request = Request::sign(
+            burner_private_key,
+            *self.program_id(),
+            *function.name(),
+            inputs.into_iter(),
+            &input_types,
+            None,
+            true,
+            rng,
+        )?;
+
+        // Verify the request, as in `Stack::execute_function`.
+        let root_tvk = circuit::Field::<A>::new(circuit::Mode::Private, *console_request.tvk());
+        let tpk = circuit::Group::<A>::new(circuit::Mode::Public, console_request.to_tpk());
+        let request = circuit::Request::new(circuit::Mode::Private, console_request.clone());
+        let is_root = circuit::Boolean::new(circuit::Mode::Public, true);
+        let parent = circuit::Address::new(circuit::Mode::Public, self.program_id().to_address()?);
+        let _caller = Ternary::ternary(&is_root, request.signer(), &parent);
+        A::assert(request.verify(&input_types, &tpk, Some(root_tvk), is_root));
+        let request_estimate = CircuitSizeEstimate::from_environment::<A>();
+
+        // Sample the outputs, which are computed by the instructions of the function.
+        let output_types = function.output_types();
+        let mut outputs = Vec::with_capacity(output_types.len());
+        let mut output_registers = Vec::with_capacity(output_types.len());
+        for (output, output_type) in function.outputs().iter().zip_eq(&output_types) {
+            let (mode, register) = match output.operand() {
+                Operand::Register(register) => (circuit::Mode::Private, Some(register.clone())),
+                Operand::Signer | Operand::Caller => (circuit::Mode::Private, None),
+                _ => (circuit::Mode::Constant, None),
+            };
+            outputs.push(circuit::Value::new(mode, self.sample_value(&burner_address, output_type, rng)?));
+            output_registers.push(register);
+        }
+
+        // Compute the response, as in `Stack::execute_function`.
+        let before_response = CircuitSizeEstimate::from_environment::<A>();
+        let _response = circuit::Response::from_outputs(
+            request.network_id(),
+            request.program_id(),
+            request.function_name(),
+            input_types.len(),
+            request.tvk(),
+            request.tcm(),
+            outputs,
+            &output_types,
+            &output_registers,
+        );
+        Ok(request_estimate.add(before_response.since::<A>()))
+    }
+
+    /// Returns the size of the circuit of `instruction`, on private operands that are sampled from their register types.
+    fn estimate_instruction_size<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        register_types: &RegisterTypes<N>,
+        instruction: &Instruction<N>,
+        burner_private_key: &PrivateKey<N>,
+        burner_address: &Address<N>,
+        rng: &mut R,
+    ) -> Result<CircuitSizeEstimate> {
+        use circuit::Inject;
+
+        // Ensure the circuit environment is clean.
+        A::reset();
+
+        let call_stack = CallStack::PackageRun(vec![], *burner_private_key, Default::default());
+        let mut registers = Registers::<N, A>::new(call_stack, register_types.clone());
+        registers.set_signer_circuit(circuit::Address::new(circuit::Mode::Private, *burner_address));
+        registers.set_caller_circuit(circuit::Address::new(circuit::Mode::Private, *burner_address));
+        registers.set_tvk_circuit(circuit::Field::new(circuit::Mode::Private, Field::rand(rng)));
+        registers.set_root_tvk_circuit(circuit::Field::new(circuit::Mode::Private, Field::rand(rng)));
+
+        // Registers are stored in order, so sample every register before the destinations of the instruction.
+        let num_registers = match instruction.destinations().iter().map(|register| register.locator()).min() {
+            Some(locator) => locator,
+            None => instruction
+                .operands()
+                .iter()
+                .filter_map(|operand| match operand {
+                    Operand::Register(register) => Some(register.locator() + 1),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or_default(),
+        };
+        for locator in 0..num_registers {
+            let register = Register::Locator(locator);
+            let value_type = match register_types.get_type(self, &register)? {
+                RegisterType::Plaintext(plaintext_type) => ValueType::Private(plaintext_type),
+                RegisterType::Record(record_name) => ValueType::Record(record_name),
+                RegisterType::ExternalRecord(locator) => ValueType::ExternalRecord(locator),
+                RegisterType::Future(locator) => ValueType::Future(locator),
+            };
+            let value = self.sample_value(burner_address, &value_type, rng)?;
+            registers.store_circuit(self, &register, circuit::Value::new(circuit::Mode::Private, value))?;
+        }
+
+        let before_instruction = CircuitSizeEstimate::from_environment::<A>();
+        instruction.execute::<A>(self, &mut registers)?;
+        Ok(before_instruction.since::<A>())
+    }
+
+    /// Returns the signature of `instruction`, which consists of its opcode, the types of its register operands,
+    /// its other operands, and the types of its destinations. Instructions with the same signature have the same
+    /// circuit on private operands.
+    fn instruction_signature(&self, register_types: &RegisterTypes<N>, instruction: &Instruction<N>) -> Result<String> {
+        let mut signature = instruction.opcode().to_string();
+        for operand in instruction.operands() {
+            match operand {
+                Operand::Register(register) => {
+                    signature.push_str(&format!(" {}", register_types.get_type(self, register)?))
+                }
+                _ => signature.push_str(&format!(" {operand}")),
+            }
+        }
+        signature.push_str(" into");
+        for destination in instruction.destinations() {
+            signature.push_str(&format!(" {}", register_types.get_type(self, &destination)?));
+        }
+        Ok(signature)
+    }
+}
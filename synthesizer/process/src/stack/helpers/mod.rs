@@ -15,6 +15,9 @@
 
 use super::*;
 
+mod estimate;
+pub use estimate::*;
+
 mod initialize;
 mod matches;
 mod sample;
@@ -36,6 +36,7 @@ mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
+pub use helpers::CircuitSizeEstimate;
 
 use crate::{CallMetrics, Process, Trace, cost_in_microcredits, traits::*};
 use console::{
@@ -772,6 +772,10 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.sha256" => ensure!(
+                matches!(instruction, Instruction::HashSha256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash.sha3_256" => ensure!(
                 matches!(instruction, Instruction::HashSha3_256(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::{
+    Assignments,
     CallStack,
     Process,
     Stack,
@@ -24,7 +25,7 @@ use circuit::{Aleo, network::AleoV0};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{MainnetV0, prelude::*},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Request, Value},
     types::{Field, U64},
 };
 use ledger_block::{Fee, Transaction};
@@ -572,6 +573,70 @@ function hello_world:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
 }
 
+#[test]
+fn test_process_estimate_circuit_size() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"program estimate.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    gte r0.amount r2 into r4;
+    assert.eq r4 true;
+    hash.bhp256 r1 into r5 as field;
+    mul r2 r2 into r6;
+    mul r3 r3 into r7;
+    cast r1 r2 into r8 as token.record;
+    cast r0.owner r3 into r9 as token.record;
+    output r8 as token.record;
+    output r9 as token.record;
+    output r5 as field.public;
+"#,
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+    let rng = &mut TestRng::default();
+
+    // Estimate the size of the circuit.
+    let estimate = stack.estimate_circuit_size::<CurrentAleo, _>(&function_name, rng).unwrap();
+
+    // Synthesize the circuit with sampled inputs.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    let input_types = stack.get_function(&function_name).unwrap().input_types();
+    let inputs =
+        input_types.iter().map(|input_type| stack.sample_value(&address, input_type, rng).unwrap()).collect::<Vec<_>>();
+    let request =
+        Request::sign(&private_key, *program.id(), function_name, inputs.iter(), &input_types, None, true, rng)
+            .unwrap();
+    let assignments = Assignments::<CurrentNetwork>::default();
+    let call_stack = CallStack::CheckDeployment(vec![request], private_key, assignments.clone(), None, None);
+    stack.execute_function::<CurrentAleo, _>(call_stack, None, None, rng).unwrap();
+    let assignment = assignments.read().last().unwrap().0.clone();
+
+    // Ensure the estimate is an upper bound, within 5% of the synthesized circuit.
+    // The synthesized circuit reuses the bit decompositions of values that are shared between instructions.
+    let num_constraints = assignment.num_constraints();
+    let num_variables = assignment.num_public() + assignment.num_private();
+    assert!(estimate.num_constraints >= num_constraints, "{estimate:?} < {num_constraints}");
+    assert!(estimate.num_variables >= num_variables, "{estimate:?} < {num_variables}");
+    assert!((estimate.num_constraints - num_constraints) * 20 <= num_constraints, "{estimate:?} > {num_constraints}");
+    assert!((estimate.num_variables - num_variables) * 20 <= num_variables, "{estimate:?} > {num_variables}");
+}
+
 #[test]
 fn test_process_multirecords() {
     // Initialize a new program.
@@ -146,6 +146,8 @@ pub enum Instruction<N: Network> {
     HashManyPSD4(HashManyPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashManyPSD8(HashManyPSD8<N>),
+    /// Performs a SHA-256 hash, outputting 256 bits.
+    HashSha256(HashSha256<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -301,6 +303,8 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New opcodes are appended here, as the position of an opcode in this list is its serialized index.
+            HashSha256,
         }}
     };
     // A variant **without** curly braces:
@@ -470,7 +474,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            69,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
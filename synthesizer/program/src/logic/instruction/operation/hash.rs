@@ -58,6 +58,9 @@ pub type HashSha3_384<N> = HashInstruction<N, { Hasher::HashSha3_384 as u8 }>;
 /// SHA3-512 is a cryptographic hash function that outputs a 512-bit digest.
 pub type HashSha3_512<N> = HashInstruction<N, { Hasher::HashSha3_512 as u8 }>;
 
+/// SHA-256 is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashSha256<N> = HashInstruction<N, { Hasher::HashSha256 as u8 }>;
+
 /// Poseidon2 is a cryptographic hash function that processes inputs in 2-field chunks.
 pub type HashManyPSD2<N> = HashInstruction<N, { Hasher::HashManyPSD2 as u8 }>;
 /// Poseidon4 is a cryptographic hash function that processes inputs in 4-field chunks.
@@ -84,6 +87,7 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashSha256,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -166,7 +170,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash.sha256"),
+            19.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -249,10 +254,13 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&input.to_bits_le())?)?)
             }
+            (18, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_sha256(&input.to_bits_le())?)?)
+            }
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (19.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -320,10 +328,13 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             (14, PlaintextType::Literal(..)) => {
                 circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_512(&input.to_bits_le())))
             }
+            (18, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_sha256(&input.to_bits_le())))
+            }
             (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
             (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
             (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (19.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
         };
@@ -366,9 +377,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+            0..=14 | 18 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            19.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
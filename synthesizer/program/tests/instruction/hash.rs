@@ -40,6 +40,7 @@ use snarkvm_synthesizer_program::{
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
+    HashSha256,
     Opcode,
     Operand,
     Program,
@@ -246,6 +247,8 @@ test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
 
+test_hash!(hash_sha256, HashSha256, 5);
+
 // Note this test must be explicitly written, instead of using the macro, because HashPED64 fails on certain input types.
 #[test]
 fn test_hash_ped64_is_consistent() {
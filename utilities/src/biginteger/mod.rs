@@ -13,10 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{FromBits, FromBytes, ToBits, ToBytes, rand::Uniform};
+use crate::{FromBits, FromBytes, ToBits, ToBytes, Vec, rand::Uniform};
 
+use core::fmt::{Debug, Display};
 use num_bigint::BigUint;
-use std::fmt::{Debug, Display};
 
 mod bigint_256;
 pub use bigint_256::*;
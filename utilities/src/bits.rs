@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Vec;
+use crate::{String, Vec};
 
 use anyhow::{Result, ensure};
 
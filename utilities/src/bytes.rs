@@ -14,6 +14,8 @@
 // limitations under the License.
 
 use crate::{
+    String,
+    ToString,
     Vec,
     error,
     fmt,
@@ -27,6 +29,7 @@ use serde::{
     ser::{self, SerializeTuple},
 };
 use smol_str::SmolStr;
+#[cfg(feature = "std")]
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// Takes as input a sequence of structs, and converts them to a series of little-endian bytes.
@@ -62,7 +65,7 @@ pub trait ToBytes {
     where
         Self: Sized,
     {
-        Ok(to_bytes_le![self]?)
+        to_bytes_le![self].map_err(anyhow::Error::msg)
     }
 }
 
@@ -77,7 +80,7 @@ pub trait FromBytes {
     where
         Self: Sized,
     {
-        Ok(Self::read_le(bytes)?)
+        Self::read_le(bytes).map_err(anyhow::Error::msg)
     }
 }
 
@@ -297,6 +300,7 @@ impl FromBytes for bool {
     }
 }
 
+#[cfg(feature = "std")]
 impl ToBytes for SocketAddr {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
@@ -317,6 +321,7 @@ impl ToBytes for SocketAddr {
     }
 }
 
+#[cfg(feature = "std")]
 impl FromBytes for SocketAddr {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -480,25 +485,28 @@ pub fn bytes_from_bits_le(bits: &[bool]) -> Vec<u8> {
 }
 
 /// A wrapper around a `Write` instance that limits the number of bytes that can be written.
+#[cfg(feature = "std")]
 pub struct LimitedWriter<W: Write> {
     writer: W,
     limit: usize,
     remaining: usize,
 }
 
+#[cfg(feature = "std")]
 impl<W: Write> LimitedWriter<W> {
     pub fn new(writer: W, limit: usize) -> Self {
         Self { writer, limit, remaining: limit }
     }
 }
 
+#[cfg(feature = "std")]
 impl<W: Write> Write for LimitedWriter<W> {
     fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
         if self.remaining == 0 && !buf.is_empty() {
             return Err(std::io::Error::new(std::io::ErrorKind::Other, format!("Byte limit exceeded: {}", self.limit)));
         }
 
-        let max_write = std::cmp::min(buf.len(), self.remaining);
+        let max_write = core::cmp::min(buf.len(), self.remaining);
         match self.writer.write(&buf[..max_write]) {
             Ok(n) => {
                 self.remaining -= n;
@@ -20,6 +20,12 @@ use core::{cmp, mem};
 #[derive(Debug)]
 pub struct Error;
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "I/O error")
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
 
 pub trait Read {
@@ -69,7 +75,7 @@ impl Write for &mut [u8] {
     #[inline]
     fn write_all(&mut self, data: &[u8]) -> Result<()> {
         let amt = cmp::min(data.len(), self.len());
-        let (a, b) = mem::replace(self, &mut []).split_at_mut(amt);
+        let (a, b) = mem::take(self).split_at_mut(amt);
         a.copy_from_slice(&data[..amt]);
         *self = b;
 
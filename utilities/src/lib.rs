@@ -19,12 +19,20 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
 #[cfg(not(feature = "std"))]
 pub use alloc::*;
 
 #[cfg(not(feature = "std"))]
 pub use core::*;
 
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+pub use alloc::{borrow, collections, ffi, fmt, rc, slice, str, sync, task};
+
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub use std::*;
@@ -32,15 +40,26 @@ pub use std::*;
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 #[doc(hidden)]
-pub use alloc::{boxed::Box, vec::Vec};
+pub use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 #[cfg(feature = "std")]
 #[allow(unused_imports)]
 #[doc(hidden)]
-pub use std::{boxed::Box, vec::Vec};
-
-#[macro_use]
-extern crate thiserror;
+pub use std::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 pub mod biginteger;
 pub use biginteger::*;
@@ -59,7 +78,9 @@ pub use bytes::*;
 pub mod error;
 pub use error::*;
 
+#[cfg(feature = "std")]
 pub mod iterator;
+#[cfg(feature = "std")]
 pub use iterator::*;
 
 #[macro_use]
@@ -76,7 +97,7 @@ pub use serialize::*;
 pub mod io;
 
 #[cfg(not(feature = "std"))]
-pub fn error(_msg: &'static str) -> io::Error {
+pub fn error<S: ToString>(_msg: S) -> io::Error {
     io::Error
 }
 
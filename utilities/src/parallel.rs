@@ -17,15 +17,24 @@ use crate::{boxed::Box, vec::Vec};
 
 pub struct ExecutionPool<'a, T> {
     jobs: Vec<Box<dyn 'a + FnOnce() -> T + Send>>,
+    /// Whether the jobs are executed one after another, rather than in parallel.
+    sequential: bool,
 }
 
 impl<'a, T> ExecutionPool<'a, T> {
     pub fn new() -> Self {
-        Self { jobs: Vec::new() }
+        Self { jobs: Vec::new(), sequential: false }
     }
 
     pub fn with_capacity(cap: usize) -> Self {
-        Self { jobs: Vec::with_capacity(cap) }
+        Self { jobs: Vec::with_capacity(cap), sequential: false }
+    }
+
+    /// Returns `self`, set to execute its jobs one after another if `sequential` is `true`.
+    /// This bounds the memory to that of a single job, while each job may still use all threads internally.
+    pub fn in_sequence(mut self, sequential: bool) -> Self {
+        self.sequential = sequential;
+        self
     }
 
     pub fn add_job<F: 'a + FnOnce() -> T + Send>(&mut self, f: F) {
@@ -36,16 +45,34 @@ impl<'a, T> ExecutionPool<'a, T> {
     where
         T: Send + Sync,
     {
-        #[cfg(not(feature = "serial"))]
+        if self.sequential {
+            return self.jobs.into_iter().map(|f| f()).collect();
+        }
+        #[cfg(all(feature = "std", not(feature = "serial")))]
         {
             use rayon::prelude::*;
             execute_with_max_available_threads(|| self.jobs.into_par_iter().map(|f| f()).collect())
         }
-        #[cfg(feature = "serial")]
+        #[cfg(any(feature = "serial", not(feature = "std")))]
         {
             self.jobs.into_iter().map(|f| f()).collect()
         }
     }
+
+    /// Executes all jobs using at most `max_threads` threads, or the maximum number of available threads if `None`.
+    pub fn execute_all_with_max_threads(self, max_threads: Option<usize>) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        match max_threads {
+            #[cfg(all(feature = "std", not(feature = "serial")))]
+            Some(max_threads) if !self.sequential => {
+                use rayon::prelude::*;
+                execute_with_at_most_threads(|| self.jobs.into_par_iter().map(|f| f()).collect(), max_threads)
+            }
+            _ => self.execute_all(),
+        }
+    }
 }
 
 impl<'a, T> Default for ExecutionPool<'a, T> {
@@ -54,7 +81,7 @@ impl<'a, T> Default for ExecutionPool<'a, T> {
     }
 }
 
-#[cfg(not(feature = "serial"))]
+#[cfg(all(feature = "std", not(feature = "serial")))]
 pub fn max_available_threads() -> usize {
     use aleo_std::Cpu;
     let rayon_threads = rayon::current_num_threads();
@@ -65,19 +92,38 @@ pub fn max_available_threads() -> usize {
     }
 }
 
+/// Executes `f` in a thread pool of the maximum number of available threads.
+/// If called from a thread of a rayon thread pool, `f` is executed in that pool instead,
+/// so that nested calls share its threads rather than each spawning a pool of their own.
 #[inline(always)]
-#[cfg(not(any(feature = "serial", feature = "wasm")))]
+#[cfg(not(any(feature = "serial", feature = "wasm", not(feature = "std"))))]
 pub fn execute_with_max_available_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send) -> T {
-    execute_with_threads(f, max_available_threads())
+    match rayon::current_thread_index() {
+        Some(_) => f(),
+        None => execute_with_threads(f, max_available_threads()),
+    }
 }
 
 #[inline(always)]
-#[cfg(any(feature = "serial", feature = "wasm"))]
+#[cfg(any(feature = "serial", feature = "wasm", not(feature = "std")))]
 pub fn execute_with_max_available_threads<T>(f: impl FnOnce() -> T + Send) -> T {
     f()
 }
 
-#[cfg(not(any(feature = "serial", feature = "wasm")))]
+/// Executes `f` in a thread pool of at most `max_threads` threads, and at least one thread.
+#[inline(always)]
+#[cfg(not(any(feature = "serial", feature = "wasm", not(feature = "std"))))]
+pub fn execute_with_at_most_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, max_threads: usize) -> T {
+    execute_with_threads(f, max_threads.clamp(1, max_available_threads()))
+}
+
+#[inline(always)]
+#[cfg(any(feature = "serial", feature = "wasm", not(feature = "std")))]
+pub fn execute_with_at_most_threads<T>(f: impl FnOnce() -> T + Send, _max_threads: usize) -> T {
+    f()
+}
+
+#[cfg(not(any(feature = "serial", feature = "wasm", not(feature = "std"))))]
 #[inline(always)]
 fn execute_with_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
     let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
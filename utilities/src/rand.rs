@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::String;
+
 use rand::{
     Rng,
     SeedableRng,
@@ -53,6 +55,7 @@ impl Default for TestRng {
 impl TestRng {
     pub fn fixed(seed: u64) -> Self {
         // Print the seed, so it's displayed if any of the tests using `test_rng` fails.
+        #[cfg(feature = "std")]
         println!("\nInitializing 'TestRng' with seed '{seed}'\n");
 
         // Use the seed to initialize a fast, non-cryptographic Rng.
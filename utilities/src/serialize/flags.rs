@@ -27,9 +27,10 @@ impl Flags for EmptyFlags {
         0
     }
 
+    /// There are no flag bits, so every byte is accepted.
     #[inline]
-    fn from_u8(value: u8) -> Option<Self> {
-        if (value >> 7) == 0 { Some(EmptyFlags) } else { None }
+    fn from_u8(_value: u8) -> Option<Self> {
+        Some(EmptyFlags)
     }
 }
 
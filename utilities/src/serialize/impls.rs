@@ -21,9 +21,10 @@ pub use crate::{
 };
 use crate::{SerializationError, serialize::traits::*};
 
+#[cfg(feature = "std")]
 use bincode::Options;
 
-use std::{borrow::Cow, collections::BTreeMap, marker::PhantomData, rc::Rc, sync::Arc};
+use crate::{String, ToOwned, borrow::Cow, collections::BTreeMap, marker::PhantomData, rc::Rc, sync::Arc};
 
 impl Valid for bool {
     fn check(&self) -> Result<(), SerializationError> {
@@ -57,7 +58,14 @@ impl CanonicalDeserialize for bool {
 impl CanonicalSerialize for String {
     #[inline]
     fn serialize_with_mode<W: Write>(&self, mut writer: W, _compress: Compress) -> Result<(), SerializationError> {
-        Ok(bincode::serialize_into(&mut writer, self)?)
+        #[cfg(feature = "std")]
+        return Ok(bincode::serialize_into(&mut writer, self)?);
+        // Without bincode, write the string with the same fixed-width length prefix.
+        #[cfg(not(feature = "std"))]
+        {
+            (self.len() as u64).serialize_with_mode(&mut writer, _compress)?;
+            Ok(writer.write_all(self.as_bytes())?)
+        }
     }
 
     #[inline]
@@ -88,11 +96,24 @@ impl CanonicalDeserialize for String {
         _compress: Compress,
         _validate: Validate,
     ) -> Result<Self, SerializationError> {
-        Ok(bincode::DefaultOptions::new()
+        #[cfg(feature = "std")]
+        return Ok(bincode::DefaultOptions::new()
             .with_fixint_encoding() // this option is for compatibility with the defaults
             .allow_trailing_bytes() // so is this
-            .with_limit(10 * 1024)  // a limit to guard against OOMs
-            .deserialize_from(reader)?)
+            .with_limit(10 * 1024) // a limit to guard against OOMs
+            .deserialize_from(reader)?);
+        // Without bincode, read the string with the same fixed-width length prefix and limit.
+        #[cfg(not(feature = "std"))]
+        {
+            let mut reader = reader;
+            let length = usize::try_from(u64::deserialize_with_mode(&mut reader, _compress, _validate)?)?;
+            if length > 10 * 1024 - 8 {
+                return Err(SerializationError::InvalidData);
+            }
+            let mut bytes = crate::vec![0u8; length];
+            reader.read_exact(&mut bytes)?;
+            String::from_utf8(bytes).map_err(|_| SerializationError::InvalidData)
+        }
     }
 }
 
@@ -110,7 +131,7 @@ macro_rules! impl_canonical_serialization_uint {
 
             #[inline]
             fn serialized_size(&self, _compress: Compress) -> usize {
-                std::mem::size_of::<$type>()
+                core::mem::size_of::<$type>()
             }
         }
         impl Valid for $type {
@@ -135,7 +156,7 @@ macro_rules! impl_canonical_serialization_uint {
                 _compress: Compress,
                 _validate: Validate,
             ) -> Result<Self, SerializationError> {
-                let mut bytes = [0u8; std::mem::size_of::<$type>()];
+                let mut bytes = [0u8; core::mem::size_of::<$type>()];
                 reader.read_exact(&mut bytes)?;
                 Ok(<$type>::from_le_bytes(bytes))
             }
@@ -238,7 +259,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 }
 
 // No-op
-impl<T> CanonicalSerialize for std::marker::PhantomData<T> {
+impl<T> CanonicalSerialize for core::marker::PhantomData<T> {
     #[inline]
     fn serialize_with_mode<W: Write>(&self, _writer: W, _compress: Compress) -> Result<(), SerializationError> {
         Ok(())
@@ -257,14 +278,14 @@ impl<T: Sync> Valid for PhantomData<T> {
     }
 }
 
-impl<T: Send + Sync> CanonicalDeserialize for std::marker::PhantomData<T> {
+impl<T: Send + Sync> CanonicalDeserialize for core::marker::PhantomData<T> {
     #[inline]
     fn deserialize_with_mode<R: Read>(
         _reader: R,
         _compress: Compress,
         _validate: Validate,
     ) -> Result<Self, SerializationError> {
-        Ok(std::marker::PhantomData)
+        Ok(core::marker::PhantomData)
     }
 }
 
@@ -437,7 +458,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
     }
 }
 
-impl<T: CanonicalDeserialize + std::fmt::Debug> CanonicalDeserialize for [T; 32] {
+impl<T: CanonicalDeserialize + core::fmt::Debug> CanonicalDeserialize for [T; 32] {
     #[inline]
     fn deserialize_with_mode<R: Read>(
         mut reader: R,
@@ -634,7 +655,7 @@ mod test {
     use super::*;
     use crate::{deserialize_vec_without_len, serialize_vec_without_len, serialized_vec_size_without_len};
 
-    fn test_serialize<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
+    fn test_serialize<T: PartialEq + core::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
         let combinations = [
             (Compress::No, Validate::No),
             (Compress::Yes, Validate::No),
@@ -649,7 +670,7 @@ mod test {
         }
     }
 
-    fn test_serialize_without_len<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(
+    fn test_serialize_without_len<T: PartialEq + core::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(
         data: Vec<T>,
     ) {
         let combinations = [
@@ -718,6 +739,6 @@ mod test {
 
     #[test]
     fn test_phantomdata() {
-        test_serialize(std::marker::PhantomData::<u64>);
+        test_serialize(core::marker::PhantomData::<u64>);
     }
 }
@@ -16,6 +16,7 @@
 use crate::SerializationError;
 pub use crate::io::{Read, Write};
 
+#[cfg(feature = "std")]
 use serde::de::{self, DeserializeOwned, Deserializer};
 
 /// Represents metadata to be appended to an object's serialization. For
@@ -77,12 +78,12 @@ pub trait Valid: Sized + Sync {
     where
         Self: 'a,
     {
-        #[cfg(not(feature = "serial"))]
+        #[cfg(all(feature = "std", not(feature = "serial")))]
         {
             use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
             batch.par_bridge().try_for_each(|e| e.check())?;
         }
-        #[cfg(feature = "serial")]
+        #[cfg(any(feature = "serial", not(feature = "std")))]
         {
             for item in batch {
                 item.check()?;
@@ -187,6 +188,7 @@ pub trait CanonicalDeserializeWithFlags: Sized {
 }
 
 /// A helper trait used to simplify value extraction.
+#[cfg(feature = "std")]
 pub trait DeserializeExt<'de>
 where
     Self: DeserializeOwned,
@@ -194,6 +196,7 @@ where
     fn take_from_value<D: Deserializer<'de>>(value: &mut serde_json::Value, field: &str) -> Result<Self, D::Error>;
 }
 
+#[cfg(feature = "std")]
 impl<'de, T> DeserializeExt<'de> for T
 where
     T: DeserializeOwned,